@@ -32,7 +32,7 @@ fn get_proposal(proposal_id: ProposalId) -> Option<Proposal> {
 
 #[query]
 fn list_proposals(filter: ListProposalsParams) -> ListProposalResults {
-    list_proposals_state(&filter)
+    list_proposals_state(&filter).unwrap_or_trap()
 }
 
 #[query]
@@ -1,12 +1,13 @@
 use crate::cdn::strategies_impls::storage::StorageState;
 use crate::certification::strategy_impls::StorageCertificate;
-use ic_cdk_macros::query;
+use ic_cdk_macros::{query, update};
 use junobuild_storage::http::types::{
     HttpRequest, HttpResponse, StreamingCallbackHttpResponse, StreamingCallbackToken,
 };
 use junobuild_storage::http_request::{
     http_request as http_request_storage,
     http_request_streaming_callback as http_request_streaming_callback_storage,
+    http_request_update as http_request_update_storage,
 };
 
 #[query]
@@ -14,6 +15,11 @@ pub fn http_request(request: HttpRequest) -> HttpResponse {
     http_request_storage(request, &StorageState, &StorageCertificate)
 }
 
+#[update]
+pub fn http_request_update(request: HttpRequest) -> HttpResponse {
+    http_request_update_storage(request, &StorageState)
+}
+
 #[query]
 pub fn http_request_streaming_callback(
     streaming_callback_token: StreamingCallbackToken,
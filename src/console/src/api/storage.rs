@@ -1,6 +1,8 @@
 use crate::cdn::helpers::store::init_asset_upload as init_asset_upload_store;
 use crate::cdn::strategies_impls::cdn::CdnHeap;
-use crate::cdn::strategies_impls::storage::{StorageAssertions, StorageState, StorageUpload};
+use crate::cdn::strategies_impls::storage::{
+    StorageAssertions, StorageBatches, StorageState, StorageUpload,
+};
 use crate::guards::caller_is_admin_controller;
 use crate::store::heap::get_controllers;
 use ic_cdk_macros::{query, update};
@@ -55,7 +57,7 @@ fn upload_proposal_asset_chunk(chunk: UploadChunk) -> UploadChunkResult {
     let caller = caller();
     let config = junobuild_cdn::storage::heap::get_config(&CdnHeap);
 
-    let chunk_id = create_chunk(caller, &config, chunk).unwrap_or_trap();
+    let chunk_id = create_chunk(caller, &config, chunk, &StorageBatches).unwrap_or_trap();
 
     UploadChunkResult { chunk_id }
 }
@@ -75,6 +77,7 @@ fn commit_proposal_asset_upload(commit: CommitBatch) {
         &StorageAssertions,
         &StorageState,
         &StorageUpload,
+        &StorageBatches,
     )
     .unwrap_or_trap();
 }
@@ -95,6 +98,7 @@ fn commit_proposal_many_assets_upload(commits: Vec<CommitBatch>) {
             &StorageAssertions,
             &StorageState,
             &StorageUpload,
+            &StorageBatches,
         )
         .unwrap_or_trap();
     }
@@ -102,5 +106,5 @@ fn commit_proposal_many_assets_upload(commits: Vec<CommitBatch>) {
 
 #[query(guard = "caller_is_admin_controller")]
 pub fn list_assets(collection: CollectionKey, filter: ListParams) -> ListResults<AssetNoContent> {
-    crate::cdn::storage::heap::list_assets(&collection, &filter)
+    crate::cdn::storage::heap::list_assets(&collection, &filter).unwrap_or_trap()
 }
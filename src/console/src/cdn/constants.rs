@@ -16,7 +16,14 @@ pub const DEFAULT_RELEASES_COLLECTIONS: [(&str, SetRule); 1] = [(
         max_size: None,
         max_capacity: None,
         max_changes_per_user: None,
+        max_bytes_per_user: None,
         version: None,
         rate_config: None,
+        indexes: None,
+        history: None,
+        ttl: None,
+        schema: None,
+        variants: None,
+        content_validation: None,
     },
 )];
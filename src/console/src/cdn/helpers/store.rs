@@ -1,6 +1,6 @@
 use crate::cdn::helpers::heap::get_config;
 use crate::cdn::helpers::stable::get_proposal;
-use crate::cdn::strategies_impls::storage::{StorageAssertions, StorageState};
+use crate::cdn::strategies_impls::storage::{StorageAssertions, StorageBatches, StorageState};
 use crate::store::heap::get_controllers;
 use candid::Principal;
 use junobuild_cdn::proposals::ProposalId;
@@ -33,5 +33,6 @@ pub fn init_asset_upload(
         Some(proposal_id),
         &StorageAssertions,
         &StorageState,
+        &StorageBatches,
     )
 }
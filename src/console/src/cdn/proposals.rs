@@ -7,7 +7,7 @@ use junobuild_cdn::proposals::{
 };
 use junobuild_cdn::proposals::{Proposal, ProposalId, ProposalType};
 
-pub fn list_proposals(filter: &ListProposalsParams) -> ListProposalResults {
+pub fn list_proposals(filter: &ListProposalsParams) -> Result<ListProposalResults, String> {
     junobuild_cdn::proposals::list_proposals(&CdnStable, filter)
 }
 
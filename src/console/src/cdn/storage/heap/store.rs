@@ -17,7 +17,7 @@ use junobuild_storage::utils::{get_token_protected_asset, map_asset_no_content};
 pub fn list_assets(
     collection: &CollectionKey,
     filters: &ListParams,
-) -> ListResults<AssetNoContent> {
+) -> Result<ListResults<AssetNoContent>, String> {
     with_assets(|assets| {
         let assets = collect_assets_heap(collection, assets);
         list_assets_impl(&assets, filters)
@@ -27,10 +27,10 @@ pub fn list_assets(
 fn list_assets_impl(
     assets: &[(&FullPath, &Asset)],
     filters: &ListParams,
-) -> ListResults<AssetNoContent> {
-    let values = list_values(assets, filters);
+) -> Result<ListResults<AssetNoContent>, String> {
+    let values = list_values(assets, filters)?;
 
-    ListResults::<AssetNoContent> {
+    Ok(ListResults::<AssetNoContent> {
         items: values
             .items
             .into_iter()
@@ -40,7 +40,8 @@ fn list_assets_impl(
         items_page: values.items_page,
         matches_length: values.matches_length,
         matches_pages: values.matches_pages,
-    }
+        next_cursor: values.next_cursor,
+    })
 }
 
 pub fn get_public_asset(full_path: FullPath, token: AssetAccessToken) -> Option<(Asset, Memory)> {
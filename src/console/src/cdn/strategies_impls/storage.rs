@@ -19,13 +19,21 @@ use junobuild_shared::segments::access_keys::is_write_access_key;
 use junobuild_shared::types::core::Blob;
 use junobuild_shared::types::domain::CustomDomains;
 use junobuild_shared::types::state::AccessKeys;
+use junobuild_storage::runtime::{
+    clear_batch as clear_runtime_batch, clear_expired_batches as clear_expired_runtime_batches,
+    clear_expired_chunks as clear_expired_runtime_chunks, get_batch as get_runtime_batch,
+    get_batch_chunks as get_runtime_batch_chunks, get_chunk as get_runtime_chunk,
+    insert_batch as insert_runtime_batch, insert_chunk as insert_runtime_chunk,
+    last_batch_id as last_runtime_batch_id,
+};
 use junobuild_storage::strategies::{
-    StorageAssertionsStrategy, StorageStateStrategy, StorageUploadStrategy,
+    StorageAssertionsStrategy, StorageBatchesStrategy, StorageStateStrategy, StorageUploadStrategy,
 };
 use junobuild_storage::types::config::StorageConfig;
-use junobuild_storage::types::state::{AssetAccessToken, FullPath};
+use junobuild_storage::types::runtime_state::{BatchId, ChunkId};
+use junobuild_storage::types::state::{AssetAccessToken, AssetUrlSignature, FullPath};
 use junobuild_storage::types::store::{
-    Asset, AssetAssertUpload, AssetEncoding, Batch, EncodingType, ReferenceId,
+    Asset, AssetAssertUpload, AssetEncoding, Batch, Chunk, ChunkSummary, EncodingType, ReferenceId,
 };
 use junobuild_storage::utils::{clone_asset_encoding_content_chunks, insert_encoding_into_asset};
 
@@ -123,7 +131,9 @@ impl StorageStateStrategy for StorageState {
         &self,
         full_path: FullPath,
         token: AssetAccessToken,
+        _signature: Option<AssetUrlSignature>,
     ) -> Option<(Asset, Memory)> {
+        // No signed URLs on the console
         crate::cdn::storage::heap::get_public_asset(full_path, token)
     }
 
@@ -139,6 +149,11 @@ impl StorageStateStrategy for StorageState {
         get_domains()
     }
 
+    fn get_http_endpoints(&self) -> Vec<FullPath> {
+        // No custom HTTP endpoints on the console
+        Vec::new()
+    }
+
     fn get_asset(
         &self,
         _collection: &CollectionKey,
@@ -246,3 +261,57 @@ impl StorageUploadStrategy for StorageUpload {
         }
     }
 }
+
+pub struct StorageBatches;
+
+// The uploads of the console are kept on the heap, they do not survive an upgrade.
+impl StorageBatchesStrategy for StorageBatches {
+    fn get_batch(&self, batch_id: &BatchId) -> Option<Batch> {
+        get_runtime_batch(batch_id)
+    }
+
+    fn last_batch_id(&self) -> Option<BatchId> {
+        last_runtime_batch_id()
+    }
+
+    fn insert_batch(&self, batch_id: &BatchId, batch: Batch) {
+        insert_runtime_batch(batch_id, batch)
+    }
+
+    fn get_chunk(&self, _batch_id: &BatchId, chunk_id: &ChunkId) -> Option<Chunk> {
+        get_runtime_chunk(chunk_id)
+    }
+
+    fn last_chunk_id(&self, batch_id: &BatchId) -> Option<ChunkId> {
+        get_runtime_batch_chunks(batch_id)
+            .into_iter()
+            .map(|(chunk_id, _)| chunk_id)
+            .max()
+    }
+
+    fn insert_chunk(&self, chunk_id: &ChunkId, chunk: Chunk) {
+        insert_runtime_chunk(chunk_id, chunk)
+    }
+
+    fn list_chunks(&self, batch_id: &BatchId) -> Vec<ChunkSummary> {
+        get_runtime_batch_chunks(batch_id)
+            .into_iter()
+            .map(|(chunk_id, chunk)| ChunkSummary {
+                chunk_id,
+                order_id: chunk.order_id,
+                length: chunk.content.len() as u128,
+            })
+            .collect()
+    }
+
+    fn clear_batch(&self, batch_id: &BatchId) {
+        clear_runtime_batch(batch_id)
+    }
+
+    fn clear_expired_batches(&self) {
+        clear_expired_runtime_batches();
+
+        // Remove chunk without existing batches (those we just deleted above)
+        clear_expired_runtime_chunks();
+    }
+}
@@ -64,6 +64,7 @@ fn map_releases_metadata_asset(metadata: &str, existing_asset: Option<Asset>) ->
         collection: RELEASES_COLLECTION_KEY.to_string(),
         owner: id(),
         description: None,
+        expires_at: None,
     };
 
     let headers = map_content_type_headers("application/json");
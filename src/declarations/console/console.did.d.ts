@@ -13,12 +13,18 @@ import type { Principal } from '@icp-sdk/core/principal';
 export interface AccessKey {
 	updated_at: bigint;
 	metadata: Array<[string, string]>;
+	restrictions: [] | [AccessKeyRestrictions];
 	kind: [] | [AccessKeyKind];
 	created_at: bigint;
 	scope: AccessKeyScope;
 	expires_at: [] | [bigint];
 }
+export type AccessKeyCollection = { Db: string } | { Storage: string };
 export type AccessKeyKind = { Emulator: null } | { Automation: null };
+export interface AccessKeyRestrictions {
+	read_only: boolean;
+	collections: [] | [Array<AccessKeyCollection>];
+}
 export type AccessKeyScope = { Write: null } | { Admin: null } | { Submit: null };
 export interface Account {
 	updated_at: bigint;
@@ -53,6 +59,7 @@ export interface AssetKey {
 	owner: Principal;
 	name: string;
 	description: [] | [string];
+	expires_at: [] | [bigint];
 	full_path: string;
 }
 export interface AssetNoContent {
@@ -63,6 +70,10 @@ export interface AssetNoContent {
 	created_at: bigint;
 	version: [] | [bigint];
 }
+export interface AssetUrlSignature {
+	signature: string;
+	expires_at: bigint;
+}
 export interface AssetsUpgradeOptions {
 	clear_existing_assets: [] | [boolean];
 }
@@ -96,6 +107,7 @@ export interface AuthenticationRules {
 	allowed_callers: Array<Principal>;
 }
 export interface CommitBatch {
+	sha256: [] | [Uint8Array];
 	batch_id: bigint;
 	headers: Array<[string, string]>;
 	chunk_ids: Array<bigint>;
@@ -146,6 +158,22 @@ export interface CustomDomain {
 export interface CyclesTokens {
 	e12s: bigint;
 }
+export interface DataFieldMatcher {
+	field: string;
+	value: DataValueMatcher;
+}
+export type DataMatcher =
+	| { Or: Array<DataMatcher> }
+	| { And: Array<DataMatcher> }
+	| { Not: DataMatcher }
+	| { Field: DataFieldMatcher };
+export type DataValueMatcher =
+	| { In: Array<IndexValue> }
+	| { Equal: IndexValue }
+	| { Between: [number, number] }
+	| { GreaterThan: number }
+	| { LessThan: number }
+	| { Exists: null };
 export interface Delegation {
 	pubkey: Uint8Array;
 	targets: [] | [Array<Principal>];
@@ -198,6 +226,7 @@ export interface HttpRequest {
 export interface HttpResponse {
 	body: Uint8Array;
 	headers: Array<[string, string]>;
+	upgrade: [] | [boolean];
 	streaming_strategy: [] | [StreamingStrategy];
 	status_code: number;
 }
@@ -220,11 +249,22 @@ export interface IcrcPaymentKey {
 	block_index: bigint;
 	ledger_id: Principal;
 }
+export interface IndexMatcher {
+	field: string;
+	value: IndexValueMatcher;
+}
+export type IndexValue = { Bool: boolean } | { Null: null } | { Text: string } | { Number: number };
+export type IndexValueMatcher =
+	| { Equal: IndexValue }
+	| { Between: [IndexValue, IndexValue] }
+	| { GreaterThan: IndexValue }
+	| { LessThan: IndexValue };
 export interface InitAssetKey {
 	token: [] | [string];
 	collection: string;
 	name: string;
 	description: [] | [string];
+	expires_at: [] | [bigint];
 	encoding_type: [] | [string];
 	full_path: string;
 }
@@ -235,6 +275,37 @@ export type InitStorageMemory = { Heap: null } | { Stable: null };
 export interface InitUploadResult {
 	batch_id: bigint;
 }
+export interface Jwk {
+	alg: [] | [string];
+	kid: [] | [string];
+	kty: JwkType;
+	params: JwkParams;
+}
+export type JwkParams =
+	| { Ec: JwkParamsEc }
+	| { Oct: JwkParamsOct }
+	| { Okp: JwkParamsOkp }
+	| { Rsa: JwkParamsRsa };
+export interface JwkParamsEc {
+	x: string;
+	y: string;
+	crv: string;
+}
+export interface JwkParamsOct {
+	k: string;
+}
+export interface JwkParamsOkp {
+	x: string;
+	crv: string;
+}
+export interface JwkParamsRsa {
+	e: string;
+	n: string;
+}
+export type JwkType = { EC: null } | { OKP: null } | { RSA: null } | { oct: null };
+export interface Jwks {
+	keys: Array<Jwk>;
+}
 export type JwtFindProviderError =
 	| { BadClaim: string }
 	| { BadSig: string }
@@ -248,8 +319,10 @@ export type JwtVerifyError =
 export interface ListMatcher {
 	key: [] | [string];
 	updated_at: [] | [TimestampMatcher];
+	data: [] | [DataMatcher];
 	description: [] | [string];
 	created_at: [] | [TimestampMatcher];
+	index: [] | [IndexMatcher];
 }
 export interface ListOrder {
 	field: ListOrderField;
@@ -257,6 +330,7 @@ export interface ListOrder {
 }
 export type ListOrderField = { UpdatedAt: null } | { Keys: null } | { CreatedAt: null };
 export interface ListPaginate {
+	cursor: [] | [Uint8Array];
 	start_after: [] | [string];
 	limit: [] | [bigint];
 }
@@ -268,6 +342,7 @@ export interface ListParams {
 }
 export interface ListProposalResults {
 	matches_length: bigint;
+	next_cursor: [] | [Uint8Array];
 	items: Array<[ProposalKey, Proposal]>;
 	items_length: bigint;
 }
@@ -275,6 +350,7 @@ export interface ListProposalsOrder {
 	desc: boolean;
 }
 export interface ListProposalsPaginate {
+	cursor: [] | [Uint8Array];
 	start_after: [] | [bigint];
 	limit: [] | [bigint];
 }
@@ -286,6 +362,7 @@ export interface ListResults {
 	matches_pages: [] | [bigint];
 	matches_length: bigint;
 	items_page: [] | [bigint];
+	next_cursor: [] | [Uint8Array];
 	items: Array<[string, AssetNoContent]>;
 	items_length: bigint;
 }
@@ -300,6 +377,7 @@ export interface OpenId {
 }
 export interface OpenIdAuthProviderConfig {
 	delegation: [] | [OpenIdAuthProviderDelegationConfig];
+	generic: [] | [OpenIdGenericProviderConfig];
 	client_id: string;
 }
 export interface OpenIdAuthProviderDelegationConfig {
@@ -315,7 +393,22 @@ export interface OpenIdData {
 	given_name: [] | [string];
 	preferred_username: [] | [string];
 }
-export type OpenIdDelegationProvider = { GitHub: null } | { Google: null };
+export type OpenIdDelegationProvider = { GitHub: null } | { Generic: string } | { Google: null };
+export interface OpenIdGenericProviderClaims {
+	name: [] | [string];
+	locale: [] | [string];
+	family_name: [] | [string];
+	email: [] | [string];
+	picture: [] | [string];
+	given_name: [] | [string];
+	preferred_username: [] | [string];
+}
+export interface OpenIdGenericProviderConfig {
+	claims: [] | [OpenIdGenericProviderClaims];
+	jwks: Jwks;
+	issuer: string;
+	audiences: [] | [Array<string>];
+}
 export interface OpenIdGetDelegationArgs {
 	jwt: string;
 	session_key: Uint8Array;
@@ -394,6 +487,7 @@ export interface SegmentsDeploymentOptions {
 }
 export interface SetAccessKey {
 	metadata: Array<[string, string]>;
+	restrictions: [] | [AccessKeyRestrictions];
 	kind: [] | [AccessKeyKind];
 	scope: AccessKeyScope;
 	expires_at: [] | [bigint];
@@ -426,8 +520,10 @@ export interface SetStorageConfig {
 	iframe: [] | [StorageConfigIFrame];
 	rewrites: Array<[string, string]>;
 	headers: Array<[string, Array<[string, string]>]>;
+	compression: [] | [Array<StorageConfigCompression>];
 	version: [] | [bigint];
 	max_memory_size: [] | [ConfigMaxMemorySize];
+	domain_roots: [] | [Array<[string, StorageConfigDomainRoot]>];
 	raw_access: [] | [StorageConfigRawAccess];
 	redirects: [] | [Array<[string, StorageConfigRedirect]>];
 }
@@ -442,16 +538,21 @@ export interface StorageConfig {
 	rewrites: Array<[string, string]>;
 	headers: Array<[string, Array<[string, string]>]>;
 	created_at: [] | [bigint];
+	compression: [] | [Array<StorageConfigCompression>];
 	version: [] | [bigint];
 	max_memory_size: [] | [ConfigMaxMemorySize];
+	domain_roots: [] | [Array<[string, StorageConfigDomainRoot]>];
 	raw_access: [] | [StorageConfigRawAccess];
 	redirects: [] | [Array<[string, StorageConfigRedirect]>];
 }
+export type StorageConfigCompression = { Gzip: null } | { Brotli: null };
+export type StorageConfigDomainRoot = { Collection: string } | { Path: string };
 export type StorageConfigIFrame = { Deny: null } | { AllowAny: null } | { SameOrigin: null };
 export type StorageConfigRawAccess = { Deny: null } | { Allow: null };
 export interface StorageConfigRedirect {
 	status_code: number;
 	location: string;
+	preserve_query: [] | [boolean];
 }
 export interface StreamingCallbackHttpResponse {
 	token: [] | [StreamingCallbackToken];
@@ -460,6 +561,7 @@ export interface StreamingCallbackHttpResponse {
 export interface StreamingCallbackToken {
 	memory: Memory;
 	token: [] | [string];
+	signature: [] | [AssetUrlSignature];
 	sha256: [] | [Uint8Array];
 	headers: Array<[string, string]>;
 	index: bigint;
@@ -533,6 +635,7 @@ export interface _SERVICE {
 		[StreamingCallbackToken],
 		StreamingCallbackHttpResponse
 	>;
+	http_request_update: ActorMethod<[HttpRequest], HttpResponse>;
 	init_proposal: ActorMethod<[ProposalType], [bigint, Proposal]>;
 	init_proposal_asset_upload: ActorMethod<[InitAssetKey, bigint], InitUploadResult>;
 	init_proposal_many_assets_upload: ActorMethod<
@@ -7,6 +7,7 @@
 // Additionally, you should also exclude this file from your linter and/or formatter to prevent it from being checked or modified.
 
 export const idlFactory = ({ IDL }) => {
+	const DataMatcher = IDL.Rec();
 	const Tokens = IDL.Record({ e8s: IDL.Nat64 });
 	const AssertMissionControlCenterArgs = IDL.Record({
 		mission_control_id: IDL.Principal,
@@ -26,6 +27,7 @@ export const idlFactory = ({ IDL }) => {
 	});
 	const OpenIdDelegationProvider = IDL.Variant({
 		GitHub: IDL.Null,
+		Generic: IDL.Text,
 		Google: IDL.Null
 	});
 	const OpenIdData = IDL.Record({
@@ -101,6 +103,7 @@ export const idlFactory = ({ IDL }) => {
 		proposal_id: IDL.Nat
 	});
 	const CommitBatch = IDL.Record({
+		sha256: IDL.Opt(IDL.Vec(IDL.Nat8)),
 		batch_id: IDL.Nat,
 		headers: IDL.Vec(IDL.Tuple(IDL.Text, IDL.Text)),
 		chunk_ids: IDL.Vec(IDL.Nat)
@@ -154,8 +157,51 @@ export const idlFactory = ({ IDL }) => {
 		targets: IDL.Opt(IDL.Vec(IDL.Principal)),
 		max_time_to_live: IDL.Opt(IDL.Nat64)
 	});
+	const OpenIdGenericProviderClaims = IDL.Record({
+		name: IDL.Opt(IDL.Text),
+		locale: IDL.Opt(IDL.Text),
+		family_name: IDL.Opt(IDL.Text),
+		email: IDL.Opt(IDL.Text),
+		picture: IDL.Opt(IDL.Text),
+		given_name: IDL.Opt(IDL.Text),
+		preferred_username: IDL.Opt(IDL.Text)
+	});
+	const JwkType = IDL.Variant({
+		EC: IDL.Null,
+		OKP: IDL.Null,
+		RSA: IDL.Null,
+		oct: IDL.Null
+	});
+	const JwkParamsEc = IDL.Record({
+		x: IDL.Text,
+		y: IDL.Text,
+		crv: IDL.Text
+	});
+	const JwkParamsOct = IDL.Record({ k: IDL.Text });
+	const JwkParamsOkp = IDL.Record({ x: IDL.Text, crv: IDL.Text });
+	const JwkParamsRsa = IDL.Record({ e: IDL.Text, n: IDL.Text });
+	const JwkParams = IDL.Variant({
+		Ec: JwkParamsEc,
+		Oct: JwkParamsOct,
+		Okp: JwkParamsOkp,
+		Rsa: JwkParamsRsa
+	});
+	const Jwk = IDL.Record({
+		alg: IDL.Opt(IDL.Text),
+		kid: IDL.Opt(IDL.Text),
+		kty: JwkType,
+		params: JwkParams
+	});
+	const Jwks = IDL.Record({ keys: IDL.Vec(Jwk) });
+	const OpenIdGenericProviderConfig = IDL.Record({
+		claims: IDL.Opt(OpenIdGenericProviderClaims),
+		jwks: Jwks,
+		issuer: IDL.Text,
+		audiences: IDL.Opt(IDL.Vec(IDL.Text))
+	});
 	const OpenIdAuthProviderConfig = IDL.Record({
 		delegation: IDL.Opt(OpenIdAuthProviderDelegationConfig),
+		generic: IDL.Opt(OpenIdGenericProviderConfig),
 		client_id: IDL.Text
 	});
 	const AuthenticationConfigOpenId = IDL.Record({
@@ -182,17 +228,26 @@ export const idlFactory = ({ IDL }) => {
 		AllowAny: IDL.Null,
 		SameOrigin: IDL.Null
 	});
+	const StorageConfigCompression = IDL.Variant({
+		Gzip: IDL.Null,
+		Brotli: IDL.Null
+	});
 	const ConfigMaxMemorySize = IDL.Record({
 		stable: IDL.Opt(IDL.Nat64),
 		heap: IDL.Opt(IDL.Nat64)
 	});
+	const StorageConfigDomainRoot = IDL.Variant({
+		Collection: IDL.Text,
+		Path: IDL.Text
+	});
 	const StorageConfigRawAccess = IDL.Variant({
 		Deny: IDL.Null,
 		Allow: IDL.Null
 	});
 	const StorageConfigRedirect = IDL.Record({
 		status_code: IDL.Nat16,
-		location: IDL.Text
+		location: IDL.Text,
+		preserve_query: IDL.Opt(IDL.Bool)
 	});
 	const StorageConfig = IDL.Record({
 		iframe: IDL.Opt(StorageConfigIFrame),
@@ -200,8 +255,10 @@ export const idlFactory = ({ IDL }) => {
 		rewrites: IDL.Vec(IDL.Tuple(IDL.Text, IDL.Text)),
 		headers: IDL.Vec(IDL.Tuple(IDL.Text, IDL.Vec(IDL.Tuple(IDL.Text, IDL.Text)))),
 		created_at: IDL.Opt(IDL.Nat64),
+		compression: IDL.Opt(IDL.Vec(StorageConfigCompression)),
 		version: IDL.Opt(IDL.Nat64),
 		max_memory_size: IDL.Opt(ConfigMaxMemorySize),
+		domain_roots: IDL.Opt(IDL.Vec(IDL.Tuple(IDL.Text, StorageConfigDomainRoot))),
 		raw_access: IDL.Opt(StorageConfigRawAccess),
 		redirects: IDL.Opt(IDL.Vec(IDL.Tuple(IDL.Text, StorageConfigRedirect)))
 	});
@@ -293,9 +350,14 @@ export const idlFactory = ({ IDL }) => {
 		certificate_version: IDL.Opt(IDL.Nat16)
 	});
 	const Memory = IDL.Variant({ Heap: IDL.Null, Stable: IDL.Null });
+	const AssetUrlSignature = IDL.Record({
+		signature: IDL.Text,
+		expires_at: IDL.Nat64
+	});
 	const StreamingCallbackToken = IDL.Record({
 		memory: Memory,
 		token: IDL.Opt(IDL.Text),
+		signature: IDL.Opt(AssetUrlSignature),
 		sha256: IDL.Opt(IDL.Vec(IDL.Nat8)),
 		headers: IDL.Vec(IDL.Tuple(IDL.Text, IDL.Text)),
 		index: IDL.Nat64,
@@ -311,6 +373,7 @@ export const idlFactory = ({ IDL }) => {
 	const HttpResponse = IDL.Record({
 		body: IDL.Vec(IDL.Nat8),
 		headers: IDL.Vec(IDL.Tuple(IDL.Text, IDL.Text)),
+		upgrade: IDL.Opt(IDL.Bool),
 		streaming_strategy: IDL.Opt(StreamingStrategy),
 		status_code: IDL.Nat16
 	});
@@ -323,6 +386,7 @@ export const idlFactory = ({ IDL }) => {
 		collection: IDL.Text,
 		name: IDL.Text,
 		description: IDL.Opt(IDL.Text),
+		expires_at: IDL.Opt(IDL.Nat64),
 		encoding_type: IDL.Opt(IDL.Text),
 		full_path: IDL.Text
 	});
@@ -339,13 +403,52 @@ export const idlFactory = ({ IDL }) => {
 		GreaterThan: IDL.Nat64,
 		LessThan: IDL.Nat64
 	});
+	const IndexValue = IDL.Variant({
+		Bool: IDL.Bool,
+		Null: IDL.Null,
+		Text: IDL.Text,
+		Number: IDL.Float64
+	});
+	const DataValueMatcher = IDL.Variant({
+		In: IDL.Vec(IndexValue),
+		Equal: IndexValue,
+		Between: IDL.Tuple(IDL.Float64, IDL.Float64),
+		GreaterThan: IDL.Float64,
+		LessThan: IDL.Float64,
+		Exists: IDL.Null
+	});
+	const DataFieldMatcher = IDL.Record({
+		field: IDL.Text,
+		value: DataValueMatcher
+	});
+	DataMatcher.fill(
+		IDL.Variant({
+			Or: IDL.Vec(DataMatcher),
+			And: IDL.Vec(DataMatcher),
+			Not: DataMatcher,
+			Field: DataFieldMatcher
+		})
+	);
+	const IndexValueMatcher = IDL.Variant({
+		Equal: IndexValue,
+		Between: IDL.Tuple(IndexValue, IndexValue),
+		GreaterThan: IndexValue,
+		LessThan: IndexValue
+	});
+	const IndexMatcher = IDL.Record({
+		field: IDL.Text,
+		value: IndexValueMatcher
+	});
 	const ListMatcher = IDL.Record({
 		key: IDL.Opt(IDL.Text),
 		updated_at: IDL.Opt(TimestampMatcher),
+		data: IDL.Opt(DataMatcher),
 		description: IDL.Opt(IDL.Text),
-		created_at: IDL.Opt(TimestampMatcher)
+		created_at: IDL.Opt(TimestampMatcher),
+		index: IDL.Opt(IndexMatcher)
 	});
 	const ListPaginate = IDL.Record({
+		cursor: IDL.Opt(IDL.Vec(IDL.Nat8)),
 		start_after: IDL.Opt(IDL.Text),
 		limit: IDL.Opt(IDL.Nat64)
 	});
@@ -361,6 +464,7 @@ export const idlFactory = ({ IDL }) => {
 		owner: IDL.Principal,
 		name: IDL.Text,
 		description: IDL.Opt(IDL.Text),
+		expires_at: IDL.Opt(IDL.Nat64),
 		full_path: IDL.Text
 	});
 	const AssetEncodingNoContent = IDL.Record({
@@ -380,9 +484,18 @@ export const idlFactory = ({ IDL }) => {
 		matches_pages: IDL.Opt(IDL.Nat64),
 		matches_length: IDL.Nat64,
 		items_page: IDL.Opt(IDL.Nat64),
+		next_cursor: IDL.Opt(IDL.Vec(IDL.Nat8)),
 		items: IDL.Vec(IDL.Tuple(IDL.Text, AssetNoContent)),
 		items_length: IDL.Nat64
 	});
+	const AccessKeyCollection = IDL.Variant({
+		Db: IDL.Text,
+		Storage: IDL.Text
+	});
+	const AccessKeyRestrictions = IDL.Record({
+		read_only: IDL.Bool,
+		collections: IDL.Opt(IDL.Vec(AccessKeyCollection))
+	});
 	const AccessKeyKind = IDL.Variant({
 		Emulator: IDL.Null,
 		Automation: IDL.Null
@@ -395,6 +508,7 @@ export const idlFactory = ({ IDL }) => {
 	const AccessKey = IDL.Record({
 		updated_at: IDL.Nat64,
 		metadata: IDL.Vec(IDL.Tuple(IDL.Text, IDL.Text)),
+		restrictions: IDL.Opt(AccessKeyRestrictions),
 		kind: IDL.Opt(AccessKeyKind),
 		created_at: IDL.Nat64,
 		scope: AccessKeyScope,
@@ -436,6 +550,7 @@ export const idlFactory = ({ IDL }) => {
 	});
 	const ListProposalsOrder = IDL.Record({ desc: IDL.Bool });
 	const ListProposalsPaginate = IDL.Record({
+		cursor: IDL.Opt(IDL.Vec(IDL.Nat8)),
 		start_after: IDL.Opt(IDL.Nat),
 		limit: IDL.Opt(IDL.Nat)
 	});
@@ -446,6 +561,7 @@ export const idlFactory = ({ IDL }) => {
 	const ProposalKey = IDL.Record({ proposal_id: IDL.Nat });
 	const ListProposalResults = IDL.Record({
 		matches_length: IDL.Nat64,
+		next_cursor: IDL.Opt(IDL.Vec(IDL.Nat8)),
 		items: IDL.Vec(IDL.Tuple(ProposalKey, Proposal)),
 		items_length: IDL.Nat64
 	});
@@ -481,6 +597,7 @@ export const idlFactory = ({ IDL }) => {
 	});
 	const SetAccessKey = IDL.Record({
 		metadata: IDL.Vec(IDL.Tuple(IDL.Text, IDL.Text)),
+		restrictions: IDL.Opt(AccessKeyRestrictions),
 		kind: IDL.Opt(AccessKeyKind),
 		scope: AccessKeyScope,
 		expires_at: IDL.Opt(IDL.Nat64)
@@ -507,8 +624,10 @@ export const idlFactory = ({ IDL }) => {
 		iframe: IDL.Opt(StorageConfigIFrame),
 		rewrites: IDL.Vec(IDL.Tuple(IDL.Text, IDL.Text)),
 		headers: IDL.Vec(IDL.Tuple(IDL.Text, IDL.Vec(IDL.Tuple(IDL.Text, IDL.Text)))),
+		compression: IDL.Opt(IDL.Vec(StorageConfigCompression)),
 		version: IDL.Opt(IDL.Nat64),
 		max_memory_size: IDL.Opt(ConfigMaxMemorySize),
+		domain_roots: IDL.Opt(IDL.Vec(IDL.Tuple(IDL.Text, StorageConfigDomainRoot))),
 		raw_access: IDL.Opt(StorageConfigRawAccess),
 		redirects: IDL.Opt(IDL.Vec(IDL.Tuple(IDL.Text, StorageConfigRedirect)))
 	});
@@ -563,6 +682,7 @@ export const idlFactory = ({ IDL }) => {
 			[StreamingCallbackHttpResponse],
 			[]
 		),
+		http_request_update: IDL.Func([HttpRequest], [HttpResponse], []),
 		init_proposal: IDL.Func([ProposalType], [IDL.Nat, Proposal], []),
 		init_proposal_asset_upload: IDL.Func([InitAssetKey, IDL.Nat], [InitUploadResult], []),
 		init_proposal_many_assets_upload: IDL.Func(
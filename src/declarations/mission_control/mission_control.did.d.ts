@@ -13,12 +13,18 @@ import type { Principal } from '@icp-sdk/core/principal';
 export interface AccessKey {
 	updated_at: bigint;
 	metadata: Array<[string, string]>;
+	restrictions: [] | [AccessKeyRestrictions];
 	kind: [] | [AccessKeyKind];
 	created_at: bigint;
 	scope: AccessKeyScope;
 	expires_at: [] | [bigint];
 }
+export type AccessKeyCollection = { Db: string } | { Storage: string };
 export type AccessKeyKind = { Emulator: null } | { Automation: null };
+export interface AccessKeyRestrictions {
+	read_only: boolean;
+	collections: [] | [Array<AccessKeyCollection>];
+}
 export type AccessKeyScope = { Write: null } | { Admin: null } | { Submit: null };
 export interface Account {
 	owner: Principal;
@@ -154,6 +160,7 @@ export interface SegmentsMonitoringStrategy {
 }
 export interface SetAccessKey {
 	metadata: Array<[string, string]>;
+	restrictions: [] | [AccessKeyRestrictions];
 	kind: [] | [AccessKeyKind];
 	scope: AccessKeyScope;
 	expires_at: [] | [bigint];
@@ -163,6 +163,14 @@ export const idlFactory = ({ IDL }) => {
 		InsufficientFunds: IDL.Record({ balance: IDL.Nat })
 	});
 	const Result_1 = IDL.Variant({ Ok: IDL.Nat, Err: TransferError_1 });
+	const AccessKeyCollection = IDL.Variant({
+		Db: IDL.Text,
+		Storage: IDL.Text
+	});
+	const AccessKeyRestrictions = IDL.Record({
+		read_only: IDL.Bool,
+		collections: IDL.Opt(IDL.Vec(AccessKeyCollection))
+	});
 	const AccessKeyKind = IDL.Variant({
 		Emulator: IDL.Null,
 		Automation: IDL.Null
@@ -175,6 +183,7 @@ export const idlFactory = ({ IDL }) => {
 	const AccessKey = IDL.Record({
 		updated_at: IDL.Nat64,
 		metadata: IDL.Vec(IDL.Tuple(IDL.Text, IDL.Text)),
+		restrictions: IDL.Opt(AccessKeyRestrictions),
 		kind: IDL.Opt(AccessKeyKind),
 		created_at: IDL.Nat64,
 		scope: AccessKeyScope,
@@ -189,6 +198,7 @@ export const idlFactory = ({ IDL }) => {
 	});
 	const SetAccessKey = IDL.Record({
 		metadata: IDL.Vec(IDL.Tuple(IDL.Text, IDL.Text)),
+		restrictions: IDL.Opt(AccessKeyRestrictions),
 		kind: IDL.Opt(AccessKeyKind),
 		scope: AccessKeyScope,
 		expires_at: IDL.Opt(IDL.Nat64)
@@ -13,12 +13,18 @@ import type { Principal } from '@icp-sdk/core/principal';
 export interface AccessKey {
 	updated_at: bigint;
 	metadata: Array<[string, string]>;
+	restrictions: [] | [AccessKeyRestrictions];
 	kind: [] | [AccessKeyKind];
 	created_at: bigint;
 	scope: AccessKeyScope;
 	expires_at: [] | [bigint];
 }
+export type AccessKeyCollection = { Db: string } | { Storage: string };
 export type AccessKeyKind = { Emulator: null } | { Automation: null };
+export interface AccessKeyRestrictions {
+	read_only: boolean;
+	collections: [] | [Array<AccessKeyCollection>];
+}
 export type AccessKeyScope = { Write: null } | { Admin: null } | { Submit: null };
 export interface CyclesBalance {
 	timestamp: bigint;
@@ -108,7 +114,11 @@ export interface OpenIdCertificate {
 	created_at: bigint;
 	version: [] | [bigint];
 }
-export type OpenIdProvider = { GitHubActions: null } | { Google: null } | { GitHubAuth: null };
+export type OpenIdProvider =
+	| { GitHubActions: null }
+	| { GitLabCi: null }
+	| { Google: null }
+	| { GitHubAuth: null };
 export interface RateConfig {
 	max_tokens: bigint;
 	time_per_token_ns: bigint;
@@ -126,6 +136,7 @@ export type SegmentKind =
 	| { Satellite: null };
 export interface SetAccessKey {
 	metadata: Array<[string, string]>;
+	restrictions: [] | [AccessKeyRestrictions];
 	kind: [] | [AccessKeyKind];
 	scope: AccessKeyScope;
 	expires_at: [] | [bigint];
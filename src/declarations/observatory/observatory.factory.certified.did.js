@@ -22,6 +22,7 @@ export const idlFactory = ({ IDL }) => {
 	});
 	const OpenIdProvider = IDL.Variant({
 		GitHubActions: IDL.Null,
+		GitLabCi: IDL.Null,
 		Google: IDL.Null,
 		GitHubAuth: IDL.Null
 	});
@@ -59,6 +60,14 @@ export const idlFactory = ({ IDL }) => {
 		created_at: IDL.Nat64,
 		version: IDL.Opt(IDL.Nat64)
 	});
+	const AccessKeyCollection = IDL.Variant({
+		Db: IDL.Text,
+		Storage: IDL.Text
+	});
+	const AccessKeyRestrictions = IDL.Record({
+		read_only: IDL.Bool,
+		collections: IDL.Opt(IDL.Vec(AccessKeyCollection))
+	});
 	const AccessKeyKind = IDL.Variant({
 		Emulator: IDL.Null,
 		Automation: IDL.Null
@@ -71,6 +80,7 @@ export const idlFactory = ({ IDL }) => {
 	const AccessKey = IDL.Record({
 		updated_at: IDL.Nat64,
 		metadata: IDL.Vec(IDL.Tuple(IDL.Text, IDL.Text)),
+		restrictions: IDL.Opt(AccessKeyRestrictions),
 		kind: IDL.Opt(AccessKeyKind),
 		created_at: IDL.Nat64,
 		scope: AccessKeyScope,
@@ -121,6 +131,7 @@ export const idlFactory = ({ IDL }) => {
 	});
 	const SetAccessKey = IDL.Record({
 		metadata: IDL.Vec(IDL.Tuple(IDL.Text, IDL.Text)),
+		restrictions: IDL.Opt(AccessKeyRestrictions),
 		kind: IDL.Opt(AccessKeyKind),
 		scope: AccessKeyScope,
 		expires_at: IDL.Opt(IDL.Nat64)
@@ -13,12 +13,18 @@ import type { Principal } from '@icp-sdk/core/principal';
 export interface AccessKey {
 	updated_at: bigint;
 	metadata: Array<[string, string]>;
+	restrictions: [] | [AccessKeyRestrictions];
 	kind: [] | [AccessKeyKind];
 	created_at: bigint;
 	scope: AccessKeyScope;
 	expires_at: [] | [bigint];
 }
+export type AccessKeyCollection = { Db: string } | { Storage: string };
 export type AccessKeyKind = { Emulator: null } | { Automation: null };
+export interface AccessKeyRestrictions {
+	read_only: boolean;
+	collections: [] | [Array<AccessKeyCollection>];
+}
 export type AccessKeyScope = { Write: null } | { Admin: null } | { Submit: null };
 export interface AnalyticKey {
 	key: string;
@@ -195,6 +201,7 @@ export type Result_2 = { Ok: PerformanceMetric } | { Err: string };
 export type Result_3 = { Ok: TrackEvent } | { Err: string };
 export interface SetAccessKey {
 	metadata: Array<[string, string]>;
+	restrictions: [] | [AccessKeyRestrictions];
 	kind: [] | [AccessKeyKind];
 	scope: AccessKeyScope;
 	expires_at: [] | [bigint];
@@ -13,6 +13,14 @@ export const idlFactory = ({ IDL }) => {
 	const DeleteControllersArgs = IDL.Record({
 		controllers: IDL.Vec(IDL.Principal)
 	});
+	const AccessKeyCollection = IDL.Variant({
+		Db: IDL.Text,
+		Storage: IDL.Text
+	});
+	const AccessKeyRestrictions = IDL.Record({
+		read_only: IDL.Bool,
+		collections: IDL.Opt(IDL.Vec(AccessKeyCollection))
+	});
 	const AccessKeyKind = IDL.Variant({
 		Emulator: IDL.Null,
 		Automation: IDL.Null
@@ -25,6 +33,7 @@ export const idlFactory = ({ IDL }) => {
 	const AccessKey = IDL.Record({
 		updated_at: IDL.Nat64,
 		metadata: IDL.Vec(IDL.Tuple(IDL.Text, IDL.Text)),
+		restrictions: IDL.Opt(AccessKeyRestrictions),
 		kind: IDL.Opt(AccessKeyKind),
 		created_at: IDL.Nat64,
 		scope: AccessKeyScope,
@@ -207,6 +216,7 @@ export const idlFactory = ({ IDL }) => {
 	const MemorySize = IDL.Record({ stable: IDL.Nat64, heap: IDL.Nat64 });
 	const SetAccessKey = IDL.Record({
 		metadata: IDL.Vec(IDL.Tuple(IDL.Text, IDL.Text)),
+		restrictions: IDL.Opt(AccessKeyRestrictions),
 		kind: IDL.Opt(AccessKeyKind),
 		scope: AccessKeyScope,
 		expires_at: IDL.Opt(IDL.Nat64)
@@ -13,12 +13,18 @@ import type { Principal } from '@icp-sdk/core/principal';
 export interface AccessKey {
 	updated_at: bigint;
 	metadata: Array<[string, string]>;
+	restrictions: [] | [AccessKeyRestrictions];
 	kind: [] | [AccessKeyKind];
 	created_at: bigint;
 	scope: AccessKeyScope;
 	expires_at: [] | [bigint];
 }
+export type AccessKeyCollection = { Db: string } | { Storage: string };
 export type AccessKeyKind = { Emulator: null } | { Automation: null };
+export interface AccessKeyRestrictions {
+	read_only: boolean;
+	collections: [] | [Array<AccessKeyCollection>];
+}
 export type AccessKeyScope = { Write: null } | { Admin: null } | { Submit: null };
 export interface AssetEncodingNoContent {
 	modified: bigint;
@@ -31,6 +37,7 @@ export interface AssetKey {
 	owner: Principal;
 	name: string;
 	description: [] | [string];
+	expires_at: [] | [bigint];
 	full_path: string;
 }
 export interface AssetNoContent {
@@ -41,9 +48,49 @@ export interface AssetNoContent {
 	created_at: bigint;
 	version: [] | [bigint];
 }
+export interface AssetUrlSignature {
+	signature: string;
+	expires_at: bigint;
+}
 export interface AssetsUpgradeOptions {
 	clear_existing_assets: [] | [boolean];
 }
+export interface AuditConfig {
+	updated_at: [] | [bigint];
+	max_entries: [] | [number];
+	retention: [] | [bigint];
+	created_at: [] | [bigint];
+	version: [] | [bigint];
+}
+export interface AuditLog {
+	after: [] | [string];
+	endpoint: AuditLogEndpoint;
+	created_at: bigint;
+	target: [] | [string];
+	before: [] | [string];
+	caller: Principal;
+}
+export type AuditLogEndpoint =
+	| { BanUser: null }
+	| { DelControllerSelf: null }
+	| { SetUserUsageConfig: null }
+	| { RevokeUserRoles: null }
+	| { AssignUserRoles: null }
+	| { SetDbConfig: null }
+	| { SetControllers: null }
+	| { SetAutomationConfig: null }
+	| { AuthenticateAutomation: null }
+	| { SetCustomDomain: null }
+	| { SetAuditConfig: null }
+	| { DelCustomDomain: null }
+	| { RevokeUserSessions: null }
+	| { SetStorageConfig: null }
+	| { ClearUserSessionsRevocation: null }
+	| { DelRule: null }
+	| { SetRule: null }
+	| { UnbanUser: null }
+	| { SetAuthConfig: null }
+	| { DelControllers: null };
 export type AuthenticateAutomationArgs = {
 	OpenId: OpenIdPrepareAutomationArgs;
 };
@@ -104,6 +151,20 @@ export interface AutomationController {
 	expires_at: bigint;
 }
 export type AutomationScope = { Write: null } | { Submit: null };
+export interface BanUserArgs {
+	code: [] | [string];
+	user: Principal;
+	expires_at: [] | [bigint];
+	reason: [] | [string];
+}
+export interface BannedUser {
+	code: [] | [string];
+	user: Principal;
+	banned_at: [] | [bigint];
+	banned_by: [] | [Principal];
+	expires_at: [] | [bigint];
+	reason: [] | [string];
+}
 export interface CertifyAssetsArgs {
 	cursor: CertifyAssetsCursor;
 	strategy: CertifyAssetsStrategy;
@@ -121,10 +182,14 @@ export type CertifyAssetsStrategy =
 	| { AppendWithRouting: null };
 export type CollectionType = { Db: null } | { Storage: null };
 export interface CommitBatch {
+	sha256: [] | [Uint8Array];
 	batch_id: bigint;
 	headers: Array<[string, string]>;
 	chunk_ids: Array<bigint>;
 }
+export type CommitDocOperation =
+	| { Set: [string, string, SetDoc] }
+	| { Delete: [string, string, DelDoc] };
 export interface CommitProposal {
 	sha256: Uint8Array;
 	proposal_id: bigint;
@@ -139,12 +204,32 @@ export interface ConfigMaxMemorySize {
 	stable: [] | [bigint];
 	heap: [] | [bigint];
 }
+export interface ContentValidation {
+	max_image_width: [] | [number];
+	max_image_height: [] | [number];
+}
 export interface CustomDomain {
 	updated_at: bigint;
 	created_at: bigint;
 	version: [] | [bigint];
 	bn_id: [] | [string];
 }
+export interface DataFieldMatcher {
+	field: string;
+	value: DataValueMatcher;
+}
+export type DataMatcher =
+	| { Or: Array<DataMatcher> }
+	| { And: Array<DataMatcher> }
+	| { Not: DataMatcher }
+	| { Field: DataFieldMatcher };
+export type DataValueMatcher =
+	| { In: Array<IndexValue> }
+	| { Equal: IndexValue }
+	| { Between: [number, number] }
+	| { GreaterThan: number }
+	| { LessThan: number }
+	| { Exists: null };
 export interface DbConfig {
 	updated_at: [] | [bigint];
 	created_at: [] | [bigint];
@@ -179,6 +264,12 @@ export interface Doc {
 	description: [] | [string];
 	created_at: bigint;
 	version: [] | [bigint];
+	expires_at: [] | [bigint];
+}
+export interface DocRevision {
+	doc: Doc;
+	revision: bigint;
+	archived_at: bigint;
 }
 export type GetDelegationArgs = { OpenId: OpenIdGetDelegationArgs };
 export type GetDelegationError =
@@ -200,6 +291,10 @@ export type GetOrRefreshJwksError =
 	| { MissingLastAttempt: string }
 	| { KeyNotFound: null }
 	| { FetchFailed: string };
+export interface HistoryConfig {
+	retention: [] | [bigint];
+	max_revisions: [] | [number];
+}
 export interface HttpRequest {
 	url: string;
 	method: string;
@@ -210,14 +305,33 @@ export interface HttpRequest {
 export interface HttpResponse {
 	body: Uint8Array;
 	headers: Array<[string, string]>;
+	upgrade: [] | [boolean];
 	streaming_strategy: [] | [StreamingStrategy];
 	status_code: number;
 }
+export type ImageFormat = { Png: null } | { Jpeg: null } | { WebP: null };
+export interface ImageVariant {
+	max_height: [] | [number];
+	name: string;
+	max_width: [] | [number];
+	format: [] | [ImageFormat];
+}
+export interface IndexMatcher {
+	field: string;
+	value: IndexValueMatcher;
+}
+export type IndexValue = { Bool: boolean } | { Null: null } | { Text: string } | { Number: number };
+export type IndexValueMatcher =
+	| { Equal: IndexValue }
+	| { Between: [IndexValue, IndexValue] }
+	| { GreaterThan: IndexValue }
+	| { LessThan: IndexValue };
 export interface InitAssetKey {
 	token: [] | [string];
 	collection: string;
 	name: string;
 	description: [] | [string];
+	expires_at: [] | [bigint];
 	encoding_type: [] | [string];
 	full_path: string;
 }
@@ -231,6 +345,37 @@ export interface InitStorageArgs {
 export interface InitUploadResult {
 	batch_id: bigint;
 }
+export interface Jwk {
+	alg: [] | [string];
+	kid: [] | [string];
+	kty: JwkType;
+	params: JwkParams;
+}
+export type JwkParams =
+	| { Ec: JwkParamsEc }
+	| { Oct: JwkParamsOct }
+	| { Okp: JwkParamsOkp }
+	| { Rsa: JwkParamsRsa };
+export interface JwkParamsEc {
+	x: string;
+	y: string;
+	crv: string;
+}
+export interface JwkParamsOct {
+	k: string;
+}
+export interface JwkParamsOkp {
+	x: string;
+	crv: string;
+}
+export interface JwkParamsRsa {
+	e: string;
+	n: string;
+}
+export type JwkType = { EC: null } | { OKP: null } | { RSA: null } | { oct: null };
+export interface Jwks {
+	keys: Array<Jwk>;
+}
 export type JwtFindProviderError =
 	| { BadClaim: string }
 	| { BadSig: string }
@@ -241,11 +386,43 @@ export type JwtVerifyError =
 	| { BadClaim: string }
 	| { BadSig: string }
 	| { NoKeyForKid: null };
+export interface ListAuditLogsOrder {
+	desc: boolean;
+}
+export interface ListAuditLogsPaginate {
+	cursor: [] | [Uint8Array];
+	start_after: [] | [bigint];
+	limit: [] | [bigint];
+}
+export interface ListAuditLogsParams {
+	order: [] | [ListAuditLogsOrder];
+	paginate: [] | [ListAuditLogsPaginate];
+}
+export interface ListAuditLogsResults {
+	next_cursor: [] | [Uint8Array];
+	items: Array<[bigint, AuditLog]>;
+	items_length: bigint;
+}
+export interface ListBannedUsersPaginate {
+	cursor: [] | [Uint8Array];
+	start_after: [] | [Principal];
+	limit: [] | [bigint];
+}
+export interface ListBannedUsersParams {
+	paginate: [] | [ListBannedUsersPaginate];
+}
+export interface ListBannedUsersResults {
+	next_cursor: [] | [Uint8Array];
+	items: Array<BannedUser>;
+	items_length: bigint;
+}
 export interface ListMatcher {
 	key: [] | [string];
 	updated_at: [] | [TimestampMatcher];
+	data: [] | [DataMatcher];
 	description: [] | [string];
 	created_at: [] | [TimestampMatcher];
+	index: [] | [IndexMatcher];
 }
 export interface ListOrder {
 	field: ListOrderField;
@@ -253,6 +430,7 @@ export interface ListOrder {
 }
 export type ListOrderField = { UpdatedAt: null } | { Keys: null } | { CreatedAt: null };
 export interface ListPaginate {
+	cursor: [] | [Uint8Array];
 	start_after: [] | [string];
 	limit: [] | [bigint];
 }
@@ -264,6 +442,7 @@ export interface ListParams {
 }
 export interface ListProposalResults {
 	matches_length: bigint;
+	next_cursor: [] | [Uint8Array];
 	items: Array<[ProposalKey, Proposal]>;
 	items_length: bigint;
 }
@@ -271,6 +450,7 @@ export interface ListProposalsOrder {
 	desc: boolean;
 }
 export interface ListProposalsPaginate {
+	cursor: [] | [Uint8Array];
 	start_after: [] | [bigint];
 	limit: [] | [bigint];
 }
@@ -282,6 +462,7 @@ export interface ListResults {
 	matches_pages: [] | [bigint];
 	matches_length: bigint;
 	items_page: [] | [bigint];
+	next_cursor: [] | [Uint8Array];
 	items: Array<[string, AssetNoContent]>;
 	items_length: bigint;
 }
@@ -289,6 +470,7 @@ export interface ListResults_1 {
 	matches_pages: [] | [bigint];
 	matches_length: bigint;
 	items_page: [] | [bigint];
+	next_cursor: [] | [Uint8Array];
 	items: Array<[string, Doc]>;
 	items_length: bigint;
 }
@@ -310,16 +492,18 @@ export interface MemorySize {
 }
 export interface OpenIdAuthProviderConfig {
 	delegation: [] | [OpenIdAuthProviderDelegationConfig];
+	generic: [] | [OpenIdGenericProviderConfig];
 	client_id: string;
 }
 export interface OpenIdAuthProviderDelegationConfig {
 	targets: [] | [Array<Principal>];
 	max_time_to_live: [] | [bigint];
 }
-export type OpenIdAutomationProvider = { GitHub: null };
+export type OpenIdAutomationProvider = { GitHub: null } | { GitLab: null };
 export interface OpenIdAutomationProviderConfig {
 	controller: [] | [OpenIdAutomationProviderControllerConfig];
 	repositories: Array<[RepositoryKey, OpenIdAutomationRepositoryConfig]>;
+	self_hosted: [] | [OpenIdAutomationSelfHostedConfig];
 }
 export interface OpenIdAutomationProviderControllerConfig {
 	scope: [] | [AutomationScope];
@@ -327,8 +511,28 @@ export interface OpenIdAutomationProviderControllerConfig {
 }
 export interface OpenIdAutomationRepositoryConfig {
 	refs: [] | [Array<string>];
+	protected_refs: [] | [boolean];
+}
+export interface OpenIdAutomationSelfHostedConfig {
+	jwks: Jwks;
+	issuer: string;
+}
+export type OpenIdDelegationProvider = { GitHub: null } | { Generic: string } | { Google: null };
+export interface OpenIdGenericProviderClaims {
+	name: [] | [string];
+	locale: [] | [string];
+	family_name: [] | [string];
+	email: [] | [string];
+	picture: [] | [string];
+	given_name: [] | [string];
+	preferred_username: [] | [string];
+}
+export interface OpenIdGenericProviderConfig {
+	claims: [] | [OpenIdGenericProviderClaims];
+	jwks: Jwks;
+	issuer: string;
+	audiences: [] | [Array<string>];
 }
-export type OpenIdDelegationProvider = { GitHub: null } | { Google: null };
 export interface OpenIdGetDelegationArgs {
 	jwt: string;
 	session_key: Uint8Array;
@@ -347,6 +551,7 @@ export interface OpenIdPrepareDelegationArgs {
 export type Permission =
 	| { Controllers: null }
 	| { Private: null }
+	| { Roles: Array<string> }
 	| { Public: null }
 	| { Managed: null };
 export type PrepareAutomationError =
@@ -404,17 +609,28 @@ export interface RepositoryKey {
 	owner: string;
 	name: string;
 }
+export interface RestoreDocRevision {
+	version: [] | [bigint];
+	revision: bigint;
+}
 export interface Rule {
+	ttl: [] | [bigint];
 	max_capacity: [] | [number];
 	memory: [] | [Memory];
 	updated_at: bigint;
 	max_size: [] | [bigint];
+	schema: [] | [string];
+	content_validation: [] | [ContentValidation];
 	read: Permission;
+	history: [] | [HistoryConfig];
+	variants: [] | [Array<ImageVariant>];
 	created_at: bigint;
 	version: [] | [bigint];
 	mutable_permissions: [] | [boolean];
 	rate_config: [] | [RateConfig];
 	write: Permission;
+	indexes: [] | [Array<string>];
+	max_bytes_per_user: [] | [bigint];
 	max_changes_per_user: [] | [number];
 }
 export interface SegmentsDeploymentOptions {
@@ -424,10 +640,16 @@ export interface SegmentsDeploymentOptions {
 }
 export interface SetAccessKey {
 	metadata: Array<[string, string]>;
+	restrictions: [] | [AccessKeyRestrictions];
 	kind: [] | [AccessKeyKind];
 	scope: AccessKeyScope;
 	expires_at: [] | [bigint];
 }
+export interface SetAuditConfig {
+	max_entries: [] | [number];
+	retention: [] | [bigint];
+	version: [] | [bigint];
+}
 export interface SetAuthenticationConfig {
 	openid: [] | [AuthenticationConfigOpenId];
 	version: [] | [bigint];
@@ -450,24 +672,34 @@ export interface SetDoc {
 	data: Uint8Array;
 	description: [] | [string];
 	version: [] | [bigint];
+	expires_at: [] | [bigint];
 }
 export interface SetRule {
+	ttl: [] | [bigint];
 	max_capacity: [] | [number];
 	memory: [] | [Memory];
 	max_size: [] | [bigint];
+	schema: [] | [string];
+	content_validation: [] | [ContentValidation];
 	read: Permission;
+	history: [] | [HistoryConfig];
+	variants: [] | [Array<ImageVariant>];
 	version: [] | [bigint];
 	mutable_permissions: [] | [boolean];
 	rate_config: [] | [RateConfig];
 	write: Permission;
+	indexes: [] | [Array<string>];
+	max_bytes_per_user: [] | [bigint];
 	max_changes_per_user: [] | [number];
 }
 export interface SetStorageConfig {
 	iframe: [] | [StorageConfigIFrame];
 	rewrites: Array<[string, string]>;
 	headers: Array<[string, Array<[string, string]>]>;
+	compression: [] | [Array<StorageConfigCompression>];
 	version: [] | [bigint];
 	max_memory_size: [] | [ConfigMaxMemorySize];
+	domain_roots: [] | [Array<[string, StorageConfigDomainRoot]>];
 	raw_access: [] | [StorageConfigRawAccess];
 	redirects: [] | [Array<[string, StorageConfigRedirect]>];
 }
@@ -478,6 +710,10 @@ export interface SetStorageConfigWithOptions {
 	config: SetStorageConfig;
 	options: SetStorageConfigOptions;
 }
+export interface SetUserUsageConfig {
+	version: [] | [bigint];
+	max_bytes_per_user: [] | [bigint];
+}
 export interface SignedDelegation {
 	signature: Uint8Array;
 	delegation: Delegation;
@@ -488,16 +724,21 @@ export interface StorageConfig {
 	rewrites: Array<[string, string]>;
 	headers: Array<[string, Array<[string, string]>]>;
 	created_at: [] | [bigint];
+	compression: [] | [Array<StorageConfigCompression>];
 	version: [] | [bigint];
 	max_memory_size: [] | [ConfigMaxMemorySize];
+	domain_roots: [] | [Array<[string, StorageConfigDomainRoot]>];
 	raw_access: [] | [StorageConfigRawAccess];
 	redirects: [] | [Array<[string, StorageConfigRedirect]>];
 }
+export type StorageConfigCompression = { Gzip: null } | { Brotli: null };
+export type StorageConfigDomainRoot = { Collection: string } | { Path: string };
 export type StorageConfigIFrame = { Deny: null } | { AllowAny: null } | { SameOrigin: null };
 export type StorageConfigRawAccess = { Deny: null } | { Allow: null };
 export interface StorageConfigRedirect {
 	status_code: number;
 	location: string;
+	preserve_query: [] | [boolean];
 }
 export interface StreamingCallbackHttpResponse {
 	token: [] | [StreamingCallbackToken];
@@ -506,6 +747,7 @@ export interface StreamingCallbackHttpResponse {
 export interface StreamingCallbackToken {
 	memory: Memory;
 	token: [] | [string];
+	signature: [] | [AssetUrlSignature];
 	sha256: [] | [Uint8Array];
 	headers: Array<[string, string]>;
 	index: bigint;
@@ -531,14 +773,63 @@ export interface UploadChunk {
 export interface UploadChunkResult {
 	chunk_id: bigint;
 }
+export interface UploadSession {
+	collection: string;
+	batch_id: bigint;
+	chunks: Array<UploadSessionChunk>;
+	expires_at: bigint;
+	full_path: string;
+	total_length: bigint;
+}
+export interface UploadSessionChunk {
+	offset: bigint;
+	chunk_id: bigint;
+	length: bigint;
+	order_id: bigint;
+}
+export interface UserCollectionUsage {
+	collection: string;
+	collection_type: CollectionType;
+	changes_count: number;
+	max_bytes: [] | [bigint];
+	bytes: bigint;
+}
+export interface UserRolesArgs {
+	user: Principal;
+	roles: Array<string>;
+}
+export interface UserSession {
+	created_at: bigint;
+	expires_at: bigint;
+}
+export interface UserSessions {
+	revoked_until: [] | [bigint];
+	revoked_at: [] | [bigint];
+	sessions: Array<UserSession>;
+}
+export interface UserUsage {
+	collections: Array<UserCollectionUsage>;
+	max_bytes: [] | [bigint];
+	bytes: bigint;
+}
+export interface UserUsageConfig {
+	updated_at: [] | [bigint];
+	created_at: [] | [bigint];
+	version: [] | [bigint];
+	max_bytes_per_user: [] | [bigint];
+}
 export interface _SERVICE {
+	assign_user_roles: ActorMethod<[UserRolesArgs], Array<string>>;
 	authenticate: ActorMethod<[AuthenticationArgs], AuthenticateResultResponse>;
 	authenticate_automation: ActorMethod<
 		[AuthenticateAutomationArgs],
 		AuthenticateAutomationResultResponse
 	>;
+	ban_user: ActorMethod<[BanUserArgs], BannedUser>;
 	certify_assets_chunk: ActorMethod<[CertifyAssetsArgs], CertifyAssetsResult>;
+	clear_user_sessions_revocation: ActorMethod<[Principal], [] | [UserSessions]>;
 	commit_asset_upload: ActorMethod<[CommitBatch], undefined>;
+	commit_docs: ActorMethod<[Array<CommitDocOperation>], Array<[string, Doc]>>;
 	commit_proposal: ActorMethod<[CommitProposal], null>;
 	commit_proposal_asset_upload: ActorMethod<[CommitBatch], undefined>;
 	commit_proposal_many_assets_upload: ActorMethod<[Array<CommitBatch>], undefined>;
@@ -562,6 +853,7 @@ export interface _SERVICE {
 	delete_proposal_assets: ActorMethod<[DeleteProposalAssets], undefined>;
 	deposit_cycles: ActorMethod<[DepositCyclesArgs], undefined>;
 	get_asset: ActorMethod<[string, string], [] | [AssetNoContent]>;
+	get_audit_config: ActorMethod<[], [] | [AuditConfig]>;
 	get_auth_config: ActorMethod<[], [] | [AuthenticationConfig]>;
 	get_automation_config: ActorMethod<[], [] | [AutomationConfig]>;
 	get_config: ActorMethod<[], Config>;
@@ -573,11 +865,16 @@ export interface _SERVICE {
 	get_proposal: ActorMethod<[bigint], [] | [Proposal]>;
 	get_rule: ActorMethod<[CollectionType, string], [] | [Rule]>;
 	get_storage_config: ActorMethod<[], StorageConfig>;
+	get_upload_session: ActorMethod<[bigint], UploadSession>;
+	get_user_sessions: ActorMethod<[Principal], [] | [UserSessions]>;
+	get_user_usage: ActorMethod<[], UserUsage>;
+	get_user_usage_config: ActorMethod<[], [] | [UserUsageConfig]>;
 	http_request: ActorMethod<[HttpRequest], HttpResponse>;
 	http_request_streaming_callback: ActorMethod<
 		[StreamingCallbackToken],
 		StreamingCallbackHttpResponse
 	>;
+	http_request_update: ActorMethod<[HttpRequest], HttpResponse>;
 	init_asset_upload: ActorMethod<[InitAssetKey], InitUploadResult>;
 	init_proposal: ActorMethod<[ProposalType], [bigint, Proposal]>;
 	init_proposal_asset_upload: ActorMethod<[InitAssetKey, bigint], InitUploadResult>;
@@ -586,14 +883,21 @@ export interface _SERVICE {
 		Array<[string, InitUploadResult]>
 	>;
 	list_assets: ActorMethod<[string, ListParams], ListResults>;
+	list_audit_logs: ActorMethod<[ListAuditLogsParams], ListAuditLogsResults>;
+	list_banned_users: ActorMethod<[ListBannedUsersParams], ListBannedUsersResults>;
 	list_controllers: ActorMethod<[], Array<[Principal, AccessKey]>>;
 	list_custom_domains: ActorMethod<[], Array<[string, CustomDomain]>>;
+	list_doc_revisions: ActorMethod<[string, string], Array<DocRevision>>;
 	list_docs: ActorMethod<[string, ListParams], ListResults_1>;
 	list_proposals: ActorMethod<[ListProposalsParams], ListProposalResults>;
 	list_rules: ActorMethod<[CollectionType, ListRulesParams], ListRulesResults>;
 	memory_size: ActorMethod<[], MemorySize>;
 	reject_proposal: ActorMethod<[CommitProposal], null>;
+	restore_doc_revision: ActorMethod<[string, string, RestoreDocRevision], Doc>;
+	revoke_user_roles: ActorMethod<[UserRolesArgs], Array<string>>;
+	revoke_user_sessions: ActorMethod<[Principal], UserSessions>;
 	set_asset_token: ActorMethod<[string, string, [] | [string]], undefined>;
+	set_audit_config: ActorMethod<[SetAuditConfig], AuditConfig>;
 	set_auth_config: ActorMethod<[SetAuthenticationConfig], AuthenticationConfig>;
 	set_automation_config: ActorMethod<[SetAutomationConfig], AutomationConfig>;
 	set_controllers: ActorMethod<[SetControllersArgs], Array<[Principal, AccessKey]>>;
@@ -604,8 +908,11 @@ export interface _SERVICE {
 	set_rule: ActorMethod<[CollectionType, string, SetRule], Rule>;
 	set_storage_config: ActorMethod<[SetStorageConfig], StorageConfig>;
 	set_storage_config_with_options: ActorMethod<[SetStorageConfigWithOptions], StorageConfig>;
+	set_user_usage_config: ActorMethod<[SetUserUsageConfig], UserUsageConfig>;
+	sign_asset_url: ActorMethod<[string, string, bigint], string>;
 	submit_proposal: ActorMethod<[bigint], [bigint, Proposal]>;
 	switch_storage_system_memory: ActorMethod<[], undefined>;
+	unban_user: ActorMethod<[Principal], undefined>;
 	upload_asset_chunk: ActorMethod<[UploadChunk], UploadChunkResult>;
 	upload_proposal_asset_chunk: ActorMethod<[UploadChunk], UploadChunkResult>;
 }
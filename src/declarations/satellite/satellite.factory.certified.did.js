@@ -7,12 +7,17 @@
 // Additionally, you should also exclude this file from your linter and/or formatter to prevent it from being checked or modified.
 
 export const idlFactory = ({ IDL }) => {
+	const DataMatcher = IDL.Rec();
 	const Memory = IDL.Variant({ Heap: IDL.Null, Stable: IDL.Null });
 	const InitStorageArgs = IDL.Record({ system_memory: IDL.Opt(Memory) });
 	const InitSatelliteArgs = IDL.Record({
 		controllers: IDL.Vec(IDL.Principal),
 		storage: IDL.Opt(InitStorageArgs)
 	});
+	const UserRolesArgs = IDL.Record({
+		user: IDL.Principal,
+		roles: IDL.Vec(IDL.Text)
+	});
 	const OpenIdPrepareDelegationArgs = IDL.Record({
 		jwt: IDL.Text,
 		session_key: IDL.Vec(IDL.Nat8),
@@ -27,7 +32,8 @@ export const idlFactory = ({ IDL }) => {
 		data: IDL.Vec(IDL.Nat8),
 		description: IDL.Opt(IDL.Text),
 		created_at: IDL.Nat64,
-		version: IDL.Opt(IDL.Nat64)
+		version: IDL.Opt(IDL.Nat64),
+		expires_at: IDL.Opt(IDL.Nat64)
 	});
 	const PreparedDelegation = IDL.Record({
 		user_key: IDL.Vec(IDL.Nat8),
@@ -111,12 +117,27 @@ export const idlFactory = ({ IDL }) => {
 		Ok: IDL.Tuple(IDL.Principal, AutomationController),
 		Err: AuthenticationAutomationError
 	});
+	const BanUserArgs = IDL.Record({
+		code: IDL.Opt(IDL.Text),
+		user: IDL.Principal,
+		expires_at: IDL.Opt(IDL.Nat64),
+		reason: IDL.Opt(IDL.Text)
+	});
+	const BannedUser = IDL.Record({
+		code: IDL.Opt(IDL.Text),
+		user: IDL.Principal,
+		banned_at: IDL.Opt(IDL.Nat64),
+		banned_by: IDL.Opt(IDL.Principal),
+		expires_at: IDL.Opt(IDL.Nat64),
+		reason: IDL.Opt(IDL.Text)
+	});
 	const AssetKey = IDL.Record({
 		token: IDL.Opt(IDL.Text),
 		collection: IDL.Text,
 		owner: IDL.Principal,
 		name: IDL.Text,
 		description: IDL.Opt(IDL.Text),
+		expires_at: IDL.Opt(IDL.Nat64),
 		full_path: IDL.Text
 	});
 	const CertifyAssetsCursor = IDL.Variant({
@@ -136,11 +157,32 @@ export const idlFactory = ({ IDL }) => {
 	const CertifyAssetsResult = IDL.Record({
 		next_cursor: IDL.Opt(CertifyAssetsCursor)
 	});
+	const UserSession = IDL.Record({
+		created_at: IDL.Nat64,
+		expires_at: IDL.Nat64
+	});
+	const UserSessions = IDL.Record({
+		revoked_until: IDL.Opt(IDL.Nat64),
+		revoked_at: IDL.Opt(IDL.Nat64),
+		sessions: IDL.Vec(UserSession)
+	});
 	const CommitBatch = IDL.Record({
+		sha256: IDL.Opt(IDL.Vec(IDL.Nat8)),
 		batch_id: IDL.Nat,
 		headers: IDL.Vec(IDL.Tuple(IDL.Text, IDL.Text)),
 		chunk_ids: IDL.Vec(IDL.Nat)
 	});
+	const SetDoc = IDL.Record({
+		data: IDL.Vec(IDL.Nat8),
+		description: IDL.Opt(IDL.Text),
+		version: IDL.Opt(IDL.Nat64),
+		expires_at: IDL.Opt(IDL.Nat64)
+	});
+	const DelDoc = IDL.Record({ version: IDL.Opt(IDL.Nat64) });
+	const CommitDocOperation = IDL.Variant({
+		Set: IDL.Tuple(IDL.Text, IDL.Text, SetDoc),
+		Delete: IDL.Tuple(IDL.Text, IDL.Text, DelDoc)
+	});
 	const CommitProposal = IDL.Record({
 		sha256: IDL.Vec(IDL.Nat8),
 		proposal_id: IDL.Nat
@@ -157,13 +199,52 @@ export const idlFactory = ({ IDL }) => {
 		GreaterThan: IDL.Nat64,
 		LessThan: IDL.Nat64
 	});
+	const IndexValue = IDL.Variant({
+		Bool: IDL.Bool,
+		Null: IDL.Null,
+		Text: IDL.Text,
+		Number: IDL.Float64
+	});
+	const DataValueMatcher = IDL.Variant({
+		In: IDL.Vec(IndexValue),
+		Equal: IndexValue,
+		Between: IDL.Tuple(IDL.Float64, IDL.Float64),
+		GreaterThan: IDL.Float64,
+		LessThan: IDL.Float64,
+		Exists: IDL.Null
+	});
+	const DataFieldMatcher = IDL.Record({
+		field: IDL.Text,
+		value: DataValueMatcher
+	});
+	DataMatcher.fill(
+		IDL.Variant({
+			Or: IDL.Vec(DataMatcher),
+			And: IDL.Vec(DataMatcher),
+			Not: DataMatcher,
+			Field: DataFieldMatcher
+		})
+	);
+	const IndexValueMatcher = IDL.Variant({
+		Equal: IndexValue,
+		Between: IDL.Tuple(IndexValue, IndexValue),
+		GreaterThan: IndexValue,
+		LessThan: IndexValue
+	});
+	const IndexMatcher = IDL.Record({
+		field: IDL.Text,
+		value: IndexValueMatcher
+	});
 	const ListMatcher = IDL.Record({
 		key: IDL.Opt(IDL.Text),
 		updated_at: IDL.Opt(TimestampMatcher),
+		data: IDL.Opt(DataMatcher),
 		description: IDL.Opt(IDL.Text),
-		created_at: IDL.Opt(TimestampMatcher)
+		created_at: IDL.Opt(TimestampMatcher),
+		index: IDL.Opt(IndexMatcher)
 	});
 	const ListPaginate = IDL.Record({
+		cursor: IDL.Opt(IDL.Vec(IDL.Nat8)),
 		start_after: IDL.Opt(IDL.Text),
 		limit: IDL.Opt(IDL.Nat64)
 	});
@@ -176,6 +257,14 @@ export const idlFactory = ({ IDL }) => {
 	const DeleteControllersArgs = IDL.Record({
 		controllers: IDL.Vec(IDL.Principal)
 	});
+	const AccessKeyCollection = IDL.Variant({
+		Db: IDL.Text,
+		Storage: IDL.Text
+	});
+	const AccessKeyRestrictions = IDL.Record({
+		read_only: IDL.Bool,
+		collections: IDL.Opt(IDL.Vec(AccessKeyCollection))
+	});
 	const AccessKeyKind = IDL.Variant({
 		Emulator: IDL.Null,
 		Automation: IDL.Null
@@ -188,12 +277,12 @@ export const idlFactory = ({ IDL }) => {
 	const AccessKey = IDL.Record({
 		updated_at: IDL.Nat64,
 		metadata: IDL.Vec(IDL.Tuple(IDL.Text, IDL.Text)),
+		restrictions: IDL.Opt(AccessKeyRestrictions),
 		kind: IDL.Opt(AccessKeyKind),
 		created_at: IDL.Nat64,
 		scope: AccessKeyScope,
 		expires_at: IDL.Opt(IDL.Nat64)
 	});
-	const DelDoc = IDL.Record({ version: IDL.Opt(IDL.Nat64) });
 	const CollectionType = IDL.Variant({ Db: IDL.Null, Storage: IDL.Null });
 	const DelRule = IDL.Record({ version: IDL.Opt(IDL.Nat64) });
 	const DeleteProposalAssets = IDL.Record({
@@ -216,16 +305,67 @@ export const idlFactory = ({ IDL }) => {
 		created_at: IDL.Nat64,
 		version: IDL.Opt(IDL.Nat64)
 	});
+	const AuditConfig = IDL.Record({
+		updated_at: IDL.Opt(IDL.Nat64),
+		max_entries: IDL.Opt(IDL.Nat32),
+		retention: IDL.Opt(IDL.Nat64),
+		created_at: IDL.Opt(IDL.Nat64),
+		version: IDL.Opt(IDL.Nat64)
+	});
 	const OpenIdDelegationProvider = IDL.Variant({
 		GitHub: IDL.Null,
+		Generic: IDL.Text,
 		Google: IDL.Null
 	});
 	const OpenIdAuthProviderDelegationConfig = IDL.Record({
 		targets: IDL.Opt(IDL.Vec(IDL.Principal)),
 		max_time_to_live: IDL.Opt(IDL.Nat64)
 	});
+	const OpenIdGenericProviderClaims = IDL.Record({
+		name: IDL.Opt(IDL.Text),
+		locale: IDL.Opt(IDL.Text),
+		family_name: IDL.Opt(IDL.Text),
+		email: IDL.Opt(IDL.Text),
+		picture: IDL.Opt(IDL.Text),
+		given_name: IDL.Opt(IDL.Text),
+		preferred_username: IDL.Opt(IDL.Text)
+	});
+	const JwkType = IDL.Variant({
+		EC: IDL.Null,
+		OKP: IDL.Null,
+		RSA: IDL.Null,
+		oct: IDL.Null
+	});
+	const JwkParamsEc = IDL.Record({
+		x: IDL.Text,
+		y: IDL.Text,
+		crv: IDL.Text
+	});
+	const JwkParamsOct = IDL.Record({ k: IDL.Text });
+	const JwkParamsOkp = IDL.Record({ x: IDL.Text, crv: IDL.Text });
+	const JwkParamsRsa = IDL.Record({ e: IDL.Text, n: IDL.Text });
+	const JwkParams = IDL.Variant({
+		Ec: JwkParamsEc,
+		Oct: JwkParamsOct,
+		Okp: JwkParamsOkp,
+		Rsa: JwkParamsRsa
+	});
+	const Jwk = IDL.Record({
+		alg: IDL.Opt(IDL.Text),
+		kid: IDL.Opt(IDL.Text),
+		kty: JwkType,
+		params: JwkParams
+	});
+	const Jwks = IDL.Record({ keys: IDL.Vec(Jwk) });
+	const OpenIdGenericProviderConfig = IDL.Record({
+		claims: IDL.Opt(OpenIdGenericProviderClaims),
+		jwks: Jwks,
+		issuer: IDL.Text,
+		audiences: IDL.Opt(IDL.Vec(IDL.Text))
+	});
 	const OpenIdAuthProviderConfig = IDL.Record({
 		delegation: IDL.Opt(OpenIdAuthProviderDelegationConfig),
+		generic: IDL.Opt(OpenIdGenericProviderConfig),
 		client_id: IDL.Text
 	});
 	const AuthenticationConfigOpenId = IDL.Record({
@@ -247,18 +387,27 @@ export const idlFactory = ({ IDL }) => {
 		internet_identity: IDL.Opt(AuthenticationConfigInternetIdentity),
 		rules: IDL.Opt(AuthenticationRules)
 	});
-	const OpenIdAutomationProvider = IDL.Variant({ GitHub: IDL.Null });
+	const OpenIdAutomationProvider = IDL.Variant({
+		GitHub: IDL.Null,
+		GitLab: IDL.Null
+	});
 	const OpenIdAutomationProviderControllerConfig = IDL.Record({
 		scope: IDL.Opt(AutomationScope),
 		max_time_to_live: IDL.Opt(IDL.Nat64)
 	});
 	const RepositoryKey = IDL.Record({ owner: IDL.Text, name: IDL.Text });
 	const OpenIdAutomationRepositoryConfig = IDL.Record({
-		refs: IDL.Opt(IDL.Vec(IDL.Text))
+		refs: IDL.Opt(IDL.Vec(IDL.Text)),
+		protected_refs: IDL.Opt(IDL.Bool)
+	});
+	const OpenIdAutomationSelfHostedConfig = IDL.Record({
+		jwks: Jwks,
+		issuer: IDL.Text
 	});
 	const OpenIdAutomationProviderConfig = IDL.Record({
 		controller: IDL.Opt(OpenIdAutomationProviderControllerConfig),
-		repositories: IDL.Vec(IDL.Tuple(RepositoryKey, OpenIdAutomationRepositoryConfig))
+		repositories: IDL.Vec(IDL.Tuple(RepositoryKey, OpenIdAutomationRepositoryConfig)),
+		self_hosted: IDL.Opt(OpenIdAutomationSelfHostedConfig)
 	});
 	const AutomationConfigOpenId = IDL.Record({
 		observatory_id: IDL.Opt(IDL.Principal),
@@ -285,13 +434,22 @@ export const idlFactory = ({ IDL }) => {
 		AllowAny: IDL.Null,
 		SameOrigin: IDL.Null
 	});
+	const StorageConfigCompression = IDL.Variant({
+		Gzip: IDL.Null,
+		Brotli: IDL.Null
+	});
+	const StorageConfigDomainRoot = IDL.Variant({
+		Collection: IDL.Text,
+		Path: IDL.Text
+	});
 	const StorageConfigRawAccess = IDL.Variant({
 		Deny: IDL.Null,
 		Allow: IDL.Null
 	});
 	const StorageConfigRedirect = IDL.Record({
 		status_code: IDL.Nat16,
-		location: IDL.Text
+		location: IDL.Text,
+		preserve_query: IDL.Opt(IDL.Bool)
 	});
 	const StorageConfig = IDL.Record({
 		iframe: IDL.Opt(StorageConfigIFrame),
@@ -299,8 +457,10 @@ export const idlFactory = ({ IDL }) => {
 		rewrites: IDL.Vec(IDL.Tuple(IDL.Text, IDL.Text)),
 		headers: IDL.Vec(IDL.Tuple(IDL.Text, IDL.Vec(IDL.Tuple(IDL.Text, IDL.Text)))),
 		created_at: IDL.Opt(IDL.Nat64),
+		compression: IDL.Opt(IDL.Vec(StorageConfigCompression)),
 		version: IDL.Opt(IDL.Nat64),
 		max_memory_size: IDL.Opt(ConfigMaxMemorySize),
+		domain_roots: IDL.Opt(IDL.Vec(IDL.Tuple(IDL.Text, StorageConfigDomainRoot))),
 		raw_access: IDL.Opt(StorageConfigRawAccess),
 		redirects: IDL.Opt(IDL.Vec(IDL.Tuple(IDL.Text, StorageConfigRedirect)))
 	});
@@ -369,29 +529,88 @@ export const idlFactory = ({ IDL }) => {
 		version: IDL.Opt(IDL.Nat64),
 		proposal_type: ProposalType
 	});
+	const ContentValidation = IDL.Record({
+		max_image_width: IDL.Opt(IDL.Nat32),
+		max_image_height: IDL.Opt(IDL.Nat32)
+	});
 	const Permission = IDL.Variant({
 		Controllers: IDL.Null,
 		Private: IDL.Null,
+		Roles: IDL.Vec(IDL.Text),
 		Public: IDL.Null,
 		Managed: IDL.Null
 	});
+	const HistoryConfig = IDL.Record({
+		retention: IDL.Opt(IDL.Nat64),
+		max_revisions: IDL.Opt(IDL.Nat32)
+	});
+	const ImageFormat = IDL.Variant({
+		Png: IDL.Null,
+		Jpeg: IDL.Null,
+		WebP: IDL.Null
+	});
+	const ImageVariant = IDL.Record({
+		max_height: IDL.Opt(IDL.Nat32),
+		name: IDL.Text,
+		max_width: IDL.Opt(IDL.Nat32),
+		format: IDL.Opt(ImageFormat)
+	});
 	const RateConfig = IDL.Record({
 		max_tokens: IDL.Nat64,
 		time_per_token_ns: IDL.Nat64
 	});
 	const Rule = IDL.Record({
+		ttl: IDL.Opt(IDL.Nat64),
 		max_capacity: IDL.Opt(IDL.Nat32),
 		memory: IDL.Opt(Memory),
 		updated_at: IDL.Nat64,
 		max_size: IDL.Opt(IDL.Nat),
+		schema: IDL.Opt(IDL.Text),
+		content_validation: IDL.Opt(ContentValidation),
 		read: Permission,
+		history: IDL.Opt(HistoryConfig),
+		variants: IDL.Opt(IDL.Vec(ImageVariant)),
 		created_at: IDL.Nat64,
 		version: IDL.Opt(IDL.Nat64),
 		mutable_permissions: IDL.Opt(IDL.Bool),
 		rate_config: IDL.Opt(RateConfig),
 		write: Permission,
+		indexes: IDL.Opt(IDL.Vec(IDL.Text)),
+		max_bytes_per_user: IDL.Opt(IDL.Nat64),
 		max_changes_per_user: IDL.Opt(IDL.Nat32)
 	});
+	const UploadSessionChunk = IDL.Record({
+		offset: IDL.Nat,
+		chunk_id: IDL.Nat,
+		length: IDL.Nat,
+		order_id: IDL.Nat
+	});
+	const UploadSession = IDL.Record({
+		collection: IDL.Text,
+		batch_id: IDL.Nat,
+		chunks: IDL.Vec(UploadSessionChunk),
+		expires_at: IDL.Nat64,
+		full_path: IDL.Text,
+		total_length: IDL.Nat
+	});
+	const UserCollectionUsage = IDL.Record({
+		collection: IDL.Text,
+		collection_type: CollectionType,
+		changes_count: IDL.Nat32,
+		max_bytes: IDL.Opt(IDL.Nat64),
+		bytes: IDL.Nat64
+	});
+	const UserUsage = IDL.Record({
+		collections: IDL.Vec(UserCollectionUsage),
+		max_bytes: IDL.Opt(IDL.Nat64),
+		bytes: IDL.Nat64
+	});
+	const UserUsageConfig = IDL.Record({
+		updated_at: IDL.Opt(IDL.Nat64),
+		created_at: IDL.Opt(IDL.Nat64),
+		version: IDL.Opt(IDL.Nat64),
+		max_bytes_per_user: IDL.Opt(IDL.Nat64)
+	});
 	const HttpRequest = IDL.Record({
 		url: IDL.Text,
 		method: IDL.Text,
@@ -399,9 +618,14 @@ export const idlFactory = ({ IDL }) => {
 		headers: IDL.Vec(IDL.Tuple(IDL.Text, IDL.Text)),
 		certificate_version: IDL.Opt(IDL.Nat16)
 	});
+	const AssetUrlSignature = IDL.Record({
+		signature: IDL.Text,
+		expires_at: IDL.Nat64
+	});
 	const StreamingCallbackToken = IDL.Record({
 		memory: Memory,
 		token: IDL.Opt(IDL.Text),
+		signature: IDL.Opt(AssetUrlSignature),
 		sha256: IDL.Opt(IDL.Vec(IDL.Nat8)),
 		headers: IDL.Vec(IDL.Tuple(IDL.Text, IDL.Text)),
 		index: IDL.Nat64,
@@ -417,6 +641,7 @@ export const idlFactory = ({ IDL }) => {
 	const HttpResponse = IDL.Record({
 		body: IDL.Vec(IDL.Nat8),
 		headers: IDL.Vec(IDL.Tuple(IDL.Text, IDL.Text)),
+		upgrade: IDL.Opt(IDL.Bool),
 		streaming_strategy: IDL.Opt(StreamingStrategy),
 		status_code: IDL.Nat16
 	});
@@ -429,6 +654,7 @@ export const idlFactory = ({ IDL }) => {
 		collection: IDL.Text,
 		name: IDL.Text,
 		description: IDL.Opt(IDL.Text),
+		expires_at: IDL.Opt(IDL.Nat64),
 		encoding_type: IDL.Opt(IDL.Text),
 		full_path: IDL.Text
 	});
@@ -437,24 +663,90 @@ export const idlFactory = ({ IDL }) => {
 		matches_pages: IDL.Opt(IDL.Nat64),
 		matches_length: IDL.Nat64,
 		items_page: IDL.Opt(IDL.Nat64),
+		next_cursor: IDL.Opt(IDL.Vec(IDL.Nat8)),
 		items: IDL.Vec(IDL.Tuple(IDL.Text, AssetNoContent)),
 		items_length: IDL.Nat64
 	});
+	const ListAuditLogsOrder = IDL.Record({ desc: IDL.Bool });
+	const ListAuditLogsPaginate = IDL.Record({
+		cursor: IDL.Opt(IDL.Vec(IDL.Nat8)),
+		start_after: IDL.Opt(IDL.Nat64),
+		limit: IDL.Opt(IDL.Nat64)
+	});
+	const ListAuditLogsParams = IDL.Record({
+		order: IDL.Opt(ListAuditLogsOrder),
+		paginate: IDL.Opt(ListAuditLogsPaginate)
+	});
+	const AuditLogEndpoint = IDL.Variant({
+		BanUser: IDL.Null,
+		DelControllerSelf: IDL.Null,
+		SetUserUsageConfig: IDL.Null,
+		RevokeUserRoles: IDL.Null,
+		AssignUserRoles: IDL.Null,
+		SetDbConfig: IDL.Null,
+		SetControllers: IDL.Null,
+		SetAutomationConfig: IDL.Null,
+		AuthenticateAutomation: IDL.Null,
+		SetCustomDomain: IDL.Null,
+		SetAuditConfig: IDL.Null,
+		DelCustomDomain: IDL.Null,
+		RevokeUserSessions: IDL.Null,
+		SetStorageConfig: IDL.Null,
+		ClearUserSessionsRevocation: IDL.Null,
+		DelRule: IDL.Null,
+		SetRule: IDL.Null,
+		UnbanUser: IDL.Null,
+		SetAuthConfig: IDL.Null,
+		DelControllers: IDL.Null
+	});
+	const AuditLog = IDL.Record({
+		after: IDL.Opt(IDL.Text),
+		endpoint: AuditLogEndpoint,
+		created_at: IDL.Nat64,
+		target: IDL.Opt(IDL.Text),
+		before: IDL.Opt(IDL.Text),
+		caller: IDL.Principal
+	});
+	const ListAuditLogsResults = IDL.Record({
+		next_cursor: IDL.Opt(IDL.Vec(IDL.Nat8)),
+		items: IDL.Vec(IDL.Tuple(IDL.Nat64, AuditLog)),
+		items_length: IDL.Nat64
+	});
+	const ListBannedUsersPaginate = IDL.Record({
+		cursor: IDL.Opt(IDL.Vec(IDL.Nat8)),
+		start_after: IDL.Opt(IDL.Principal),
+		limit: IDL.Opt(IDL.Nat64)
+	});
+	const ListBannedUsersParams = IDL.Record({
+		paginate: IDL.Opt(ListBannedUsersPaginate)
+	});
+	const ListBannedUsersResults = IDL.Record({
+		next_cursor: IDL.Opt(IDL.Vec(IDL.Nat8)),
+		items: IDL.Vec(BannedUser),
+		items_length: IDL.Nat64
+	});
 	const CustomDomain = IDL.Record({
 		updated_at: IDL.Nat64,
 		created_at: IDL.Nat64,
 		version: IDL.Opt(IDL.Nat64),
 		bn_id: IDL.Opt(IDL.Text)
 	});
+	const DocRevision = IDL.Record({
+		doc: Doc,
+		revision: IDL.Nat64,
+		archived_at: IDL.Nat64
+	});
 	const ListResults_1 = IDL.Record({
 		matches_pages: IDL.Opt(IDL.Nat64),
 		matches_length: IDL.Nat64,
 		items_page: IDL.Opt(IDL.Nat64),
+		next_cursor: IDL.Opt(IDL.Vec(IDL.Nat8)),
 		items: IDL.Vec(IDL.Tuple(IDL.Text, Doc)),
 		items_length: IDL.Nat64
 	});
 	const ListProposalsOrder = IDL.Record({ desc: IDL.Bool });
 	const ListProposalsPaginate = IDL.Record({
+		cursor: IDL.Opt(IDL.Vec(IDL.Nat8)),
 		start_after: IDL.Opt(IDL.Nat),
 		limit: IDL.Opt(IDL.Nat)
 	});
@@ -465,6 +757,7 @@ export const idlFactory = ({ IDL }) => {
 	const ProposalKey = IDL.Record({ proposal_id: IDL.Nat });
 	const ListProposalResults = IDL.Record({
 		matches_length: IDL.Nat64,
+		next_cursor: IDL.Opt(IDL.Vec(IDL.Nat8)),
 		items: IDL.Vec(IDL.Tuple(ProposalKey, Proposal)),
 		items_length: IDL.Nat64
 	});
@@ -476,6 +769,15 @@ export const idlFactory = ({ IDL }) => {
 		items_length: IDL.Nat64
 	});
 	const MemorySize = IDL.Record({ stable: IDL.Nat64, heap: IDL.Nat64 });
+	const RestoreDocRevision = IDL.Record({
+		version: IDL.Opt(IDL.Nat64),
+		revision: IDL.Nat64
+	});
+	const SetAuditConfig = IDL.Record({
+		max_entries: IDL.Opt(IDL.Nat32),
+		retention: IDL.Opt(IDL.Nat64),
+		version: IDL.Opt(IDL.Nat64)
+	});
 	const SetAuthenticationConfig = IDL.Record({
 		openid: IDL.Opt(AuthenticationConfigOpenId),
 		version: IDL.Opt(IDL.Nat64),
@@ -488,6 +790,7 @@ export const idlFactory = ({ IDL }) => {
 	});
 	const SetAccessKey = IDL.Record({
 		metadata: IDL.Vec(IDL.Tuple(IDL.Text, IDL.Text)),
+		restrictions: IDL.Opt(AccessKeyRestrictions),
 		kind: IDL.Opt(AccessKeyKind),
 		scope: AccessKeyScope,
 		expires_at: IDL.Opt(IDL.Nat64)
@@ -500,28 +803,32 @@ export const idlFactory = ({ IDL }) => {
 		version: IDL.Opt(IDL.Nat64),
 		max_memory_size: IDL.Opt(ConfigMaxMemorySize)
 	});
-	const SetDoc = IDL.Record({
-		data: IDL.Vec(IDL.Nat8),
-		description: IDL.Opt(IDL.Text),
-		version: IDL.Opt(IDL.Nat64)
-	});
 	const SetRule = IDL.Record({
+		ttl: IDL.Opt(IDL.Nat64),
 		max_capacity: IDL.Opt(IDL.Nat32),
 		memory: IDL.Opt(Memory),
 		max_size: IDL.Opt(IDL.Nat),
+		schema: IDL.Opt(IDL.Text),
+		content_validation: IDL.Opt(ContentValidation),
 		read: Permission,
+		history: IDL.Opt(HistoryConfig),
+		variants: IDL.Opt(IDL.Vec(ImageVariant)),
 		version: IDL.Opt(IDL.Nat64),
 		mutable_permissions: IDL.Opt(IDL.Bool),
 		rate_config: IDL.Opt(RateConfig),
 		write: Permission,
+		indexes: IDL.Opt(IDL.Vec(IDL.Text)),
+		max_bytes_per_user: IDL.Opt(IDL.Nat64),
 		max_changes_per_user: IDL.Opt(IDL.Nat32)
 	});
 	const SetStorageConfig = IDL.Record({
 		iframe: IDL.Opt(StorageConfigIFrame),
 		rewrites: IDL.Vec(IDL.Tuple(IDL.Text, IDL.Text)),
 		headers: IDL.Vec(IDL.Tuple(IDL.Text, IDL.Vec(IDL.Tuple(IDL.Text, IDL.Text)))),
+		compression: IDL.Opt(IDL.Vec(StorageConfigCompression)),
 		version: IDL.Opt(IDL.Nat64),
 		max_memory_size: IDL.Opt(ConfigMaxMemorySize),
+		domain_roots: IDL.Opt(IDL.Vec(IDL.Tuple(IDL.Text, StorageConfigDomainRoot))),
 		raw_access: IDL.Opt(StorageConfigRawAccess),
 		redirects: IDL.Opt(IDL.Vec(IDL.Tuple(IDL.Text, StorageConfigRedirect)))
 	});
@@ -532,6 +839,10 @@ export const idlFactory = ({ IDL }) => {
 		config: SetStorageConfig,
 		options: SetStorageConfigOptions
 	});
+	const SetUserUsageConfig = IDL.Record({
+		version: IDL.Opt(IDL.Nat64),
+		max_bytes_per_user: IDL.Opt(IDL.Nat64)
+	});
 	const UploadChunk = IDL.Record({
 		content: IDL.Vec(IDL.Nat8),
 		batch_id: IDL.Nat,
@@ -540,14 +851,18 @@ export const idlFactory = ({ IDL }) => {
 	const UploadChunkResult = IDL.Record({ chunk_id: IDL.Nat });
 
 	return IDL.Service({
+		assign_user_roles: IDL.Func([UserRolesArgs], [IDL.Vec(IDL.Text)], []),
 		authenticate: IDL.Func([AuthenticationArgs], [AuthenticateResultResponse], []),
 		authenticate_automation: IDL.Func(
 			[AuthenticateAutomationArgs],
 			[AuthenticateAutomationResultResponse],
 			[]
 		),
+		ban_user: IDL.Func([BanUserArgs], [BannedUser], []),
 		certify_assets_chunk: IDL.Func([CertifyAssetsArgs], [CertifyAssetsResult], []),
+		clear_user_sessions_revocation: IDL.Func([IDL.Principal], [IDL.Opt(UserSessions)], []),
 		commit_asset_upload: IDL.Func([CommitBatch], [], []),
+		commit_docs: IDL.Func([IDL.Vec(CommitDocOperation)], [IDL.Vec(IDL.Tuple(IDL.Text, Doc))], []),
 		commit_proposal: IDL.Func([CommitProposal], [IDL.Null], []),
 		commit_proposal_asset_upload: IDL.Func([CommitBatch], [], []),
 		commit_proposal_many_assets_upload: IDL.Func([IDL.Vec(CommitBatch)], [], []),
@@ -575,6 +890,7 @@ export const idlFactory = ({ IDL }) => {
 		delete_proposal_assets: IDL.Func([DeleteProposalAssets], [], []),
 		deposit_cycles: IDL.Func([DepositCyclesArgs], [], []),
 		get_asset: IDL.Func([IDL.Text, IDL.Text], [IDL.Opt(AssetNoContent)], []),
+		get_audit_config: IDL.Func([], [IDL.Opt(AuditConfig)], []),
 		get_auth_config: IDL.Func([], [IDL.Opt(AuthenticationConfig)], []),
 		get_automation_config: IDL.Func([], [IDL.Opt(AutomationConfig)], []),
 		get_config: IDL.Func([], [Config], []),
@@ -594,12 +910,17 @@ export const idlFactory = ({ IDL }) => {
 		get_proposal: IDL.Func([IDL.Nat], [IDL.Opt(Proposal)], []),
 		get_rule: IDL.Func([CollectionType, IDL.Text], [IDL.Opt(Rule)], []),
 		get_storage_config: IDL.Func([], [StorageConfig], []),
+		get_upload_session: IDL.Func([IDL.Nat], [UploadSession], []),
+		get_user_sessions: IDL.Func([IDL.Principal], [IDL.Opt(UserSessions)], []),
+		get_user_usage: IDL.Func([], [UserUsage], []),
+		get_user_usage_config: IDL.Func([], [IDL.Opt(UserUsageConfig)], []),
 		http_request: IDL.Func([HttpRequest], [HttpResponse], []),
 		http_request_streaming_callback: IDL.Func(
 			[StreamingCallbackToken],
 			[StreamingCallbackHttpResponse],
 			[]
 		),
+		http_request_update: IDL.Func([HttpRequest], [HttpResponse], []),
 		init_asset_upload: IDL.Func([InitAssetKey], [InitUploadResult], []),
 		init_proposal: IDL.Func([ProposalType], [IDL.Nat, Proposal], []),
 		init_proposal_asset_upload: IDL.Func([InitAssetKey, IDL.Nat], [InitUploadResult], []),
@@ -609,14 +930,21 @@ export const idlFactory = ({ IDL }) => {
 			[]
 		),
 		list_assets: IDL.Func([IDL.Text, ListParams], [ListResults], []),
+		list_audit_logs: IDL.Func([ListAuditLogsParams], [ListAuditLogsResults], []),
+		list_banned_users: IDL.Func([ListBannedUsersParams], [ListBannedUsersResults], []),
 		list_controllers: IDL.Func([], [IDL.Vec(IDL.Tuple(IDL.Principal, AccessKey))], []),
 		list_custom_domains: IDL.Func([], [IDL.Vec(IDL.Tuple(IDL.Text, CustomDomain))], []),
+		list_doc_revisions: IDL.Func([IDL.Text, IDL.Text], [IDL.Vec(DocRevision)], []),
 		list_docs: IDL.Func([IDL.Text, ListParams], [ListResults_1], []),
 		list_proposals: IDL.Func([ListProposalsParams], [ListProposalResults], []),
 		list_rules: IDL.Func([CollectionType, ListRulesParams], [ListRulesResults], []),
 		memory_size: IDL.Func([], [MemorySize], []),
 		reject_proposal: IDL.Func([CommitProposal], [IDL.Null], []),
+		restore_doc_revision: IDL.Func([IDL.Text, IDL.Text, RestoreDocRevision], [Doc], []),
+		revoke_user_roles: IDL.Func([UserRolesArgs], [IDL.Vec(IDL.Text)], []),
+		revoke_user_sessions: IDL.Func([IDL.Principal], [UserSessions], []),
 		set_asset_token: IDL.Func([IDL.Text, IDL.Text, IDL.Opt(IDL.Text)], [], []),
+		set_audit_config: IDL.Func([SetAuditConfig], [AuditConfig], []),
 		set_auth_config: IDL.Func([SetAuthenticationConfig], [AuthenticationConfig], []),
 		set_automation_config: IDL.Func([SetAutomationConfig], [AutomationConfig], []),
 		set_controllers: IDL.Func(
@@ -635,8 +963,11 @@ export const idlFactory = ({ IDL }) => {
 		set_rule: IDL.Func([CollectionType, IDL.Text, SetRule], [Rule], []),
 		set_storage_config: IDL.Func([SetStorageConfig], [StorageConfig], []),
 		set_storage_config_with_options: IDL.Func([SetStorageConfigWithOptions], [StorageConfig], []),
+		set_user_usage_config: IDL.Func([SetUserUsageConfig], [UserUsageConfig], []),
+		sign_asset_url: IDL.Func([IDL.Text, IDL.Text, IDL.Nat64], [IDL.Text], []),
 		submit_proposal: IDL.Func([IDL.Nat], [IDL.Nat, Proposal], []),
 		switch_storage_system_memory: IDL.Func([], [], []),
+		unban_user: IDL.Func([IDL.Principal], [], []),
 		upload_asset_chunk: IDL.Func([UploadChunk], [UploadChunkResult], []),
 		upload_proposal_asset_chunk: IDL.Func([UploadChunk], [UploadChunkResult], [])
 	});
@@ -5,5 +5,6 @@ mod prepare;
 pub mod types;
 mod utils;
 
+pub use constants::MAX_EXPIRATION_PERIOD_NS;
 pub use get::*;
 pub use prepare::*;
@@ -1,24 +1,66 @@
 use crate::openid::credentials::automation::types::interface::OpenIdAutomationCredential;
 use crate::openid::credentials::automation::types::token::AutomationClaims;
 use crate::openid::jwt::types::token::JwtClaims;
+use crate::openid::types::provider::OpenIdAutomationProvider;
 use jsonwebtoken::TokenData;
 
-impl From<TokenData<AutomationClaims>> for OpenIdAutomationCredential {
-    fn from(token: TokenData<AutomationClaims>) -> Self {
-        Self {
-            sub: token.claims.sub,
-            iss: token.claims.iss,
-            jti: token.claims.jti,
-            repository: token.claims.repository,
-            repository_owner: token.claims.repository_owner,
-            r#ref: token.claims.r#ref,
-            run_id: token.claims.run_id,
-            run_number: token.claims.run_number,
-            run_attempt: token.claims.run_attempt,
-            sha: token.claims.sha,
-            actor: token.claims.actor,
-            workflow: token.claims.workflow,
-            event_name: token.claims.event_name,
+impl OpenIdAutomationCredential {
+    pub(crate) fn from_token(
+        provider: &OpenIdAutomationProvider,
+        token: TokenData<AutomationClaims>,
+    ) -> Self {
+        let claims = token.claims;
+
+        match provider {
+            OpenIdAutomationProvider::GitHub => Self {
+                sub: claims.sub,
+                iss: claims.iss,
+                jti: claims.jti,
+                repository: claims.repository,
+                repository_owner: claims.repository_owner,
+                r#ref: claims.r#ref,
+                run_id: claims.run_id,
+                run_number: claims.run_number,
+                run_attempt: claims.run_attempt,
+                sha: claims.sha,
+                actor: claims.actor,
+                workflow: claims.workflow,
+                event_name: claims.event_name,
+            },
+            // GitLab has no run number, attempt or workflow name. A retried job runs within the same pipeline.
+            OpenIdAutomationProvider::GitLab => Self {
+                sub: claims.sub,
+                iss: claims.iss,
+                jti: claims.jti,
+                repository: claims.project_path,
+                repository_owner: claims.namespace_path,
+                r#ref: claims.ref_path,
+                run_id: claims.pipeline_id,
+                run_number: None,
+                run_attempt: None,
+                sha: claims.sha,
+                actor: claims.user_login,
+                workflow: None,
+                event_name: claims.pipeline_source,
+            },
+        }
+    }
+}
+
+impl AutomationClaims {
+    // The repository - or project path - that was used to run the automation.
+    pub(crate) fn repository(&self, provider: &OpenIdAutomationProvider) -> Option<&String> {
+        match provider {
+            OpenIdAutomationProvider::GitHub => self.repository.as_ref(),
+            OpenIdAutomationProvider::GitLab => self.project_path.as_ref(),
+        }
+    }
+
+    // The full reference - e.g. "refs/heads/main" - that triggered the automation.
+    pub(crate) fn full_ref(&self, provider: &OpenIdAutomationProvider) -> Option<&String> {
+        match provider {
+            OpenIdAutomationProvider::GitHub => self.r#ref.as_ref(),
+            OpenIdAutomationProvider::GitLab => self.ref_path.as_ref(),
         }
     }
 }
@@ -28,7 +70,7 @@ impl JwtClaims for AutomationClaims {
         self.iat
     }
 
-    // We use the audience to match the caller's principal + salt because GitHub does not allow customizing
+    // We use the audience to match the caller's principal + salt because GitHub and GitLab do not allow customizing
     // other JWT fields, making audience our only option for binding the JWT to a specific principal.
     fn nonce(&self) -> Option<&str> {
         Some(&self.aud)
@@ -6,6 +6,7 @@ pub mod interface {
         pub jti: Option<String>,
 
         // See https://docs.github.com/en/actions/concepts/security/openid-connect#understanding-the-oidc-token
+        // GitLab claims are mapped onto the same fields, see https://docs.gitlab.com/ci/secrets/id_token_authentication/#token-payload
         pub repository: Option<String>,       // "octo-org/octo-repo"
         pub repository_owner: Option<String>, // "octo-org"
         pub r#ref: Option<String>,            // "refs/heads/main"
@@ -43,5 +44,14 @@ pub(crate) mod token {
         pub actor: Option<String>,
         pub workflow: Option<String>,
         pub event_name: Option<String>,
+        pub ref_protected: Option<String>,
+
+        // GitLab
+        pub project_path: Option<String>,
+        pub namespace_path: Option<String>,
+        pub ref_path: Option<String>,
+        pub pipeline_id: Option<String>,
+        pub pipeline_source: Option<String>,
+        pub user_login: Option<String>,
     }
 }
@@ -5,6 +5,7 @@ use crate::openid::credentials::types::errors::VerifyOpenidCredentialsError;
 use crate::openid::jwkset::get_or_refresh_jwks;
 use crate::openid::jwt::types::cert::Jwks;
 use crate::openid::jwt::types::errors::JwtVerifyError;
+use crate::openid::jwt::types::provider::JwtIssuers;
 use crate::openid::jwt::{unsafe_find_jwt_provider, verify_openid_jwt};
 use crate::openid::types::provider::{OpenIdAutomationProvider, OpenIdProvider};
 use crate::state::types::automation::{
@@ -16,7 +17,7 @@ use crate::strategies::AuthHeapStrategy;
 type VerifyOpenIdAutomationCredentialsResult =
     Result<(OpenIdAutomationCredential, OpenIdAutomationProvider), VerifyOpenidCredentialsError>;
 
-/// Verifies automation OIDC credentials (e.g. GitHub Actions) and returns the credential.
+/// Verifies automation OIDC credentials (e.g. GitHub Actions or GitLab CI) and returns the credential.
 ///
 /// ⚠️ **Warning:** This function does NOT enforce replay protection via JTI tracking.
 ///
@@ -35,14 +36,20 @@ pub async fn verify_openid_credentials_with_jwks_renewal(
     let (automation_provider, config) = unsafe_find_jwt_provider(providers, jwt)
         .map_err(VerifyOpenidCredentialsError::JwtFindProvider)?;
 
-    let provider: OpenIdProvider = (&automation_provider).into();
+    let jwks = match &config.self_hosted {
+        // The key set of a self-managed instance is part of the configuration.
+        Some(self_hosted) => self_hosted.jwks.clone(),
+        None => {
+            let provider: OpenIdProvider = (&automation_provider).into();
 
-    let observatory_id = target_observatory_id(auth_heap)
-        .map_err(VerifyOpenidCredentialsError::InvalidObservatoryId)?;
+            let observatory_id = target_observatory_id(auth_heap)
+                .map_err(VerifyOpenidCredentialsError::InvalidObservatoryId)?;
 
-    let jwks = get_or_refresh_jwks(&provider, jwt, observatory_id, auth_heap)
-        .await
-        .map_err(VerifyOpenidCredentialsError::GetOrFetchJwks)?;
+            get_or_refresh_jwks(&provider, jwt, observatory_id, auth_heap)
+                .await
+                .map_err(VerifyOpenidCredentialsError::GetOrFetchJwks)?
+        }
+    };
 
     verify_openid_credentials(jwt, &jwks, &automation_provider, config, salt)
 }
@@ -56,19 +63,11 @@ fn verify_openid_credentials(
 ) -> VerifyOpenIdAutomationCredentialsResult {
     let assert_repository = |claims: &AutomationClaims| -> Result<(), JwtVerifyError> {
         let repository = claims
-            .repository
-            .as_ref()
+            .repository(provider)
             .ok_or_else(|| JwtVerifyError::BadClaim("repository".to_string()))?;
 
-        let parts: Vec<&str> = repository.split('/').collect();
-        if parts.len() != 2 {
-            return Err(JwtVerifyError::BadClaim("repository_format".to_string()));
-        }
-
-        let repo_key = RepositoryKey {
-            owner: parts[0].to_string(),
-            name: parts[1].to_string(),
-        };
+        let repo_key = repository_key(provider, repository)
+            .ok_or_else(|| JwtVerifyError::BadClaim("repository_format".to_string()))?;
 
         let repo_config = config
             .repositories
@@ -77,8 +76,7 @@ fn verify_openid_credentials(
 
         if let Some(allowed_refs) = &repo_config.refs {
             let ref_claim = claims
-                .r#ref
-                .as_ref()
+                .full_ref(provider)
                 .ok_or_else(|| JwtVerifyError::BadClaim("ref".to_string()))?;
 
             if !allowed_refs.contains(ref_claim) {
@@ -86,17 +84,47 @@ fn verify_openid_credentials(
             }
         }
 
+        if repo_config.protected_refs == Some(true)
+            && claims.ref_protected.as_deref() != Some("true")
+        {
+            return Err(JwtVerifyError::BadClaim("ref_unprotected".to_string()));
+        }
+
         Ok(())
     };
 
-    let token = verify_openid_jwt(jwt, provider.issuers(), &jwks.keys, salt, assert_repository)
+    let issuers = JwtIssuers::issuers(provider, config);
+
+    let token = verify_openid_jwt(jwt, &issuers, &jwks.keys, salt, assert_repository)
         .map_err(VerifyOpenidCredentialsError::JwtVerify)?;
 
-    let credential = OpenIdAutomationCredential::from(token);
+    let credential = OpenIdAutomationCredential::from_token(provider, token);
 
     Ok((credential, provider.clone()))
 }
 
+// GitHub repositories are "owner/name" while GitLab projects can be nested in subgroups
+// e.g. "my-group/my-subgroup/my-project".
+fn repository_key(provider: &OpenIdAutomationProvider, repository: &str) -> Option<RepositoryKey> {
+    let (owner, name) = repository.rsplit_once('/')?;
+
+    let valid = !owner.is_empty()
+        && !name.is_empty()
+        && match provider {
+            OpenIdAutomationProvider::GitHub => !owner.contains('/'),
+            OpenIdAutomationProvider::GitLab => owner.split('/').all(|part| !part.is_empty()),
+        };
+
+    if !valid {
+        return None;
+    }
+
+    Some(RepositoryKey {
+        owner: owner.to_string(),
+        name: name.to_string(),
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -105,7 +133,7 @@ mod tests {
     use crate::openid::utils::nonce::build_nonce;
     use crate::state::types::automation::{
         OpenIdAutomationProviderConfig, OpenIdAutomationRepositories,
-        OpenIdAutomationRepositoryConfig, RepositoryKey,
+        OpenIdAutomationRepositoryConfig, OpenIdAutomationSelfHostedConfig, RepositoryKey,
     };
     use crate::state::types::state::Salt;
     use jsonwebtoken::{encode, Algorithm, EncodingKey, Header};
@@ -157,12 +185,14 @@ mod tests {
                     "refs/heads/develop".to_string(),
                     "refs/pull/74/merge".to_string(), // Specific PR
                 ]),
+                protected_refs: None,
             },
         );
 
         OpenIdAutomationProviderConfig {
             repositories,
             controller: None,
+            self_hosted: None,
         }
     }
 
@@ -199,6 +229,13 @@ mod tests {
             actor: Some("octocat".into()),
             workflow: Some("deploy".into()),
             event_name: Some("push".into()),
+            ref_protected: None,
+            project_path: None,
+            namespace_path: None,
+            ref_path: None,
+            pipeline_id: None,
+            pipeline_source: None,
+            user_login: None,
         };
 
         let jwt = create_token(&claims);
@@ -247,6 +284,13 @@ mod tests {
             actor: Some("octocat".into()),
             workflow: Some("deploy".into()),
             event_name: Some("push".into()),
+            ref_protected: None,
+            project_path: None,
+            namespace_path: None,
+            ref_path: None,
+            pipeline_id: None,
+            pipeline_source: None,
+            user_login: None,
         };
 
         let jwt = create_token(&claims);
@@ -292,6 +336,13 @@ mod tests {
             actor: Some("octocat".into()),
             workflow: Some("deploy".into()),
             event_name: Some("push".into()),
+            ref_protected: None,
+            project_path: None,
+            namespace_path: None,
+            ref_path: None,
+            pipeline_id: None,
+            pipeline_source: None,
+            user_login: None,
         };
 
         let jwt = create_token(&claims);
@@ -337,6 +388,13 @@ mod tests {
             actor: Some("octocat".into()),
             workflow: Some("deploy".into()),
             event_name: Some("push".into()),
+            ref_protected: None,
+            project_path: None,
+            namespace_path: None,
+            ref_path: None,
+            pipeline_id: None,
+            pipeline_source: None,
+            user_login: None,
         };
 
         let jwt = create_token(&claims);
@@ -370,12 +428,16 @@ mod tests {
                 owner: "octo-org".to_string(),
                 name: "octo-repo".to_string(),
             },
-            OpenIdAutomationRepositoryConfig { refs: None },
+            OpenIdAutomationRepositoryConfig {
+                refs: None,
+                protected_refs: None,
+            },
         );
 
         let config = OpenIdAutomationProviderConfig {
             repositories,
             controller: None,
+            self_hosted: None,
         };
 
         let claims = AutomationClaims {
@@ -396,6 +458,13 @@ mod tests {
             actor: Some("octocat".into()),
             workflow: Some("deploy".into()),
             event_name: Some("push".into()),
+            ref_protected: None,
+            project_path: None,
+            namespace_path: None,
+            ref_path: None,
+            pipeline_id: None,
+            pipeline_source: None,
+            user_login: None,
         };
 
         let jwt = create_token(&claims);
@@ -436,6 +505,13 @@ mod tests {
             actor: Some("octocat".into()),
             workflow: Some("deploy".into()),
             event_name: Some("push".into()),
+            ref_protected: None,
+            project_path: None,
+            namespace_path: None,
+            ref_path: None,
+            pipeline_id: None,
+            pipeline_source: None,
+            user_login: None,
         };
 
         let jwt = create_token(&claims);
@@ -477,6 +553,13 @@ mod tests {
             actor: Some("octocat".into()),
             workflow: Some("deploy".into()),
             event_name: Some("push".into()),
+            ref_protected: None,
+            project_path: None,
+            namespace_path: None,
+            ref_path: None,
+            pipeline_id: None,
+            pipeline_source: None,
+            user_login: None,
         };
 
         let jwt = create_token(&claims);
@@ -522,6 +605,13 @@ mod tests {
             actor: Some("octocat".into()),
             workflow: Some("deploy".into()),
             event_name: Some("push".into()),
+            ref_protected: None,
+            project_path: None,
+            namespace_path: None,
+            ref_path: None,
+            pipeline_id: None,
+            pipeline_source: None,
+            user_login: None,
         };
 
         let jwt = create_token(&claims);
@@ -542,4 +632,199 @@ mod tests {
             VerifyOpenidCredentialsError::JwtVerify(JwtVerifyError::BadClaim(ref c)) if c == "repository"
         ));
     }
+
+    const ISS_GITLAB: &str = "https://gitlab.com";
+
+    fn gitlab_claims(nonce: String, ref_path: &str, ref_protected: &str) -> AutomationClaims {
+        let now = now_secs();
+
+        AutomationClaims {
+            iss: ISS_GITLAB.into(),
+            sub: "project_path:my-group/my-subgroup/my-project:ref_type:branch:ref:main".into(),
+            aud: nonce,
+            iat: Some(now),
+            exp: Some(now + 600),
+            nbf: Some(now - 5),
+            jti: Some("gitlab-jti".into()),
+            repository: None,
+            repository_owner: None,
+            r#ref: Some("main".into()),
+            run_id: None,
+            run_number: None,
+            run_attempt: None,
+            sha: Some("abc123def456".into()),
+            actor: None,
+            workflow: None,
+            event_name: None,
+            ref_protected: Some(ref_protected.into()),
+            project_path: Some("my-group/my-subgroup/my-project".into()),
+            namespace_path: Some("my-group/my-subgroup".into()),
+            ref_path: Some(ref_path.into()),
+            pipeline_id: Some("1212".into()),
+            pipeline_source: Some("push".into()),
+            user_login: Some("gitlab-user".into()),
+        }
+    }
+
+    fn gitlab_config(protected_refs: Option<bool>) -> OpenIdAutomationProviderConfig {
+        let mut repositories: OpenIdAutomationRepositories = HashMap::new();
+
+        repositories.insert(
+            RepositoryKey {
+                owner: "my-group/my-subgroup".to_string(),
+                name: "my-project".to_string(),
+            },
+            OpenIdAutomationRepositoryConfig {
+                refs: Some(vec!["refs/heads/main".to_string()]),
+                protected_refs,
+            },
+        );
+
+        OpenIdAutomationProviderConfig {
+            repositories,
+            controller: None,
+            self_hosted: None,
+        }
+    }
+
+    #[test]
+    fn verifies_valid_gitlab_credentials() {
+        let salt = test_salt();
+        let nonce = build_nonce(&salt);
+
+        let jwt = create_token(&gitlab_claims(nonce, "refs/heads/main", "true"));
+
+        let (credential, provider) = verify_openid_credentials(
+            &jwt,
+            &test_jwks(),
+            &OpenIdAutomationProvider::GitLab,
+            &gitlab_config(Some(true)),
+            &salt,
+        )
+        .expect("should verify gitlab credentials");
+
+        assert_eq!(provider, OpenIdAutomationProvider::GitLab);
+        assert_eq!(
+            credential.repository.as_deref(),
+            Some("my-group/my-subgroup/my-project")
+        );
+        assert_eq!(
+            credential.repository_owner.as_deref(),
+            Some("my-group/my-subgroup")
+        );
+        assert_eq!(credential.r#ref.as_deref(), Some("refs/heads/main"));
+        assert_eq!(credential.run_id.as_deref(), Some("1212"));
+        assert_eq!(credential.actor.as_deref(), Some("gitlab-user"));
+        assert_eq!(credential.event_name.as_deref(), Some("push"));
+    }
+
+    #[test]
+    fn rejects_gitlab_unauthorized_ref() {
+        let salt = test_salt();
+        let nonce = build_nonce(&salt);
+
+        let jwt = create_token(&gitlab_claims(nonce, "refs/heads/feature", "true"));
+
+        let result = verify_openid_credentials(
+            &jwt,
+            &test_jwks(),
+            &OpenIdAutomationProvider::GitLab,
+            &gitlab_config(None),
+            &salt,
+        );
+
+        assert!(matches!(
+            result.unwrap_err(),
+            VerifyOpenidCredentialsError::JwtVerify(JwtVerifyError::BadClaim(ref c)) if c == "ref_unauthorized"
+        ));
+    }
+
+    #[test]
+    fn rejects_unprotected_ref_when_protected_refs_required() {
+        let salt = test_salt();
+        let nonce = build_nonce(&salt);
+
+        let jwt = create_token(&gitlab_claims(nonce.clone(), "refs/heads/main", "false"));
+
+        let result = verify_openid_credentials(
+            &jwt,
+            &test_jwks(),
+            &OpenIdAutomationProvider::GitLab,
+            &gitlab_config(Some(true)),
+            &salt,
+        );
+
+        assert!(matches!(
+            result.unwrap_err(),
+            VerifyOpenidCredentialsError::JwtVerify(JwtVerifyError::BadClaim(ref c)) if c == "ref_unprotected"
+        ));
+
+        let jwt = create_token(&gitlab_claims(nonce, "refs/heads/main", "false"));
+
+        let result = verify_openid_credentials(
+            &jwt,
+            &test_jwks(),
+            &OpenIdAutomationProvider::GitLab,
+            &gitlab_config(Some(false)),
+            &salt,
+        );
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn verifies_self_hosted_gitlab_issuer() {
+        let salt = test_salt();
+        let nonce = build_nonce(&salt);
+
+        let mut claims = gitlab_claims(nonce, "refs/heads/main", "true");
+        claims.iss = "https://gitlab.example.com".into();
+
+        let jwt = create_token(&claims);
+
+        let mut config = gitlab_config(None);
+
+        // The gitlab.com issuer does not match a self-hosted instance.
+        let result = verify_openid_credentials(
+            &jwt,
+            &test_jwks(),
+            &OpenIdAutomationProvider::GitLab,
+            &config,
+            &salt,
+        );
+
+        assert!(result.is_err());
+
+        config.self_hosted = Some(OpenIdAutomationSelfHostedConfig {
+            issuer: "https://gitlab.example.com".into(),
+            jwks: test_jwks(),
+        });
+
+        let result = verify_openid_credentials(
+            &jwt,
+            &test_jwks(),
+            &OpenIdAutomationProvider::GitLab,
+            &config,
+            &salt,
+        );
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn parses_repository_keys() {
+        let github = OpenIdAutomationProvider::GitHub;
+        let gitlab = OpenIdAutomationProvider::GitLab;
+
+        assert!(repository_key(&github, "octo-org/octo-repo").is_some());
+        assert!(repository_key(&github, "octo-org/nested/octo-repo").is_none());
+        assert!(repository_key(&github, "octo-repo").is_none());
+
+        let key = repository_key(&gitlab, "my-group/my-subgroup/my-project").unwrap();
+        assert_eq!(key.owner, "my-group/my-subgroup");
+        assert_eq!(key.name, "my-project");
+
+        assert!(repository_key(&gitlab, "my-group//my-project").is_none());
+        assert!(repository_key(&gitlab, "my-project/").is_none());
+    }
 }
@@ -1,22 +1,23 @@
 use crate::openid::credentials::delegation::types::interface::{
     OpenIdDelegationCredential, OpenIdDelegationCredentialKey,
 };
-use crate::openid::credentials::delegation::types::token::DelegationClaims;
+use crate::openid::credentials::delegation::types::token::{DelegationAudience, DelegationClaims};
 use crate::openid::jwt::types::token::JwtClaims;
-use jsonwebtoken::TokenData;
+use crate::state::types::config::OpenIdGenericProviderClaims;
+use serde_json::Value;
 
-impl From<TokenData<DelegationClaims>> for OpenIdDelegationCredential {
-    fn from(token: TokenData<DelegationClaims>) -> Self {
+impl From<DelegationClaims> for OpenIdDelegationCredential {
+    fn from(claims: DelegationClaims) -> Self {
         Self {
-            sub: token.claims.sub,
-            iss: token.claims.iss,
-            email: token.claims.email,
-            name: token.claims.name,
-            given_name: token.claims.given_name,
-            family_name: token.claims.family_name,
-            preferred_username: token.claims.preferred_username,
-            picture: token.claims.picture,
-            locale: token.claims.locale,
+            sub: claims.sub,
+            iss: claims.iss,
+            email: claims.email,
+            name: claims.name,
+            given_name: claims.given_name,
+            family_name: claims.family_name,
+            preferred_username: claims.preferred_username,
+            picture: claims.picture,
+            locale: claims.locale,
         }
     }
 }
@@ -39,3 +40,56 @@ impl JwtClaims for DelegationClaims {
         self.nonce.as_deref()
     }
 }
+
+impl DelegationClaims {
+    /// Maps the profile claims of a generic provider to the standard claims.
+    /// A claim without mapping remains the standard claim.
+    pub fn map(self, mapping: &OpenIdGenericProviderClaims) -> Self {
+        let map_claim = |name: &Option<String>, standard: &Option<String>| match name {
+            None => standard.clone(),
+            Some(name) => self.claim(name),
+        };
+
+        Self {
+            email: map_claim(&mapping.email, &self.email),
+            name: map_claim(&mapping.name, &self.name),
+            given_name: map_claim(&mapping.given_name, &self.given_name),
+            family_name: map_claim(&mapping.family_name, &self.family_name),
+            preferred_username: map_claim(&mapping.preferred_username, &self.preferred_username),
+            picture: map_claim(&mapping.picture, &self.picture),
+            locale: map_claim(&mapping.locale, &self.locale),
+            ..self
+        }
+    }
+
+    fn claim(&self, name: &str) -> Option<String> {
+        match name {
+            "email" => self.email.clone(),
+            "name" => self.name.clone(),
+            "given_name" => self.given_name.clone(),
+            "family_name" => self.family_name.clone(),
+            "preferred_username" => self.preferred_username.clone(),
+            "picture" => self.picture.clone(),
+            "locale" => self.locale.clone(),
+            _ => match self.custom.get(name) {
+                Some(Value::String(value)) => Some(value.clone()),
+                _ => None,
+            },
+        }
+    }
+}
+
+impl DelegationAudience {
+    pub fn audiences(&self) -> Vec<&str> {
+        match self {
+            Self::Single(aud) => vec![aud.as_str()],
+            Self::Multiple(auds) => auds.iter().map(String::as_str).collect(),
+        }
+    }
+}
+
+impl From<&str> for DelegationAudience {
+    fn from(aud: &str) -> Self {
+        Self::Single(aud.to_string())
+    }
+}
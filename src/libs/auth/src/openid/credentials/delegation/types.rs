@@ -22,12 +22,14 @@ pub mod interface {
 pub(crate) mod token {
     use candid::Deserialize;
     use serde::Serialize;
+    use serde_json::Value;
+    use std::collections::BTreeMap;
 
     #[derive(Debug, Clone, Deserialize, Serialize)]
     pub struct DelegationClaims {
         pub iss: String,
         pub sub: String,
-        pub aud: String,
+        pub aud: DelegationAudience,
         pub exp: Option<u64>,
         pub nbf: Option<u64>,
         pub iat: Option<u64>,
@@ -41,5 +43,18 @@ pub(crate) mod token {
         pub preferred_username: Option<String>,
         pub picture: Option<String>,
         pub locale: Option<String>,
+
+        // Any other claim - used to map the profile of generic providers.
+        #[serde(flatten)]
+        pub custom: BTreeMap<String, Value>,
+    }
+
+    // Google and GitHub issue a single audience, while other providers may issue a list.
+    // https://openid.net/specs/openid-connect-core-1_0.html#IDToken
+    #[derive(Debug, Clone, Deserialize, Serialize)]
+    #[serde(untagged)]
+    pub enum DelegationAudience {
+        Single(String),
+        Multiple(Vec<String>),
     }
 }
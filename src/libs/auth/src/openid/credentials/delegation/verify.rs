@@ -2,13 +2,15 @@ use crate::openid::credentials::delegation::types::interface::OpenIdDelegationCr
 use crate::openid::credentials::delegation::types::token::DelegationClaims;
 use crate::openid::credentials::delegation::utils::targets::target_observatory_id;
 use crate::openid::credentials::types::errors::VerifyOpenidCredentialsError;
+use crate::openid::jwkset::types::errors::GetOrRefreshJwksError;
 use crate::openid::jwkset::{get_jwks, get_or_refresh_jwks};
 use crate::openid::jwt::types::cert::Jwks;
 use crate::openid::jwt::types::errors::JwtVerifyError;
+use crate::openid::jwt::types::provider::JwtIssuers;
 use crate::openid::jwt::{unsafe_find_jwt_provider, verify_openid_jwt};
 use crate::openid::types::provider::OpenIdDelegationProvider;
 use crate::openid::types::provider::OpenIdProvider;
-use crate::state::types::config::{OpenIdAuthProviderClientId, OpenIdAuthProviders};
+use crate::state::types::config::{OpenIdAuthProviderConfig, OpenIdAuthProviders};
 use crate::state::types::state::Salt;
 use crate::strategies::AuthHeapStrategy;
 
@@ -27,7 +29,14 @@ pub async fn verify_openid_credentials_with_jwks_renewal(
     let (delegation_provider, config) = unsafe_find_jwt_provider(providers, jwt)
         .map_err(VerifyOpenidCredentialsError::JwtFindProvider)?;
 
-    let provider: OpenIdProvider = (&delegation_provider).into();
+    // Generic providers hold their key set in their configuration.
+    if let Some(jwks) = generic_jwks(config) {
+        return verify_openid_credentials(jwt, &jwks, &delegation_provider, config, salt);
+    }
+
+    let provider = OpenIdProvider::try_from(&delegation_provider).map_err(|err| {
+        VerifyOpenidCredentialsError::GetOrFetchJwks(GetOrRefreshJwksError::InvalidConfig(err))
+    })?;
 
     let observatory_id = target_observatory_id(auth_heap)
         .map_err(VerifyOpenidCredentialsError::InvalidObservatoryId)?;
@@ -36,7 +45,7 @@ pub async fn verify_openid_credentials_with_jwks_renewal(
         .await
         .map_err(VerifyOpenidCredentialsError::GetOrFetchJwks)?;
 
-    verify_openid_credentials(jwt, &jwks, &delegation_provider, &config.client_id, salt)
+    verify_openid_credentials(jwt, &jwks, &delegation_provider, config, salt)
 }
 
 pub fn verify_openid_credentials_with_cached_jwks(
@@ -48,43 +57,92 @@ pub fn verify_openid_credentials_with_cached_jwks(
     let (delegation_provider, config) = unsafe_find_jwt_provider(providers, jwt)
         .map_err(VerifyOpenidCredentialsError::JwtFindProvider)?;
 
-    let provider: OpenIdProvider = (&delegation_provider).into();
+    let jwks = match generic_jwks(config) {
+        Some(jwks) => jwks,
+        None => {
+            let provider = OpenIdProvider::try_from(&delegation_provider)
+                .map_err(|_| VerifyOpenidCredentialsError::GetCachedJwks)?;
 
-    let jwks = get_jwks(&provider, auth_heap).ok_or(VerifyOpenidCredentialsError::GetCachedJwks)?;
+            get_jwks(&provider, auth_heap).ok_or(VerifyOpenidCredentialsError::GetCachedJwks)?
+        }
+    };
 
-    verify_openid_credentials(jwt, &jwks, &delegation_provider, &config.client_id, salt)
+    verify_openid_credentials(jwt, &jwks, &delegation_provider, config, salt)
+}
+
+fn generic_jwks(config: &OpenIdAuthProviderConfig) -> Option<Jwks> {
+    config.generic.as_ref().map(|generic| generic.jwks.clone())
 }
 
 fn verify_openid_credentials(
     jwt: &str,
     jwks: &Jwks,
     provider: &OpenIdDelegationProvider,
-    client_id: &OpenIdAuthProviderClientId,
+    config: &OpenIdAuthProviderConfig,
     salt: &Salt,
 ) -> VerifyOpenIdDelegationCredentialsResult {
+    let accepted_audiences = accepted_audiences(config);
+
+    // Every audience of the token must be trusted, and the token must have at least one.
+    // https://openid.net/specs/openid-connect-core-1_0.html#IDTokenValidation
     let assert_audience = |claims: &DelegationClaims| -> Result<(), JwtVerifyError> {
-        if claims.aud != client_id.as_str() {
+        let audiences = claims.aud.audiences();
+
+        if audiences.is_empty()
+            || audiences
+                .iter()
+                .any(|aud| !accepted_audiences.contains(aud))
+        {
             return Err(JwtVerifyError::BadClaim("aud".to_string()));
         }
 
         Ok(())
     };
 
-    let token = verify_openid_jwt(jwt, provider.issuers(), &jwks.keys, salt, assert_audience)
+    let issuers = JwtIssuers::issuers(provider, config);
+
+    let token = verify_openid_jwt(jwt, &issuers, &jwks.keys, salt, assert_audience)
         .map_err(VerifyOpenidCredentialsError::JwtVerify)?;
 
-    let credential = OpenIdDelegationCredential::from(token);
+    let claims = match config
+        .generic
+        .as_ref()
+        .and_then(|generic| generic.claims.as_ref())
+    {
+        Some(mapping) => token.claims.map(mapping),
+        None => token.claims,
+    };
+
+    let credential = OpenIdDelegationCredential::from(claims);
 
     Ok((credential, provider.clone()))
 }
 
+fn accepted_audiences(config: &OpenIdAuthProviderConfig) -> Vec<&str> {
+    let mut audiences = vec![config.client_id.as_str()];
+
+    if let Some(additional_audiences) = config
+        .generic
+        .as_ref()
+        .and_then(|generic| generic.audiences.as_ref())
+    {
+        audiences.extend(additional_audiences.iter().map(String::as_str));
+    }
+
+    audiences
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::openid::credentials::delegation::types::token::DelegationAudience;
     use crate::openid::jwt::types::cert::{Jwk, JwkParams, JwkParamsRsa, JwkType, Jwks};
     use crate::openid::types::provider::OpenIdDelegationProvider;
     use crate::openid::utils::nonce::build_nonce;
+    use crate::state::types::config::{OpenIdGenericProviderClaims, OpenIdGenericProviderConfig};
     use jsonwebtoken::{encode, Algorithm, EncodingKey, Header};
+    use serde_json::json;
+    use std::collections::BTreeMap;
     use std::time::{SystemTime, UNIX_EPOCH};
 
     const TEST_RSA_PEM: &str = include_str!("../../../../tests/keys/test_rsa.pem");
@@ -93,6 +151,7 @@ mod tests {
     const KID: &str = "test-kid";
     const ISS_GOOGLE: &str = "https://accounts.google.com";
     const CLIENT_ID: &str = "test-client-id";
+    const ISS_GENERIC: &str = "https://my-tenant.eu.auth0.com/";
 
     fn now_secs() -> u64 {
         SystemTime::now()
@@ -119,6 +178,53 @@ mod tests {
         }
     }
 
+    fn test_config() -> OpenIdAuthProviderConfig {
+        OpenIdAuthProviderConfig {
+            client_id: CLIENT_ID.into(),
+            ..OpenIdAuthProviderConfig::default()
+        }
+    }
+
+    fn test_generic_config(
+        claims: Option<OpenIdGenericProviderClaims>,
+    ) -> OpenIdAuthProviderConfig {
+        OpenIdAuthProviderConfig {
+            client_id: CLIENT_ID.into(),
+            delegation: None,
+            generic: Some(OpenIdGenericProviderConfig {
+                issuer: ISS_GENERIC.into(),
+                jwks: test_jwks(),
+                audiences: Some(vec!["https://api.example.com".into()]),
+                claims,
+            }),
+        }
+    }
+
+    fn generic_claims(aud: DelegationAudience, salt: &Salt) -> DelegationClaims {
+        let now = now_secs();
+
+        DelegationClaims {
+            iss: ISS_GENERIC.into(),
+            sub: "user-123".into(),
+            aud,
+            iat: Some(now),
+            exp: Some(now + 600),
+            nbf: Some(now - 5),
+            nonce: Some(build_nonce(salt)),
+            email: None,
+            name: Some("Hello World".into()),
+            given_name: None,
+            family_name: None,
+            preferred_username: None,
+            picture: None,
+            locale: None,
+            custom: BTreeMap::from([
+                ("upn".to_string(), json!("hello@example.com")),
+                ("nickname".to_string(), json!("hello_world")),
+            ]),
+        }
+    }
+
     fn create_token(claims: &DelegationClaims) -> String {
         let mut header = Header::new(Algorithm::RS256);
         header.kid = Some(KID.into());
@@ -149,6 +255,7 @@ mod tests {
             preferred_username: None,
             picture: None,
             locale: None,
+            custom: BTreeMap::new(),
         };
 
         let jwt = create_token(&claims);
@@ -158,7 +265,7 @@ mod tests {
             &jwt,
             &jwks,
             &OpenIdDelegationProvider::Google,
-            &CLIENT_ID.to_string(),
+            &test_config(),
             &salt,
         );
 
@@ -189,6 +296,7 @@ mod tests {
             preferred_username: None,
             picture: None,
             locale: None,
+            custom: BTreeMap::new(),
         };
 
         let jwt = create_token(&claims);
@@ -198,7 +306,7 @@ mod tests {
             &jwt,
             &jwks,
             &OpenIdDelegationProvider::Google,
-            &CLIENT_ID.to_string(),
+            &test_config(),
             &salt,
         );
 
@@ -229,6 +337,7 @@ mod tests {
             preferred_username: None,
             picture: None,
             locale: None,
+            custom: BTreeMap::new(),
         };
 
         let jwt = create_token(&claims);
@@ -238,7 +347,7 @@ mod tests {
             &jwt,
             &jwks,
             &OpenIdDelegationProvider::Google,
-            &CLIENT_ID.to_string(),
+            &test_config(),
             &salt,
         );
 
@@ -270,6 +379,7 @@ mod tests {
             preferred_username: Some("hello_world".into()),
             picture: Some("https://example.com/pic.png".into()),
             locale: Some("en-US".into()),
+            custom: BTreeMap::new(),
         };
 
         let jwt = create_token(&claims);
@@ -279,7 +389,7 @@ mod tests {
             &jwt,
             &jwks,
             &OpenIdDelegationProvider::Google,
-            &CLIENT_ID.to_string(),
+            &test_config(),
             &salt,
         );
 
@@ -299,4 +409,112 @@ mod tests {
         );
         assert_eq!(credential.locale.as_deref(), Some("en-US"));
     }
+
+    #[test]
+    fn verifies_generic_provider_with_multiple_audiences() {
+        let salt = test_salt();
+
+        let claims = generic_claims(
+            DelegationAudience::Multiple(vec![CLIENT_ID.into(), "https://api.example.com".into()]),
+            &salt,
+        );
+
+        let jwt = create_token(&claims);
+        let provider = OpenIdDelegationProvider::Generic("auth0".into());
+
+        let (credential, verified_provider) = verify_openid_credentials(
+            &jwt,
+            &test_jwks(),
+            &provider,
+            &test_generic_config(None),
+            &salt,
+        )
+        .expect("should verify");
+
+        assert_eq!(verified_provider, provider);
+        assert_eq!(credential.iss, ISS_GENERIC);
+        assert_eq!(credential.name.as_deref(), Some("Hello World"));
+        assert_eq!(credential.email, None);
+    }
+
+    #[test]
+    fn rejects_generic_provider_with_untrusted_audience() {
+        let salt = test_salt();
+
+        let claims = generic_claims(
+            DelegationAudience::Multiple(vec![CLIENT_ID.into(), "untrusted-client".into()]),
+            &salt,
+        );
+
+        let jwt = create_token(&claims);
+
+        let result = verify_openid_credentials(
+            &jwt,
+            &test_jwks(),
+            &OpenIdDelegationProvider::Generic("auth0".into()),
+            &test_generic_config(None),
+            &salt,
+        );
+
+        assert!(matches!(
+            result.unwrap_err(),
+            VerifyOpenidCredentialsError::JwtVerify(JwtVerifyError::BadClaim(ref c)) if c == "aud"
+        ));
+    }
+
+    #[test]
+    fn rejects_generic_provider_with_other_issuer() {
+        let salt = test_salt();
+
+        let mut claims = generic_claims(CLIENT_ID.into(), &salt);
+        claims.iss = ISS_GOOGLE.into();
+
+        let jwt = create_token(&claims);
+
+        let result = verify_openid_credentials(
+            &jwt,
+            &test_jwks(),
+            &OpenIdDelegationProvider::Generic("auth0".into()),
+            &test_generic_config(None),
+            &salt,
+        );
+
+        assert!(matches!(
+            result.unwrap_err(),
+            VerifyOpenidCredentialsError::JwtVerify(JwtVerifyError::BadSig(_))
+        ));
+    }
+
+    #[test]
+    fn maps_generic_provider_claims() {
+        let salt = test_salt();
+
+        let claims = generic_claims(CLIENT_ID.into(), &salt);
+
+        let jwt = create_token(&claims);
+
+        let mapping = OpenIdGenericProviderClaims {
+            email: Some("upn".into()),
+            preferred_username: Some("nickname".into()),
+            locale: Some("unknown".into()),
+            ..OpenIdGenericProviderClaims::default()
+        };
+
+        let (credential, _) = verify_openid_credentials(
+            &jwt,
+            &test_jwks(),
+            &OpenIdDelegationProvider::Generic("auth0".into()),
+            &test_generic_config(Some(mapping)),
+            &salt,
+        )
+        .expect("should verify");
+
+        assert_eq!(credential.email.as_deref(), Some("hello@example.com"));
+        assert_eq!(
+            credential.preferred_username.as_deref(),
+            Some("hello_world")
+        );
+        assert_eq!(credential.name.as_deref(), Some("Hello World"));
+        assert_eq!(credential.locale, None);
+    }
 }
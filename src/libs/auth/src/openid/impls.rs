@@ -1,8 +1,11 @@
+use crate::errors::JUNO_AUTH_ERROR_OPENID_GENERIC_PROVIDER_NOT_FETCHABLE;
 use crate::openid::jwt::types::cert::Jwks;
 use crate::openid::jwt::types::provider::JwtIssuers;
 use crate::openid::types::provider::{
     OpenIdAutomationProvider, OpenIdCertificate, OpenIdDelegationProvider, OpenIdProvider,
 };
+use crate::state::types::automation::OpenIdAutomationProviderConfig;
+use crate::state::types::config::OpenIdAuthProviderConfig;
 use junobuild_shared::data::version::next_version;
 use junobuild_shared::ic::api::time;
 use junobuild_shared::types::state::{Version, Versioned};
@@ -16,6 +19,7 @@ impl OpenIdProvider {
             // http://host.docker.internal:3000/v1/auth/certs
             Self::GitHubAuth => "https://api.juno.build/v1/auth/certs",
             Self::GitHubActions => "https://token.actions.githubusercontent.com/.well-known/jwks",
+            Self::GitLabCi => "https://gitlab.com/oauth/discovery/keys",
         }
     }
 
@@ -24,38 +28,55 @@ impl OpenIdProvider {
             OpenIdProvider::Google => &["https://accounts.google.com", "accounts.google.com"],
             OpenIdProvider::GitHubAuth => &["https://api.juno.build/auth/github"],
             OpenIdProvider::GitHubActions => &["https://token.actions.githubusercontent.com"],
+            OpenIdProvider::GitLabCi => &["https://gitlab.com"],
         }
     }
 }
 
-impl From<&OpenIdDelegationProvider> for OpenIdProvider {
-    fn from(delegation_provider: &OpenIdDelegationProvider) -> Self {
+impl TryFrom<&OpenIdDelegationProvider> for OpenIdProvider {
+    type Error = String;
+
+    fn try_from(delegation_provider: &OpenIdDelegationProvider) -> Result<Self, Self::Error> {
         match delegation_provider {
-            OpenIdDelegationProvider::Google => OpenIdProvider::Google,
-            OpenIdDelegationProvider::GitHub => OpenIdProvider::GitHubAuth,
+            OpenIdDelegationProvider::Google => Ok(OpenIdProvider::Google),
+            OpenIdDelegationProvider::GitHub => Ok(OpenIdProvider::GitHubAuth),
+            OpenIdDelegationProvider::Generic(id) => Err(format!(
+                "{JUNO_AUTH_ERROR_OPENID_GENERIC_PROVIDER_NOT_FETCHABLE} ({id})"
+            )),
         }
     }
 }
 
 impl OpenIdDelegationProvider {
-    pub fn jwks_url(&self) -> &'static str {
+    // The key set of a generic provider is part of its configuration.
+    pub fn jwks_url(&self) -> Option<&'static str> {
         match self {
-            Self::Google => OpenIdProvider::Google.jwks_url(),
-            Self::GitHub => OpenIdProvider::GitHubAuth.jwks_url(),
+            Self::Google => Some(OpenIdProvider::Google.jwks_url()),
+            Self::GitHub => Some(OpenIdProvider::GitHubAuth.jwks_url()),
+            Self::Generic(_) => None,
         }
     }
 
+    // The issuer of a generic provider is part of its configuration.
     pub fn issuers(&self) -> &[&'static str] {
         match self {
             Self::Google => OpenIdProvider::Google.issuers(),
             Self::GitHub => OpenIdProvider::GitHubAuth.issuers(),
+            Self::Generic(_) => &[],
         }
     }
 }
 
-impl JwtIssuers for OpenIdDelegationProvider {
-    fn issuers(&self) -> &[&'static str] {
-        self.issuers()
+impl JwtIssuers<OpenIdAuthProviderConfig> for OpenIdDelegationProvider {
+    fn issuers<'a>(&'a self, config: &'a OpenIdAuthProviderConfig) -> Vec<&'a str> {
+        match self {
+            Self::Generic(_) => config
+                .generic
+                .as_ref()
+                .map(|generic| vec![generic.issuer.as_str()])
+                .unwrap_or_default(),
+            _ => self.issuers().to_vec(),
+        }
     }
 }
 
@@ -63,6 +84,7 @@ impl From<&OpenIdAutomationProvider> for OpenIdProvider {
     fn from(automation_provider: &OpenIdAutomationProvider) -> Self {
         match automation_provider {
             OpenIdAutomationProvider::GitHub => OpenIdProvider::GitHubActions,
+            OpenIdAutomationProvider::GitLab => OpenIdProvider::GitLabCi,
         }
     }
 }
@@ -71,19 +93,25 @@ impl OpenIdAutomationProvider {
     pub fn jwks_url(&self) -> &'static str {
         match self {
             Self::GitHub => OpenIdProvider::GitHubActions.jwks_url(),
+            Self::GitLab => OpenIdProvider::GitLabCi.jwks_url(),
         }
     }
 
     pub fn issuers(&self) -> &[&'static str] {
         match self {
             Self::GitHub => OpenIdProvider::GitHubActions.issuers(),
+            Self::GitLab => OpenIdProvider::GitLabCi.issuers(),
         }
     }
 }
 
-impl JwtIssuers for OpenIdAutomationProvider {
-    fn issuers(&self) -> &[&'static str] {
-        self.issuers()
+impl JwtIssuers<OpenIdAutomationProviderConfig> for OpenIdAutomationProvider {
+    // The issuer of a self-managed instance is part of its configuration.
+    fn issuers<'a>(&'a self, config: &'a OpenIdAutomationProviderConfig) -> Vec<&'a str> {
+        match &config.self_hosted {
+            Some(self_hosted) => vec![self_hosted.issuer.as_str()],
+            None => self.issuers().to_vec(),
+        }
     }
 }
 
@@ -91,6 +119,7 @@ impl Display for OpenIdAutomationProvider {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match self {
             OpenIdAutomationProvider::GitHub => write!(f, "GitHub"),
+            OpenIdAutomationProvider::GitLab => write!(f, "GitLab"),
         }
     }
 }
@@ -139,6 +168,7 @@ impl Display for OpenIdProvider {
             OpenIdProvider::Google => write!(f, "Google"),
             OpenIdProvider::GitHubAuth => write!(f, "GitHub"),
             OpenIdProvider::GitHubActions => write!(f, "GitHub Actions"),
+            OpenIdProvider::GitLabCi => write!(f, "GitLab CI"),
         }
     }
 }
@@ -161,6 +191,10 @@ mod tests {
             OpenIdProvider::GitHubActions.jwks_url(),
             "https://token.actions.githubusercontent.com/.well-known/jwks"
         );
+        assert_eq!(
+            OpenIdProvider::GitLabCi.jwks_url(),
+            "https://gitlab.com/oauth/discovery/keys"
+        );
     }
 
     #[test]
@@ -177,17 +211,22 @@ mod tests {
             OpenIdProvider::GitHubActions.issuers(),
             &["https://token.actions.githubusercontent.com"]
         );
+        assert_eq!(OpenIdProvider::GitLabCi.issuers(), &["https://gitlab.com"]);
     }
 
     #[test]
     fn test_delegation_provider_to_openid_provider() {
         assert_eq!(
-            OpenIdProvider::from(&OpenIdDelegationProvider::Google),
-            OpenIdProvider::Google
+            OpenIdProvider::try_from(&OpenIdDelegationProvider::Google),
+            Ok(OpenIdProvider::Google)
         );
         assert_eq!(
-            OpenIdProvider::from(&OpenIdDelegationProvider::GitHub),
-            OpenIdProvider::GitHubAuth
+            OpenIdProvider::try_from(&OpenIdDelegationProvider::GitHub),
+            Ok(OpenIdProvider::GitHubAuth)
+        );
+        assert!(
+            OpenIdProvider::try_from(&OpenIdDelegationProvider::Generic("auth0".to_string()))
+                .is_err()
         );
     }
 
@@ -195,11 +234,15 @@ mod tests {
     fn test_delegation_provider_jwks_urls() {
         assert_eq!(
             OpenIdDelegationProvider::Google.jwks_url(),
-            "https://www.googleapis.com/oauth2/v3/certs"
+            Some("https://www.googleapis.com/oauth2/v3/certs")
         );
         assert_eq!(
             OpenIdDelegationProvider::GitHub.jwks_url(),
-            "https://api.juno.build/v1/auth/certs"
+            Some("https://api.juno.build/v1/auth/certs")
+        );
+        assert_eq!(
+            OpenIdDelegationProvider::Generic("auth0".to_string()).jwks_url(),
+            None
         );
     }
 
@@ -215,12 +258,42 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_generic_provider_issuers_from_config() {
+        use crate::openid::jwt::types::cert::Jwks;
+        use crate::state::types::config::OpenIdGenericProviderConfig;
+
+        let provider = OpenIdDelegationProvider::Generic("auth0".to_string());
+
+        assert!(provider.issuers().is_empty());
+        assert!(JwtIssuers::issuers(&provider, &OpenIdAuthProviderConfig::default()).is_empty());
+
+        let config = OpenIdAuthProviderConfig {
+            generic: Some(OpenIdGenericProviderConfig {
+                issuer: "https://my-tenant.eu.auth0.com/".to_string(),
+                jwks: Jwks { keys: vec![] },
+                audiences: None,
+                claims: None,
+            }),
+            ..OpenIdAuthProviderConfig::default()
+        };
+
+        assert_eq!(
+            JwtIssuers::issuers(&provider, &config),
+            vec!["https://my-tenant.eu.auth0.com/"]
+        );
+    }
+
     #[test]
     fn test_automation_provider_to_openid_provider() {
         assert_eq!(
             OpenIdProvider::from(&OpenIdAutomationProvider::GitHub),
             OpenIdProvider::GitHubActions
         );
+        assert_eq!(
+            OpenIdProvider::from(&OpenIdAutomationProvider::GitLab),
+            OpenIdProvider::GitLabCi
+        );
     }
 
     #[test]
@@ -237,6 +310,35 @@ mod tests {
             OpenIdAutomationProvider::GitHub.issuers(),
             &["https://token.actions.githubusercontent.com"]
         );
+        assert_eq!(
+            OpenIdAutomationProvider::GitLab.issuers(),
+            &["https://gitlab.com"]
+        );
+    }
+
+    #[test]
+    fn test_self_hosted_automation_provider_issuers_from_config() {
+        use crate::state::types::automation::OpenIdAutomationSelfHostedConfig;
+
+        let provider = OpenIdAutomationProvider::GitLab;
+
+        assert_eq!(
+            JwtIssuers::issuers(&provider, &OpenIdAutomationProviderConfig::default()),
+            vec!["https://gitlab.com"]
+        );
+
+        let config = OpenIdAutomationProviderConfig {
+            self_hosted: Some(OpenIdAutomationSelfHostedConfig {
+                issuer: "https://gitlab.example.com".to_string(),
+                jwks: Jwks { keys: vec![] },
+            }),
+            ..OpenIdAutomationProviderConfig::default()
+        };
+
+        assert_eq!(
+            JwtIssuers::issuers(&provider, &config),
+            vec!["https://gitlab.example.com"]
+        );
     }
 
     #[test]
@@ -269,5 +371,6 @@ mod tests {
             format!("{}", OpenIdProvider::GitHubActions),
             "GitHub Actions"
         );
+        assert_eq!(format!("{}", OpenIdProvider::GitLabCi), "GitLab CI");
     }
 }
@@ -1,10 +1,14 @@
 use crate::openid::jwt::types::errors::JwtHeaderError;
 use jsonwebtoken::{decode_header, Algorithm, Header};
 
+// RS256 is used by most providers (e.g. Google or GitHub), while some sign their tokens
+// with elliptic curve keys.
+const SUPPORTED_ALGORITHMS: [Algorithm; 3] = [Algorithm::RS256, Algorithm::ES256, Algorithm::EdDSA];
+
 pub fn decode_jwt_header(jwt: &str) -> Result<Header, JwtHeaderError> {
     let header = decode_header(jwt).map_err(|e| JwtHeaderError::BadSig(e.to_string()))?;
 
-    if header.alg != Algorithm::RS256 {
+    if !SUPPORTED_ALGORITHMS.contains(&header.alg) {
         return Err(JwtHeaderError::BadClaim("alg".to_string()));
     }
 
@@ -51,6 +55,32 @@ mod tests {
         assert!(header.typ.is_none());
     }
 
+    #[test]
+    fn accepts_es256_with_typ_jwt() {
+        let jwt = jwt_with_header(json!({"alg":"ES256","typ":"JWT"}));
+        let header = decode_jwt_header(&jwt).expect("should parse");
+        assert_eq!(header.alg, Algorithm::ES256);
+        assert_eq!(header.typ.as_deref(), Some("JWT"));
+    }
+
+    #[test]
+    fn accepts_eddsa_without_typ() {
+        let jwt = jwt_with_header(json!({"alg":"EdDSA"}));
+        let header = decode_jwt_header(&jwt).expect("should parse");
+        assert_eq!(header.alg, Algorithm::EdDSA);
+        assert!(header.typ.is_none());
+    }
+
+    #[test]
+    fn rejects_other_ec_alg() {
+        let jwt = jwt_with_header(json!({"alg":"ES384","typ":"JWT"}));
+        let err = decode_jwt_header(&jwt).unwrap_err();
+        match err {
+            JwtHeaderError::BadClaim(f) => assert_eq!(f, "alg"),
+            other => panic!("expected BadClaim(\"alg\"), got {other:?}"),
+        }
+    }
+
     #[test]
     fn rejects_non_rs256_alg() {
         let jwt = jwt_with_header(json!({"alg":"HS256","typ":"JWT"}));
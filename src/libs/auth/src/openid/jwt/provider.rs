@@ -12,7 +12,7 @@ pub fn unsafe_find_jwt_provider<'a, Provider, Config>(
     jwt: &str,
 ) -> Result<(Provider, &'a Config), JwtFindProviderError>
 where
-    Provider: Clone + JwtIssuers,
+    Provider: Clone + JwtIssuers<Config>,
 {
     // 1) Header sanity check
     decode_jwt_header(jwt).map_err(JwtFindProviderError::from)?;
@@ -25,7 +25,7 @@ where
     if let Some(iss) = token_data.claims.iss.as_deref() {
         if let Some((prov, cfg)) = providers
             .iter()
-            .find(|(provider, _)| provider.issuers().contains(&iss))
+            .find(|(provider, config)| provider.issuers(config).contains(&iss))
         {
             return Ok((prov.clone(), cfg));
         }
@@ -37,9 +37,12 @@ where
 #[cfg(test)]
 mod tests {
     use super::unsafe_find_jwt_provider;
+    use crate::openid::jwt::types::cert::Jwks;
     use crate::openid::jwt::types::errors::JwtFindProviderError;
     use crate::openid::types::provider::OpenIdDelegationProvider;
-    use crate::state::types::config::{OpenIdAuthProviderConfig, OpenIdAuthProviders};
+    use crate::state::types::config::{
+        OpenIdAuthProviderConfig, OpenIdAuthProviders, OpenIdGenericProviderConfig,
+    };
     use base64::engine::general_purpose::URL_SAFE_NO_PAD;
     use base64::Engine;
     use serde_json::json;
@@ -61,6 +64,7 @@ mod tests {
             OpenIdAuthProviderConfig {
                 client_id: "client-123".into(),
                 delegation: None,
+                generic: None,
             },
         );
         map
@@ -169,4 +173,37 @@ mod tests {
         let err = unsafe_find_jwt_provider(&provs, &jwt).unwrap_err();
         assert!(matches!(err, JwtFindProviderError::NoMatchingProvider));
     }
+
+    #[test]
+    fn finds_generic_provider_by_configured_issuer() {
+        let iss = "https://my-tenant.eu.auth0.com/";
+
+        let mut provs = providers_with_google();
+        provs.insert(
+            OpenIdDelegationProvider::Generic("auth0".into()),
+            OpenIdAuthProviderConfig {
+                client_id: "client-456".into(),
+                delegation: None,
+                generic: Some(OpenIdGenericProviderConfig {
+                    issuer: iss.into(),
+                    jwks: Jwks { keys: vec![] },
+                    audiences: None,
+                    claims: None,
+                }),
+            },
+        );
+
+        let jwt = jwt_with(json!({"alg":"ES256","typ":"JWT"}), json!({"iss": iss}));
+        let (provider, cfg) =
+            unsafe_find_jwt_provider(&provs, &jwt).expect("should match generic provider");
+        assert_eq!(provider, OpenIdDelegationProvider::Generic("auth0".into()));
+        assert_eq!(cfg.client_id, "client-456");
+
+        let jwt = jwt_with(
+            json!({"alg":"RS256","typ":"JWT"}),
+            json!({"iss": "https://accounts.google.com"}),
+        );
+        let (provider, _) = unsafe_find_jwt_provider(&provs, &jwt).expect("should match google");
+        assert_eq!(provider, OpenIdDelegationProvider::Google);
+    }
 }
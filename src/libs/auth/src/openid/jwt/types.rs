@@ -16,7 +16,7 @@ pub mod cert {
     use candid::{CandidType, Deserialize};
     use serde::Serialize;
 
-    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    #[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
     pub struct Jwk {
         // Key type, e.g. "RSA".
         // https://tools.ietf.org/html/rfc7517#section-4.1
@@ -37,7 +37,7 @@ pub mod cert {
 
     // Supported types for the JSON Web Key `kty` property.
     // https://www.iana.org/assignments/jose/jose.xhtml#web-key-types
-    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    #[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
     pub enum JwkType {
         // Elliptic Curve.
         #[serde(rename = "EC")]
@@ -55,7 +55,7 @@ pub mod cert {
 
     // Algorithm-specific parameters for JSON Web Keys.
     // https://tools.ietf.org/html/rfc7518#section-6
-    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    #[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
     pub enum JwkParams {
         // Elliptic Curve parameters.
         Ec(JwkParamsEc),
@@ -72,7 +72,7 @@ pub mod cert {
 
     // Parameters for Elliptic Curve Keys.
     // https://tools.ietf.org/html/rfc7518#section-6.2
-    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    #[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
     pub struct JwkParamsEc {
         // Identifies the cryptographic curve used with the key.
         // https://tools.ietf.org/html/rfc7518#section-6.2.1.1
@@ -96,7 +96,7 @@ pub mod cert {
 
     // Parameters for RSA Keys.
     // https://tools.ietf.org/html/rfc7518#section-6.3
-    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    #[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
     pub struct JwkParamsRsa {
         // The modulus (part of the RSA public key).
         // https://datatracker.ietf.org/doc/html/rfc7518#section-6.3.1.1
@@ -112,7 +112,7 @@ pub mod cert {
 
     // Parameters for Symmetric Keys.
     // https://tools.ietf.org/html/rfc7518#section-6.4
-    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    #[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
     pub struct JwkParamsOct {
         // The symmetric key as a base64url-encoded value.
         // https://tools.ietf.org/html/rfc7518#section-6.4.1
@@ -121,7 +121,7 @@ pub mod cert {
 
     // Parameters for Octet Key Pairs.
     // https://tools.ietf.org/html/rfc8037#section-2
-    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    #[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
     pub struct JwkParamsOkp {
         // The subtype of the key pair.
         // https://tools.ietf.org/html/rfc8037#section-2
@@ -138,7 +138,7 @@ pub mod cert {
     }
 
     // JSON Web Key Set
-    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    #[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
     pub struct Jwks {
         pub keys: Vec<Jwk>,
     }
@@ -179,7 +179,7 @@ pub(crate) mod errors {
 }
 
 pub mod provider {
-    pub trait JwtIssuers {
-        fn issuers(&self) -> &[&'static str];
+    pub trait JwtIssuers<Config> {
+        fn issuers<'a>(&'a self, config: &'a Config) -> Vec<&'a str>;
     }
 }
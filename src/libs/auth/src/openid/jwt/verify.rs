@@ -4,6 +4,8 @@ use crate::openid::jwt::types::token::JwtClaims;
 use crate::openid::jwt::types::{cert::Jwk, errors::JwtVerifyError};
 use crate::openid::utils::nonce::build_nonce;
 use crate::state::types::state::Salt;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
 use jsonwebtoken::{decode, Algorithm, DecodingKey, TokenData, Validation};
 use serde::de::DeserializeOwned;
 
@@ -11,6 +13,42 @@ fn pick_key<'a>(kid: &str, jwks: &'a [Jwk]) -> Option<&'a Jwk> {
     jwks.iter().find(|j| j.kid.as_deref() == Some(kid))
 }
 
+// Ed25519 public keys are 32 bytes.
+const ED25519_PUBLIC_KEY_LENGTH: usize = 32;
+
+fn decoding_key(alg: &Algorithm, jwk: &Jwk) -> Result<DecodingKey, JwtVerifyError> {
+    // If the key declares an algorithm, the token must be signed with it.
+    if let Some(jwk_alg) = jwk.alg.as_deref() {
+        if jwk_alg.parse::<Algorithm>().ok().as_ref() != Some(alg) {
+            return Err(JwtVerifyError::WrongKeyType);
+        }
+    }
+
+    match (alg, &jwk.kty, &jwk.params) {
+        (Algorithm::RS256, JwkType::Rsa, JwkParams::Rsa(params)) => {
+            DecodingKey::from_rsa_components(&params.n, &params.e)
+                .map_err(|e| JwtVerifyError::BadSig(e.to_string()))
+        }
+        (Algorithm::ES256, JwkType::Ec, JwkParams::Ec(params)) if params.crv == "P-256" => {
+            DecodingKey::from_ec_components(&params.x, &params.y)
+                .map_err(|e| JwtVerifyError::BadSig(e.to_string()))
+        }
+        (Algorithm::EdDSA, JwkType::Okp, JwkParams::Okp(params)) if params.crv == "Ed25519" => {
+            let x = URL_SAFE_NO_PAD
+                .decode(&params.x)
+                .map_err(|e| JwtVerifyError::BadSig(e.to_string()))?;
+
+            // The library expects the raw public key and does not assert its length.
+            if x.len() != ED25519_PUBLIC_KEY_LENGTH {
+                return Err(JwtVerifyError::BadSig("Invalid Ed25519 key".to_string()));
+            }
+
+            Ok(DecodingKey::from_ed_der(&x))
+        }
+        _ => Err(JwtVerifyError::WrongKeyType),
+    }
+}
+
 pub fn verify_openid_jwt<Claims, Custom>(
     jwt: &str,
     issuers: &[&str],
@@ -27,22 +65,14 @@ where
 
     let kid = header.kid.ok_or(JwtVerifyError::MissingKid)?;
 
-    // 2) Find matching RSA key
+    // 2) Find matching key
     let jwk = pick_key(&kid, jwks).ok_or(JwtVerifyError::NoKeyForKid)?;
 
-    // 3) Extract RSA components - We support only Google at the moment,
-    // which always uses RSA keys, and we also did so for GitHub.
-    let (n, e) = match (&jwk.kty, &jwk.params) {
-        (JwkType::Rsa, JwkParams::Rsa(params)) => (&params.n, &params.e),
-        _ => return Err(JwtVerifyError::WrongKeyType),
-    };
-
-    // 4) Build decoding key
-    let key = DecodingKey::from_rsa_components(n, e)
-        .map_err(|e| JwtVerifyError::BadSig(e.to_string()))?;
+    // 3) Build decoding key - the type of the key must match the algorithm of the token
+    let key = decoding_key(&header.alg, jwk)?;
 
-    // 5) Build validation but disable automatic audience validation
-    let mut val = Validation::new(Algorithm::RS256);
+    // 4) Build validation but disable automatic audience validation
+    let mut val = Validation::new(header.alg);
 
     // The forked library uses IC time for time-based checks.
 
@@ -66,7 +96,7 @@ where
 
     let c = &token.claims;
 
-    // 6) Checks the nonce - i.e. the caller + salt is present in the jwt
+    // 5) Checks the nonce - i.e. the caller + salt is present in the jwt
     // This ensures the JWT has not been intercepted and submitted with a different identity.
     let nonce = build_nonce(salt);
 
@@ -74,10 +104,10 @@ where
         return Err(JwtVerifyError::BadClaim("nonce".to_string()));
     }
 
-    // 7) Assert custom fields according consumer's flow
+    // 6) Assert custom fields according consumer's flow
     assert_custom(c)?;
 
-    // 8) Assert expiration
+    // 7) Assert expiration
     let now_ns = now_ns();
     const MAX_VALIDITY_WINDOW_NS: u64 = 10 * 60 * 1_000_000_000; // 10 min
     const IAT_FUTURE_SKEW_NS: u64 = 2 * 60 * 1_000_000_000; // 2 min
@@ -118,7 +148,9 @@ fn now_ns() -> u64 {
 #[cfg(test)]
 mod verify_tests {
     use super::verify_openid_jwt;
-    use crate::openid::jwt::types::cert::{JwkParams, JwkParamsRsa, JwkType};
+    use crate::openid::jwt::types::cert::{
+        JwkParams, JwkParamsEc, JwkParamsOkp, JwkParamsRsa, JwkType,
+    };
     use crate::openid::jwt::types::token::JwtClaims;
     use crate::openid::jwt::types::{cert::Jwk, errors::JwtVerifyError};
     use crate::openid::utils::nonce::build_nonce;
@@ -133,6 +165,15 @@ mod verify_tests {
     const N_B64URL: &str = "qtQHkWpyd489-_bWjRtrvlQX9CwiQreOsi6kNeeySznI8u-8sxyuO3spW1r2pRmu-rc4jnD9vY6eTGZ3WFNIMxe1geXsF_3nQc5fcNJUUZj19BZE4Ud3dCmUQ4ezkslTvBj8RgD-iBJL7BT7YpxpPgvmqQy_9IgYUkDW4I9_e6kME5kVpySvpRznlk73PfAaDkHWmUTN0j2WcxkW09SGJ_f-tStaYXtc4uH5J-PWMRjwsfL66A_sxLxAwUODJ0VUbeDxVFHGJa0L-58_6GYDTqeel1vH4XjezDL8lf53YRyva3aFxGrC_JeLuIUaJOJX1hXWQb2DruB4hVcQX9afrQ";
     const E_B64URL: &str = "AQAB";
 
+    const TEST_EC_PEM: &str = include_str!("../../../tests/keys/test_ec.pem");
+
+    const EC_X_B64URL: &str = "-xjaKUhhwgNgh2FIskei7pJUgFnXCRrw97XFVypcfrI";
+    const EC_Y_B64URL: &str = "KPVk8G33Nt2vVVV9q_mJamDm-JSrM15dPG-PB7DpdAQ";
+
+    const TEST_ED25519_PEM: &str = include_str!("../../../tests/keys/test_ed25519.pem");
+
+    const ED25519_X_B64URL: &str = "xsWLEzDM16KaHCJdzCpA8EXTvvjUkmAwCE88hTOYDaE";
+
     const ISS_GOOGLE: &str = "https://accounts.google.com";
     const AUD_OK: &str = "client-123";
     const KID_OK: &str = "test-kid-1";
@@ -227,6 +268,52 @@ mod verify_tests {
         }
     }
 
+    fn ec_jwk_with_kid(kid: &str) -> Jwk {
+        Jwk {
+            kty: JwkType::Ec,
+            alg: Some("ES256".into()),
+            kid: Some(kid.into()),
+            params: JwkParams::Ec(JwkParamsEc {
+                crv: "P-256".into(),
+                x: EC_X_B64URL.into(),
+                y: EC_Y_B64URL.into(),
+            }),
+        }
+    }
+
+    fn ed25519_jwk_with_kid(kid: &str) -> Jwk {
+        Jwk {
+            kty: JwkType::Okp,
+            alg: Some("EdDSA".into()),
+            kid: Some(kid.into()),
+            params: JwkParams::Okp(JwkParamsOkp {
+                crv: "Ed25519".into(),
+                x: ED25519_X_B64URL.into(),
+            }),
+        }
+    }
+
+    fn sign_token_with(alg: Algorithm, enc: &EncodingKey, c: &GoogleClaims) -> String {
+        let mut h = Header::new(alg);
+        h.typ = Some("JWT".to_string());
+        h.kid = Some(KID_OK.to_string());
+        encode(&h, c, enc).expect("jwt encode")
+    }
+
+    fn valid_claims(salt: &Salt) -> GoogleClaims {
+        let now = now_secs();
+        let nonce = build_nonce(salt);
+
+        claims(
+            ISS_GOOGLE,
+            AUD_OK,
+            Some(now),
+            Some(now - 5),
+            Some(&nonce),
+            Some(now + 600),
+        )
+    }
+
     fn assert_audience(claims: &GoogleClaims) -> Result<(), JwtVerifyError> {
         if claims.aud != AUD_OK {
             return Err(JwtVerifyError::BadClaim("aud".to_string()));
@@ -627,4 +714,119 @@ mod verify_tests {
         );
         assert_eq!(claims.locale.as_deref(), Some("fr-CH"));
     }
+
+    #[test]
+    fn verifies_es256_ok() {
+        let salt = test_salt();
+
+        let enc = EncodingKey::from_ec_pem(TEST_EC_PEM.as_bytes()).expect("valid pem");
+        let token = sign_token_with(Algorithm::ES256, &enc, &valid_claims(&salt));
+
+        let out = verify_openid_jwt(
+            &token,
+            &[ISS_GOOGLE],
+            &[ec_jwk_with_kid(KID_OK)],
+            &salt,
+            assert_audience,
+        )
+        .expect("should verify");
+
+        assert_eq!(out.claims.iss, ISS_GOOGLE);
+        assert_eq!(out.claims.aud, AUD_OK);
+    }
+
+    #[test]
+    fn verifies_eddsa_ok() {
+        let salt = test_salt();
+
+        let enc = EncodingKey::from_ed_pem(TEST_ED25519_PEM.as_bytes()).expect("valid pem");
+        let token = sign_token_with(Algorithm::EdDSA, &enc, &valid_claims(&salt));
+
+        let out = verify_openid_jwt(
+            &token,
+            &[ISS_GOOGLE],
+            &[ed25519_jwk_with_kid(KID_OK)],
+            &salt,
+            assert_audience,
+        )
+        .expect("should verify");
+
+        assert_eq!(out.claims.iss, ISS_GOOGLE);
+        assert_eq!(out.claims.aud, AUD_OK);
+    }
+
+    #[test]
+    fn key_type_not_matching_alg() {
+        let salt = test_salt();
+
+        let enc = EncodingKey::from_ec_pem(TEST_EC_PEM.as_bytes()).expect("valid pem");
+        let token = sign_token_with(Algorithm::ES256, &enc, &valid_claims(&salt));
+
+        // An ES256 token cannot be verified with an RSA key.
+        let err = verify_openid_jwt(
+            &token,
+            &[ISS_GOOGLE],
+            &[jwk_with_kid(KID_OK)],
+            &salt,
+            assert_audience,
+        )
+        .unwrap_err();
+        assert!(matches!(err, JwtVerifyError::WrongKeyType));
+
+        // An EC key on another curve is not supported for ES256.
+        let mut jwk = ec_jwk_with_kid(KID_OK);
+        jwk.params = JwkParams::Ec(JwkParamsEc {
+            crv: "P-384".into(),
+            x: EC_X_B64URL.into(),
+            y: EC_Y_B64URL.into(),
+        });
+
+        let err =
+            verify_openid_jwt(&token, &[ISS_GOOGLE], &[jwk], &salt, assert_audience).unwrap_err();
+        assert!(matches!(err, JwtVerifyError::WrongKeyType));
+    }
+
+    #[test]
+    fn key_alg_not_matching_token_alg() {
+        let salt = test_salt();
+
+        let enc = EncodingKey::from_ed_pem(TEST_ED25519_PEM.as_bytes()).expect("valid pem");
+        let token = sign_token_with(Algorithm::EdDSA, &enc, &valid_claims(&salt));
+
+        let mut jwk = ed25519_jwk_with_kid(KID_OK);
+        jwk.alg = Some("ES256".into());
+
+        let err =
+            verify_openid_jwt(&token, &[ISS_GOOGLE], &[jwk], &salt, assert_audience).unwrap_err();
+        assert!(matches!(err, JwtVerifyError::WrongKeyType));
+    }
+
+    #[test]
+    fn bad_signature_with_wrong_eddsa_key_material() {
+        let salt = test_salt();
+
+        let enc = EncodingKey::from_ed_pem(TEST_ED25519_PEM.as_bytes()).expect("valid pem");
+        let token = sign_token_with(Algorithm::EdDSA, &enc, &valid_claims(&salt));
+
+        let mut jwk = ed25519_jwk_with_kid(KID_OK);
+        jwk.params = JwkParams::Okp(JwkParamsOkp {
+            crv: "Ed25519".into(),
+            x: EC_X_B64URL.into(),
+        });
+
+        let err =
+            verify_openid_jwt(&token, &[ISS_GOOGLE], &[jwk], &salt, assert_audience).unwrap_err();
+        assert!(matches!(err, JwtVerifyError::BadSig(_)));
+
+        // A truncated key is rejected.
+        let mut jwk = ed25519_jwk_with_kid(KID_OK);
+        jwk.params = JwkParams::Okp(JwkParamsOkp {
+            crv: "Ed25519".into(),
+            x: "AQAB".into(),
+        });
+
+        let err =
+            verify_openid_jwt(&token, &[ISS_GOOGLE], &[jwk], &salt, assert_audience).unwrap_err();
+        assert!(matches!(err, JwtVerifyError::BadSig(_)));
+    }
 }
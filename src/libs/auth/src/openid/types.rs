@@ -11,6 +11,7 @@ pub mod provider {
         Google,
         GitHubAuth, // GitHub user authentication (OAuth) via Juno API proxy
         GitHubActions,
+        GitLabCi, // GitLab CI/CD ID tokens issued by gitlab.com
     }
 
     #[derive(
@@ -19,13 +20,19 @@ pub mod provider {
     pub enum OpenIdDelegationProvider {
         Google,
         GitHub,
+        // An OpenID Connect provider configured by the developer (e.g. Auth0, Keycloak, Microsoft Entra or Apple)
+        Generic(OpenIdGenericProviderId),
     }
 
+    // A unique identifier for a generic OpenID Connect provider, chosen by the developer (e.g. "auth0").
+    pub type OpenIdGenericProviderId = String;
+
     #[derive(
         CandidType, Serialize, Deserialize, Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Debug,
     )]
     pub enum OpenIdAutomationProvider {
         GitHub,
+        GitLab,
     }
 
     #[derive(CandidType, Serialize, Deserialize, Clone)]
@@ -1,9 +1,18 @@
-use crate::state::errors::JUNO_AUTH_ERROR_INVALID_ORIGIN;
-use crate::state::types::config::AuthenticationConfig;
+use crate::openid::jwt::types::provider::JwtIssuers;
+use crate::openid::types::provider::OpenIdDelegationProvider;
+use crate::state::asserts::openid::assert_openid_issuer_and_jwks;
+use crate::state::errors::{
+    JUNO_AUTH_ERROR_INVALID_ORIGIN, JUNO_AUTH_ERROR_OPENID_DUPLICATE_ISSUER,
+    JUNO_AUTH_ERROR_OPENID_INVALID_GENERIC_PROVIDER,
+};
+use crate::state::types::config::{
+    AuthenticationConfig, OpenIdAuthProviderConfig, OpenIdGenericProviderConfig,
+};
 use crate::state::types::interface::SetAuthenticationConfig;
 use junobuild_shared::assert::assert_version;
 use junobuild_shared::types::core::DomainName;
 use junobuild_shared::types::state::Version;
+use std::collections::HashSet;
 use url::Url;
 
 pub fn assert_set_authentication_config(
@@ -12,6 +21,8 @@ pub fn assert_set_authentication_config(
 ) -> Result<(), String> {
     assert_config_origin_urls(proposed_config)?;
 
+    assert_config_openid_providers(proposed_config)?;
+
     assert_config_version(current_config, proposed_config.version)?;
 
     Ok(())
@@ -34,6 +45,48 @@ fn assert_config_origin_urls(config: &SetAuthenticationConfig) -> Result<(), Str
     Ok(())
 }
 
+fn assert_config_openid_providers(config: &SetAuthenticationConfig) -> Result<(), String> {
+    let Some(openid) = &config.openid else {
+        return Ok(());
+    };
+
+    for (provider, provider_config) in &openid.providers {
+        assert_generic_provider(provider, provider_config)?;
+    }
+
+    // A token is matched to its provider through its issuer, which therefore must be unique.
+    let mut issuers: HashSet<&str> = HashSet::new();
+
+    for (provider, provider_config) in &openid.providers {
+        for issuer in JwtIssuers::issuers(provider, provider_config) {
+            if !issuers.insert(issuer) {
+                return Err(format!(
+                    "{JUNO_AUTH_ERROR_OPENID_DUPLICATE_ISSUER} ({issuer})"
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn assert_generic_provider(
+    provider: &OpenIdDelegationProvider,
+    config: &OpenIdAuthProviderConfig,
+) -> Result<(), String> {
+    match (provider, &config.generic) {
+        (OpenIdDelegationProvider::Generic(id), Some(generic)) if !id.trim().is_empty() => {
+            assert_generic_provider_config(generic)
+        }
+        (OpenIdDelegationProvider::Google | OpenIdDelegationProvider::GitHub, None) => Ok(()),
+        _ => Err(JUNO_AUTH_ERROR_OPENID_INVALID_GENERIC_PROVIDER.to_string()),
+    }
+}
+
+fn assert_generic_provider_config(config: &OpenIdGenericProviderConfig) -> Result<(), String> {
+    assert_openid_issuer_and_jwks(&config.issuer, &config.jwks)
+}
+
 fn assert_url(domain: &DomainName) -> Result<(), String> {
     let parsed_url = Url::parse(&format!("https://{domain}"));
 
@@ -1,3 +1,4 @@
+use crate::state::asserts::openid::assert_openid_issuer_and_jwks;
 use crate::state::types::automation::AutomationConfig;
 use crate::state::types::interface::SetAutomationConfig;
 use junobuild_shared::assert::assert_version;
@@ -7,11 +8,27 @@ pub fn assert_set_automation_config(
     proposed_config: &SetAutomationConfig,
     current_config: &Option<AutomationConfig>,
 ) -> Result<(), String> {
+    assert_config_openid_providers(proposed_config)?;
+
     assert_config_version(current_config, proposed_config.version)?;
 
     Ok(())
 }
 
+fn assert_config_openid_providers(config: &SetAutomationConfig) -> Result<(), String> {
+    let Some(openid) = &config.openid else {
+        return Ok(());
+    };
+
+    for provider_config in openid.providers.values() {
+        if let Some(self_hosted) = &provider_config.self_hosted {
+            assert_openid_issuer_and_jwks(&self_hosted.issuer, &self_hosted.jwks)?;
+        }
+    }
+
+    Ok(())
+}
+
 fn assert_config_version(
     current_config: &Option<AutomationConfig>,
     proposed_version: Option<Version>,
@@ -1,5 +1,6 @@
 mod authentication;
 mod automation;
+mod openid;
 
 pub use authentication::*;
 pub use automation::*;
@@ -0,0 +1,23 @@
+use crate::openid::jwt::types::cert::Jwks;
+use crate::state::errors::{
+    JUNO_AUTH_ERROR_OPENID_INVALID_ISSUER, JUNO_AUTH_ERROR_OPENID_INVALID_JWKS,
+};
+use url::Url;
+
+pub fn assert_openid_issuer_and_jwks(issuer: &str, jwks: &Jwks) -> Result<(), String> {
+    let url = Url::parse(issuer)
+        .map_err(|_| format!("{JUNO_AUTH_ERROR_OPENID_INVALID_ISSUER} ({issuer})"))?;
+
+    if url.scheme() != "https" {
+        return Err(format!(
+            "{JUNO_AUTH_ERROR_OPENID_INVALID_ISSUER} ({issuer})"
+        ));
+    }
+
+    // Tokens are verified with the key matching their kid.
+    if jwks.keys.is_empty() || jwks.keys.iter().any(|key| key.kid.is_none()) {
+        return Err(JUNO_AUTH_ERROR_OPENID_INVALID_JWKS.to_string());
+    }
+
+    Ok(())
+}
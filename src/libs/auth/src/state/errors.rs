@@ -7,3 +7,15 @@ pub const JUNO_AUTH_ERROR_AUTOMATION_NOT_CONFIGURED: &str =
     "juno.auth.error.automation_not_configured";
 // Authentication with OpenId disabled.
 pub const JUNO_AUTH_ERROR_OPENID_DISABLED: &str = "juno.auth.error.openid_disabled";
+// The key set of a generic OpenID provider is part of its configuration and is not fetched.
+pub const JUNO_AUTH_ERROR_OPENID_GENERIC_PROVIDER_NOT_FETCHABLE: &str =
+    "juno.auth.error.openid_generic_provider_not_fetchable";
+// A generic OpenID provider requires a configuration, which is not supported for other providers.
+pub const JUNO_AUTH_ERROR_OPENID_INVALID_GENERIC_PROVIDER: &str =
+    "juno.auth.error.openid_invalid_generic_provider";
+// The issuer of a generic OpenID provider or a self-hosted instance must be a valid https URL.
+pub const JUNO_AUTH_ERROR_OPENID_INVALID_ISSUER: &str = "juno.auth.error.openid_invalid_issuer";
+// The key set of a generic OpenID provider or a self-hosted instance must not be empty and each key requires a kid.
+pub const JUNO_AUTH_ERROR_OPENID_INVALID_JWKS: &str = "juno.auth.error.openid_invalid_jwks";
+// Each OpenID provider must have its own issuer.
+pub const JUNO_AUTH_ERROR_OPENID_DUPLICATE_ISSUER: &str = "juno.auth.error.openid_duplicate_issuer";
@@ -58,6 +58,7 @@ pub(crate) mod runtime_state {
 
 pub mod config {
     use crate::delegation::types::DelegationTargets;
+    use crate::openid::jwt::types::cert::Jwks;
     use crate::openid::types::provider::OpenIdDelegationProvider;
     use candid::{CandidType, Deserialize, Principal};
     use junobuild_shared::types::core::DomainName;
@@ -100,6 +101,35 @@ pub mod config {
     pub struct OpenIdAuthProviderConfig {
         pub client_id: OpenIdAuthProviderClientId,
         pub delegation: Option<OpenIdAuthProviderDelegationConfig>,
+        // Required for - and only for - generic providers.
+        pub generic: Option<OpenIdGenericProviderConfig>,
+    }
+
+    #[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+    pub struct OpenIdGenericProviderConfig {
+        // The issuer of the ID tokens, matched against their `iss` claim (e.g. "https://my-tenant.eu.auth0.com/").
+        pub issuer: String,
+        // The public keys used to verify the ID tokens.
+        // The key set is held by the module and not fetched, therefore it should be updated when the provider rotates its keys.
+        pub jwks: Jwks,
+        // Audiences accepted in addition to the client ID.
+        pub audiences: Option<Vec<String>>,
+        // Mapping of the claims of the provider to the profile of the user.
+        pub claims: Option<OpenIdGenericProviderClaims>,
+    }
+
+    // The names of the claims providing the profile of the user, if they differ from the standard claims.
+    // e.g. `email: Some("upn".to_string())` for Microsoft Entra.
+    // See: https://openid.net/specs/openid-connect-core-1_0.html#StandardClaims
+    #[derive(Default, CandidType, Serialize, Deserialize, Clone, Debug)]
+    pub struct OpenIdGenericProviderClaims {
+        pub email: Option<String>,
+        pub name: Option<String>,
+        pub given_name: Option<String>,
+        pub family_name: Option<String>,
+        pub preferred_username: Option<String>,
+        pub picture: Option<String>,
+        pub locale: Option<String>,
     }
 
     #[derive(Default, CandidType, Serialize, Deserialize, Clone, Debug)]
@@ -111,6 +141,7 @@ pub mod config {
 
 pub mod automation {
     use crate::automation::types::AutomationScope;
+    use crate::openid::jwt::types::cert::Jwks;
     use crate::openid::types::provider::OpenIdAutomationProvider;
     use candid::{CandidType, Deserialize, Principal};
     use junobuild_shared::types::state::{Timestamp, Version};
@@ -134,14 +165,16 @@ pub mod automation {
     pub type OpenIdAutomationProviders =
         BTreeMap<OpenIdAutomationProvider, OpenIdAutomationProviderConfig>;
 
-    // Repository identifier for GitHub automation.
-    // Corresponds to the `repository` claim in GitHub OIDC tokens (e.g., "octo-org/octo-repo").
+    // Repository identifier for GitHub and GitLab automation.
+    // Corresponds to the `repository` claim in GitHub OIDC tokens (e.g., "octo-org/octo-repo")
+    // and to the `project_path` claim in GitLab ID tokens (e.g., "my-group/my-subgroup/my-project").
     // See: https://docs.github.com/en/actions/concepts/security/openid-connect#understanding-the-oidc-token
+    // See: https://docs.gitlab.com/ci/secrets/id_token_authentication/#token-payload
     #[derive(CandidType, Serialize, Deserialize, Clone, Debug, Hash, Eq, PartialEq)]
     pub struct RepositoryKey {
-        // Repository owner (e.g. "octo-org")
+        // Repository owner or GitLab namespace (e.g. "octo-org" or "my-group/my-subgroup")
         pub owner: String,
-        // Repository name (e.g. "octo-repo")
+        // Repository or GitLab project name (e.g. "octo-repo")
         pub name: String,
     }
 
@@ -152,12 +185,25 @@ pub mod automation {
     pub struct OpenIdAutomationProviderConfig {
         pub repositories: OpenIdAutomationRepositories,
         pub controller: Option<OpenIdAutomationProviderControllerConfig>,
+        // A self-managed instance (e.g. a self-hosted GitLab) whose tokens are verified with the
+        // key set of the configuration, since the Observatory only monitors well-known providers.
+        pub self_hosted: Option<OpenIdAutomationSelfHostedConfig>,
     }
 
     #[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
     pub struct OpenIdAutomationRepositoryConfig {
         // Optionally restrict to specific references / branches (e.g. ["refs/heads/main", "refs/pull/74/merge"])
         pub refs: Option<Vec<String>>,
+        // Optionally restrict to protected references / branches, according to the `ref_protected` claim
+        pub protected_refs: Option<bool>,
+    }
+
+    #[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+    pub struct OpenIdAutomationSelfHostedConfig {
+        // The URL of the instance, which is the issuer of the tokens (e.g. "https://gitlab.example.com")
+        pub issuer: String,
+        // The key set published by the instance (e.g. "https://gitlab.example.com/oauth/discovery/keys")
+        pub jwks: Jwks,
     }
 
     #[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
@@ -34,6 +34,7 @@ fn list_proposals_impl(
         items,
         items_length,
         matches_length: items_length,
+        next_cursor: None,
     }
 }
 
@@ -1,54 +1,73 @@
 use crate::proposals::state::stable::list_proposals as list_proposals_stable;
-use crate::proposals::{count_proposals, ListProposalResults, ListProposalsParams};
+use crate::proposals::{count_proposals, ListProposalResults, ListProposalsParams, ProposalId};
 use crate::strategies::CdnStableStrategy;
+use junobuild_shared::data::list::{decode_list_cursor, encode_list_cursor};
 use std::cmp::Reverse;
 
 const FIRST_PROPOSAL_ID: u128 = 1;
 
 const DEFAULT_MAX_PROPOSALS: u128 = 100;
 
+/// Lists proposals by ID.
+///
+/// Pagination either starts after a `start_after` proposal ID or resumes from a `cursor` returned
+/// with a previous page - the cursor takes precedence if both are provided. Proposals are kept in
+/// stable memory by ID, therefore resuming is a range lookup.
+///
+/// # Returns
+/// - `Ok(ListProposalResults)`: The proposals and a `next_cursor` if more proposals follow.
+/// - `Err(String)`: If the cursor cannot be decoded.
 pub fn list_proposals(
     cdn_stable: &impl CdnStableStrategy,
     ListProposalsParams { paginate, order }: &ListProposalsParams,
-) -> ListProposalResults {
+) -> Result<ListProposalResults, String> {
     let desc = order.as_ref().map(|order| order.desc).unwrap_or(false);
     let limit = paginate
         .as_ref()
         .and_then(|p| p.limit)
         .unwrap_or(DEFAULT_MAX_PROPOSALS);
 
+    let count = u128::try_from(count_proposals(cdn_stable)).unwrap_or(u128::MAX);
+
+    let start_after = match paginate {
+        None => None,
+        Some(paginate) => match &paginate.cursor {
+            Some(cursor) => Some(decode_list_cursor::<ProposalId>(cursor)?),
+            None => paginate.start_after,
+        },
+    };
+
+    // One proposal beyond the page is looked up to know whether more proposals follow.
+    let page_size = match paginate {
+        None => DEFAULT_MAX_PROPOSALS,
+        Some(_) => limit,
+    };
+
     let filters = match (paginate, desc) {
         (None, false) => {
             let start = FIRST_PROPOSAL_ID;
-            let end = start.saturating_add(DEFAULT_MAX_PROPOSALS);
+            let end = start.saturating_add(page_size).saturating_add(1);
             (start, end)
         }
 
         (None, true) => {
-            let end = u128::try_from(count_proposals(cdn_stable))
-                .unwrap_or(u128::MAX)
-                .saturating_add(1);
-            let start = end.saturating_sub(DEFAULT_MAX_PROPOSALS);
+            let end = count.saturating_add(1);
+            let start = end.saturating_sub(page_size).saturating_sub(1);
             (start, end)
         }
 
-        (Some(paginate), false) => {
-            let start = paginate
-                .start_after
+        (Some(_), false) => {
+            let start = start_after
                 .as_ref()
                 .map(|proposal_id| proposal_id.saturating_add(1))
                 .unwrap_or(FIRST_PROPOSAL_ID);
-            let end = start.saturating_add(limit);
+            let end = start.saturating_add(page_size).saturating_add(1);
             (start, end)
         }
 
-        (Some(paginate), true) => {
-            let end = paginate.start_after.unwrap_or(
-                u128::try_from(count_proposals(cdn_stable))
-                    .unwrap_or(u128::MAX)
-                    .saturating_add(1),
-            );
-            let start = end.saturating_sub(limit);
+        (Some(_), true) => {
+            let end = start_after.unwrap_or(count.saturating_add(1));
+            let start = end.saturating_sub(page_size).saturating_sub(1);
             (start, end)
         }
     };
@@ -59,5 +78,19 @@ pub fn list_proposals(
         proposals.items.sort_by_key(|b| Reverse(b.0.proposal_id));
     }
 
-    proposals
+    let page_size = usize::try_from(page_size).unwrap_or(usize::MAX);
+
+    let has_more = proposals.items.len() > page_size;
+
+    proposals.items.truncate(page_size);
+    proposals.items_length = proposals.items.len();
+    proposals.matches_length = proposals.items_length;
+
+    proposals.next_cursor = proposals
+        .items
+        .last()
+        .filter(|_| has_more)
+        .map(|(key, _)| encode_list_cursor(&key.proposal_id));
+
+    Ok(proposals)
 }
@@ -1,6 +1,7 @@
 use candid::{CandidType, Deserialize, Principal};
 use ic_stable_structures::StableBTreeMap;
 use junobuild_shared::types::core::Hash;
+use junobuild_shared::types::list::ListCursor;
 use junobuild_shared::types::memory::Memory;
 use junobuild_shared::types::state::{Timestamp, Version};
 use serde::Serialize;
@@ -66,6 +67,7 @@ pub struct ListProposalsParams {
 pub struct ListProposalsPaginate {
     pub start_after: Option<ProposalId>,
     pub limit: Option<u128>,
+    pub cursor: Option<ListCursor>,
 }
 
 #[derive(Default, CandidType, Deserialize, Clone)]
@@ -78,4 +80,5 @@ pub struct ListProposalResults {
     pub items: Vec<(ProposalKey, Proposal)>,
     pub items_length: usize,
     pub matches_length: usize,
+    pub next_cursor: Option<ListCursor>,
 }
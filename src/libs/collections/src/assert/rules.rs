@@ -1,15 +1,22 @@
 use crate::assert::collection::{is_not_system_collection, is_system_collection};
 use crate::constants::core::SYS_COLLECTION_PREFIX;
 use crate::errors::{
-    JUNO_COLLECTIONS_ERROR_DELETE_PREFIX_RESERVED,
+    JUNO_COLLECTIONS_ERROR_CONTENT_VALIDATION_NOT_SUPPORTED,
+    JUNO_COLLECTIONS_ERROR_DELETE_PREFIX_RESERVED, JUNO_COLLECTIONS_ERROR_HISTORY_NOT_SUPPORTED,
+    JUNO_COLLECTIONS_ERROR_INDEXES_NOT_SUPPORTED,
+    JUNO_COLLECTIONS_ERROR_INVALID_CONTENT_VALIDATION, JUNO_COLLECTIONS_ERROR_INVALID_HISTORY,
+    JUNO_COLLECTIONS_ERROR_INVALID_INDEX, JUNO_COLLECTIONS_ERROR_INVALID_ROLES,
+    JUNO_COLLECTIONS_ERROR_INVALID_TTL, JUNO_COLLECTIONS_ERROR_INVALID_VARIANT,
     JUNO_COLLECTIONS_ERROR_MODIFY_RESERVED_COLLECTION, JUNO_COLLECTIONS_ERROR_PREFIX_RESERVED,
     JUNO_COLLECTIONS_ERROR_RATE_CONFIG_ENABLED, JUNO_COLLECTIONS_ERROR_RESERVED_NAME,
+    JUNO_COLLECTIONS_ERROR_SCHEMA_NOT_SUPPORTED, JUNO_COLLECTIONS_ERROR_VARIANTS_NOT_SUPPORTED,
 };
 use crate::types::core::CollectionKey;
 use crate::types::interface::SetRule;
-use crate::types::rules::{Memory, Rule, Rules};
+use crate::types::rules::{Memory, Permission, RoleName, Rule, Rules};
 use junobuild_shared::assert::assert_version;
 use junobuild_shared::types::state::Version;
+use std::collections::HashSet;
 
 pub fn assert_memory(current_rule: Option<&Rule>, memory: &Option<Memory>) -> Result<(), String> {
     // Validate memory type does not change
@@ -115,6 +122,12 @@ pub fn assert_system_collection_set_permission(
         || current_rule.mutable_permissions != user_rule.mutable_permissions
         || current_rule.max_size != user_rule.max_size
         || current_rule.max_capacity != user_rule.max_capacity
+        || current_rule.indexes != user_rule.indexes
+        || current_rule.history != user_rule.history
+        || current_rule.ttl != user_rule.ttl
+        || current_rule.schema != user_rule.schema
+        || current_rule.variants != user_rule.variants
+        || current_rule.content_validation != user_rule.content_validation
     {
         return Err(format!(
             "{JUNO_COLLECTIONS_ERROR_MODIFY_RESERVED_COLLECTION} ({collection})"
@@ -128,6 +141,156 @@ pub fn assert_system_collection_set_permission(
     Ok(())
 }
 
+pub fn assert_indexes(user_rule: &SetRule, storage_checks: bool) -> Result<(), String> {
+    let indexes = match &user_rule.indexes {
+        None => return Ok(()),
+        Some(indexes) => indexes,
+    };
+
+    // Indexes are built from the JSON data of the documents, assets have no such data.
+    if storage_checks {
+        return Err(JUNO_COLLECTIONS_ERROR_INDEXES_NOT_SUPPORTED.to_string());
+    }
+
+    let mut fields = HashSet::new();
+
+    for field in indexes {
+        let valid = !field.is_empty()
+            && field.split('.').all(|segment| !segment.is_empty())
+            && fields.insert(field);
+
+        if !valid {
+            return Err(format!("{JUNO_COLLECTIONS_ERROR_INVALID_INDEX} ({field})"));
+        }
+    }
+
+    Ok(())
+}
+
+pub fn assert_history(user_rule: &SetRule, storage_checks: bool) -> Result<(), String> {
+    let history = match &user_rule.history {
+        None => return Ok(()),
+        Some(history) => history,
+    };
+
+    // Revisions are documents, assets are not versioned.
+    if storage_checks {
+        return Err(JUNO_COLLECTIONS_ERROR_HISTORY_NOT_SUPPORTED.to_string());
+    }
+
+    let max_revisions = history.max_revisions.unwrap_or(0);
+    let retention = history.retention.unwrap_or(0);
+
+    if max_revisions == 0 && retention == 0 {
+        return Err(JUNO_COLLECTIONS_ERROR_INVALID_HISTORY.to_string());
+    }
+
+    Ok(())
+}
+
+pub fn assert_schema(user_rule: &SetRule, storage_checks: bool) -> Result<(), String> {
+    // Schemas describe the JSON data of the documents, assets have no such data.
+    if storage_checks && user_rule.schema.is_some() {
+        return Err(JUNO_COLLECTIONS_ERROR_SCHEMA_NOT_SUPPORTED.to_string());
+    }
+
+    Ok(())
+}
+
+pub fn assert_variants(user_rule: &SetRule, storage_checks: bool) -> Result<(), String> {
+    let variants = match &user_rule.variants {
+        None => return Ok(()),
+        Some(variants) => variants,
+    };
+
+    // Variants are images, documents have no binary content.
+    if !storage_checks {
+        return Err(JUNO_COLLECTIONS_ERROR_VARIANTS_NOT_SUPPORTED.to_string());
+    }
+
+    let mut names = HashSet::new();
+
+    for variant in variants {
+        let valid = !variant.name.is_empty()
+            && variant
+                .name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+            && variant.max_width != Some(0)
+            && variant.max_height != Some(0)
+            && names.insert(&variant.name);
+
+        if !valid {
+            return Err(format!(
+                "{JUNO_COLLECTIONS_ERROR_INVALID_VARIANT} ({})",
+                variant.name
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+pub fn assert_content_validation(user_rule: &SetRule, storage_checks: bool) -> Result<(), String> {
+    let content_validation = match &user_rule.content_validation {
+        None => return Ok(()),
+        Some(content_validation) => content_validation,
+    };
+
+    // Documents have no binary content.
+    if !storage_checks {
+        return Err(JUNO_COLLECTIONS_ERROR_CONTENT_VALIDATION_NOT_SUPPORTED.to_string());
+    }
+
+    if content_validation.max_image_width == Some(0)
+        || content_validation.max_image_height == Some(0)
+    {
+        return Err(JUNO_COLLECTIONS_ERROR_INVALID_CONTENT_VALIDATION.to_string());
+    }
+
+    Ok(())
+}
+
+pub fn assert_ttl(user_rule: &SetRule) -> Result<(), String> {
+    if user_rule.ttl == Some(0) {
+        return Err(JUNO_COLLECTIONS_ERROR_INVALID_TTL.to_string());
+    }
+
+    Ok(())
+}
+
+pub fn assert_roles(user_rule: &SetRule) -> Result<(), String> {
+    for permission in [&user_rule.read, &user_rule.write] {
+        let roles = match permission {
+            Permission::Roles(roles) => roles,
+            _ => continue,
+        };
+
+        let mut names = HashSet::new();
+
+        let valid = !roles.is_empty()
+            && roles
+                .iter()
+                .all(|role| is_valid_role_name(role) && names.insert(role));
+
+        if !valid {
+            return Err(format!(
+                "{JUNO_COLLECTIONS_ERROR_INVALID_ROLES} ({})",
+                roles.join(", ")
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+pub fn is_valid_role_name(role: &RoleName) -> bool {
+    !role.is_empty()
+        && role
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
 pub fn assert_system_collection_delete_permission(
     collection: &CollectionKey,
 ) -> Result<(), String> {
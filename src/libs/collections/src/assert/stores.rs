@@ -1,4 +1,4 @@
-use crate::types::rules::Permission;
+use crate::types::rules::{Permission, RoleName};
 use candid::Principal;
 use junobuild_shared::segments::access_keys::is_write_access_key;
 use junobuild_shared::types::state::{AccessKeys, UserId};
@@ -19,6 +19,26 @@ pub fn assert_permission_with(
     caller: Principal,
     controllers: &AccessKeys,
     is_allowed_controller: fn(UserId, &AccessKeys) -> bool,
+) -> bool {
+    assert_permission_with_roles(
+        permission,
+        owner,
+        caller,
+        controllers,
+        is_allowed_controller,
+        has_no_role,
+    )
+}
+
+/// Same as `assert_permission_with` but resolves role-based permissions with the provided function.
+/// Without such a resolver - e.g. in the Console - only the controllers are granted access to a collection ruled by roles.
+pub fn assert_permission_with_roles(
+    permission: &Permission,
+    owner: Principal,
+    caller: Principal,
+    controllers: &AccessKeys,
+    is_allowed_controller: fn(UserId, &AccessKeys) -> bool,
+    has_role: fn(UserId, &[RoleName]) -> bool,
 ) -> bool {
     match permission {
         Permission::Public => true,
@@ -30,6 +50,9 @@ pub fn assert_permission_with(
                 || is_write_access_key(caller, controllers)
         }
         Permission::Controllers => is_allowed_controller(caller, controllers),
+        Permission::Roles(roles) => {
+            is_allowed_controller(caller, controllers) || has_any_role(caller, roles, has_role)
+        }
     }
 }
 
@@ -48,10 +71,29 @@ pub fn assert_create_permission_with(
     caller: Principal,
     controllers: &AccessKeys,
     is_allowed_controller: fn(UserId, &AccessKeys) -> bool,
+) -> bool {
+    assert_create_permission_with_roles(
+        permission,
+        caller,
+        controllers,
+        is_allowed_controller,
+        has_no_role,
+    )
+}
+
+pub fn assert_create_permission_with_roles(
+    permission: &Permission,
+    caller: Principal,
+    controllers: &AccessKeys,
+    is_allowed_controller: fn(UserId, &AccessKeys) -> bool,
+    has_role: fn(UserId, &[RoleName]) -> bool,
 ) -> bool {
     match permission {
         Permission::Public => true,
         Permission::Controllers => is_allowed_controller(caller, controllers),
+        Permission::Roles(roles) => {
+            is_allowed_controller(caller, controllers) || has_any_role(caller, roles, has_role)
+        }
         _ => {
             assert_not_anonymous(caller)
                 && (is_not_controller(caller, controllers)
@@ -72,6 +114,18 @@ pub fn public_permission(permission: &Permission) -> bool {
     matches!(permission, Permission::Public)
 }
 
+fn has_no_role(_caller: UserId, _roles: &[RoleName]) -> bool {
+    false
+}
+
+fn has_any_role(
+    caller: Principal,
+    roles: &[RoleName],
+    has_role: fn(UserId, &[RoleName]) -> bool,
+) -> bool {
+    assert_not_anonymous(caller) && has_role(caller, roles)
+}
+
 fn is_controller(caller: Principal, controllers: &AccessKeys) -> bool {
     controllers.contains_key(&caller)
 }
@@ -103,6 +157,7 @@ mod tests {
             expires_at,
             scope,
             kind: None,
+            restrictions: None,
         }
     }
 
@@ -443,4 +498,91 @@ mod tests {
             &controllers
         ));
     }
+
+    fn has_editor_role(_caller: UserId, roles: &[RoleName]) -> bool {
+        roles.iter().any(|role| role == "editor")
+    }
+
+    fn roles(names: &[&str]) -> Permission {
+        Permission::Roles(names.iter().map(|name| name.to_string()).collect())
+    }
+
+    #[test]
+    fn test_roles_permission_allows_user_with_role() {
+        let controllers = AccessKeys::new();
+        let owner = test_principal(1);
+        let caller = test_principal(2);
+
+        assert!(assert_permission_with_roles(
+            &roles(&["viewer", "editor"]),
+            owner,
+            caller,
+            &controllers,
+            is_write_access_key,
+            has_editor_role
+        ));
+        assert!(!assert_permission_with_roles(
+            &roles(&["moderator"]),
+            owner,
+            caller,
+            &controllers,
+            is_write_access_key,
+            has_editor_role
+        ));
+    }
+
+    #[test]
+    fn test_roles_permission_rejects_anonymous() {
+        let controllers = AccessKeys::new();
+
+        assert!(!assert_permission_with_roles(
+            &roles(&["editor"]),
+            test_principal(1),
+            Principal::anonymous(),
+            &controllers,
+            is_write_access_key,
+            has_editor_role
+        ));
+    }
+
+    #[test]
+    fn test_roles_permission_allows_controller_only_without_resolver() {
+        let mut controllers = AccessKeys::new();
+        let owner = test_principal(1);
+        let controller = test_principal(2);
+
+        controllers.insert(controller, create_controller(AccessKeyScope::Write, None));
+
+        assert!(assert_permission(
+            &roles(&["editor"]),
+            owner,
+            controller,
+            &controllers
+        ));
+        assert!(!assert_permission(
+            &roles(&["editor"]),
+            owner,
+            owner,
+            &controllers
+        ));
+    }
+
+    #[test]
+    fn test_create_roles_permission() {
+        let controllers = AccessKeys::new();
+        let caller = test_principal(1);
+
+        assert!(assert_create_permission_with_roles(
+            &roles(&["editor"]),
+            caller,
+            &controllers,
+            is_write_access_key,
+            has_editor_role
+        ));
+        assert!(!assert_create_permission(
+            &roles(&["editor"]),
+            caller,
+            &controllers
+        ));
+    }
 }
@@ -12,8 +12,15 @@ pub const COLLECTION_ASSET_DEFAULT_RULE: SetRule = SetRule {
     max_size: None,
     max_capacity: None,
     max_changes_per_user: None,
+    max_bytes_per_user: None,
     version: None,
     rate_config: None,
+    indexes: None,
+    history: None,
+    ttl: None,
+    schema: None,
+    variants: None,
+    content_validation: None,
 };
 
 pub const DEFAULT_ASSETS_COLLECTIONS: [(&str, SetRule); 1] =
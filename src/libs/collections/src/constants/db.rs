@@ -20,8 +20,15 @@ const COLLECTION_USER_DEFAULT_RULE: SetRule = SetRule {
     max_size: None,
     max_capacity: None,
     max_changes_per_user: None,
+    max_bytes_per_user: None,
     version: None,
     rate_config: Some(DEFAULT_RATE_CONFIG),
+    indexes: None,
+    history: None,
+    ttl: None,
+    schema: None,
+    variants: None,
+    content_validation: None,
 };
 
 pub const COLLECTION_LOG_DEFAULT_RULE: SetRule = SetRule {
@@ -32,8 +39,15 @@ pub const COLLECTION_LOG_DEFAULT_RULE: SetRule = SetRule {
     max_size: None,
     max_capacity: Some(100),
     max_changes_per_user: None,
+    max_bytes_per_user: None,
     version: None,
     rate_config: None,
+    indexes: None,
+    history: None,
+    ttl: None,
+    schema: None,
+    variants: None,
+    content_validation: None,
 };
 
 pub const COLLECTION_USER_USAGE_DEFAULT_RULE: SetRule = SetRule {
@@ -44,8 +58,15 @@ pub const COLLECTION_USER_USAGE_DEFAULT_RULE: SetRule = SetRule {
     max_size: None,
     max_capacity: None,
     max_changes_per_user: None,
+    max_bytes_per_user: None,
     version: None,
     rate_config: None,
+    indexes: None,
+    history: None,
+    ttl: None,
+    schema: None,
+    variants: None,
+    content_validation: None,
 };
 
 pub const COLLECTION_USER_WEBAUTHN_DEFAULT_RULE: SetRule = SetRule {
@@ -60,8 +81,15 @@ pub const COLLECTION_USER_WEBAUTHN_DEFAULT_RULE: SetRule = SetRule {
     max_size: None,
     max_capacity: None,
     max_changes_per_user: None,
+    max_bytes_per_user: None,
     version: None,
     rate_config: None,
+    indexes: None,
+    history: None,
+    ttl: None,
+    schema: None,
+    variants: None,
+    content_validation: None,
 };
 
 pub const COLLECTION_USER_WEBAUTHN_INDEX_DEFAULT_RULE: SetRule = SetRule {
@@ -74,8 +102,15 @@ pub const COLLECTION_USER_WEBAUTHN_INDEX_DEFAULT_RULE: SetRule = SetRule {
     max_size: None,
     max_capacity: None,
     max_changes_per_user: None,
+    max_bytes_per_user: None,
     version: None,
     rate_config: None,
+    indexes: None,
+    history: None,
+    ttl: None,
+    schema: None,
+    variants: None,
+    content_validation: None,
 };
 
 pub const COLLECTION_AUTOMATION_TOKEN_DEFAULT_RULE: SetRule = SetRule {
@@ -87,8 +122,15 @@ pub const COLLECTION_AUTOMATION_TOKEN_DEFAULT_RULE: SetRule = SetRule {
     max_size: None,
     max_capacity: None,
     max_changes_per_user: None,
+    max_bytes_per_user: None,
     version: None,
     rate_config: None,
+    indexes: None,
+    history: None,
+    ttl: None,
+    schema: None,
+    variants: None,
+    content_validation: None,
 };
 
 pub const COLLECTION_AUTOMATION_WORKFLOW_DEFAULT_RULE: SetRule = SetRule {
@@ -101,8 +143,15 @@ pub const COLLECTION_AUTOMATION_WORKFLOW_DEFAULT_RULE: SetRule = SetRule {
     max_size: None,
     max_capacity: None,
     max_changes_per_user: None,
+    max_bytes_per_user: None,
     version: None,
     rate_config: None,
+    indexes: None,
+    history: None,
+    ttl: None,
+    schema: None,
+    variants: None,
+    content_validation: None,
 };
 
 pub const DEFAULT_DB_COLLECTIONS: [(&str, SetRule); 7] = [
@@ -18,3 +18,31 @@ pub const JUNO_COLLECTIONS_ERROR_COLLECTION_NOT_EMPTY: &str = "juno.collections.
 pub const JUNO_COLLECTIONS_ERROR_COLLECTION_NOT_FOUND: &str = "juno.collections.error.not_found";
 // Collection starts with {}, a reserved prefix
 pub const JUNO_COLLECTIONS_ERROR_PREFIX_RESERVED: &str = "juno.collections.error.prefix_reserved";
+// Indexes are only supported for datastore collections.
+pub const JUNO_COLLECTIONS_ERROR_INDEXES_NOT_SUPPORTED: &str =
+    "juno.collections.error.indexes_not_supported";
+// Index field {} is invalid or declared more than once.
+pub const JUNO_COLLECTIONS_ERROR_INVALID_INDEX: &str = "juno.collections.error.invalid_index";
+// History is only supported for datastore collections.
+pub const JUNO_COLLECTIONS_ERROR_HISTORY_NOT_SUPPORTED: &str =
+    "juno.collections.error.history_not_supported";
+// History requires a maximum number of revisions or a retention period greater than zero.
+pub const JUNO_COLLECTIONS_ERROR_INVALID_HISTORY: &str = "juno.collections.error.invalid_history";
+// Schemas are only supported for datastore collections.
+pub const JUNO_COLLECTIONS_ERROR_SCHEMA_NOT_SUPPORTED: &str =
+    "juno.collections.error.schema_not_supported";
+// Image variants are only supported for storage collections.
+pub const JUNO_COLLECTIONS_ERROR_VARIANTS_NOT_SUPPORTED: &str =
+    "juno.collections.error.variants_not_supported";
+// An image variant requires a unique name made of alphanumeric characters, dashes or underscores, and bounds greater than zero.
+pub const JUNO_COLLECTIONS_ERROR_INVALID_VARIANT: &str = "juno.collections.error.invalid_variant";
+// Content validation is only supported for storage collections.
+pub const JUNO_COLLECTIONS_ERROR_CONTENT_VALIDATION_NOT_SUPPORTED: &str =
+    "juno.collections.error.content_validation_not_supported";
+// The image bounds of a content validation must be greater than zero.
+pub const JUNO_COLLECTIONS_ERROR_INVALID_CONTENT_VALIDATION: &str =
+    "juno.collections.error.invalid_content_validation";
+// The default time-to-live of a collection must be greater than zero.
+pub const JUNO_COLLECTIONS_ERROR_INVALID_TTL: &str = "juno.collections.error.invalid_ttl";
+// A role-based permission requires at least one unique role name made of alphanumeric characters, dashes or underscores.
+pub const JUNO_COLLECTIONS_ERROR_INVALID_ROLES: &str = "juno.collections.error.invalid_roles";
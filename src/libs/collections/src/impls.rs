@@ -3,8 +3,8 @@ use crate::errors::JUNO_COLLECTIONS_ERROR_RESERVED_COLLECTION;
 use crate::types::core::CollectionKey;
 use crate::types::interface::SetRule;
 use crate::types::rules::{Memory, Rule};
-use ic_cdk::api::time;
 use junobuild_shared::data::version::next_version;
+use junobuild_shared::ic::api::time;
 use junobuild_shared::types::state::{Timestamp, Version, Versioned};
 
 impl Rule {
@@ -12,6 +12,12 @@ impl Rule {
         self.memory.clone().unwrap_or_default()
     }
 
+    /// Resolves when an entry written in the collection expires: the requested expiration if any,
+    /// otherwise the default time-to-live of the collection if one is configured.
+    pub fn expires_at(&self, requested: &Option<Timestamp>, now: Timestamp) -> Option<Timestamp> {
+        requested.or_else(|| self.ttl.map(|ttl| now.saturating_add(ttl)))
+    }
+
     pub fn prepare(
         collection: &CollectionKey,
         current_rule: &Option<&Rule>,
@@ -50,10 +56,17 @@ impl Rule {
             max_size: user_rule.max_size,
             max_capacity: user_rule.max_capacity,
             max_changes_per_user: user_rule.max_changes_per_user,
+            max_bytes_per_user: user_rule.max_bytes_per_user,
             created_at,
             updated_at,
             version: Some(version),
             rate_config: user_rule.rate_config.clone(),
+            indexes: user_rule.indexes.clone(),
+            history: user_rule.history.clone(),
+            ttl: user_rule.ttl,
+            schema: user_rule.schema.clone(),
+            variants: user_rule.variants.clone(),
+            content_validation: user_rule.content_validation.clone(),
         }
     }
 
@@ -78,10 +91,17 @@ impl Rule {
                     max_size: current_rule.max_size,
                     max_capacity: current_rule.max_capacity,
                     max_changes_per_user: current_rule.max_changes_per_user,
+                    max_bytes_per_user: current_rule.max_bytes_per_user,
                     created_at,
                     updated_at,
                     version: Some(version),
                     rate_config: user_rule.rate_config.clone(),
+                    indexes: current_rule.indexes.clone(),
+                    history: current_rule.history.clone(),
+                    ttl: current_rule.ttl,
+                    schema: current_rule.schema.clone(),
+                    variants: current_rule.variants.clone(),
+                    content_validation: current_rule.content_validation.clone(),
                 };
 
                 Ok(rule)
@@ -117,10 +137,17 @@ impl From<SetRule> for Rule {
             max_size: rule.max_size,
             max_capacity: rule.max_capacity,
             max_changes_per_user: rule.max_changes_per_user,
+            max_bytes_per_user: rule.max_bytes_per_user,
             created_at: now,
             updated_at: now,
             version: rule.version,
             rate_config: rule.rate_config,
+            indexes: rule.indexes,
+            history: rule.history,
+            ttl: rule.ttl,
+            schema: rule.schema,
+            variants: rule.variants,
+            content_validation: rule.content_validation,
         }
     }
 }
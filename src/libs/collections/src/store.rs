@@ -1,7 +1,8 @@
 use crate::assert::rules::{
-    assert_memory, assert_mutable_permissions, assert_storage_reserved_collection,
+    assert_content_validation, assert_history, assert_indexes, assert_memory,
+    assert_mutable_permissions, assert_roles, assert_schema, assert_storage_reserved_collection,
     assert_system_collection_delete_permission, assert_system_collection_set_permission,
-    assert_write_version,
+    assert_ttl, assert_variants, assert_write_version,
 };
 use crate::constants::core::SYS_COLLECTION_PREFIX;
 use crate::types::core::CollectionKey;
@@ -38,6 +39,13 @@ pub fn set_rule(
 
     assert_memory(current_rule, &user_rule.memory)?;
     assert_mutable_permissions(current_rule, &user_rule)?;
+    assert_indexes(&user_rule, storage_checks)?;
+    assert_history(&user_rule, storage_checks)?;
+    assert_ttl(&user_rule)?;
+    assert_roles(&user_rule)?;
+    assert_schema(&user_rule, storage_checks)?;
+    assert_variants(&user_rule, storage_checks)?;
+    assert_content_validation(&user_rule, storage_checks)?;
 
     let rule: Rule = Rule::prepare(&collection, &current_rule, &user_rule)?;
 
@@ -20,6 +20,12 @@ pub mod rules {
 
     pub type Rules = HashMap<CollectionKey, Rule>;
 
+    /// A dot-separated path to a field within the JSON data of a document - e.g. `address.city`.
+    pub type IndexField = String;
+
+    /// The name of a role granted to users - e.g. `editor`.
+    pub type RoleName = String;
+
     #[derive(CandidType, Serialize, Deserialize, Clone)]
     pub struct Rule {
         pub read: Permission,
@@ -30,10 +36,64 @@ pub mod rules {
         pub max_size: Option<u128>,
         pub max_capacity: Option<u32>,
         pub max_changes_per_user: Option<u32>,
+        // The total size in bytes of the documents or assets a user can own in the collection
+        pub max_bytes_per_user: Option<u64>,
         pub created_at: Timestamp,
         pub updated_at: Timestamp,
         pub version: Option<Version>,
         pub rate_config: Option<RateConfig>,
+        pub indexes: Option<Vec<IndexField>>,
+        pub history: Option<HistoryConfig>,
+        // Default time-to-live in nanoseconds of the documents or assets written without an explicit expiration
+        pub ttl: Option<u64>,
+        // A JSON Schema, as text, the data of the documents must satisfy
+        pub schema: Option<String>,
+        pub variants: Option<Vec<ImageVariant>>,
+        pub content_validation: Option<ContentValidation>,
+    }
+
+    /// Keeps the previous revisions of the documents of a collection.
+    ///
+    /// - `max_revisions`: The number of revisions kept per document.
+    /// - `retention`: How long, in nanoseconds, a revision is kept.
+    ///
+    /// At least one limit must be provided. A revision is removed as soon as it exceeds one of them.
+    #[derive(CandidType, Serialize, Deserialize, Clone, PartialEq)]
+    pub struct HistoryConfig {
+        pub max_revisions: Option<u32>,
+        pub retention: Option<u64>,
+    }
+
+    /// A resized copy of the images uploaded to a storage collection, generated on upload.
+    ///
+    /// - `name`: Identifies the variant. The copy of `/images/photo.jpg` is stored as `/images/photo.jpg@{name}`.
+    /// - `max_width` / `max_height`: The bounds, in pixels, the image is scaled down to - preserving its aspect ratio.
+    /// - `format`: The output format. Defaults to the format of the uploaded image.
+    #[derive(CandidType, Serialize, Deserialize, Clone, PartialEq)]
+    pub struct ImageVariant {
+        pub name: String,
+        pub max_width: Option<u32>,
+        pub max_height: Option<u32>,
+        pub format: Option<ImageFormat>,
+    }
+
+    /// Validates the content of the assets uploaded to a storage collection before they are committed.
+    ///
+    /// - `max_image_width` / `max_image_height`: The bounds, in pixels, uploaded PNG, JPEG and WebP images must fit in.
+    ///
+    /// The content must match the `content-type` it is uploaded with - e.g. an `image/png` must be a PNG -
+    /// HTML is only accepted as `text/html` and executables are rejected.
+    #[derive(CandidType, Serialize, Deserialize, Clone, PartialEq)]
+    pub struct ContentValidation {
+        pub max_image_width: Option<u32>,
+        pub max_image_height: Option<u32>,
+    }
+
+    #[derive(CandidType, Serialize, Deserialize, Clone, PartialEq)]
+    pub enum ImageFormat {
+        Png,
+        Jpeg,
+        WebP,
     }
 
     #[derive(CandidType, Serialize, Deserialize, Default, Clone, PartialEq)]
@@ -56,12 +116,16 @@ pub mod rules {
         Managed,
         // The controllers - and only these - can rule the document
         Controllers,
+        // The users holding at least one of the roles and the controllers can rule the document
+        Roles(Vec<RoleName>),
     }
 }
 
 pub mod interface {
     use crate::types::core::CollectionKey;
-    use crate::types::rules::{Memory, Permission, Rule};
+    use crate::types::rules::{
+        ContentValidation, HistoryConfig, ImageVariant, IndexField, Memory, Permission, Rule,
+    };
     use candid::CandidType;
     use junobuild_shared::rate::types::RateConfig;
     use junobuild_shared::types::state::Version;
@@ -76,8 +140,15 @@ pub mod interface {
         pub max_size: Option<u128>,
         pub max_capacity: Option<u32>,
         pub max_changes_per_user: Option<u32>,
+        pub max_bytes_per_user: Option<u64>,
         pub version: Option<Version>,
         pub rate_config: Option<RateConfig>,
+        pub indexes: Option<Vec<IndexField>>,
+        pub history: Option<HistoryConfig>,
+        pub ttl: Option<u64>,
+        pub schema: Option<String>,
+        pub variants: Option<Vec<ImageVariant>>,
+        pub content_validation: Option<ContentValidation>,
     }
 
     #[derive(Default, CandidType, Deserialize, Clone)]
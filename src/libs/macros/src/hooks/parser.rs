@@ -10,6 +10,7 @@ use syn::{parse, ItemFn, ReturnType, Signature, Type};
 #[derive(Default, Deserialize)]
 struct HookAttributes {
     collections: Option<Vec<String>>,
+    paths: Option<Vec<String>>,
 }
 
 #[allow(clippy::enum_variant_names)]
@@ -33,10 +34,13 @@ pub enum Hook {
     AssertDeleteDoc,
     AssertUploadAsset,
     AssertDeleteAsset,
+    OnHttpRequest,
 }
 
 const CONTEXT_PARAM: &str = "context";
 
+const HTTP_HOOK_PATHS_FN: &str = "juno_on_http_request_paths";
+
 fn map_hook_name(hook: Hook) -> String {
     match hook {
         Hook::OnSetDoc => "juno_on_set_doc".to_string(),
@@ -57,6 +61,7 @@ fn map_hook_name(hook: Hook) -> String {
         Hook::AssertDeleteDoc => "juno_assert_delete_doc".to_string(),
         Hook::AssertUploadAsset => "juno_assert_upload_asset".to_string(),
         Hook::AssertDeleteAsset => "juno_assert_delete_asset".to_string(),
+        Hook::OnHttpRequest => "juno_on_http_request".to_string(),
     }
 }
 
@@ -96,6 +101,7 @@ fn map_hook_type(hook: &Hook) -> Option<String> {
         Hook::AssertDeleteDoc => Some("AssertDeleteDocContext".to_string()),
         Hook::AssertUploadAsset => Some("AssertUploadAssetContext".to_string()),
         Hook::AssertDeleteAsset => Some("AssertDeleteAssetContext".to_string()),
+        Hook::OnHttpRequest => Some("OnHttpRequestContext".to_string()),
         _ => None,
     }
 }
@@ -118,6 +124,7 @@ fn parse_hook(hook: &Hook, attr: TokenStream, item: TokenStream) -> Result<Token
         Hook::OnPostUpgradeSync | Hook::OnInitSync => {
             parse_lifecycle_sync_hook(&ast, signature, &hook_fn)
         }
+        Hook::OnHttpRequest => parse_http_hook(&ast, signature, &hook_fn, hook, attr),
         _ => parse_doc_hook(&ast, signature, &hook_fn, hook, attr),
     }
 }
@@ -246,6 +253,52 @@ fn parse_assert_hook(
     }
 }
 
+fn parse_http_hook(
+    ast: &ItemFn,
+    signature: &Signature,
+    hook_fn: &Ident,
+    hook: &Hook,
+    attr: TokenStream,
+) -> Result<TokenStream, String> {
+    let hook_paths_fn = Ident::new(HTTP_HOOK_PATHS_FN, proc_macro2::Span::call_site());
+
+    let hook_param = Ident::new(CONTEXT_PARAM, proc_macro2::Span::call_site());
+
+    let hook_param_type = match &map_hook_type(hook) {
+        Some(hook_type) => Ident::new(hook_type, proc_macro2::Span::call_site()),
+        None => return Err("Hook type cannot be None.".to_string()),
+    };
+
+    let converted_attr: proc_macro2::TokenStream = attr.into();
+    let attrs = from_tokenstream::<HookAttributes>(&converted_attr)
+        .map_err(|_| "Expected valid attributes to register the hooks")?;
+
+    let paths = match attrs.paths {
+        Some(paths) => paths,
+        None => return Err("Expected the paths served by the HTTP endpoints".to_string()),
+    };
+
+    let paths_tokens = paths.iter().map(|path| quote! { #path.to_string() });
+
+    let func_name = &signature.ident;
+
+    let result = quote! {
+        #ast
+
+        #[no_mangle]
+        pub extern "Rust" fn #hook_paths_fn() -> Option<Vec<String>> {
+            Some(vec![#(#paths_tokens,)*])
+        }
+
+        #[no_mangle]
+        pub extern "Rust" fn #hook_fn(#hook_param: #hook_param_type) -> Result<OnHttpRequestResponse, String> {
+            #func_name(#hook_param)
+        }
+    };
+
+    Ok(result.into())
+}
+
 fn parse_lifecycle_hook(
     ast: &ItemFn,
     signature: &Signature,
@@ -382,6 +382,24 @@ pub fn assert_delete_doc(attr: TokenStream, item: TokenStream) -> TokenStream {
 ///
 /// The attributes accept a list of comma-separated collections. If the attribute array is left empty, the assertion will always be evaluated.
 ///
+/// The context contains the metadata of the upload. The uploaded bytes can be inspected one chunk at a time
+/// with `get_asset_upload_chunk_store`.
+///
+/// Example:
+/// ```rust
+/// #[assert_upload_asset]
+/// fn assert_upload_asset(context: AssertUploadAssetContext) -> Result<(), String> {
+///     let mut index = 0;
+///
+///     while let Some(chunk) = get_asset_upload_chunk_store(&context.data, index) {
+///         // Your assertion logic here, on the content of the chunk
+///         index += 1;
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+///
 #[proc_macro_attribute]
 pub fn assert_upload_asset(attr: TokenStream, item: TokenStream) -> TokenStream {
     hook_macro(Hook::AssertUploadAsset, attr, item)
@@ -417,6 +435,30 @@ pub fn assert_delete_asset(attr: TokenStream, item: TokenStream) -> TokenStream
     hook_macro(Hook::AssertDeleteAsset, attr, item)
 }
 
+/// The `on_http_request` function is a procedural macro attribute for serving custom HTTP endpoints.
+/// It allows you to define custom logic to respond to the HTTP requests received on a list of paths - e.g. a webhook.
+///
+/// Example:
+///
+/// ```rust
+/// #[on_http_request(paths = ["/api/webhooks"])]
+/// fn on_http_request(context: OnHttpRequestContext) -> Result<OnHttpRequestResponse, String> {
+///     // Your endpoint logic here
+/// }
+/// ```
+///
+/// A path also serves the paths it prefixes - e.g. `/api/webhooks/stripe` for `/api/webhooks`.
+///
+/// `GET` and `HEAD` requests are handled in a query, changes made to the state are therefore not persisted.
+/// Other methods - e.g. `POST`, `PUT` or `DELETE` - are upgraded to an update call and handled in `http_request_update`.
+///
+/// The attributes require a list of comma-separated paths.
+///
+#[proc_macro_attribute]
+pub fn on_http_request(attr: TokenStream, item: TokenStream) -> TokenStream {
+    hook_macro(Hook::OnHttpRequest, attr, item)
+}
+
 /// The `on_post_upgrade` function is a procedural macro attribute for hooking into the `OnPostUpgrade` event.
 /// It allows you to define custom logic to be executed after a satellite upgrade.
 ///
@@ -1,10 +1,13 @@
 use crate::memory::state::STATE;
+use junobuild_collections::types::core::CollectionKey;
 use junobuild_shared::segments::access_keys::{
-    delete_access_keys as delete_controllers_impl, filter_admin_access_keys,
+    delete_access_keys as delete_controllers_impl, filter_admin_access_keys, scope_access_keys,
     set_access_keys as set_controllers_impl,
 };
 use junobuild_shared::types::interface::SetAccessKey;
-use junobuild_shared::types::state::{AccessKeyId, AccessKeys};
+use junobuild_shared::types::state::{
+    AccessKeyCollection, AccessKeyId, AccessKeyOperation, AccessKeys,
+};
 
 // ---------------------------------------------------------
 // Access keys
@@ -34,6 +37,30 @@ pub fn get_access_keys() -> AccessKeys {
     STATE.with(|state| state.borrow().heap.controllers.clone())
 }
 
+/// Returns the access keys of this satellite that apply to a collection of the datastore.
+///
+/// Access keys restricted to other collections - or to read-only use when writing - are omitted.
+pub fn get_db_access_keys(collection: &CollectionKey, operation: AccessKeyOperation) -> AccessKeys {
+    get_collection_access_keys(&AccessKeyCollection::Db(collection.clone()), operation)
+}
+
+/// Returns the access keys of this satellite that apply to a collection of the storage.
+///
+/// Access keys restricted to other collections - or to read-only use when writing - are omitted.
+pub fn get_storage_access_keys(
+    collection: &CollectionKey,
+    operation: AccessKeyOperation,
+) -> AccessKeys {
+    get_collection_access_keys(&AccessKeyCollection::Storage(collection.clone()), operation)
+}
+
+fn get_collection_access_keys(
+    collection: &AccessKeyCollection,
+    operation: AccessKeyOperation,
+) -> AccessKeys {
+    STATE.with(|state| scope_access_keys(&state.borrow().heap.controllers, collection, operation))
+}
+
 /// Returns all admin access keys of this satellite.
 ///
 /// Filters the full access key list to only those with `ControllerScope::Admin`.
@@ -0,0 +1,7 @@
+use crate::audit::store::list_audit_logs_store;
+use crate::audit::types::interface::{ListAuditLogsParams, ListAuditLogsResults};
+use junobuild_shared::ic::UnwrapOrTrap;
+
+pub fn list_audit_logs(params: ListAuditLogsParams) -> ListAuditLogsResults {
+    list_audit_logs_store(&params).unwrap_or_trap()
+}
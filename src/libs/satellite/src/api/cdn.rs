@@ -6,7 +6,9 @@ use crate::assets::storage::certified_assets::upgrade::defer_init_certified_asse
 use crate::assets::storage::store::{
     delete_domain_store, get_config_store, get_custom_domains_store, set_domain_store,
 };
-use crate::assets::storage::strategy_impls::StorageState;
+use crate::assets::storage::strategy_impls::{StorageBatches, StorageState};
+use crate::audit::store::record_audit_log;
+use crate::audit::types::state::AuditLogEndpoint;
 use crate::types::interface::DeleteProposalAssets;
 use crate::{caller, get_access_keys};
 use junobuild_cdn::proposals::{
@@ -33,7 +35,7 @@ pub fn get_proposal(proposal_id: &ProposalId) -> Option<Proposal> {
 }
 
 pub fn list_proposals(filter: &ListProposalsParams) -> ListProposalResults {
-    junobuild_cdn::proposals::list_proposals(&CdnStable, filter)
+    junobuild_cdn::proposals::list_proposals(&CdnStable, filter).unwrap_or_trap()
 }
 
 pub fn count_proposals() -> usize {
@@ -115,7 +117,7 @@ pub fn upload_proposal_asset_chunk(chunk: UploadChunk) -> UploadChunkResult {
     let caller = caller();
     let config = get_config_store();
 
-    let chunk_id = create_chunk(caller, &config, chunk).unwrap_or_trap();
+    let chunk_id = create_chunk(caller, &config, chunk, &StorageBatches).unwrap_or_trap();
 
     UploadChunkResult { chunk_id }
 }
@@ -134,6 +136,7 @@ pub fn commit_proposal_asset_upload(commit: CommitBatch) {
         &CdnStorageAssertions,
         &StorageState,
         &CdnStorageUpload,
+        &StorageBatches,
     )
     .unwrap_or_trap();
 }
@@ -153,6 +156,7 @@ pub fn commit_proposal_many_assets_upload(commits: Vec<CommitBatch>) {
             &CdnStorageAssertions,
             &StorageState,
             &CdnStorageUpload,
+            &StorageBatches,
         )
         .unwrap_or_trap();
     }
@@ -167,9 +171,29 @@ pub fn list_custom_domains() -> CustomDomains {
 }
 
 pub fn set_custom_domain(domain_name: DomainName, bn_id: Option<String>) {
+    let current_domain = get_custom_domains_store().get(&domain_name).cloned();
+
     set_domain_store(&domain_name, &bn_id).unwrap_or_trap();
+
+    let domain = get_custom_domains_store().get(&domain_name).cloned();
+
+    record_audit_log(
+        AuditLogEndpoint::SetCustomDomain,
+        Some(domain_name),
+        current_domain.as_ref(),
+        domain.as_ref(),
+    );
 }
 
 pub fn del_custom_domain(domain_name: DomainName) {
+    let current_domain = get_custom_domains_store().get(&domain_name).cloned();
+
     delete_domain_store(&domain_name).unwrap_or_trap();
+
+    record_audit_log(
+        AuditLogEndpoint::DelCustomDomain,
+        Some(domain_name),
+        current_domain.as_ref(),
+        None,
+    );
 }
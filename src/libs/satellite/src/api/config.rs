@@ -1,5 +1,12 @@
 use crate::assets::storage::services::apply_storage_config;
 use crate::assets::storage::store::get_config_store as get_storage_config_store;
+use crate::audit::store::{
+    get_config_store as get_audit_config_store, record_audit_log,
+    set_config_store as set_audit_config_store,
+};
+use crate::audit::types::config::AuditConfig;
+use crate::audit::types::interface::SetAuditConfig;
+use crate::audit::types::state::AuditLogEndpoint;
 use crate::auth::store::{
     get_config as get_auth_config_store, set_config as set_auth_config_store,
 };
@@ -12,6 +19,12 @@ use crate::db::store::{
 use crate::db::types::config::DbConfig;
 use crate::db::types::interface::SetDbConfig;
 use crate::types::interface::Config;
+use crate::user::usage::store::{
+    get_config_store as get_user_usage_config_store,
+    set_config_store as set_user_usage_config_store,
+};
+use crate::user::usage::types::config::UserUsageConfig;
+use crate::user::usage::types::interface::SetUserUsageConfig;
 use junobuild_auth::state::types::automation::AutomationConfig;
 use junobuild_auth::state::types::config::AuthenticationConfig;
 use junobuild_auth::state::types::interface::{SetAuthenticationConfig, SetAutomationConfig};
@@ -42,7 +55,18 @@ pub fn get_config() -> Config {
 // ---------------------------------------------------------
 
 pub async fn set_auth_config(config: SetAuthenticationConfig) -> AuthenticationConfig {
-    set_auth_config_store(&config).await.unwrap_or_trap()
+    let current_config = get_auth_config_store();
+
+    let config = set_auth_config_store(&config).await.unwrap_or_trap();
+
+    record_audit_log(
+        AuditLogEndpoint::SetAuthConfig,
+        None,
+        current_config.as_ref(),
+        Some(&config),
+    );
+
+    config
 }
 
 pub fn get_auth_config() -> Option<AuthenticationConfig> {
@@ -54,7 +78,18 @@ pub fn get_auth_config() -> Option<AuthenticationConfig> {
 // ---------------------------------------------------------
 
 pub async fn set_automation_config(config: SetAutomationConfig) -> AutomationConfig {
-    set_automation_config_store(&config).await.unwrap_or_trap()
+    let current_config = get_automation_config_store();
+
+    let config = set_automation_config_store(&config).await.unwrap_or_trap();
+
+    record_audit_log(
+        AuditLogEndpoint::SetAutomationConfig,
+        None,
+        current_config.as_ref(),
+        Some(&config),
+    );
+
+    config
 }
 
 pub fn get_automation_config() -> Option<AutomationConfig> {
@@ -66,7 +101,18 @@ pub fn get_automation_config() -> Option<AutomationConfig> {
 // ---------------------------------------------------------
 
 pub fn set_db_config(config: SetDbConfig) -> DbConfig {
-    set_db_config_store(&config).unwrap_or_trap()
+    let current_config = get_db_config_store();
+
+    let config = set_db_config_store(&config).unwrap_or_trap();
+
+    record_audit_log(
+        AuditLogEndpoint::SetDbConfig,
+        None,
+        current_config.as_ref(),
+        Some(&config),
+    );
+
+    config
 }
 
 pub fn get_db_config() -> Option<DbConfig> {
@@ -78,15 +124,71 @@ pub fn get_db_config() -> Option<DbConfig> {
 // ---------------------------------------------------------
 
 pub fn set_storage_config(config: SetStorageConfig) -> StorageConfig {
-    apply_storage_config(&config, None).unwrap_or_trap()
+    let current_config = get_storage_config_store();
+
+    let config = apply_storage_config(&config, None).unwrap_or_trap();
+
+    record_storage_config(&current_config, &config);
+
+    config
 }
 
 pub fn set_storage_config_with_options(
     SetStorageConfigWithOptions { config, options }: SetStorageConfigWithOptions,
 ) -> StorageConfig {
-    apply_storage_config(&config, options.skip_certification).unwrap_or_trap()
+    let current_config = get_storage_config_store();
+
+    let config = apply_storage_config(&config, options.skip_certification).unwrap_or_trap();
+
+    record_storage_config(&current_config, &config);
+
+    config
+}
+
+fn record_storage_config(current_config: &StorageConfig, config: &StorageConfig) {
+    record_audit_log(
+        AuditLogEndpoint::SetStorageConfig,
+        None,
+        Some(current_config),
+        Some(config),
+    );
 }
 
 pub fn get_storage_config() -> StorageConfig {
     get_storage_config_store()
 }
+
+// ---------------------------------------------------------
+// Audit config
+// ---------------------------------------------------------
+
+pub fn set_audit_config(config: SetAuditConfig) -> AuditConfig {
+    set_audit_config_store(&config).unwrap_or_trap()
+}
+
+pub fn get_audit_config() -> Option<AuditConfig> {
+    get_audit_config_store()
+}
+
+// ---------------------------------------------------------
+// User usage config
+// ---------------------------------------------------------
+
+pub fn set_user_usage_config(config: SetUserUsageConfig) -> UserUsageConfig {
+    let current_config = get_user_usage_config_store();
+
+    let config = set_user_usage_config_store(&config).unwrap_or_trap();
+
+    record_audit_log(
+        AuditLogEndpoint::SetUserUsageConfig,
+        None,
+        current_config.as_ref(),
+        Some(&config),
+    );
+
+    config
+}
+
+pub fn get_user_usage_config() -> Option<UserUsageConfig> {
+    get_user_usage_config_store()
+}
@@ -1,8 +1,11 @@
 use crate::access_keys::store::{delete_access_keys, set_access_keys as set_controllers_store};
+use crate::audit::store::record_audit_log;
+use crate::audit::types::state::AuditLogEndpoint;
 use crate::{caller, get_access_keys};
 use junobuild_shared::ic::UnwrapOrTrap;
 use junobuild_shared::segments::access_keys::{
-    assert_access_key_expiration, assert_controllers, assert_max_number_of_access_keys,
+    assert_access_key_expiration, assert_access_key_restrictions, assert_controllers,
+    assert_max_number_of_access_keys,
 };
 use junobuild_shared::types::interface::{DeleteControllersArgs, SetControllersArgs};
 use junobuild_shared::types::state::{AccessKeyId, AccessKeys};
@@ -20,14 +23,36 @@ pub fn set_controllers(
 
     assert_access_key_expiration(&controller).unwrap_or_trap();
 
+    assert_access_key_restrictions(&controller).unwrap_or_trap();
+
+    let current_access_keys = filter_access_keys(&get_access_keys(), &controllers);
+
     set_controllers_store(&controllers, &controller);
 
-    get_access_keys()
+    let access_keys = get_access_keys();
+
+    record_audit_log(
+        AuditLogEndpoint::SetControllers,
+        Some(audit_target(&controllers)),
+        Some(&current_access_keys),
+        Some(&filter_access_keys(&access_keys, &controllers)),
+    );
+
+    access_keys
 }
 
 pub fn del_controllers(DeleteControllersArgs { controllers }: DeleteControllersArgs) -> AccessKeys {
+    let current_access_keys = filter_access_keys(&get_access_keys(), &controllers);
+
     delete_access_keys(&controllers);
 
+    record_audit_log(
+        AuditLogEndpoint::DelControllers,
+        Some(audit_target(&controllers)),
+        Some(&current_access_keys),
+        None,
+    );
+
     get_access_keys()
 }
 
@@ -35,9 +60,33 @@ pub fn del_controller_self() {
     let caller = caller();
     let controllers: [AccessKeyId; 1] = [caller];
 
+    let current_access_keys = filter_access_keys(&get_access_keys(), &controllers);
+
     delete_access_keys(&controllers);
+
+    record_audit_log(
+        AuditLogEndpoint::DelControllerSelf,
+        Some(audit_target(&controllers)),
+        Some(&current_access_keys),
+        None,
+    );
 }
 
 pub fn list_controllers() -> AccessKeys {
     get_access_keys()
 }
+
+fn filter_access_keys(access_keys: &AccessKeys, ids: &[AccessKeyId]) -> AccessKeys {
+    access_keys
+        .iter()
+        .filter(|(id, _)| ids.contains(id))
+        .map(|(id, access_key)| (*id, access_key.clone()))
+        .collect()
+}
+
+fn audit_target(ids: &[AccessKeyId]) -> String {
+    ids.iter()
+        .map(|id| id.to_text())
+        .collect::<Vec<String>>()
+        .join(",")
+}
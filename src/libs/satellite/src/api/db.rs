@@ -6,9 +6,10 @@ use crate::user::internal_hooks::{
     on_delete_many_users, on_delete_user, on_set_many_users, on_set_user,
 };
 use crate::{
-    caller, count_collection_docs_store, count_docs_store, delete_doc_store, delete_docs_store,
-    delete_filtered_docs_store, get_doc_store, list_docs_store, set_doc_store, DelDoc, Doc,
-    DocContext, DocUpsert, SetDoc,
+    caller, commit_docs_store, count_collection_docs_store, count_docs_store, delete_doc_store,
+    delete_docs_store, delete_filtered_docs_store, get_doc_store, list_doc_revisions_store,
+    list_docs_store, restore_doc_revision_store, set_doc_store, CommitDocOperation, DelDoc, Doc,
+    DocContext, DocRevision, DocUpsert, RestoreDocRevision, SetDoc,
 };
 use junobuild_collections::types::core::CollectionKey;
 use junobuild_shared::ic::UnwrapOrTrap;
@@ -100,6 +101,53 @@ pub fn del_many_docs(docs: Vec<(CollectionKey, Key, DelDoc)>) {
     invoke_on_delete_many_docs(&caller, &results);
 }
 
+pub fn commit_docs(operations: Vec<CommitDocOperation>) -> Vec<(Key, Doc)> {
+    let caller = caller();
+
+    let context = commit_docs_store(caller, operations).unwrap_or_trap();
+
+    on_set_many_users(&context.set).unwrap_or_trap();
+    on_delete_many_users(&context.deleted).unwrap_or_trap();
+
+    let results: Vec<(Key, Doc)> = context
+        .set
+        .iter()
+        .map(|result| (result.key.clone(), result.data.after.clone()))
+        .collect();
+
+    if !context.set.is_empty() {
+        invoke_on_set_many_docs(&caller, &context.set);
+    }
+
+    if !context.deleted.is_empty() {
+        invoke_on_delete_many_docs(&caller, &context.deleted);
+    }
+
+    results
+}
+
+pub fn list_doc_revisions(collection: CollectionKey, key: Key) -> Vec<DocRevision> {
+    let caller = caller();
+
+    list_doc_revisions_store(caller, collection, key).unwrap_or_trap()
+}
+
+pub fn restore_doc_revision(
+    collection: CollectionKey,
+    key: Key,
+    revision: RestoreDocRevision,
+) -> Doc {
+    let caller = caller();
+
+    let doc = restore_doc_revision_store(caller, collection, key, revision).unwrap_or_trap();
+
+    on_set_user(&doc).unwrap_or_trap();
+
+    invoke_on_set_doc(&caller, &doc);
+
+    doc.data.after
+}
+
 pub fn del_filtered_docs(collection: CollectionKey, filter: ListParams) {
     let caller = caller();
 
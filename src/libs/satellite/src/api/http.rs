@@ -1,11 +1,17 @@
 use crate::assets::storage::strategy_impls::StorageState;
 use crate::certification::strategy_impls::StorageCertificate;
+use crate::http::endpoints::{
+    find_http_endpoint, http_request_endpoint, http_request_update_endpoint,
+};
+use junobuild_storage::constants::RESPONSE_STATUS_CODE_500;
+use junobuild_storage::http::response::error_response;
 use junobuild_storage::http::types::{
     HttpRequest, HttpResponse, StreamingCallbackHttpResponse, StreamingCallbackToken,
 };
 use junobuild_storage::http_request::{
     http_request as http_request_storage,
     http_request_streaming_callback as http_request_streaming_callback_storage,
+    http_request_update as http_request_update_storage,
 };
 
 // ---------------------------------------------------------
@@ -13,7 +19,19 @@ use junobuild_storage::http_request::{
 // ---------------------------------------------------------
 
 pub fn http_request(request: HttpRequest) -> HttpResponse {
-    http_request_storage(request, &StorageState, &StorageCertificate)
+    match find_http_endpoint(&request) {
+        Some(endpoint) => http_request_endpoint(request, &endpoint)
+            .unwrap_or_else(|err| error_response(RESPONSE_STATUS_CODE_500, err)),
+        None => http_request_storage(request, &StorageState, &StorageCertificate),
+    }
+}
+
+pub fn http_request_update(request: HttpRequest) -> HttpResponse {
+    match find_http_endpoint(&request) {
+        Some(_) => http_request_update_endpoint(request)
+            .unwrap_or_else(|err| error_response(RESPONSE_STATUS_CODE_500, err)),
+        None => http_request_update_storage(request, &StorageState),
+    }
 }
 
 pub fn http_request_streaming_callback(
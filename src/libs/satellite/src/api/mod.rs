@@ -1,3 +1,4 @@
+pub mod audit;
 pub mod auth;
 pub mod automation;
 pub mod cdn;
@@ -7,3 +8,4 @@ pub mod db;
 pub mod http;
 pub mod rules;
 pub mod storage;
+pub mod user;
@@ -1,3 +1,5 @@
+use crate::audit::store::record_audit_log;
+use crate::audit::types::state::AuditLogEndpoint;
 use crate::rules::store::{
     del_rule_db, del_rule_storage, get_rule_db, get_rule_storage, list_rules_db,
     list_rules_storage, set_rule_db, set_rule_storage,
@@ -26,17 +28,46 @@ pub fn list_rules(collection_type: &CollectionType, filter: &ListRulesParams) ->
 }
 
 pub fn set_rule(collection_type: CollectionType, collection: CollectionKey, rule: SetRule) -> Rule {
-    match collection_type {
+    let current_rule = get_rule(&collection_type, &collection);
+    let target = audit_target(&collection_type, &collection);
+
+    let rule = match collection_type {
         CollectionType::Db => set_rule_db(collection, rule).unwrap_or_trap(),
         CollectionType::Storage => set_rule_storage(collection, rule).unwrap_or_trap(),
-    }
+    };
+
+    record_audit_log(
+        AuditLogEndpoint::SetRule,
+        Some(target),
+        current_rule.as_ref(),
+        Some(&rule),
+    );
+
+    rule
 }
 
 pub fn del_rule(collection_type: CollectionType, collection: CollectionKey, rule: DelRule) {
+    let current_rule = get_rule(&collection_type, &collection);
+    let target = audit_target(&collection_type, &collection);
+
     match collection_type {
         CollectionType::Db => del_rule_db(collection, rule).unwrap_or_trap(),
         CollectionType::Storage => del_rule_storage(collection, rule).unwrap_or_trap(),
     }
+
+    record_audit_log(
+        AuditLogEndpoint::DelRule,
+        Some(target),
+        current_rule.as_ref(),
+        None,
+    );
+}
+
+fn audit_target(collection_type: &CollectionType, collection: &CollectionKey) -> String {
+    match collection_type {
+        CollectionType::Db => format!("db/{collection}"),
+        CollectionType::Storage => format!("storage/{collection}"),
+    }
 }
 
 pub fn switch_storage_system_memory() {
@@ -1,5 +1,7 @@
 use crate::assets::storage::certified_assets::chunk::certify_assets_chunk as init_certify_assets_chunk;
-use crate::assets::storage::store::{commit_batch_store, create_batch_store, create_chunk_store};
+use crate::assets::storage::store::{
+    commit_batch_store, create_batch_store, create_chunk_store, get_upload_session_store,
+};
 use crate::hooks::storage::{
     invoke_on_delete_asset, invoke_on_delete_filtered_assets, invoke_on_delete_many_assets,
     invoke_upload_asset,
@@ -8,14 +10,17 @@ use crate::types::interface::{CertifyAssetsArgs, CertifyAssetsResult};
 use crate::{
     caller, count_assets_store, count_collection_assets_store, delete_asset_store,
     delete_assets_store, delete_filtered_assets_store, get_asset_store, list_assets_store,
-    set_asset_token_store,
+    set_asset_token_store, sign_asset_url_store,
 };
 use junobuild_collections::types::core::CollectionKey;
 use junobuild_shared::ic::UnwrapOrTrap;
 use junobuild_shared::types::list::{ListParams, ListResults};
+use junobuild_shared::types::state::Timestamp;
 use junobuild_storage::types::interface::{
     AssetNoContent, CommitBatch, InitAssetKey, InitUploadResult, UploadChunk, UploadChunkResult,
+    UploadSession,
 };
+use junobuild_storage::types::runtime_state::BatchId;
 use junobuild_storage::types::state::{AssetAccessToken, FullPath};
 use junobuild_storage::types::store::Asset;
 
@@ -35,12 +40,22 @@ pub fn upload_asset_chunk(chunk: UploadChunk) -> UploadChunkResult {
     UploadChunkResult { chunk_id }
 }
 
+pub fn get_upload_session(batch_id: BatchId) -> UploadSession {
+    let caller = caller();
+
+    get_upload_session_store(caller, &batch_id).unwrap_or_trap()
+}
+
 pub fn commit_asset_upload(commit: CommitBatch) {
     let caller = caller();
 
-    let asset = commit_batch_store(caller, commit).unwrap_or_trap();
+    let (asset, variants) = commit_batch_store(caller, commit).unwrap_or_trap();
 
     invoke_upload_asset(&caller, &asset);
+
+    for variant in &variants {
+        invoke_upload_asset(&caller, variant);
+    }
 }
 
 pub fn list_assets(collection: CollectionKey, filter: ListParams) -> ListResults<AssetNoContent> {
@@ -121,6 +136,16 @@ pub fn set_asset_token(collection: CollectionKey, full_path: FullPath, token: As
     // but not against adding one if it proves useful.
 }
 
+pub fn sign_asset_url(
+    collection: CollectionKey,
+    full_path: FullPath,
+    expires_at: Timestamp,
+) -> String {
+    let caller = caller();
+
+    sign_asset_url_store(caller, &collection, &full_path, expires_at).unwrap_or_trap()
+}
+
 pub fn certify_assets_chunk(args: CertifyAssetsArgs) -> CertifyAssetsResult {
     init_certify_assets_chunk(args)
 }
@@ -0,0 +1,128 @@
+use crate::audit::store::record_audit_log;
+use crate::audit::types::state::AuditLogEndpoint;
+use crate::caller;
+use crate::user::bans::store::{
+    ban_user as ban_user_store, get_user_ban, list_banned_users as list_banned_users_store,
+    unban_user as unban_user_store,
+};
+use crate::user::bans::types::interface::{
+    BanUserArgs, BannedUser, ListBannedUsersParams, ListBannedUsersResults,
+};
+use crate::user::roles::store::get_user_roles;
+use crate::user::roles::store::{
+    assign_user_roles as assign_user_roles_store, revoke_user_roles as revoke_user_roles_store,
+};
+use crate::user::roles::types::interface::UserRolesArgs;
+use crate::user::sessions::store::{
+    clear_user_sessions_revocation as clear_user_sessions_revocation_store,
+    get_user_sessions as get_user_sessions_store,
+    revoke_user_sessions as revoke_user_sessions_store,
+};
+use crate::user::sessions::types::state::UserSessions;
+use crate::user::usage::store::get_user_usage as get_user_usage_store;
+use crate::user::usage::types::interface::UserUsage;
+use junobuild_collections::types::rules::RoleName;
+use junobuild_shared::ic::UnwrapOrTrap;
+use junobuild_shared::types::state::UserId;
+
+pub fn get_user_usage() -> UserUsage {
+    let caller = caller();
+
+    get_user_usage_store(&caller).unwrap_or_trap()
+}
+
+pub fn assign_user_roles(args: UserRolesArgs) -> Vec<RoleName> {
+    let current_roles = get_user_roles(&args.user).unwrap_or_trap();
+
+    let roles = assign_user_roles_store(&args).unwrap_or_trap();
+
+    record_audit_log(
+        AuditLogEndpoint::AssignUserRoles,
+        Some(args.user.to_text()),
+        Some(&current_roles),
+        Some(&roles),
+    );
+
+    roles
+}
+
+pub fn revoke_user_roles(args: UserRolesArgs) -> Vec<RoleName> {
+    let current_roles = get_user_roles(&args.user).unwrap_or_trap();
+
+    let roles = revoke_user_roles_store(&args).unwrap_or_trap();
+
+    record_audit_log(
+        AuditLogEndpoint::RevokeUserRoles,
+        Some(args.user.to_text()),
+        Some(&current_roles),
+        Some(&roles),
+    );
+
+    roles
+}
+
+pub fn get_user_sessions(user: UserId) -> Option<UserSessions> {
+    get_user_sessions_store(&user)
+}
+
+pub fn revoke_user_sessions(user: UserId) -> UserSessions {
+    let current_sessions = get_user_sessions_store(&user);
+
+    let sessions = revoke_user_sessions_store(&user);
+
+    record_audit_log(
+        AuditLogEndpoint::RevokeUserSessions,
+        Some(user.to_text()),
+        current_sessions.as_ref(),
+        Some(&sessions),
+    );
+
+    sessions
+}
+
+pub fn clear_user_sessions_revocation(user: UserId) -> Option<UserSessions> {
+    let current_sessions = get_user_sessions_store(&user);
+
+    let sessions = clear_user_sessions_revocation_store(&user);
+
+    record_audit_log(
+        AuditLogEndpoint::ClearUserSessionsRevocation,
+        Some(user.to_text()),
+        current_sessions.as_ref(),
+        sessions.as_ref(),
+    );
+
+    sessions
+}
+
+pub fn ban_user(args: BanUserArgs) -> BannedUser {
+    let current_ban = get_user_ban(&args.user).unwrap_or_trap();
+
+    let ban = ban_user_store(&args).unwrap_or_trap();
+
+    record_audit_log(
+        AuditLogEndpoint::BanUser,
+        Some(args.user.to_text()),
+        current_ban.as_ref(),
+        Some(&ban),
+    );
+
+    ban
+}
+
+pub fn unban_user(user: UserId) {
+    let current_ban = get_user_ban(&user).unwrap_or_trap();
+
+    unban_user_store(&user).unwrap_or_trap();
+
+    record_audit_log(
+        AuditLogEndpoint::UnbanUser,
+        Some(user.to_text()),
+        current_ban.as_ref(),
+        None,
+    );
+}
+
+pub fn list_banned_users(params: ListBannedUsersParams) -> ListBannedUsersResults {
+    list_banned_users_store(&params).unwrap_or_trap()
+}
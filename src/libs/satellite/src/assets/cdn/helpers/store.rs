@@ -1,7 +1,7 @@
 use crate::assets::cdn::helpers::stable::get_proposal;
 use crate::assets::cdn::strategies_impls::storage::CdnStorageAssertions;
 use crate::assets::storage::store::get_config_store;
-use crate::assets::storage::strategy_impls::StorageState;
+use crate::assets::storage::strategy_impls::{StorageBatches, StorageState};
 use crate::get_access_keys;
 use candid::Principal;
 use junobuild_cdn::proposals::ProposalId;
@@ -34,5 +34,6 @@ pub fn init_asset_upload(
         Some(proposal_id),
         &CdnStorageAssertions,
         &StorageState,
+        &StorageBatches,
     )
 }
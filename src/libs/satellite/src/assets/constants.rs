@@ -14,6 +14,13 @@ pub const COLLECTION_RELEASES_DEFAULT_RULE: SetRule = SetRule {
     max_size: None,
     max_capacity: None,
     max_changes_per_user: None,
+    max_bytes_per_user: None,
     version: None,
     rate_config: None,
+    indexes: None,
+    history: None,
+    ttl: None,
+    schema: None,
+    variants: None,
+    content_validation: None,
 };
@@ -2,19 +2,20 @@ use crate::auth::assert::assert_caller_is_allowed;
 use crate::hooks::storage::invoke_assert_delete_asset;
 use crate::types::store::{AssertContext, StoreContext};
 use crate::user::core::assert::{assert_user_is_not_banned, is_known_user};
+use crate::user::roles::assert::assert_permission_with_user_roles;
 use crate::user::usage::assert::increment_and_assert_storage_usage;
 use candid::Principal;
-use junobuild_collections::assert::stores::{
-    assert_permission, assert_permission_with, public_permission,
-};
+use junobuild_collections::assert::stores::{assert_permission_with, public_permission};
 use junobuild_collections::constants::assets::COLLECTION_ASSET_KEY;
 use junobuild_collections::types::core::CollectionKey;
 use junobuild_collections::types::rules::Permission;
 use junobuild_shared::assert::assert_version;
 use junobuild_shared::segments::access_keys::{is_valid_access_key, is_write_access_key};
+use junobuild_shared::types::domain::CustomDomains;
 use junobuild_shared::types::state::AccessKeys;
 use junobuild_storage::errors::{
     JUNO_STORAGE_ERROR_ASSET_NOT_FOUND, JUNO_STORAGE_ERROR_CANNOT_READ_ASSET,
+    JUNO_STORAGE_ERROR_DOMAIN_ROOT_UNKNOWN_DOMAIN, JUNO_STORAGE_ERROR_INVALID_DOMAIN_ROOT,
     JUNO_STORAGE_ERROR_UPLOAD_NOT_ALLOWED,
 };
 use junobuild_storage::runtime::increment_and_assert_rate as increment_and_assert_rate_runtime;
@@ -71,7 +72,7 @@ pub fn assert_storage_list_permission(
         return assert_permission_with(permission, owner, caller, controllers, is_valid_access_key);
     }
 
-    assert_permission(permission, owner, caller, controllers)
+    assert_permission_with_user_roles(permission, owner, caller, controllers)
 }
 
 pub fn assert_create_batch(
@@ -100,7 +101,7 @@ pub fn assert_write_asset(
     assert_caller_is_allowed(context.caller, context.controllers, auth_config)?;
     assert_user_is_not_banned(context.caller, context.controllers)?;
 
-    if !assert_permission(
+    if !assert_permission_with_user_roles(
         &rule.write,
         asset.key.owner,
         context.caller,
@@ -133,7 +134,7 @@ fn assert_read_permission(
     current_asset: &Asset,
     rule: &Permission,
 ) -> Result<(), String> {
-    if !assert_permission(rule, current_asset.key.owner, caller, controllers) {
+    if !assert_permission_with_user_roles(rule, current_asset.key.owner, caller, controllers) {
         return Err(JUNO_STORAGE_ERROR_CANNOT_READ_ASSET.to_string());
     }
 
@@ -143,8 +144,37 @@ fn assert_read_permission(
 pub fn assert_set_config(
     proposed_config: &SetStorageConfig,
     current_config: &StorageConfig,
+    custom_domains: &CustomDomains,
 ) -> Result<(), String> {
     assert_version(proposed_config.version, current_config.version)?;
 
+    assert_domain_roots(proposed_config, custom_domains)?;
+
+    Ok(())
+}
+
+fn assert_domain_roots(
+    proposed_config: &SetStorageConfig,
+    custom_domains: &CustomDomains,
+) -> Result<(), String> {
+    let domain_roots = match &proposed_config.domain_roots {
+        None => return Ok(()),
+        Some(domain_roots) => domain_roots,
+    };
+
+    for (domain_name, root) in domain_roots {
+        if !custom_domains.contains_key(domain_name) {
+            return Err(format!(
+                "{JUNO_STORAGE_ERROR_DOMAIN_ROOT_UNKNOWN_DOMAIN} ({domain_name})"
+            ));
+        }
+
+        if root.to_path_prefix() == "/" {
+            return Err(format!(
+                "{JUNO_STORAGE_ERROR_INVALID_DOMAIN_ROOT} ({domain_name})"
+            ));
+        }
+    }
+
     Ok(())
 }
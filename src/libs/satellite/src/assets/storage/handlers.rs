@@ -3,7 +3,9 @@ use crate::assets::storage::state::{
     get_asset, get_config, get_rule, insert_asset, insert_asset_encoding,
 };
 use crate::certification::strategy_impls::StorageCertificate;
-use junobuild_collections::assert::stores::assert_permission;
+use crate::types::store::StoreContext;
+use crate::user::roles::assert::assert_permission_with_user_roles;
+use crate::user::usage::assert::update_and_assert_storage_bytes_usage;
 use junobuild_collections::types::rules::Rule;
 use junobuild_shared::ic::api::id;
 use junobuild_shared::types::core::Blob;
@@ -12,7 +14,7 @@ use junobuild_storage::constants::ASSET_ENCODING_NO_COMPRESSION;
 use junobuild_storage::errors::JUNO_STORAGE_ERROR_SET_NOT_ALLOWED;
 use junobuild_storage::http::types::HeaderField;
 use junobuild_storage::runtime::update_certified_asset as update_runtime_certified_asset;
-use junobuild_storage::types::store::{Asset, AssetKey};
+use junobuild_storage::types::store::{Asset, AssetEncoding, AssetKey};
 use junobuild_storage::utils::map_content_encoding;
 
 /// Handles the setting of an asset within the store. This function performs
@@ -48,21 +50,33 @@ pub fn set_asset_handler(
 
     let existing_asset = get_asset(&key.collection, &key.full_path, &rule);
 
-    if let Some(ref existing_asset) = existing_asset {
-        let controllers: AccessKeys = get_access_keys();
-        // The handler is used in Serverless Functions therefore the caller is itself.
-        // This allows to assert for permission. Useful for collection set as "Private".
-        let caller = id();
+    let controllers: AccessKeys = get_access_keys();
+    // The handler is used in Serverless Functions therefore the caller is itself.
+    // This allows to assert for permission. Useful for collection set as "Private".
+    let caller = id();
 
-        if !assert_permission(&rule.write, existing_asset.key.owner, caller, &controllers) {
+    if let Some(ref existing_asset) = existing_asset {
+        if !assert_permission_with_user_roles(
+            &rule.write,
+            existing_asset.key.owner,
+            caller,
+            &controllers,
+        ) {
             return Err(JUNO_STORAGE_ERROR_SET_NOT_ALLOWED.to_string());
         }
     }
 
-    set_asset_handler_impl(key, &existing_asset, content, headers, &rule)
+    let context = StoreContext {
+        caller,
+        controllers: &controllers,
+        collection: &key.collection,
+    };
+
+    set_asset_handler_impl(&context, key, &existing_asset, content, headers, &rule)
 }
 
 fn set_asset_handler_impl(
+    context: &StoreContext,
     key: &AssetKey,
     existing_asset: &Option<Asset>,
     content: &Blob,
@@ -73,6 +87,15 @@ fn set_asset_handler_impl(
 
     let encoding = map_content_encoding(content);
 
+    // The quota is asserted before anything is written since an error is returned, not trapped.
+    update_and_assert_storage_bytes_usage(
+        context,
+        asset.key.owner,
+        existing_asset.as_ref().map_or(0, |asset| asset.size()),
+        size_with_encoding(&asset, ASSET_ENCODING_NO_COMPRESSION, &encoding),
+        rule.max_bytes_per_user,
+    )?;
+
     insert_asset_encoding(
         &key.full_path,
         ASSET_ENCODING_NO_COMPRESSION,
@@ -89,3 +112,152 @@ fn set_asset_handler_impl(
 
     Ok(())
 }
+
+/// The size of the asset once the encoding is inserted - i.e. replacing the encoding of the same type.
+fn size_with_encoding(asset: &Asset, encoding_type: &str, encoding: &AssetEncoding) -> u64 {
+    let total_length = asset
+        .encodings
+        .iter()
+        .filter(|(current_type, _)| current_type.as_str() != encoding_type)
+        .fold(encoding.total_length, |size, (_, current)| {
+            size.saturating_add(current.total_length)
+        });
+
+    u64::try_from(total_length).unwrap_or(u64::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::internal::unsafe_set_doc;
+    use crate::rules::store::{get_rule_db, set_rule_storage};
+    use crate::rules::test_utils::public_rule;
+    use crate::rules::upgrade::init_system_collections;
+    use crate::types::state::CollectionType;
+    use crate::user::usage::store::get_user_usage;
+    use crate::user::usage::types::state::UserUsageKey;
+    use crate::SetDoc;
+    use candid::Principal;
+    use junobuild_collections::constants::db::COLLECTION_USER_USAGE_KEY;
+    use junobuild_collections::types::interface::SetRule;
+    use junobuild_collections::types::rules::Memory;
+
+    fn init_collection(collection: &str, max_bytes_per_user: Option<u64>) -> Rule {
+        init_system_collections();
+
+        set_rule_storage(
+            collection.to_string(),
+            SetRule {
+                max_bytes_per_user,
+                ..public_rule(Memory::Heap)
+            },
+        )
+        .unwrap()
+    }
+
+    fn user() -> Principal {
+        Principal::from_slice(&[1, 2, 3])
+    }
+
+    fn key(collection: &str, name: &str) -> AssetKey {
+        AssetKey {
+            name: name.to_string(),
+            full_path: format!("/{collection}/{name}"),
+            token: None,
+            collection: collection.to_string(),
+            owner: user(),
+            description: None,
+            expires_at: None,
+        }
+    }
+
+    fn set(collection: &str, name: &str, content: &[u8], rule: &Rule) -> Result<(), String> {
+        let controllers = AccessKeys::new();
+        let collection = collection.to_string();
+
+        let context = StoreContext {
+            caller: user(),
+            controllers: &controllers,
+            collection: &collection,
+        };
+
+        let key = key(&collection, name);
+        let existing_asset = get_asset(&collection, &key.full_path, rule);
+
+        set_asset_handler_impl(
+            &context,
+            &key,
+            &existing_asset,
+            &content.to_vec(),
+            &[],
+            rule,
+        )
+    }
+
+    fn get(collection: &str, name: &str, rule: &Rule) -> Option<Asset> {
+        get_asset(
+            &collection.to_string(),
+            &key(collection, name).full_path,
+            rule,
+        )
+    }
+
+    fn usage_bytes(collection: &str) -> u64 {
+        get_user_usage(&user())
+            .unwrap()
+            .collections
+            .iter()
+            .find(|usage| usage.collection == collection)
+            .map_or(0, |usage| usage.bytes)
+    }
+
+    #[test]
+    fn asserts_quota_before_inserting() {
+        let rule = init_collection("quota", Some(10));
+
+        assert!(set("quota", "large.txt", &[0; 11], &rule).is_err());
+        assert!(get("quota", "large.txt", &rule).is_none());
+
+        // The bytes of the rejected content are not accounted for.
+        assert_eq!(usage_bytes("quota"), 0);
+    }
+
+    #[test]
+    fn computes_usage_recorded_before_sizes() {
+        let rule = init_collection("backfill", Some(10));
+
+        // An asset written before the sizes were tracked, and its usage.
+        let mut asset = Asset::prepare(key("backfill", "first.txt"), vec![], &None);
+        insert_asset_encoding(
+            &asset.key.full_path.clone(),
+            ASSET_ENCODING_NO_COMPRESSION,
+            &map_content_encoding(&vec![0; 8]),
+            &mut asset,
+            &rule,
+        );
+        insert_asset(&asset.key.collection, &asset.key.full_path, &asset, &rule);
+
+        let usage_collection = COLLECTION_USER_USAGE_KEY.to_string();
+        let usage_rule = get_rule_db(&usage_collection).unwrap();
+        let usage_key =
+            UserUsageKey::create(&user(), &"backfill".to_string(), &CollectionType::Storage);
+
+        unsafe_set_doc(
+            id(),
+            &usage_collection,
+            &usage_key.to_key(),
+            SetDoc {
+                data: br#"{"changes_count":1}"#.to_vec(),
+                ..Default::default()
+            },
+            &usage_rule,
+        )
+        .unwrap();
+
+        // The 8 bytes owned previously count toward the quota.
+        assert!(set("backfill", "second.txt", &[0; 4], &rule).is_err());
+        assert!(get("backfill", "second.txt", &rule).is_none());
+
+        assert_eq!(usage_bytes("backfill"), 8);
+    }
+}
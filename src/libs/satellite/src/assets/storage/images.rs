@@ -0,0 +1,151 @@
+use crate::errors::storage::JUNO_STORAGE_ERROR_INVALID_IMAGE;
+use image::imageops::FilterType;
+use image::{DynamicImage, ImageFormat as Format, ImageReader, Limits};
+use junobuild_collections::types::rules::{ImageFormat, ImageVariant};
+use junobuild_shared::types::core::Blob;
+use std::io::Cursor;
+
+// The bounds of the images that are decoded. An image that declares larger dimensions - e.g. a
+// small file that would decompress to gigabytes - is rejected before its pixels are allocated.
+const MAX_IMAGE_WIDTH: u32 = 8192;
+const MAX_IMAGE_HEIGHT: u32 = 8192;
+const MAX_IMAGE_ALLOC: u64 = 128 * 1024 * 1024;
+
+// ---------------------------------------------------------
+// Decode
+// ---------------------------------------------------------
+
+fn image_reader<'a>(content: &'a [u8], format: &ImageFormat) -> ImageReader<Cursor<&'a [u8]>> {
+    let mut limits = Limits::default();
+    limits.max_image_width = Some(MAX_IMAGE_WIDTH);
+    limits.max_image_height = Some(MAX_IMAGE_HEIGHT);
+    limits.max_alloc = Some(MAX_IMAGE_ALLOC);
+
+    let mut reader = ImageReader::with_format(Cursor::new(content), image_format(format));
+    reader.limits(limits);
+
+    reader
+}
+
+/// Decodes an image within the bounds of the images processed by the Satellite.
+pub fn decode_image(content: &[u8], format: &ImageFormat) -> Result<DynamicImage, String> {
+    image_reader(content, format)
+        .decode()
+        .map_err(|err| format!("{JUNO_STORAGE_ERROR_INVALID_IMAGE} ({err})"))
+}
+
+/// Reads the dimensions declared by the header of an image, without decoding its pixels. No
+/// limits apply since nothing is allocated for the pixels.
+pub fn decode_image_dimensions(head: &[u8], format: &ImageFormat) -> Result<(u32, u32), String> {
+    ImageReader::with_format(Cursor::new(head), image_format(format))
+        .into_dimensions()
+        .map_err(|err| format!("{JUNO_STORAGE_ERROR_INVALID_IMAGE} ({err})"))
+}
+
+// ---------------------------------------------------------
+// Encode
+// ---------------------------------------------------------
+
+/// Scales the image down to the bounds of the variant and encodes it in the given format.
+pub fn encode_image_variant(
+    image: &DynamicImage,
+    variant: &ImageVariant,
+    format: &ImageFormat,
+) -> Result<Blob, String> {
+    let resized = resize(image, variant);
+
+    // JPEG has no alpha channel.
+    let resized = match format {
+        ImageFormat::Jpeg => DynamicImage::ImageRgb8(resized.to_rgb8()),
+        _ => resized,
+    };
+
+    let mut content = Vec::new();
+
+    resized
+        .write_to(&mut Cursor::new(&mut content), image_format(format))
+        .map_err(|err| format!("{JUNO_STORAGE_ERROR_INVALID_IMAGE} ({err})"))?;
+
+    Ok(content)
+}
+
+/// Scales the image down to fit the bounds of the variant, preserving its aspect ratio. Images
+/// are never scaled up.
+fn resize(image: &DynamicImage, variant: &ImageVariant) -> DynamicImage {
+    let max_width = variant.max_width.unwrap_or(u32::MAX).min(image.width());
+    let max_height = variant.max_height.unwrap_or(u32::MAX).min(image.height());
+
+    if max_width == image.width() && max_height == image.height() {
+        return image.clone();
+    }
+
+    image.resize(max_width, max_height, FilterType::Triangle)
+}
+
+fn image_format(format: &ImageFormat) -> Format {
+    match format {
+        ImageFormat::Png => Format::Png,
+        ImageFormat::Jpeg => Format::Jpeg,
+        ImageFormat::WebP => Format::WebP,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn variant(max_width: Option<u32>, max_height: Option<u32>) -> ImageVariant {
+        ImageVariant {
+            name: "thumbnail".to_string(),
+            max_width,
+            max_height,
+            format: None,
+        }
+    }
+
+    fn png(width: u32, height: u32) -> Blob {
+        let mut content = Vec::new();
+
+        DynamicImage::new_rgba8(width, height)
+            .write_to(&mut Cursor::new(&mut content), Format::Png)
+            .unwrap();
+
+        content
+    }
+
+    #[test]
+    fn scales_down_preserving_aspect_ratio() {
+        let image = DynamicImage::new_rgba8(400, 200);
+
+        let resized = resize(&image, &variant(Some(100), None));
+        assert_eq!((resized.width(), resized.height()), (100, 50));
+
+        let resized = resize(&image, &variant(Some(100), Some(20)));
+        assert_eq!((resized.width(), resized.height()), (40, 20));
+
+        let resized = resize(&image, &variant(Some(1000), Some(1000)));
+        assert_eq!((resized.width(), resized.height()), (400, 200));
+    }
+
+    #[test]
+    fn encodes_variants_in_the_requested_format() {
+        let image = DynamicImage::new_rgba8(64, 64);
+
+        for format in [ImageFormat::Png, ImageFormat::Jpeg, ImageFormat::WebP] {
+            let content = encode_image_variant(&image, &variant(Some(16), None), &format)
+                .ok()
+                .unwrap();
+
+            let decoded = decode_image(&content, &format).ok().unwrap();
+            assert_eq!((decoded.width(), decoded.height()), (16, 16));
+        }
+    }
+
+    #[test]
+    fn rejects_images_beyond_limits() {
+        let content = png(MAX_IMAGE_WIDTH + 1, 1);
+
+        assert!(decode_image(&content, &ImageFormat::Png).is_err());
+        assert!(decode_image(&png(MAX_IMAGE_WIDTH, 1), &ImageFormat::Png).is_ok());
+    }
+}
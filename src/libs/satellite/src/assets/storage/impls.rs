@@ -1,4 +1,6 @@
-use crate::assets::storage::types::state::{StableEncodingChunkKey, StableKey};
+use crate::assets::storage::types::state::{
+    StableEncodingChunkKey, StableKey, UploadChunkStableKey,
+};
 use ic_stable_structures::storable::Bound;
 use ic_stable_structures::Storable;
 use junobuild_shared::memory::serializers::{
@@ -37,3 +39,19 @@ impl Storable for StableEncodingChunkKey {
 
     const BOUND: Bound = Bound::Unbounded;
 }
+
+impl Storable for UploadChunkStableKey {
+    fn to_bytes(&self) -> Cow<'_, [u8]> {
+        serialize_to_bytes(self)
+    }
+
+    fn into_bytes(self) -> Vec<u8> {
+        serialize_into_bytes(&self)
+    }
+
+    fn from_bytes(bytes: Cow<[u8]>) -> Self {
+        deserialize_from_bytes(bytes)
+    }
+
+    const BOUND: Bound = Bound::Unbounded;
+}
@@ -1,6 +1,9 @@
-use crate::assets::storage::state::{delete_asset, insert_asset, insert_asset_encoding};
+use crate::assets::storage::state::{
+    delete_asset, get_owner_assets_size, insert_asset, insert_asset_encoding,
+};
 use junobuild_collections::types::core::CollectionKey;
 use junobuild_collections::types::rules::Rule;
+use junobuild_shared::types::state::UserId;
 use junobuild_storage::types::state::FullPath;
 use junobuild_storage::types::store::{Asset, AssetEncoding};
 
@@ -42,3 +45,15 @@ pub fn unsafe_delete_asset(
 ) -> Option<Asset> {
     delete_asset(collection, full_path, rule)
 }
+
+/// Computes the total size of the assets of a collection owned by a user directly from the state.
+///
+/// ⚠️ **Warning:** This function is for internal use only and does not perform any assertions.
+///
+pub fn unsafe_get_owner_assets_size(
+    collection: &CollectionKey,
+    owner: &UserId,
+    rule: &Rule,
+) -> u64 {
+    get_owner_assets_size(collection, owner, rule)
+}
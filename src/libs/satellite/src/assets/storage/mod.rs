@@ -1,10 +1,15 @@
 mod assert;
 pub mod certified_assets;
 pub mod handlers;
+#[cfg(feature = "image")]
+mod images;
 mod impls;
 pub mod internal;
 pub mod services;
+mod signatures;
 mod state;
 pub mod store;
 pub mod strategy_impls;
 pub mod types;
+mod validation;
+mod variants;
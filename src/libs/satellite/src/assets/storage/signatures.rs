@@ -0,0 +1,38 @@
+use crate::assets::storage::state::{get_signing_key, insert_signing_key};
+use crate::errors::storage::JUNO_STORAGE_ERROR_NO_SIGNING_KEY;
+use crate::memory::state::services::with_runtime_rng_mut;
+use junobuild_shared::ic::api::time;
+use junobuild_storage::signatures::is_valid_asset_url_signature;
+use junobuild_storage::types::state::{AssetSigningKey, AssetUrlSignature};
+use junobuild_storage::types::store::Asset;
+use rand::RngCore;
+
+const SIGNING_KEY_LENGTH: usize = 32;
+
+/// Returns the asset if the URL it was requested with carries a valid, not expired, signature.
+pub fn get_signed_asset(asset: &Asset, signature: &Option<AssetUrlSignature>) -> Option<Asset> {
+    let signature = signature.as_ref()?;
+    let key = get_signing_key()?;
+
+    is_valid_asset_url_signature(asset, signature, &key, time()).then(|| asset.clone())
+}
+
+/// Returns the key used to sign the URLs of the assets, generating it on first use.
+pub fn get_or_init_signing_key() -> Result<AssetSigningKey, String> {
+    if let Some(key) = get_signing_key() {
+        return Ok(key);
+    }
+
+    let key = with_runtime_rng_mut(|rng| match rng {
+        None => Err(JUNO_STORAGE_ERROR_NO_SIGNING_KEY.to_string()),
+        Some(rng) => {
+            let mut key = vec![0u8; SIGNING_KEY_LENGTH];
+            rng.fill_bytes(&mut key);
+            Ok(key)
+        }
+    })?;
+
+    insert_signing_key(&key);
+
+    Ok(key)
+}
@@ -1,7 +1,9 @@
 use crate::assets::constants::{CDN_JUNO_PATH, CDN_JUNO_RELEASES_COLLECTION_KEY};
 use crate::assets::storage::types::state::{
-    AssetsStable, ContentChunksStable, StableEncodingChunkKey, StableKey,
+    AssetsStable, ContentChunksStable, StableEncodingChunkKey, StableKey, UploadChunkStableKey,
 };
+use crate::expiry::state::insert_expiry;
+use crate::expiry::types::state::{ExpiryStableKey, ExpiryTarget};
 use crate::memory::state::STATE;
 use crate::types::state::{StableState, State};
 use junobuild_collections::constants::assets::COLLECTION_ASSET_KEY;
@@ -13,13 +15,16 @@ use junobuild_shared::data::collect::collect_stable_vec;
 use junobuild_shared::memory::serializers::deserialize_from_bytes;
 use junobuild_shared::types::core::{Blob, DomainName};
 use junobuild_shared::types::domain::{CustomDomain, CustomDomains};
+use junobuild_shared::types::state::{Timestamp, UserId};
+use junobuild_storage::heap_utils::collect_assets_heap;
 use junobuild_storage::stable_utils::insert_asset_encoding_stable;
 use junobuild_storage::types::config::StorageConfig;
-use junobuild_storage::types::state::{AssetsHeap, FullPath, StorageHeapState};
-use junobuild_storage::types::store::{Asset, AssetEncoding};
+use junobuild_storage::types::runtime_state::{BatchId, ChunkId};
+use junobuild_storage::types::state::{AssetSigningKey, AssetsHeap, FullPath, StorageHeapState};
+use junobuild_storage::types::store::{Asset, AssetEncoding, Batch, Chunk, ChunkSummary};
 use junobuild_storage::utils::{clone_asset_encoding_content_chunks, insert_encoding_into_asset};
 use std::borrow::Cow;
-use std::ops::RangeBounds;
+use std::ops::{Bound, RangeBounds};
 // ---------------------------------------------------------
 // Assets
 // ---------------------------------------------------------
@@ -135,6 +140,13 @@ pub fn insert_asset_encoding(
 }
 
 pub fn insert_asset(collection: &CollectionKey, full_path: &FullPath, asset: &Asset, rule: &Rule) {
+    if let Some(expires_at) = asset.key.expires_at {
+        insert_expiry(
+            ExpiryTarget::Asset(collection.clone(), full_path.clone()),
+            expires_at,
+        );
+    }
+
     match rule.mem() {
         Memory::Heap => STATE.with(|state| {
             insert_asset_heap(
@@ -249,10 +261,69 @@ pub fn get_assets_stable(
     collect_stable_vec(assets.range(filter_assets_range(collection)))
 }
 
+/// The assets of a collection that follow a full path - or precede it, in descending order - read
+/// lazily by seeking in the stable memory.
+pub fn iter_assets_stable_after<'a>(
+    collection: &CollectionKey,
+    start_after: &FullPath,
+    desc: bool,
+    assets: &'a AssetsStable,
+) -> Box<dyn Iterator<Item = (StableKey, Asset)> + 'a> {
+    let after = stable_full_path(collection, start_after);
+
+    if desc {
+        let start_key = StableKey {
+            collection: collection.clone(),
+            full_path: "".to_string(),
+        };
+
+        return Box::new(
+            assets
+                .range((Bound::Included(start_key), Bound::Excluded(after)))
+                .rev()
+                .map(|entry| (entry.key().clone(), entry.value().clone())),
+        );
+    }
+
+    let end_key = StableKey {
+        collection: range_collection_end(collection).clone(),
+        full_path: "".to_string(),
+    };
+
+    Box::new(
+        assets
+            .range((Bound::Excluded(after), Bound::Excluded(end_key)))
+            .map(|entry| (entry.key().clone(), entry.value().clone())),
+    )
+}
+
 pub fn count_assets_stable(collection: &CollectionKey, assets: &AssetsStable) -> usize {
     assets.range(filter_assets_range(collection)).count()
 }
 
+/// The total size of the assets of a collection owned by a user - including the generated variants,
+/// which are assets of the same owner.
+pub fn get_owner_assets_size(collection: &CollectionKey, owner: &UserId, rule: &Rule) -> u64 {
+    match rule.mem() {
+        Memory::Heap => STATE.with(|state| {
+            collect_assets_heap(collection, &state.borrow().heap.storage.assets)
+                .iter()
+                .filter(|(_, asset)| asset.key.owner == *owner)
+                .fold(0u64, |size, (_, asset)| size.saturating_add(asset.size()))
+        }),
+        Memory::Stable => STATE.with(|state| {
+            state
+                .borrow()
+                .stable
+                .assets
+                .range(filter_assets_range(collection))
+                .map(|entry| entry.value())
+                .filter(|asset| asset.key.owner == *owner)
+                .fold(0u64, |size, asset| size.saturating_add(asset.size()))
+        }),
+    }
+}
+
 fn filter_assets_range(collection: &CollectionKey) -> impl RangeBounds<StableKey> {
     let start_key = StableKey {
         collection: collection.clone(),
@@ -366,3 +437,169 @@ pub fn insert_config(config: &StorageConfig) {
 fn insert_config_impl(config: &StorageConfig, state: &mut StorageHeapState) {
     state.config = config.clone();
 }
+
+// ---------------------------------------------------------
+// Upload batches
+// ---------------------------------------------------------
+
+pub fn get_upload_batch(batch_id: &BatchId) -> Option<Batch> {
+    STATE.with(|state| state.borrow().stable.upload_batches.get(batch_id))
+}
+
+pub fn get_last_upload_batch_id() -> Option<BatchId> {
+    STATE.with(|state| {
+        state
+            .borrow()
+            .stable
+            .upload_batches
+            .last_key_value()
+            .map(|(batch_id, _)| batch_id)
+    })
+}
+
+/// Inserts or extends a batch. Each batch has a single entry in the expiration schedule, the one of
+/// its current expiration, which is replaced when the batch is extended.
+pub fn insert_upload_batch(batch_id: &BatchId, batch: Batch) {
+    let expires_at = batch.expires_at;
+
+    let previous = STATE.with(|state| {
+        let stable = &mut state.borrow_mut().stable;
+
+        let previous = stable.upload_batches.insert(*batch_id, batch);
+
+        if let Some(previous) = &previous {
+            stable
+                .expiries
+                .remove(&upload_batch_expiry(batch_id, previous.expires_at));
+        }
+
+        previous
+    });
+
+    if previous.is_some_and(|previous| previous.expires_at == expires_at) {
+        return;
+    }
+
+    insert_expiry(ExpiryTarget::UploadBatch(*batch_id), expires_at);
+}
+
+pub fn get_upload_chunk(batch_id: &BatchId, chunk_id: &ChunkId) -> Option<Chunk> {
+    STATE.with(|state| {
+        state
+            .borrow()
+            .stable
+            .upload_chunks
+            .get(&UploadChunkStableKey {
+                batch_id: *batch_id,
+                chunk_id: *chunk_id,
+            })
+    })
+}
+
+pub fn get_last_upload_chunk_id(batch_id: &BatchId) -> Option<ChunkId> {
+    STATE.with(|state| {
+        state
+            .borrow()
+            .stable
+            .upload_chunks
+            .keys_range(filter_upload_chunks_range(batch_id))
+            .last()
+            .map(|key| key.chunk_id)
+    })
+}
+
+pub fn insert_upload_chunk(chunk_id: &ChunkId, chunk: Chunk) {
+    let key = UploadChunkStableKey {
+        batch_id: chunk.batch_id,
+        chunk_id: *chunk_id,
+    };
+
+    STATE.with(|state| state.borrow_mut().stable.upload_chunks.insert(key, chunk));
+}
+
+pub fn list_upload_chunks(batch_id: &BatchId) -> Vec<ChunkSummary> {
+    STATE.with(|state| {
+        state
+            .borrow()
+            .stable
+            .upload_chunks
+            .range(filter_upload_chunks_range(batch_id))
+            .map(|entry| {
+                let chunk = entry.value();
+
+                ChunkSummary {
+                    chunk_id: entry.key().chunk_id,
+                    order_id: chunk.order_id,
+                    length: chunk.content.len() as u128,
+                }
+            })
+            .collect()
+    })
+}
+
+pub fn delete_upload_batch(batch_id: &BatchId) {
+    STATE.with(|state| delete_upload_batch_impl(batch_id, &mut state.borrow_mut().stable))
+}
+
+/// Deletes the batch if it is still the one that was scheduled to expire at `expires_at` - i.e.
+/// it was neither committed nor extended in the meantime.
+pub fn delete_expired_upload_batch(batch_id: &BatchId, expires_at: Timestamp, now: Timestamp) {
+    STATE.with(|state| {
+        let stable = &mut state.borrow_mut().stable;
+
+        let expired = stable
+            .upload_batches
+            .get(batch_id)
+            .is_some_and(|batch| batch.expires_at == expires_at && now > batch.expires_at);
+
+        if expired {
+            delete_upload_batch_impl(batch_id, stable);
+        }
+    })
+}
+
+fn delete_upload_batch_impl(batch_id: &BatchId, stable: &mut StableState) {
+    let chunk_keys: Vec<UploadChunkStableKey> = stable
+        .upload_chunks
+        .keys_range(filter_upload_chunks_range(batch_id))
+        .collect();
+
+    for key in chunk_keys {
+        stable.upload_chunks.remove(&key);
+    }
+
+    if let Some(batch) = stable.upload_batches.remove(batch_id) {
+        stable
+            .expiries
+            .remove(&upload_batch_expiry(batch_id, batch.expires_at));
+    }
+}
+
+fn upload_batch_expiry(batch_id: &BatchId, expires_at: Timestamp) -> ExpiryStableKey {
+    ExpiryStableKey {
+        expires_at,
+        target: ExpiryTarget::UploadBatch(*batch_id),
+    }
+}
+
+fn filter_upload_chunks_range(batch_id: &BatchId) -> impl RangeBounds<UploadChunkStableKey> {
+    UploadChunkStableKey {
+        batch_id: *batch_id,
+        chunk_id: ChunkId::MIN,
+    }..=UploadChunkStableKey {
+        batch_id: *batch_id,
+        chunk_id: ChunkId::MAX,
+    }
+}
+
+// ---------------------------------------------------------
+// Signing key
+// ---------------------------------------------------------
+
+pub fn get_signing_key() -> Option<AssetSigningKey> {
+    STATE.with(|state| state.borrow().heap.storage.signing_key.clone())
+}
+
+pub fn insert_signing_key(key: &AssetSigningKey) {
+    STATE.with(|state| state.borrow_mut().heap.storage.signing_key = Some(key.clone()))
+}
@@ -1,30 +1,47 @@
-use crate::access_keys::store::get_access_keys;
+use crate::access_keys::store::get_storage_access_keys;
 use crate::assets::storage::assert::{
     assert_create_batch, assert_delete_asset, assert_get_asset, assert_list_assets,
     assert_set_config, assert_write_asset,
 };
+use crate::assets::storage::signatures::{get_or_init_signing_key, get_signed_asset};
 use crate::assets::storage::state::{
     count_assets_stable, delete_asset as delete_state_asset, delete_domain as delete_state_domain,
-    get_asset as get_state_asset, get_assets_stable, get_config as get_state_config, get_config,
-    get_content_chunks as get_state_content_chunks, get_domain as get_state_domain,
-    get_domains as get_state_domains, get_public_asset as get_state_public_asset,
-    get_rule as get_state_rule, insert_asset, insert_config as insert_state_config,
-    insert_domain as insert_state_domain,
+    delete_expired_upload_batch, get_asset as get_state_asset, get_assets_stable,
+    get_config as get_state_config, get_config, get_content_chunks as get_state_content_chunks,
+    get_domain as get_state_domain, get_domains as get_state_domains,
+    get_public_asset as get_state_public_asset, get_rule as get_state_rule, get_upload_batch,
+    insert_asset, insert_config as insert_state_config, insert_domain as insert_state_domain,
+    iter_assets_stable_after,
+};
+use crate::assets::storage::strategy_impls::{
+    StorageAssertions, StorageBatches, StorageState, StorageUpload,
+};
+use crate::assets::storage::types::state::{AssetsStable, StableKey};
+use crate::assets::storage::validation::get_upload_content_chunk;
+use crate::assets::storage::variants::{
+    delete_asset_variants, get_asset_variants_size, insert_asset_variants, prepare_asset_variants,
 };
-use crate::assets::storage::strategy_impls::{StorageAssertions, StorageState, StorageUpload};
 use crate::auth::store::get_config as get_auth_config;
 use crate::certification::strategy_impls::StorageCertificate;
+use crate::errors::storage::{
+    JUNO_STORAGE_ERROR_ASSET_NOT_PROTECTED, JUNO_STORAGE_ERROR_NO_SIGNING_KEY,
+    JUNO_STORAGE_ERROR_SIGNED_URL_EXPIRED,
+};
 use crate::memory::state::STATE;
+use crate::types::state::CollectionType;
 use crate::types::store::{AssertContext, StoreContext};
+use crate::user::usage::assert::update_and_assert_storage_bytes_usage;
+use crate::user::usage::store::{backfill_bytes_usage, release_bytes_usage};
 use candid::Principal;
 use junobuild_collections::msg::msg_storage_collection_not_empty;
 use junobuild_collections::types::core::CollectionKey;
 use junobuild_collections::types::rules::{Memory, Rule};
-use junobuild_shared::data::list::list_values;
+use junobuild_shared::data::list::{list_seek, list_values, list_values_after_seek};
+use junobuild_shared::ic::api::time;
 use junobuild_shared::types::core::{Blob, DomainName};
 use junobuild_shared::types::domain::CustomDomains;
-use junobuild_shared::types::list::{ListParams, ListResults};
-use junobuild_shared::types::state::AccessKeys;
+use junobuild_shared::types::list::{ListParams, ListResults, ListSeek};
+use junobuild_shared::types::state::{AccessKeyOperation, AccessKeys, Expirable, Timestamp};
 use junobuild_storage::constants::{ROOT_404_HTML, ROOT_INDEX_HTML};
 use junobuild_storage::errors::JUNO_STORAGE_ERROR_ASSET_NOT_FOUND;
 use junobuild_storage::heap_utils::{
@@ -34,15 +51,18 @@ use junobuild_storage::runtime::{
     delete_certified_asset as delete_runtime_certified_asset,
     update_certified_asset as update_runtime_certified_asset,
 };
-use junobuild_storage::store::{commit_batch as commit_batch_storage, create_batch, create_chunk};
+use junobuild_storage::signatures::sign_asset_url;
+use junobuild_storage::store::{
+    commit_batch as commit_batch_storage, create_batch, create_chunk, get_upload_session,
+};
 use junobuild_storage::strategies::StorageAssertionsStrategy;
 use junobuild_storage::types::config::StorageConfig;
 use junobuild_storage::types::interface::{
-    AssetNoContent, CommitBatch, InitAssetKey, SetStorageConfig, UploadChunk,
+    AssetNoContent, CommitBatch, InitAssetKey, SetStorageConfig, UploadChunk, UploadSession,
 };
 use junobuild_storage::types::runtime_state::{BatchId, ChunkId};
-use junobuild_storage::types::state::{AssetAccessToken, FullPath};
-use junobuild_storage::types::store::{Asset, AssetEncoding};
+use junobuild_storage::types::state::{AssetAccessToken, AssetUrlSignature, FullPath};
+use junobuild_storage::types::store::{Asset, AssetAssertUpload, AssetEncoding};
 use junobuild_storage::utils::{
     filter_collection_values, filter_values, get_token_protected_asset, map_asset_no_content,
     should_include_asset_for_deletion,
@@ -100,7 +120,7 @@ pub fn delete_asset_store(
     collection: &CollectionKey,
     full_path: FullPath,
 ) -> Result<Option<Asset>, String> {
-    let controllers: AccessKeys = get_access_keys();
+    let controllers: AccessKeys = get_storage_access_keys(collection, AccessKeyOperation::Write);
     let config = get_config_store();
 
     let context = StoreContext {
@@ -172,7 +192,7 @@ pub fn list_assets_store(
     collection: &CollectionKey,
     filters: &ListParams,
 ) -> Result<ListResults<AssetNoContent>, String> {
-    let controllers: AccessKeys = get_access_keys();
+    let controllers: AccessKeys = get_storage_access_keys(collection, AccessKeyOperation::Read);
 
     let context = StoreContext {
         caller,
@@ -234,7 +254,7 @@ pub fn get_asset_store(
     collection: &CollectionKey,
     full_path: FullPath,
 ) -> Result<Option<Asset>, String> {
-    let controllers: AccessKeys = get_access_keys();
+    let controllers: AccessKeys = get_storage_access_keys(collection, AccessKeyOperation::Read);
 
     let context = StoreContext {
         caller,
@@ -265,7 +285,8 @@ fn get_asset_impl(
     assert_context: &AssertContext,
     full_path: FullPath,
 ) -> Result<Option<Asset>, String> {
-    let asset = get_state_asset(context.collection, &full_path, assert_context.rule);
+    let asset = get_state_asset(context.collection, &full_path, assert_context.rule)
+        .filter(|asset| !asset.is_expired(time()));
 
     match asset {
         None => Ok(None),
@@ -282,15 +303,18 @@ fn get_asset_impl(
 pub fn get_public_asset_store(
     full_path: FullPath,
     token: AssetAccessToken,
+    signature: Option<AssetUrlSignature>,
 ) -> Option<(Asset, Memory)> {
     let (asset, memory) = get_state_public_asset(&full_path);
 
     match asset {
         None => None,
+        Some(asset) if asset.is_expired(time()) => None,
         Some(asset) => match &asset.key.token {
             None => Some((asset.clone(), memory)),
             Some(asset_token) => {
-                let protected_asset = get_token_protected_asset(&asset, asset_token, token);
+                let protected_asset = get_token_protected_asset(&asset, asset_token, token)
+                    .or_else(|| get_signed_asset(&asset, &signature));
                 protected_asset.map(|protected_asset| (protected_asset, memory))
             }
         },
@@ -351,6 +375,17 @@ fn secure_list_assets_impl(
             list_assets_impl(&assets, context, &rule, filters, &StorageAssertions)
         }),
         Memory::Stable => STATE.with(|state| {
+            // A page ordered by keys that resumes from a cursor is read by seeking to it.
+            if let Some(seek) = list_seek(filters)? {
+                return list_assets_stable_after_impl(
+                    &seek,
+                    context,
+                    &rule,
+                    filters,
+                    &state.borrow().stable.assets,
+                );
+            }
+
             let stable = get_assets_stable(context.collection, &state.borrow().stable.assets);
             let assets: Vec<(&FullPath, &Asset)> = stable
                 .iter()
@@ -361,6 +396,63 @@ fn secure_list_assets_impl(
     }
 }
 
+/// Reads the page that follows a cursor in a stable collection. The assets are filtered in
+/// batches, until one beyond the limit is found, to know whether more assets follow.
+fn list_assets_stable_after_impl(
+    seek: &ListSeek,
+    &StoreContext {
+        caller,
+        controllers,
+        collection,
+    }: &StoreContext,
+    rule: &Rule,
+    filters: &ListParams,
+    stable_assets: &AssetsStable,
+) -> Result<ListResults<AssetNoContent>, String> {
+    let wanted = seek.limit.map(|limit| limit.saturating_add(1));
+
+    let mut entries =
+        iter_assets_stable_after(collection, &seek.start_after, seek.desc, stable_assets);
+    let mut matches: Vec<(FullPath, AssetNoContent)> = Vec::new();
+
+    loop {
+        let missing = wanted.map_or(usize::MAX, |wanted| wanted.saturating_sub(matches.len()));
+
+        if missing == 0 {
+            break;
+        }
+
+        let batch: Vec<(StableKey, Asset)> = entries.by_ref().take(missing).collect();
+
+        if batch.is_empty() {
+            break;
+        }
+
+        let assets: Vec<(&FullPath, &Asset)> = batch
+            .iter()
+            .map(|(_, asset)| (&asset.key.full_path, asset))
+            .collect();
+
+        let filtered = filter_values(
+            caller,
+            controllers,
+            &rule.read,
+            collection.clone(),
+            filters,
+            &assets,
+            &StorageAssertions,
+        )?;
+
+        matches.extend(
+            filtered
+                .into_iter()
+                .map(|(_, asset)| map_asset_no_content(asset)),
+        );
+    }
+
+    Ok(list_values_after_seek(matches, seek))
+}
+
 fn list_assets_impl(
     assets: &[(&FullPath, &Asset)],
     &StoreContext {
@@ -382,7 +474,7 @@ fn list_assets_impl(
         assertions,
     )?;
 
-    let values = list_values(&matches, filters);
+    let values = list_values(&matches, filters)?;
 
     let result = ListResults::<AssetNoContent> {
         items: values
@@ -394,6 +486,7 @@ fn list_assets_impl(
         items_page: values.items_page,
         matches_length: values.matches_length,
         matches_pages: values.matches_pages,
+        next_cursor: values.next_cursor,
     };
 
     Ok(result)
@@ -430,25 +523,87 @@ fn delete_asset_impl(
 
             assert_delete_asset(context, &asset)?;
 
-            let certificate = &StorageCertificate;
+            update_and_assert_storage_bytes_usage(
+                context,
+                asset.key.owner,
+                get_asset_size_with_variants(&asset, assert_context.rule),
+                0,
+                assert_context.rule.max_bytes_per_user,
+            )?;
+
+            let deleted = delete_certified_asset_impl(
+                context.collection,
+                &full_path,
+                &asset,
+                assert_context.rule,
+                config,
+            );
 
-            let deleted = delete_state_asset(context.collection, &full_path, assert_context.rule);
-            delete_runtime_certified_asset(&asset, certificate);
+            Ok(deleted)
+        }
+    }
+}
 
-            // We just removed the rewrite for /404.html in the certification tree therefore if /index.html exists, we want to reintroduce it as rewrite
-            if *full_path == *ROOT_404_HTML {
-                if let Some(index_asset) = get_state_asset(
-                    context.collection,
-                    &ROOT_INDEX_HTML.to_string(),
-                    assert_context.rule,
-                ) {
-                    update_runtime_certified_asset(&index_asset, config, certificate);
-                }
-            }
+fn delete_certified_asset_impl(
+    collection: &CollectionKey,
+    full_path: &FullPath,
+    asset: &Asset,
+    rule: &Rule,
+    config: &StorageConfig,
+) -> Option<Asset> {
+    let certificate = &StorageCertificate;
 
-            Ok(deleted)
+    let deleted = delete_state_asset(collection, full_path, rule);
+    delete_runtime_certified_asset(asset, config, certificate);
+
+    delete_asset_variants(collection, full_path, rule, config);
+
+    // We just removed the rewrite for /404.html in the certification tree therefore if /index.html exists, we want to reintroduce it as rewrite
+    if *full_path == *ROOT_404_HTML {
+        if let Some(index_asset) = get_state_asset(collection, &ROOT_INDEX_HTML.to_string(), rule) {
+            update_runtime_certified_asset(&index_asset, config, certificate);
         }
     }
+
+    deleted
+}
+
+/// Deletes an asset whose expiration is due.
+///
+/// The asset is only deleted if `expires_at` is still its expiration - i.e. it was not uploaded
+/// again with another expiration since. No assertion is performed, this function is used by the
+/// purge of the expired entries that occurs at `now`.
+///
+/// # Returns
+/// - `Ok(Some(Asset))`: The deleted asset.
+/// - `Ok(None)`: The asset does not exist anymore or is not expired.
+/// - `Err(String)`: An error message if the collection does not exist.
+pub fn delete_expired_asset_store(
+    collection: &CollectionKey,
+    full_path: &FullPath,
+    expires_at: Timestamp,
+    now: Timestamp,
+) -> Result<Option<Asset>, String> {
+    let rule = get_state_rule(collection)?;
+    let config = get_config_store();
+
+    let asset = get_state_asset(collection, full_path, &rule);
+
+    match asset {
+        Some(asset) if asset.key.expires_at == Some(expires_at) && asset.is_expired(now) => {
+            release_bytes_usage(
+                collection,
+                &CollectionType::Storage,
+                &asset.key.owner,
+                get_asset_size_with_variants(&asset, &rule),
+            )?;
+
+            Ok(delete_certified_asset_impl(
+                collection, full_path, &asset, &rule, &config,
+            ))
+        }
+        _ => Ok(None),
+    }
 }
 
 fn delete_assets_impl(
@@ -456,13 +611,22 @@ fn delete_assets_impl(
     collection: &CollectionKey,
     rule: &Rule,
 ) -> Result<(), String> {
+    let config = get_config_store();
+
     for full_path in full_paths {
         let deleted_asset = delete_state_asset(collection, full_path, rule);
 
         match deleted_asset {
             None => {}
             Some(deleted_asset) => {
-                delete_runtime_certified_asset(&deleted_asset, &StorageCertificate);
+                delete_runtime_certified_asset(&deleted_asset, &config, &StorageCertificate);
+
+                release_bytes_usage(
+                    collection,
+                    &CollectionType::Storage,
+                    &deleted_asset.key.owner,
+                    deleted_asset.size(),
+                )?;
             }
         }
     }
@@ -526,7 +690,7 @@ pub fn delete_filtered_assets_store(
     collection: CollectionKey,
     filters: &ListParams,
 ) -> Result<Vec<Option<Asset>>, String> {
-    let controllers: AccessKeys = get_access_keys();
+    let controllers: AccessKeys = get_storage_access_keys(&collection, AccessKeyOperation::Write);
 
     let context = StoreContext {
         caller,
@@ -593,7 +757,7 @@ pub fn set_asset_token_store(
     full_path: &FullPath,
     token: &AssetAccessToken,
 ) -> Result<(), String> {
-    let controllers: AccessKeys = get_access_keys();
+    let controllers: AccessKeys = get_storage_access_keys(collection, AccessKeyOperation::Write);
     let config = get_config_store();
 
     let context = StoreContext {
@@ -652,12 +816,88 @@ fn set_asset_token_impl(
     Ok(())
 }
 
+/// Sign a URL that grants access to a protected asset until it expires.
+///
+/// This function returns the URL of an asset protected by a token - e.g. `/invoices/123.pdf?expires=...&signature=...` -
+/// that can be shared without revealing the token. The URL is served until the provided expiry, after which it
+/// stops working. It returns a `Result<String, String>` where `Ok(String)` contains the signed URL, or an error
+/// message as `Err(String)` if the operation encounters issues.
+///
+/// # Parameters
+/// - `caller`: The `Principal` representing the caller initiating the operation.
+/// - `collection`: A reference to the `CollectionKey` representing the collection containing the asset.
+/// - `full_path`: A reference to the `FullPath` identifying the asset to share.
+/// - `expires_at`: The `Timestamp`, in nanoseconds, until which the URL is valid.
+///
+/// # Returns
+/// - `Ok(String)`: The signed URL, relative to the satellite.
+/// - `Err(String)`: An error message if the operation fails.
+///
+/// Setting a new token for the asset revokes the URLs signed previously.
+pub fn sign_asset_url_store(
+    caller: Principal,
+    collection: &CollectionKey,
+    full_path: &FullPath,
+    expires_at: Timestamp,
+) -> Result<String, String> {
+    let controllers: AccessKeys = get_storage_access_keys(collection, AccessKeyOperation::Read);
+
+    let context = StoreContext {
+        caller,
+        controllers: &controllers,
+        collection,
+    };
+
+    secure_sign_asset_url_impl(&context, full_path, expires_at)
+}
+
+fn secure_sign_asset_url_impl(
+    context: &StoreContext,
+    full_path: &FullPath,
+    expires_at: Timestamp,
+) -> Result<String, String> {
+    let rule = get_state_rule(context.collection)?;
+    let auth_config = get_auth_config();
+
+    let assert_context = AssertContext {
+        rule: &rule,
+        auth_config: &auth_config,
+    };
+
+    sign_asset_url_impl(context, &assert_context, full_path, expires_at)
+}
+
+fn sign_asset_url_impl(
+    context: &StoreContext,
+    assert_context: &AssertContext,
+    full_path: &FullPath,
+    expires_at: Timestamp,
+) -> Result<String, String> {
+    let asset = get_state_asset(context.collection, full_path, assert_context.rule)
+        .ok_or(JUNO_STORAGE_ERROR_ASSET_NOT_FOUND.to_string())?;
+
+    assert_write_asset(context, assert_context, &asset)?;
+
+    if asset.key.token.is_none() {
+        return Err(JUNO_STORAGE_ERROR_ASSET_NOT_PROTECTED.to_string());
+    }
+
+    if expires_at <= time() {
+        return Err(JUNO_STORAGE_ERROR_SIGNED_URL_EXPIRED.to_string());
+    }
+
+    let key = get_or_init_signing_key()?;
+
+    sign_asset_url(&asset, expires_at, &key).ok_or(JUNO_STORAGE_ERROR_NO_SIGNING_KEY.to_string())
+}
+
 // ---------------------------------------------------------
 // Upload batch and chunks
 // ---------------------------------------------------------
 
 pub fn create_batch_store(caller: Principal, init: InitAssetKey) -> Result<BatchId, String> {
-    let controllers: AccessKeys = get_access_keys();
+    let controllers: AccessKeys =
+        get_storage_access_keys(&init.collection, AccessKeyOperation::Write);
     let config = get_config();
 
     secure_create_batch_impl(caller, &controllers, &config, init)
@@ -666,13 +906,65 @@ pub fn create_batch_store(caller: Principal, init: InitAssetKey) -> Result<Batch
 pub fn create_chunk_store(caller: Principal, chunk: UploadChunk) -> Result<ChunkId, String> {
     let config = get_config();
 
-    create_chunk(caller, &config, chunk)
+    create_chunk(caller, &config, chunk, &StorageBatches)
+}
+
+/// Read a chunk of the content of an upload being committed.
+///
+/// The chunks are returned one at a time, in the order they are committed, which keeps the memory
+/// used to inspect an upload bounded. Useful in the `assert_upload_asset` hook to validate the
+/// uploaded bytes before the asset is committed.
+///
+/// # Parameters
+/// - `upload`: A reference to the `AssetAssertUpload` provided to the hook.
+/// - `index`: The position of the chunk in the content, starting at zero.
+///
+/// # Returns
+/// - `Some(Blob)`: The content of the chunk.
+/// - `None`: If there is no chunk at this position.
+pub fn get_asset_upload_chunk_store(upload: &AssetAssertUpload, index: usize) -> Option<Blob> {
+    get_upload_content_chunk(upload, index)
+}
+
+/// Deletes an upload batch - and its chunks - that expired before it was committed. Invoked by
+/// the purge of the expiration schedule.
+pub fn delete_expired_upload_batch_store(
+    batch_id: &BatchId,
+    expires_at: Timestamp,
+    now: Timestamp,
+) {
+    delete_expired_upload_batch(batch_id, expires_at, now);
 }
 
-pub fn commit_batch_store(caller: Principal, commit_batch: CommitBatch) -> Result<Asset, String> {
-    let controllers: AccessKeys = get_access_keys();
+pub fn get_upload_session_store(
+    caller: Principal,
+    batch_id: &BatchId,
+) -> Result<UploadSession, String> {
+    get_upload_session(caller, batch_id, &StorageBatches)
+}
+
+/// Commit an upload and generate the variants of the asset declared on the rule of its collection.
+///
+/// # Returns
+/// - `Ok((Asset, Vec<Asset>))`: The committed asset and its generated variants.
+/// - `Err(String)`: An error message if the upload, a variant or the usage of the owner cannot be asserted.
+pub fn commit_batch_store(
+    caller: Principal,
+    commit_batch: CommitBatch,
+) -> Result<(Asset, Vec<Asset>), String> {
+    // The collection is the one of the batch, which was asserted when the batch was created.
+    let controllers: AccessKeys = get_upload_batch(&commit_batch.batch_id)
+        .map(|batch| get_storage_access_keys(&batch.key.collection, AccessKeyOperation::Write))
+        .unwrap_or_default();
     let config = get_config();
 
+    let current_size = get_batch_current_asset_size(&commit_batch.batch_id);
+
+    // The usage is asserted once the asset is written, its bytes must be computed before.
+    backfill_batch_bytes_usage(caller, &commit_batch.batch_id)?;
+
+    let batch_id = commit_batch.batch_id;
+
     let asset = commit_batch_storage(
         caller,
         &controllers,
@@ -681,13 +973,73 @@ pub fn commit_batch_store(caller: Principal, commit_batch: CommitBatch) -> Resul
         &StorageAssertions,
         &StorageState,
         &StorageUpload,
+        &StorageBatches,
     )?;
 
     let config = get_config();
 
+    let rule = get_state_rule(&asset.key.collection)?;
+
+    let variants = prepare_asset_variants(caller, &asset, &batch_id, &rule)?;
+
+    let context = StoreContext {
+        caller,
+        controllers: &controllers,
+        collection: &asset.key.collection,
+    };
+
+    // The variants are counted in the usage of the owner of the asset.
+    let size = variants.iter().fold(asset.size(), |size, variant| {
+        size.saturating_add(variant.size())
+    });
+
+    update_and_assert_storage_bytes_usage(
+        &context,
+        asset.key.owner,
+        current_size,
+        size,
+        rule.max_bytes_per_user,
+    )?;
+
     update_runtime_certified_asset(&asset, &config, &StorageCertificate);
 
-    Ok(asset)
+    let variants = insert_asset_variants(&asset, variants, &rule, &config);
+
+    Ok((asset, variants))
+}
+
+/// The size of the asset the batch replaces once committed, with its variants - zero for a new asset.
+fn get_batch_current_asset_size(batch_id: &BatchId) -> u64 {
+    get_upload_batch(batch_id)
+        .and_then(|batch| {
+            let rule = get_state_rule(&batch.key.collection).ok()?;
+            let asset = get_state_asset(&batch.key.collection, &batch.key.full_path, &rule)?;
+
+            Some(get_asset_size_with_variants(&asset, &rule))
+        })
+        .unwrap_or(0)
+}
+
+fn backfill_batch_bytes_usage(caller: Principal, batch_id: &BatchId) -> Result<(), String> {
+    let Some(batch) = get_upload_batch(batch_id) else {
+        return Ok(());
+    };
+
+    let owner = get_state_rule(&batch.key.collection)
+        .ok()
+        .and_then(|rule| get_state_asset(&batch.key.collection, &batch.key.full_path, &rule))
+        .map_or(caller, |asset| asset.key.owner);
+
+    backfill_bytes_usage(&batch.key.collection, &CollectionType::Storage, &owner)
+}
+
+/// The generated variants of an asset are counted in the usage of its owner.
+fn get_asset_size_with_variants(asset: &Asset, rule: &Rule) -> u64 {
+    asset.size().saturating_add(get_asset_variants_size(
+        &asset.key.collection,
+        &asset.key.full_path,
+        rule,
+    ))
 }
 
 fn secure_create_batch_impl(
@@ -714,6 +1066,7 @@ fn secure_create_batch_impl(
         None,
         &StorageAssertions,
         &StorageState,
+        &StorageBatches,
     )
 }
 
@@ -724,7 +1077,7 @@ fn secure_create_batch_impl(
 pub fn set_config_store(proposed_config: &SetStorageConfig) -> Result<StorageConfig, String> {
     let current_config = get_config();
 
-    assert_set_config(proposed_config, &current_config)?;
+    assert_set_config(proposed_config, &current_config, &get_state_domains())?;
 
     let config = StorageConfig::prepare(&current_config, proposed_config);
 
@@ -772,3 +1125,138 @@ fn set_state_domain_impl(domain_name: &DomainName, bn_id: &Option<String>) {
 
     insert_state_domain(domain_name, &custom_domain);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expiry::state::get_expired_entries;
+    use crate::expiry::types::state::ExpiryTarget;
+    use crate::rules::store::set_rule_storage;
+    use crate::rules::test_utils::public_rule;
+    use crate::rules::upgrade::init_system_collections;
+    use junobuild_collections::types::rules::Memory;
+    use junobuild_storage::types::interface::UploadChunk;
+
+    const COLLECTION: &str = "uploads";
+
+    fn init_collection() {
+        init_system_collections();
+
+        set_rule_storage(COLLECTION.to_string(), public_rule(Memory::Stable)).unwrap();
+    }
+
+    fn user() -> Principal {
+        Principal::from_slice(&[1, 2, 3])
+    }
+
+    fn create_batch(name: &str) -> BatchId {
+        create_batch_store(
+            user(),
+            InitAssetKey {
+                name: name.to_string(),
+                full_path: format!("/{COLLECTION}/{name}"),
+                token: None,
+                collection: COLLECTION.to_string(),
+                encoding_type: None,
+                description: None,
+                expires_at: None,
+            },
+        )
+        .unwrap()
+    }
+
+    fn upload(batch_id: BatchId, content: &[u8], order_id: u128) -> ChunkId {
+        create_chunk_store(
+            user(),
+            UploadChunk {
+                batch_id,
+                content: content.to_vec(),
+                order_id: Some(order_id),
+            },
+        )
+        .unwrap()
+    }
+
+    fn batch_expiries(batch_id: BatchId) -> Vec<Timestamp> {
+        get_expired_entries(Timestamp::MAX, usize::MAX)
+            .into_iter()
+            .filter(|entry| entry.target == ExpiryTarget::UploadBatch(batch_id))
+            .map(|entry| entry.expires_at)
+            .collect()
+    }
+
+    #[test]
+    fn lists_the_chunks_of_a_session_by_order() {
+        init_collection();
+
+        let batch_id = create_batch("ordered.txt");
+
+        let third = upload(batch_id, b"ccc", 2);
+        let first = upload(batch_id, b"a", 0);
+        let second = upload(batch_id, b"bb", 1);
+
+        let session = get_upload_session_store(user(), &batch_id).unwrap();
+
+        let chunks: Vec<(ChunkId, u128, u128)> = session
+            .chunks
+            .iter()
+            .map(|chunk| (chunk.chunk_id, chunk.order_id, chunk.offset))
+            .collect();
+
+        assert_eq!(chunks, vec![(first, 0, 0), (second, 1, 1), (third, 2, 3)]);
+        assert_eq!(session.total_length, 6);
+    }
+
+    #[test]
+    fn resumes_an_interrupted_upload() {
+        init_collection();
+
+        let batch_id = create_batch("resumed.txt");
+
+        upload(batch_id, b"a", 0);
+        upload(batch_id, b"ccc", 2);
+
+        // The client finds out which chunk is missing and uploads only that one
+        let session = get_upload_session_store(user(), &batch_id).unwrap();
+
+        let received: Vec<u128> = session.chunks.iter().map(|chunk| chunk.order_id).collect();
+        assert_eq!(received, vec![0, 2]);
+
+        upload(batch_id, b"bb", 1);
+
+        let session = get_upload_session_store(user(), &batch_id).unwrap();
+
+        assert_eq!(session.chunks.len(), 3);
+        assert_eq!(session.total_length, 6);
+
+        // Only the caller who created the batch can inspect it
+        assert!(get_upload_session_store(Principal::anonymous(), &batch_id).is_err());
+    }
+
+    #[test]
+    fn schedules_a_single_expiry_per_batch() {
+        init_collection();
+
+        let batch_id = create_batch("scheduled.txt");
+
+        let created = batch_expiries(batch_id);
+        assert_eq!(created.len(), 1);
+
+        upload(batch_id, b"a", 0);
+
+        // The batch was extended and its entry replaced
+        let extended = batch_expiries(batch_id);
+        assert_eq!(extended.len(), 1);
+        assert!(extended[0] >= created[0]);
+
+        // The entry of the previous expiration does not delete the extended batch
+        delete_expired_upload_batch(&batch_id, created[0], Timestamp::MAX);
+        assert!(get_upload_batch(&batch_id).is_some());
+
+        delete_expired_upload_batch(&batch_id, extended[0], extended[0] + 1);
+
+        assert!(get_upload_batch(&batch_id).is_none());
+        assert!(get_upload_session_store(user(), &batch_id).is_err());
+        assert!(batch_expiries(batch_id).is_empty());
+    }
+}
@@ -2,13 +2,20 @@ use crate::assets::assert::assert_cdn_asset_keys;
 use crate::assets::storage::assert::assert_storage_list_permission;
 use crate::assets::storage::certified_assets::all::certify_all_assets;
 use crate::assets::storage::state::{
-    delete_asset, get_asset, get_config, get_domains, get_rule, insert_asset, insert_asset_encoding,
+    delete_asset, delete_upload_batch, get_asset, get_config, get_domains,
+    get_last_upload_batch_id, get_last_upload_chunk_id, get_rule, get_upload_batch,
+    get_upload_chunk, insert_asset, insert_asset_encoding, insert_upload_batch,
+    insert_upload_chunk, list_upload_chunks,
 };
 use crate::assets::storage::store::{get_content_chunks_store, get_public_asset_store};
+use crate::assets::storage::validation::assert_upload_content;
+use crate::hooks::http::invoke_http_endpoints;
 use crate::hooks::storage::invoke_assert_upload_asset;
+use crate::user::roles::assert::{
+    assert_create_permission_with_user_roles, assert_permission_with_user_roles,
+};
 use crate::user::usage::assert::increment_and_assert_storage_usage;
 use candid::Principal;
-use junobuild_collections::assert::stores::{assert_create_permission, assert_permission};
 use junobuild_collections::types::core::CollectionKey;
 use junobuild_collections::types::rules::{Memory, Permission, Rule};
 use junobuild_shared::segments::access_keys::is_write_access_key;
@@ -16,12 +23,13 @@ use junobuild_shared::types::core::Blob;
 use junobuild_shared::types::domain::CustomDomains;
 use junobuild_shared::types::state::AccessKeys;
 use junobuild_storage::strategies::{
-    StorageAssertionsStrategy, StorageStateStrategy, StorageUploadStrategy,
+    StorageAssertionsStrategy, StorageBatchesStrategy, StorageStateStrategy, StorageUploadStrategy,
 };
 use junobuild_storage::types::config::StorageConfig;
-use junobuild_storage::types::state::{AssetAccessToken, FullPath};
+use junobuild_storage::types::runtime_state::{BatchId, ChunkId};
+use junobuild_storage::types::state::{AssetAccessToken, AssetUrlSignature, FullPath};
 use junobuild_storage::types::store::{
-    Asset, AssetAssertUpload, AssetEncoding, Batch, EncodingType, ReferenceId,
+    Asset, AssetAssertUpload, AssetEncoding, Batch, Chunk, ChunkSummary, EncodingType, ReferenceId,
 };
 
 pub struct StorageAssertions;
@@ -56,7 +64,7 @@ impl StorageAssertionsStrategy for StorageAssertions {
         _collection: &CollectionKey,
         controllers: &AccessKeys,
     ) -> bool {
-        assert_create_permission(permission, caller, controllers)
+        assert_create_permission_with_user_roles(permission, caller, controllers)
     }
 
     fn assert_update_permission(
@@ -67,7 +75,7 @@ impl StorageAssertionsStrategy for StorageAssertions {
         _collection: &CollectionKey,
         controllers: &AccessKeys,
     ) -> bool {
-        assert_permission(permission, owner, caller, controllers)
+        assert_permission_with_user_roles(permission, owner, caller, controllers)
     }
 
     fn assert_list_permission(
@@ -86,6 +94,9 @@ impl StorageAssertionsStrategy for StorageAssertions {
         caller: &Principal,
         asset: &AssetAssertUpload,
     ) -> Result<(), String> {
+        // The built-in validation of the content runs before the assertions of the developer.
+        assert_upload_content(asset)?;
+
         invoke_assert_upload_asset(caller, asset)
     }
 
@@ -116,8 +127,9 @@ impl StorageStateStrategy for StorageState {
         &self,
         full_path: FullPath,
         token: AssetAccessToken,
+        signature: Option<AssetUrlSignature>,
     ) -> Option<(Asset, Memory)> {
-        get_public_asset_store(full_path, token)
+        get_public_asset_store(full_path, token, signature)
     }
 
     fn get_rule(&self, collection: &CollectionKey) -> Result<Rule, String> {
@@ -132,6 +144,10 @@ impl StorageStateStrategy for StorageState {
         get_domains()
     }
 
+    fn get_http_endpoints(&self) -> Vec<FullPath> {
+        invoke_http_endpoints()
+    }
+
     fn get_asset(
         &self,
         collection: &CollectionKey,
@@ -208,3 +224,44 @@ impl StorageUploadStrategy for StorageUpload {
         Ok(asset)
     }
 }
+
+pub struct StorageBatches;
+
+impl StorageBatchesStrategy for StorageBatches {
+    fn get_batch(&self, batch_id: &BatchId) -> Option<Batch> {
+        get_upload_batch(batch_id)
+    }
+
+    fn last_batch_id(&self) -> Option<BatchId> {
+        get_last_upload_batch_id()
+    }
+
+    fn insert_batch(&self, batch_id: &BatchId, batch: Batch) {
+        insert_upload_batch(batch_id, batch)
+    }
+
+    fn get_chunk(&self, batch_id: &BatchId, chunk_id: &ChunkId) -> Option<Chunk> {
+        get_upload_chunk(batch_id, chunk_id)
+    }
+
+    fn last_chunk_id(&self, batch_id: &BatchId) -> Option<ChunkId> {
+        get_last_upload_chunk_id(batch_id)
+    }
+
+    fn insert_chunk(&self, chunk_id: &ChunkId, chunk: Chunk) {
+        insert_upload_chunk(chunk_id, chunk)
+    }
+
+    fn list_chunks(&self, batch_id: &BatchId) -> Vec<ChunkSummary> {
+        list_upload_chunks(batch_id)
+    }
+
+    fn clear_batch(&self, batch_id: &BatchId) {
+        delete_upload_batch(batch_id)
+    }
+
+    fn clear_expired_batches(&self) {
+        // The batches are deleted once expired by the purge of the expiration schedule.
+        // See `ExpiryTarget::UploadBatch`.
+    }
+}
@@ -4,13 +4,18 @@ pub mod state {
     use junobuild_collections::types::core::CollectionKey;
     use junobuild_shared::types::core::Blob;
     use junobuild_shared::types::memory::Memory;
+    use junobuild_storage::types::runtime_state::{BatchId, ChunkId};
     use junobuild_storage::types::state::FullPath;
-    use junobuild_storage::types::store::{Asset, EncodingType};
+    use junobuild_storage::types::store::{Asset, Batch, Chunk, EncodingType};
     use serde::{Deserialize, Serialize};
 
     pub type AssetsStable = StableBTreeMap<StableKey, Asset, Memory>;
     pub type ContentChunksStable = StableBTreeMap<StableEncodingChunkKey, Blob, Memory>;
 
+    // The uploads in progress are kept in stable memory so that they survive upgrades and can be resumed.
+    pub type UploadBatchesStable = StableBTreeMap<BatchId, Batch, Memory>;
+    pub type UploadChunksStable = StableBTreeMap<UploadChunkStableKey, Chunk, Memory>;
+
     #[derive(CandidType, Serialize, Deserialize, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
     pub struct StableKey {
         pub collection: CollectionKey,
@@ -23,4 +28,10 @@ pub mod state {
         pub encoding_type: EncodingType,
         pub chunk_index: usize,
     }
+
+    #[derive(CandidType, Serialize, Deserialize, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct UploadChunkStableKey {
+        pub batch_id: BatchId,
+        pub chunk_id: ChunkId,
+    }
 }
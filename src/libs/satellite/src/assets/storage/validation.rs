@@ -0,0 +1,408 @@
+#[cfg(feature = "image")]
+use crate::assets::storage::images::decode_image_dimensions;
+use crate::assets::storage::state::{get_rule, get_upload_chunk};
+use crate::assets::storage::variants::image_format;
+#[cfg(not(feature = "image"))]
+use crate::errors::storage::JUNO_STORAGE_ERROR_IMAGE_PROCESSING_DISABLED;
+use crate::errors::storage::{
+    JUNO_STORAGE_ERROR_CONTENT_TYPE_MISMATCH, JUNO_STORAGE_ERROR_EXECUTABLE_NOT_ALLOWED,
+    JUNO_STORAGE_ERROR_IMAGE_DIMENSIONS_EXCEEDED, JUNO_STORAGE_ERROR_SVG_SCRIPT_NOT_ALLOWED,
+};
+use junobuild_collections::types::rules::{ContentValidation, ImageFormat};
+use junobuild_shared::types::core::Blob;
+use junobuild_storage::http::types::HeaderField;
+use junobuild_storage::types::store::AssetAssertUpload;
+
+// The number of bytes read at the start of the content to identify its type.
+const SNIFF_LENGTH: usize = 512;
+
+// The number of bytes read at most at the start of an image to find its dimensions - e.g. those of
+// a JPEG follow its metadata.
+const IMAGE_HEAD_LENGTH: usize = 1024 * 1024;
+
+// ---------------------------------------------------------
+// Content
+// ---------------------------------------------------------
+
+/// Returns the content of a chunk of an upload, in the order the chunks are committed.
+pub fn get_upload_content_chunk(upload: &AssetAssertUpload, index: usize) -> Option<Blob> {
+    // The chunk ids of the commit provided to the assertions are sorted in that order
+    let chunk_id = upload.commit_batch.chunk_ids.get(index)?;
+
+    get_upload_chunk(&upload.commit_batch.batch_id, chunk_id).map(|chunk| chunk.content)
+}
+
+fn upload_content_chunks(upload: &AssetAssertUpload) -> impl Iterator<Item = Blob> + '_ {
+    (0..upload.commit_batch.chunk_ids.len())
+        .filter_map(|index| get_upload_content_chunk(upload, index))
+}
+
+fn read_upload_content(upload: &AssetAssertUpload, max_length: Option<usize>) -> Blob {
+    let mut content: Blob = Vec::new();
+
+    for chunk in upload_content_chunks(upload) {
+        if max_length.is_some_and(|max_length| content.len() >= max_length) {
+            break;
+        }
+
+        content.extend(chunk);
+    }
+
+    if let Some(max_length) = max_length {
+        content.truncate(max_length);
+    }
+
+    content
+}
+
+// ---------------------------------------------------------
+// Validation
+// ---------------------------------------------------------
+
+/// Validates the content of an upload against the content validation of the rule of its
+/// collection, if any, before the asset is committed.
+pub fn assert_upload_content(upload: &AssetAssertUpload) -> Result<(), String> {
+    let rule = get_rule(&upload.batch.key.collection)?;
+
+    let content_validation = match &rule.content_validation {
+        None => return Ok(()),
+        Some(content_validation) => content_validation,
+    };
+
+    let head = read_upload_content(upload, Some(SNIFF_LENGTH));
+
+    assert_not_executable(&head)?;
+
+    let content_type = content_type(&upload.commit_batch.headers);
+
+    assert_content_type(&head, &content_type)?;
+
+    assert_svg_inactive(upload, &head, &content_type)?;
+
+    assert_image_dimensions(upload, content_validation)
+}
+
+fn assert_not_executable(head: &[u8]) -> Result<(), String> {
+    if is_executable(head) {
+        return Err(JUNO_STORAGE_ERROR_EXECUTABLE_NOT_ALLOWED.to_string());
+    }
+
+    Ok(())
+}
+
+fn assert_content_type(head: &[u8], content_type: &Option<String>) -> Result<(), String> {
+    let content_type = content_type.as_deref().unwrap_or_default();
+
+    // Browsers render content sniffed as HTML - e.g. an "image" served without its type.
+    if is_html(head) && !matches!(content_type, "text/html" | "application/xhtml+xml") {
+        return Err(format!(
+            "{JUNO_STORAGE_ERROR_CONTENT_TYPE_MISMATCH} (text/html - {content_type})"
+        ));
+    }
+
+    // Content types without a known signature - e.g. text - cannot be matched.
+    match matches_signature(head, content_type) {
+        Some(false) => Err(format!(
+            "{JUNO_STORAGE_ERROR_CONTENT_TYPE_MISMATCH} ({content_type})"
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Browsers run the scripts of an SVG image opened directly - e.g. `<svg onload="...">`. Its whole
+/// content is inspected since those can appear anywhere in the document.
+fn assert_svg_inactive(
+    upload: &AssetAssertUpload,
+    head: &[u8],
+    content_type: &Option<String>,
+) -> Result<(), String> {
+    if content_type.as_deref() != Some("image/svg+xml") && !is_svg(head) {
+        return Ok(());
+    }
+
+    let content = read_upload_content(upload, None);
+
+    if is_active_svg(&content) {
+        return Err(JUNO_STORAGE_ERROR_SVG_SCRIPT_NOT_ALLOWED.to_string());
+    }
+
+    Ok(())
+}
+
+fn assert_image_dimensions(
+    upload: &AssetAssertUpload,
+    ContentValidation {
+        max_image_width,
+        max_image_height,
+    }: &ContentValidation,
+) -> Result<(), String> {
+    if max_image_width.is_none() && max_image_height.is_none() {
+        return Ok(());
+    }
+
+    let format = match image_format(&upload.commit_batch.headers) {
+        None => return Ok(()),
+        Some(format) => format,
+    };
+
+    let (width, height) = read_image_dimensions(upload, &format)?;
+
+    let exceeded = max_image_width.is_some_and(|max_width| width > max_width)
+        || max_image_height.is_some_and(|max_height| height > max_height);
+
+    if exceeded {
+        return Err(format!(
+            "{JUNO_STORAGE_ERROR_IMAGE_DIMENSIONS_EXCEEDED} ({width}x{height})"
+        ));
+    }
+
+    Ok(())
+}
+
+/// Reads the head of the image, chunk by chunk, until its dimensions are found.
+fn read_image_dimensions(
+    upload: &AssetAssertUpload,
+    format: &ImageFormat,
+) -> Result<(u32, u32), String> {
+    let mut head: Blob = Vec::new();
+
+    for chunk in upload_content_chunks(upload) {
+        head.extend(chunk);
+
+        match image_dimensions(&head, format) {
+            Err(_) if head.len() < IMAGE_HEAD_LENGTH => continue,
+            result => return result,
+        }
+    }
+
+    image_dimensions(&head, format)
+}
+
+#[cfg(feature = "image")]
+fn image_dimensions(content: &[u8], format: &ImageFormat) -> Result<(u32, u32), String> {
+    decode_image_dimensions(content, format)
+}
+
+#[cfg(not(feature = "image"))]
+fn image_dimensions(_content: &[u8], _format: &ImageFormat) -> Result<(u32, u32), String> {
+    Err(JUNO_STORAGE_ERROR_IMAGE_PROCESSING_DISABLED.to_string())
+}
+
+// ---------------------------------------------------------
+// Signatures
+// ---------------------------------------------------------
+
+fn content_type(headers: &[HeaderField]) -> Option<String> {
+    headers
+        .iter()
+        .find(|HeaderField(key, _)| key.eq_ignore_ascii_case("content-type"))
+        .and_then(|HeaderField(_, value)| value.split(';').next())
+        .map(|value| value.trim().to_lowercase())
+}
+
+/// Windows (PE), Linux (ELF) and macOS (Mach-O) binaries, and scripts starting with a shebang.
+fn is_executable(head: &[u8]) -> bool {
+    const SIGNATURES: [&[u8]; 8] = [
+        b"MZ",
+        b"\x7FELF",
+        b"\xFE\xED\xFA\xCE",
+        b"\xFE\xED\xFA\xCF",
+        b"\xCE\xFA\xED\xFE",
+        b"\xCF\xFA\xED\xFE",
+        b"\xCA\xFE\xBA\xBE",
+        b"#!",
+    ];
+
+    SIGNATURES
+        .iter()
+        .any(|signature| head.starts_with(signature))
+}
+
+fn is_html(head: &[u8]) -> bool {
+    const TAGS: [&[u8]; 6] = [
+        b"<!doctype html",
+        b"<html",
+        b"<head",
+        b"<body",
+        b"<script",
+        b"<iframe",
+    ];
+
+    let head = trim_start(head).to_ascii_lowercase();
+
+    TAGS.iter().any(|tag| head.starts_with(tag))
+}
+
+fn is_svg(head: &[u8]) -> bool {
+    let head = trim_start(head).to_ascii_lowercase();
+
+    head.starts_with(b"<svg") || (head.starts_with(b"<?xml") && contains(&head, b"<svg"))
+}
+
+/// Scripts, elements embedding HTML, `javascript:` links and event handler attributes - e.g. `onload`.
+fn is_active_svg(content: &[u8]) -> bool {
+    const PATTERNS: [&[u8]; 3] = [b"<script", b"<foreignobject", b"javascript:"];
+
+    let content = content.to_ascii_lowercase();
+
+    PATTERNS.iter().any(|pattern| contains(&content, pattern)) || has_event_handler(&content)
+}
+
+fn has_event_handler(content: &[u8]) -> bool {
+    let mut in_tag = false;
+
+    for (index, byte) in content.iter().enumerate() {
+        match byte {
+            b'<' => in_tag = true,
+            b'>' => in_tag = false,
+            byte if in_tag && (byte.is_ascii_whitespace() || *byte == b'/') => {
+                let attribute = &content[index + 1..];
+
+                if !attribute.starts_with(b"on") {
+                    continue;
+                }
+
+                let name_length = attribute[2..]
+                    .iter()
+                    .take_while(|byte| byte.is_ascii_alphabetic())
+                    .count();
+
+                let next = attribute[2 + name_length..]
+                    .iter()
+                    .find(|byte| !byte.is_ascii_whitespace());
+
+                if name_length > 0 && next == Some(&b'=') {
+                    return true;
+                }
+            }
+            _ => (),
+        }
+    }
+
+    false
+}
+
+fn contains(content: &[u8], pattern: &[u8]) -> bool {
+    content
+        .windows(pattern.len())
+        .any(|window| window == pattern)
+}
+
+fn trim_start(head: &[u8]) -> &[u8] {
+    let head = head.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(head);
+
+    let start = head
+        .iter()
+        .position(|byte| !byte.is_ascii_whitespace())
+        .unwrap_or(head.len());
+
+    &head[start..]
+}
+
+/// Returns whether the content starts with the signature of its content-type, or `None` if the
+/// content-type has no known signature.
+fn matches_signature(head: &[u8], content_type: &str) -> Option<bool> {
+    let ftyp = |brands: &[&[u8]]| {
+        head.get(4..8) == Some(b"ftyp")
+            && brands.iter().any(|brand| head.get(8..12) == Some(*brand))
+    };
+
+    let matches = match content_type {
+        "image/png" => head.starts_with(b"\x89PNG\r\n\x1A\n"),
+        "image/jpeg" | "image/jpg" => head.starts_with(b"\xFF\xD8\xFF"),
+        "image/gif" => head.starts_with(b"GIF87a") || head.starts_with(b"GIF89a"),
+        "image/webp" => head.starts_with(b"RIFF") && head.get(8..12) == Some(b"WEBP"),
+        "image/bmp" => head.starts_with(b"BM"),
+        "image/x-icon" | "image/vnd.microsoft.icon" => head.starts_with(b"\x00\x00\x01\x00"),
+        "image/avif" => ftyp(&[b"avif", b"avis"]),
+        "application/pdf" => head.starts_with(b"%PDF-"),
+        "application/zip" => head.starts_with(b"PK\x03\x04") || head.starts_with(b"PK\x05\x06"),
+        "application/gzip" => head.starts_with(b"\x1F\x8B"),
+        "font/woff" => head.starts_with(b"wOFF"),
+        "font/woff2" => head.starts_with(b"wOF2"),
+        _ => return None,
+    };
+
+    Some(matches)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_executables() {
+        assert!(is_executable(b"MZ\x90\x00\x03"));
+        assert!(is_executable(b"\x7FELF\x02\x01"));
+        assert!(is_executable(b"#!/bin/sh\necho"));
+        assert!(!is_executable(b"\x89PNG\r\n\x1A\n"));
+    }
+
+    #[test]
+    fn detects_html() {
+        assert!(is_html(b"<html><script>alert(1)</script>"));
+        assert!(is_html(b"\xEF\xBB\xBF  \n<!DOCTYPE HTML>"));
+        assert!(is_html(b"<script>alert(1)</script>"));
+        assert!(!is_html(b"hello <html>"));
+    }
+
+    #[test]
+    fn rejects_html_disguised_as_image() {
+        let head = b"<html><script>alert(1)</script></html>";
+
+        assert!(assert_content_type(head, &Some("image/png".to_string())).is_err());
+        assert!(assert_content_type(head, &None).is_err());
+        assert!(assert_content_type(head, &Some("text/html".to_string())).is_ok());
+    }
+
+    #[test]
+    fn matches_declared_content_type() {
+        let png = b"\x89PNG\r\n\x1A\n\x00\x00\x00\x0DIHDR";
+
+        assert!(assert_content_type(png, &Some("image/png".to_string())).is_ok());
+        assert!(assert_content_type(png, &Some("image/jpeg".to_string())).is_err());
+        assert!(
+            assert_content_type(b"body { color: red; }", &Some("text/css".to_string())).is_ok()
+        );
+    }
+
+    #[test]
+    fn detects_svg() {
+        assert!(is_svg(b"<svg xmlns=\"http://www.w3.org/2000/svg\"></svg>"));
+        assert!(is_svg(b"\xEF\xBB\xBF<?xml version=\"1.0\"?>\n<SVG></SVG>"));
+        assert!(!is_svg(b"<?xml version=\"1.0\"?><rss></rss>"));
+        assert!(!is_svg(b"\x89PNG\r\n\x1A\n"));
+    }
+
+    #[test]
+    fn detects_svg_with_scripts() {
+        assert!(is_active_svg(b"<svg onload=\"alert(1)\"></svg>"));
+        assert!(is_active_svg(b"<svg><g\n  ONCLICK = 'alert(1)'/></svg>"));
+        assert!(is_active_svg(b"<svg/onload=alert(1)>"));
+        assert!(is_active_svg(b"<svg><script>alert(1)</script></svg>"));
+        assert!(is_active_svg(
+            b"<svg><a href=\"javascript:alert(1)\"><text>x</text></a></svg>"
+        ));
+        assert!(is_active_svg(
+            b"<svg><foreignObject><iframe/></foreignObject></svg>"
+        ));
+    }
+
+    #[test]
+    fn accepts_svg_without_scripts() {
+        assert!(!is_active_svg(
+            b"<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 10 10\"><circle r=\"4\"/><text> one = two</text></svg>"
+        ));
+        assert!(!is_active_svg(b"<svg><g font=\"mono\" /></svg>"));
+    }
+
+    #[test]
+    fn reads_content_type_without_parameters() {
+        let headers = vec![HeaderField(
+            "Content-Type".to_string(),
+            "Image/PNG; charset=binary".to_string(),
+        )];
+
+        assert_eq!(content_type(&headers), Some("image/png".to_string()));
+    }
+}
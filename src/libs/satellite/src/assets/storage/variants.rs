@@ -0,0 +1,359 @@
+#[cfg(feature = "image")]
+use crate::assets::storage::images::{decode_image, encode_image_variant};
+use crate::assets::storage::state::{
+    delete_asset as delete_state_asset, get_asset as get_state_asset,
+    get_content_chunks as get_state_content_chunks, insert_asset, insert_asset_encoding,
+};
+use crate::certification::strategy_impls::StorageCertificate;
+#[cfg(not(feature = "image"))]
+use crate::errors::storage::JUNO_STORAGE_ERROR_IMAGE_PROCESSING_DISABLED;
+use crate::hooks::storage::invoke_assert_upload_asset;
+use candid::Principal;
+use junobuild_collections::types::core::CollectionKey;
+use junobuild_collections::types::rules::{ImageFormat, ImageVariant, Rule};
+use junobuild_shared::ic::api::time;
+use junobuild_shared::types::core::Blob;
+use junobuild_storage::constants::ASSET_ENCODING_NO_COMPRESSION;
+use junobuild_storage::errors::JUNO_STORAGE_ERROR_ASSET_MAX_ALLOWED_SIZE;
+use junobuild_storage::http::types::HeaderField;
+use junobuild_storage::runtime::{
+    delete_certified_asset as delete_runtime_certified_asset,
+    update_certified_asset as update_runtime_certified_asset,
+};
+use junobuild_storage::types::config::StorageConfig;
+use junobuild_storage::types::interface::CommitBatch;
+use junobuild_storage::types::runtime_state::BatchId;
+use junobuild_storage::types::state::FullPath;
+use junobuild_storage::types::store::{Asset, AssetAssertUpload, AssetEncoding, AssetKey, Batch};
+use junobuild_storage::utils::map_content_type_headers;
+
+/// A variant generated for an uploaded image, asserted but not stored yet.
+pub struct PreparedAssetVariant {
+    pub asset: Asset,
+    pub encoding: AssetEncoding,
+}
+
+impl PreparedAssetVariant {
+    pub fn size(&self) -> u64 {
+        u64::try_from(self.encoding.total_length).unwrap_or(u64::MAX)
+    }
+}
+
+// ---------------------------------------------------------
+// Generate
+// ---------------------------------------------------------
+
+/// Generates the variants declared on the rule of the collection for an uploaded image, without
+/// storing them.
+///
+/// Each variant is an asset of the same collection - e.g. `/images/photo.jpg@thumbnail` - that
+/// inherits the owner, token, description and expiration of the source. Variants are subject to
+/// the assertions of the uploads: the maximal size of the collection and the `assert_upload_asset`
+/// hook, with which the chunks of a variant cannot be read since its content is generated. Assets
+/// that are not PNG, JPEG or WebP images are skipped.
+pub fn prepare_asset_variants(
+    caller: Principal,
+    asset: &Asset,
+    batch_id: &BatchId,
+    rule: &Rule,
+) -> Result<Vec<PreparedAssetVariant>, String> {
+    let variants = match &rule.variants {
+        None => return Ok(Vec::new()),
+        Some(variants) if variants.is_empty() => return Ok(Vec::new()),
+        Some(variants) => variants,
+    };
+
+    // A variant does not have variants.
+    if is_variant_full_path(&asset.key.full_path, variants) {
+        return Ok(Vec::new());
+    }
+
+    let source_format = match image_format(&asset.headers) {
+        None => return Ok(Vec::new()),
+        Some(format) => format,
+    };
+
+    let content = match asset.encodings.get(ASSET_ENCODING_NO_COMPRESSION) {
+        None => return Ok(Vec::new()),
+        Some(encoding) => read_content(encoding, rule),
+    };
+
+    let contents = generate_variant_contents(&content, &source_format, variants)?;
+
+    variants
+        .iter()
+        .zip(contents)
+        .map(|(variant, (format, content))| {
+            prepare_variant(caller, asset, batch_id, variant, &format, content, rule)
+        })
+        .collect()
+}
+
+#[cfg(feature = "image")]
+fn generate_variant_contents(
+    content: &[u8],
+    source_format: &ImageFormat,
+    variants: &[ImageVariant],
+) -> Result<Vec<(ImageFormat, Blob)>, String> {
+    let image = decode_image(content, source_format)?;
+
+    variants
+        .iter()
+        .map(|variant| {
+            let format = variant.format.clone().unwrap_or(source_format.clone());
+            let content = encode_image_variant(&image, variant, &format)?;
+            Ok((format, content))
+        })
+        .collect()
+}
+
+#[cfg(not(feature = "image"))]
+fn generate_variant_contents(
+    _content: &[u8],
+    _source_format: &ImageFormat,
+    _variants: &[ImageVariant],
+) -> Result<Vec<(ImageFormat, Blob)>, String> {
+    Err(JUNO_STORAGE_ERROR_IMAGE_PROCESSING_DISABLED.to_string())
+}
+
+fn read_content(encoding: &AssetEncoding, rule: &Rule) -> Blob {
+    (0..encoding.content_chunks.len())
+        .filter_map(|index| get_state_content_chunks(encoding, index, &rule.mem()))
+        .flatten()
+        .collect()
+}
+
+fn prepare_variant(
+    caller: Principal,
+    source: &Asset,
+    batch_id: &BatchId,
+    variant: &ImageVariant,
+    format: &ImageFormat,
+    content: Blob,
+    rule: &Rule,
+) -> Result<PreparedAssetVariant, String> {
+    let full_path = variant_full_path(&source.key.full_path, &variant.name);
+
+    let key = AssetKey {
+        full_path: full_path.clone(),
+        ..source.key.clone()
+    };
+
+    let headers: Vec<HeaderField> = map_content_type_headers(image_content_type(format));
+
+    let current = get_state_asset(&source.key.collection, &full_path, rule);
+
+    let asset = Asset::prepare(key.clone(), headers.clone(), &current);
+
+    let encoding = AssetEncoding::from(&vec![content]);
+
+    if rule
+        .max_size
+        .is_some_and(|max_size| encoding.total_length > max_size)
+    {
+        return Err(JUNO_STORAGE_ERROR_ASSET_MAX_ALLOWED_SIZE.to_string());
+    }
+
+    let upload = AssetAssertUpload {
+        current,
+        batch: Batch {
+            key,
+            reference_id: None,
+            expires_at: time(),
+            encoding_type: None,
+        },
+        commit_batch: CommitBatch {
+            batch_id: *batch_id,
+            headers,
+            chunk_ids: Vec::new(),
+            sha256: Some(encoding.sha256),
+        },
+    };
+
+    invoke_assert_upload_asset(&caller, &upload)?;
+
+    Ok(PreparedAssetVariant { asset, encoding })
+}
+
+// ---------------------------------------------------------
+// Insert
+// ---------------------------------------------------------
+
+/// Stores and certifies the prepared variants of an asset, replacing those of a previous upload.
+pub fn insert_asset_variants(
+    source: &Asset,
+    variants: Vec<PreparedAssetVariant>,
+    rule: &Rule,
+    config: &StorageConfig,
+) -> Vec<Asset> {
+    delete_asset_variants(&source.key.collection, &source.key.full_path, rule, config);
+
+    variants
+        .into_iter()
+        .map(
+            |PreparedAssetVariant {
+                 mut asset,
+                 encoding,
+             }| {
+                let full_path = asset.key.full_path.clone();
+
+                insert_asset_encoding(
+                    &full_path,
+                    ASSET_ENCODING_NO_COMPRESSION,
+                    &encoding,
+                    &mut asset,
+                    rule,
+                );
+
+                insert_asset(&source.key.collection, &full_path, &asset, rule);
+
+                update_runtime_certified_asset(&asset, config, &StorageCertificate);
+
+                asset
+            },
+        )
+        .collect()
+}
+
+/// The total size of the variants of an asset that are currently stored.
+pub fn get_asset_variants_size(
+    collection: &CollectionKey,
+    full_path: &FullPath,
+    rule: &Rule,
+) -> u64 {
+    let variants = match &rule.variants {
+        None => return 0,
+        Some(variants) => variants,
+    };
+
+    if is_variant_full_path(full_path, variants) {
+        return 0;
+    }
+
+    variants
+        .iter()
+        .filter_map(|variant| {
+            get_state_asset(
+                collection,
+                &variant_full_path(full_path, &variant.name),
+                rule,
+            )
+        })
+        .fold(0, |size, asset| size.saturating_add(asset.size()))
+}
+
+// ---------------------------------------------------------
+// Delete
+// ---------------------------------------------------------
+
+/// Deletes the variants, declared on the rule of the collection, of an asset.
+pub fn delete_asset_variants(
+    collection: &CollectionKey,
+    full_path: &FullPath,
+    rule: &Rule,
+    config: &StorageConfig,
+) {
+    let variants = match &rule.variants {
+        None => return,
+        Some(variants) => variants,
+    };
+
+    if is_variant_full_path(full_path, variants) {
+        return;
+    }
+
+    for variant in variants {
+        let variant_path = variant_full_path(full_path, &variant.name);
+
+        if let Some(asset) = get_state_asset(collection, &variant_path, rule) {
+            delete_state_asset(collection, &variant_path, rule);
+            delete_runtime_certified_asset(&asset, config, &StorageCertificate);
+        }
+    }
+}
+
+// ---------------------------------------------------------
+// Utils
+// ---------------------------------------------------------
+
+fn variant_full_path(full_path: &FullPath, name: &str) -> FullPath {
+    format!("{full_path}@{name}")
+}
+
+fn is_variant_full_path(full_path: &FullPath, variants: &[ImageVariant]) -> bool {
+    variants
+        .iter()
+        .any(|variant| full_path.ends_with(&format!("@{}", variant.name)))
+}
+
+/// The format of an image supported for processing, according to the content-type of the headers.
+pub fn image_format(headers: &[HeaderField]) -> Option<ImageFormat> {
+    let content_type = headers
+        .iter()
+        .find(|HeaderField(key, _)| key.eq_ignore_ascii_case("content-type"))
+        .map(|HeaderField(_, value)| value.to_lowercase())?;
+
+    match content_type.split(';').next().map(str::trim) {
+        Some("image/png") => Some(ImageFormat::Png),
+        Some("image/jpeg") | Some("image/jpg") => Some(ImageFormat::Jpeg),
+        Some("image/webp") => Some(ImageFormat::WebP),
+        _ => None,
+    }
+}
+
+fn image_content_type(format: &ImageFormat) -> &'static str {
+    match format {
+        ImageFormat::Png => "image/png",
+        ImageFormat::Jpeg => "image/jpeg",
+        ImageFormat::WebP => "image/webp",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn identifies_variant_paths() {
+        let variants = vec![ImageVariant {
+            name: "thumbnail".to_string(),
+            max_width: Some(100),
+            max_height: None,
+            format: None,
+        }];
+
+        assert_eq!(
+            variant_full_path(&"/images/photo.jpg".to_string(), "thumbnail"),
+            "/images/photo.jpg@thumbnail"
+        );
+        assert!(is_variant_full_path(
+            &"/images/photo.jpg@thumbnail".to_string(),
+            &variants
+        ));
+        assert!(!is_variant_full_path(
+            &"/images/photo.jpg".to_string(),
+            &variants
+        ));
+    }
+
+    #[test]
+    fn identifies_image_formats() {
+        let headers = |content_type: &str| {
+            vec![HeaderField(
+                "Content-Type".to_string(),
+                content_type.to_string(),
+            )]
+        };
+
+        assert!(matches!(
+            image_format(&headers("image/PNG")),
+            Some(ImageFormat::Png)
+        ));
+        assert!(matches!(
+            image_format(&headers("image/jpg; charset=binary")),
+            Some(ImageFormat::Jpeg)
+        ));
+        assert!(image_format(&headers("image/gif")).is_none());
+        assert!(image_format(&[]).is_none());
+    }
+}
@@ -0,0 +1,83 @@
+use crate::audit::constants::{AUDIT_LOG_MIN_MAX_ENTRIES, AUDIT_LOG_MIN_RETENTION};
+use crate::audit::types::config::AuditConfig;
+use crate::audit::types::interface::SetAuditConfig;
+use crate::errors::audit::{
+    JUNO_AUDIT_ERROR_MAX_ENTRIES_TOO_LOW, JUNO_AUDIT_ERROR_RETENTION_TOO_SHORT,
+};
+use junobuild_shared::assert::assert_version;
+
+pub fn assert_set_config(
+    proposed_config: &SetAuditConfig,
+    current_config: &Option<AuditConfig>,
+) -> Result<(), String> {
+    if let Some(cfg) = current_config {
+        assert_version(proposed_config.version, cfg.version)?
+    }
+
+    assert_retention(proposed_config)
+}
+
+// Lowering the limits prunes the existing entries with the next insert. The minimums ensure that the
+// trail cannot be emptied by the changes it is meant to record.
+fn assert_retention(
+    SetAuditConfig {
+        max_entries,
+        retention,
+        ..
+    }: &SetAuditConfig,
+) -> Result<(), String> {
+    if max_entries
+        .is_some_and(|max_entries| max_entries > 0 && max_entries < AUDIT_LOG_MIN_MAX_ENTRIES)
+    {
+        return Err(format!(
+            "{JUNO_AUDIT_ERROR_MAX_ENTRIES_TOO_LOW} ({AUDIT_LOG_MIN_MAX_ENTRIES})"
+        ));
+    }
+
+    if retention.is_some_and(|retention| retention > 0 && retention < AUDIT_LOG_MIN_RETENTION) {
+        return Err(format!(
+            "{JUNO_AUDIT_ERROR_RETENTION_TOO_SHORT} ({AUDIT_LOG_MIN_RETENTION})"
+        ));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(max_entries: Option<u32>, retention: Option<u64>) -> SetAuditConfig {
+        SetAuditConfig {
+            max_entries,
+            retention,
+            version: None,
+        }
+    }
+
+    #[test]
+    fn test_rejects_limits_below_the_minimum() {
+        assert!(assert_set_config(&config(Some(1), None), &None).is_err());
+        assert!(
+            assert_set_config(&config(Some(AUDIT_LOG_MIN_MAX_ENTRIES - 1), None), &None).is_err()
+        );
+        assert!(assert_set_config(&config(None, Some(1)), &None).is_err());
+        assert!(
+            assert_set_config(&config(None, Some(AUDIT_LOG_MIN_RETENTION - 1)), &None).is_err()
+        );
+    }
+
+    #[test]
+    fn test_accepts_limits_from_the_minimum() {
+        assert!(assert_set_config(&config(None, None), &None).is_ok());
+        assert!(assert_set_config(&config(Some(0), Some(0)), &None).is_ok());
+        assert!(assert_set_config(
+            &config(
+                Some(AUDIT_LOG_MIN_MAX_ENTRIES),
+                Some(AUDIT_LOG_MIN_RETENTION)
+            ),
+            &None
+        )
+        .is_ok());
+    }
+}
@@ -0,0 +1,14 @@
+// The maximum number of audit log entries kept if none is configured.
+pub const AUDIT_LOG_DEFAULT_MAX_ENTRIES: u32 = 10_000;
+
+// The minimum number of entries kept, whatever the configuration.
+pub const AUDIT_LOG_MIN_MAX_ENTRIES: u32 = 1_000;
+
+// The minimum time, in nanoseconds, the entries are kept, whatever the configuration. 30 days.
+pub const AUDIT_LOG_MIN_RETENTION: u64 = 30 * 24 * 60 * 60 * 1_000_000_000;
+
+// The number of entries returned per page if no limit is provided.
+pub const AUDIT_LOG_DEFAULT_LIST_LIMIT: usize = 100;
+
+// The maximum length of the before and after summaries of an entry.
+pub const AUDIT_LOG_MAX_SUMMARY_LENGTH: usize = 2048;
@@ -0,0 +1,57 @@
+use crate::audit::types::config::AuditConfig;
+use crate::audit::types::interface::SetAuditConfig;
+use crate::audit::types::state::AuditLog;
+use ic_stable_structures::storable::Bound;
+use ic_stable_structures::Storable;
+use junobuild_shared::data::version::next_version;
+use junobuild_shared::ic::api::time;
+use junobuild_shared::memory::serializers::{
+    deserialize_from_bytes, serialize_into_bytes, serialize_to_bytes,
+};
+use junobuild_shared::types::state::{Timestamp, Version, Versioned};
+use std::borrow::Cow;
+
+impl Storable for AuditLog {
+    fn to_bytes(&self) -> Cow<'_, [u8]> {
+        serialize_to_bytes(self)
+    }
+
+    fn into_bytes(self) -> Vec<u8> {
+        serialize_into_bytes(&self)
+    }
+
+    fn from_bytes(bytes: Cow<[u8]>) -> Self {
+        deserialize_from_bytes(bytes)
+    }
+
+    const BOUND: Bound = Bound::Unbounded;
+}
+
+impl AuditConfig {
+    pub fn prepare(current_config: &Option<AuditConfig>, user_config: &SetAuditConfig) -> Self {
+        let now = time();
+
+        let created_at: Timestamp = match current_config {
+            None => now,
+            Some(current_config) => current_config.created_at.unwrap_or(now),
+        };
+
+        let version = next_version(current_config);
+
+        let updated_at: Timestamp = now;
+
+        AuditConfig {
+            max_entries: user_config.max_entries,
+            retention: user_config.retention,
+            created_at: Some(created_at),
+            updated_at: Some(updated_at),
+            version: Some(version),
+        }
+    }
+}
+
+impl Versioned for AuditConfig {
+    fn version(&self) -> Option<Version> {
+        self.version
+    }
+}
@@ -0,0 +1,7 @@
+mod assert;
+mod constants;
+mod impls;
+mod state;
+pub mod store;
+pub mod types;
+mod utils;
@@ -0,0 +1,207 @@
+use crate::audit::types::config::AuditConfig;
+use crate::audit::types::state::{AuditHeapState, AuditLog, AuditLogId, AuditLogsStable};
+use crate::memory::state::STATE;
+use junobuild_shared::types::state::Timestamp;
+use std::ops::Bound;
+
+const FIRST_AUDIT_LOG_ID: AuditLogId = 1;
+
+// ---------------------------------------------------------
+// Logs
+// ---------------------------------------------------------
+
+/// Appends an entry to the audit trail and removes the oldest entries that exceed the maximum
+/// number of entries or that are expired.
+pub fn insert_audit_log(log: AuditLog, max_entries: usize, expired_before: Option<Timestamp>) {
+    STATE.with(|state| {
+        insert_audit_log_impl(
+            log,
+            max_entries,
+            expired_before,
+            &mut state.borrow_mut().stable.audit_logs,
+        )
+    })
+}
+
+fn insert_audit_log_impl(
+    log: AuditLog,
+    max_entries: usize,
+    expired_before: Option<Timestamp>,
+    logs: &mut AuditLogsStable,
+) {
+    let id = logs
+        .keys()
+        .next_back()
+        .map(|last_id| last_id.saturating_add(1))
+        .unwrap_or(FIRST_AUDIT_LOG_ID);
+
+    logs.insert(id, log);
+
+    prune_audit_logs(logs, max_entries, expired_before);
+}
+
+fn prune_audit_logs(
+    logs: &mut AuditLogsStable,
+    max_entries: usize,
+    expired_before: Option<Timestamp>,
+) {
+    while let Some((id, log)) = logs.first_key_value() {
+        let exceeds_max = usize::try_from(logs.len()).unwrap_or(usize::MAX) > max_entries;
+        let expired = expired_before.is_some_and(|before| log.created_at < before);
+
+        if !exceeds_max && !expired {
+            break;
+        }
+
+        logs.remove(&id);
+    }
+}
+
+/// Returns up to `limit` entries following `start_after` - or preceding it if `desc` - skipping
+/// the expired entries, and whether more entries follow.
+pub fn list_audit_logs(
+    start_after: Option<AuditLogId>,
+    desc: bool,
+    limit: usize,
+    expired_before: Option<Timestamp>,
+) -> (Vec<(AuditLogId, AuditLog)>, bool) {
+    STATE.with(|state| {
+        list_audit_logs_impl(
+            start_after,
+            desc,
+            limit,
+            expired_before,
+            &state.borrow().stable.audit_logs,
+        )
+    })
+}
+
+fn list_audit_logs_impl(
+    start_after: Option<AuditLogId>,
+    desc: bool,
+    limit: usize,
+    expired_before: Option<Timestamp>,
+    logs: &AuditLogsStable,
+) -> (Vec<(AuditLogId, AuditLog)>, bool) {
+    let bounds: (Bound<AuditLogId>, Bound<AuditLogId>) = match (desc, start_after) {
+        (_, None) => (Bound::Unbounded, Bound::Unbounded),
+        (false, Some(start_after)) => (Bound::Excluded(start_after), Bound::Unbounded),
+        (true, Some(start_after)) => (Bound::Unbounded, Bound::Excluded(start_after)),
+    };
+
+    let entries = logs
+        .range(bounds)
+        .map(|entry| (*entry.key(), entry.value()));
+
+    let entries: Box<dyn Iterator<Item = (AuditLogId, AuditLog)>> = if desc {
+        Box::new(entries.rev())
+    } else {
+        Box::new(entries)
+    };
+
+    let mut items: Vec<(AuditLogId, AuditLog)> = entries
+        .filter(|(_, log)| expired_before.is_none_or(|before| log.created_at >= before))
+        .take(limit.saturating_add(1))
+        .collect();
+
+    let has_more = items.len() > limit;
+
+    items.truncate(limit);
+
+    (items, has_more)
+}
+
+// ---------------------------------------------------------
+// Config
+// ---------------------------------------------------------
+
+pub fn get_config() -> Option<AuditConfig> {
+    STATE.with(|state| {
+        state
+            .borrow()
+            .heap
+            .audit
+            .as_ref()
+            .map(|audit| audit.config.clone())
+    })
+}
+
+pub fn insert_config(config: &AuditConfig) {
+    STATE.with(|state| {
+        state.borrow_mut().heap.audit = Some(AuditHeapState {
+            config: config.clone(),
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audit::types::state::AuditLogEndpoint;
+    use candid::Principal;
+    use ic_stable_structures::memory_manager::{MemoryId, MemoryManager};
+    use ic_stable_structures::{DefaultMemoryImpl, StableBTreeMap};
+
+    fn init_logs() -> AuditLogsStable {
+        StableBTreeMap::init(
+            MemoryManager::init(DefaultMemoryImpl::default()).get(MemoryId::new(0)),
+        )
+    }
+
+    fn log(created_at: Timestamp) -> AuditLog {
+        AuditLog {
+            caller: Principal::anonymous(),
+            endpoint: AuditLogEndpoint::SetRule,
+            target: None,
+            before: None,
+            after: None,
+            created_at,
+        }
+    }
+
+    fn ids(items: &[(AuditLogId, AuditLog)]) -> Vec<AuditLogId> {
+        items.iter().map(|(id, _)| *id).collect()
+    }
+
+    #[test]
+    fn test_insert_audit_log_prunes_oldest_entries() {
+        let mut logs = init_logs();
+
+        for created_at in 1..=5 {
+            insert_audit_log_impl(log(created_at), 3, None, &mut logs);
+        }
+
+        let (items, has_more) = list_audit_logs_impl(None, false, 10, None, &logs);
+        assert_eq!(ids(&items), vec![3, 4, 5]);
+        assert!(!has_more);
+
+        insert_audit_log_impl(log(10), 3, Some(5), &mut logs);
+
+        let (items, _) = list_audit_logs_impl(None, false, 10, None, &logs);
+        assert_eq!(ids(&items), vec![5, 6]);
+    }
+
+    #[test]
+    fn test_list_audit_logs_paginates() {
+        let mut logs = init_logs();
+
+        for created_at in 1..=5 {
+            insert_audit_log_impl(log(created_at), 100, None, &mut logs);
+        }
+
+        let (items, has_more) = list_audit_logs_impl(None, false, 2, None, &logs);
+        assert_eq!(ids(&items), vec![1, 2]);
+        assert!(has_more);
+
+        let (items, has_more) = list_audit_logs_impl(Some(4), false, 2, None, &logs);
+        assert_eq!(ids(&items), vec![5]);
+        assert!(!has_more);
+
+        let (items, has_more) = list_audit_logs_impl(Some(4), true, 2, None, &logs);
+        assert_eq!(ids(&items), vec![3, 2]);
+        assert!(has_more);
+
+        let (items, _) = list_audit_logs_impl(None, true, 10, Some(4), &logs);
+        assert_eq!(ids(&items), vec![5, 4]);
+    }
+}
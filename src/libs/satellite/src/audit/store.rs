@@ -0,0 +1,140 @@
+use crate::audit::assert::assert_set_config;
+use crate::audit::constants::{
+    AUDIT_LOG_DEFAULT_LIST_LIMIT, AUDIT_LOG_DEFAULT_MAX_ENTRIES, AUDIT_LOG_MIN_MAX_ENTRIES,
+    AUDIT_LOG_MIN_RETENTION,
+};
+use crate::audit::state::{
+    get_config as get_state_config, insert_audit_log, insert_config,
+    list_audit_logs as list_state_audit_logs,
+};
+use crate::audit::types::config::AuditConfig;
+use crate::audit::types::interface::{ListAuditLogsParams, ListAuditLogsResults, SetAuditConfig};
+use crate::audit::types::state::{AuditLog, AuditLogEndpoint, AuditLogId};
+use crate::audit::utils::summarize;
+use junobuild_shared::data::list::{decode_list_cursor, encode_list_cursor};
+use junobuild_shared::ic::api::caller;
+use junobuild_shared::ic::api::time;
+use junobuild_shared::types::state::Timestamp;
+use serde::Serialize;
+
+// ---------------------------------------------------------
+// Logs
+// ---------------------------------------------------------
+
+/// Records an administrative change performed by the caller in the audit trail.
+///
+/// # Arguments
+/// - `endpoint`: The endpoint that performed the change.
+/// - `target`: What the change applies to, e.g. a collection.
+/// - `before`: The state before the change, if any.
+/// - `after`: The state after the change, if any.
+pub fn record_audit_log<T: Serialize>(
+    endpoint: AuditLogEndpoint,
+    target: Option<String>,
+    before: Option<&T>,
+    after: Option<&T>,
+) {
+    let now = time();
+    let config = get_state_config();
+
+    let log = AuditLog {
+        caller: caller(),
+        endpoint,
+        target,
+        before: before.map(summarize),
+        after: after.map(summarize),
+        created_at: now,
+    };
+
+    insert_audit_log(log, max_entries(&config), expired_before(&config, now));
+}
+
+/// Lists the entries of the audit trail, the oldest first unless ordered descending.
+///
+/// Pagination either starts after a `start_after` ID or resumes from a `cursor` returned with a
+/// previous page - the cursor takes precedence if both are provided.
+///
+/// # Returns
+/// - `Ok(ListAuditLogsResults)`: The entries and a `next_cursor` if more entries follow.
+/// - `Err(String)`: If the cursor cannot be decoded.
+pub fn list_audit_logs_store(
+    ListAuditLogsParams { paginate, order }: &ListAuditLogsParams,
+) -> Result<ListAuditLogsResults, String> {
+    let desc = order.as_ref().map(|order| order.desc).unwrap_or(false);
+
+    let limit = paginate
+        .as_ref()
+        .and_then(|paginate| paginate.limit)
+        .unwrap_or(AUDIT_LOG_DEFAULT_LIST_LIMIT);
+
+    let start_after = match paginate {
+        None => None,
+        Some(paginate) => match &paginate.cursor {
+            Some(cursor) => Some(decode_list_cursor::<AuditLogId>(cursor)?),
+            None => paginate.start_after,
+        },
+    };
+
+    let config = get_state_config();
+
+    let (items, has_more) =
+        list_state_audit_logs(start_after, desc, limit, expired_before(&config, time()));
+
+    let next_cursor = items
+        .last()
+        .filter(|_| has_more)
+        .map(|(id, _)| encode_list_cursor(id));
+
+    Ok(ListAuditLogsResults {
+        items_length: items.len(),
+        items,
+        next_cursor,
+    })
+}
+
+// The minimums also apply to the configurations set before they were enforced.
+fn max_entries(config: &Option<AuditConfig>) -> usize {
+    let max_entries = config
+        .as_ref()
+        .and_then(|config| config.max_entries)
+        .filter(|max| *max > 0)
+        .unwrap_or(AUDIT_LOG_DEFAULT_MAX_ENTRIES)
+        .max(AUDIT_LOG_MIN_MAX_ENTRIES);
+
+    usize::try_from(max_entries).unwrap_or(usize::MAX)
+}
+
+fn expired_before(config: &Option<AuditConfig>, now: Timestamp) -> Option<Timestamp> {
+    config
+        .as_ref()
+        .and_then(|config| config.retention)
+        .filter(|retention| *retention > 0)
+        .map(|retention| now.saturating_sub(retention.max(AUDIT_LOG_MIN_RETENTION)))
+}
+
+// ---------------------------------------------------------
+// Config
+// ---------------------------------------------------------
+
+pub fn set_config_store(proposed_config: &SetAuditConfig) -> Result<AuditConfig, String> {
+    let current_config = get_state_config();
+
+    assert_set_config(proposed_config, &current_config)?;
+
+    let config = AuditConfig::prepare(&current_config, proposed_config);
+
+    insert_config(&config);
+
+    record_audit_log(
+        AuditLogEndpoint::SetAuditConfig,
+        None,
+        current_config.as_ref(),
+        Some(&config),
+    );
+
+    Ok(config)
+}
+
+pub fn get_config_store() -> Option<AuditConfig> {
+    get_state_config()
+}
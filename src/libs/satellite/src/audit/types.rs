@@ -0,0 +1,114 @@
+pub mod state {
+    use crate::audit::types::config::AuditConfig;
+    use candid::{CandidType, Principal};
+    use ic_stable_structures::StableBTreeMap;
+    use junobuild_shared::types::memory::Memory;
+    use junobuild_shared::types::state::Timestamp;
+    use serde::{Deserialize, Serialize};
+
+    pub type AuditLogId = u64;
+
+    pub type AuditLogsStable = StableBTreeMap<AuditLogId, AuditLog, Memory>;
+
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct AuditHeapState {
+        pub config: AuditConfig,
+    }
+
+    /// An entry of the audit trail of the administrative changes.
+    ///
+    /// Entries are append-only and keyed by an incremented ID, therefore sorted chronologically.
+    /// The oldest entries are removed according to the retention of the configuration.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct AuditLog {
+        pub caller: Principal,
+        pub endpoint: AuditLogEndpoint,
+        // What the change applies to - e.g. a collection, a custom domain or access keys
+        pub target: Option<String>,
+        // JSON summaries of the state before and after the change
+        pub before: Option<String>,
+        pub after: Option<String>,
+        pub created_at: Timestamp,
+    }
+
+    #[derive(CandidType, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+    pub enum AuditLogEndpoint {
+        SetControllers,
+        DelControllers,
+        DelControllerSelf,
+        AuthenticateAutomation,
+        SetRule,
+        DelRule,
+        SetDbConfig,
+        SetStorageConfig,
+        SetAuthConfig,
+        SetAutomationConfig,
+        SetAuditConfig,
+        SetUserUsageConfig,
+        SetCustomDomain,
+        DelCustomDomain,
+        AssignUserRoles,
+        RevokeUserRoles,
+        RevokeUserSessions,
+        ClearUserSessionsRevocation,
+        BanUser,
+        UnbanUser,
+    }
+}
+
+pub mod config {
+    use candid::CandidType;
+    use junobuild_shared::types::state::{Timestamp, Version};
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Default, CandidType, Serialize, Deserialize, Clone)]
+    pub struct AuditConfig {
+        // The maximum number of entries kept. Defaults to AUDIT_LOG_DEFAULT_MAX_ENTRIES, at least AUDIT_LOG_MIN_MAX_ENTRIES.
+        pub max_entries: Option<u32>,
+        // How long, in nanoseconds, the entries are kept, at least AUDIT_LOG_MIN_RETENTION. No limit if not set.
+        pub retention: Option<u64>,
+        pub version: Option<Version>,
+        pub created_at: Option<Timestamp>,
+        pub updated_at: Option<Timestamp>,
+    }
+}
+
+pub mod interface {
+    use crate::audit::types::state::{AuditLog, AuditLogId};
+    use candid::CandidType;
+    use junobuild_shared::types::list::ListCursor;
+    use junobuild_shared::types::state::Version;
+    use serde::Deserialize;
+
+    #[derive(CandidType, Deserialize, Clone)]
+    pub struct SetAuditConfig {
+        pub max_entries: Option<u32>,
+        pub retention: Option<u64>,
+        pub version: Option<Version>,
+    }
+
+    #[derive(Default, CandidType, Deserialize, Clone)]
+    pub struct ListAuditLogsParams {
+        pub paginate: Option<ListAuditLogsPaginate>,
+        pub order: Option<ListAuditLogsOrder>,
+    }
+
+    #[derive(Default, CandidType, Deserialize, Clone)]
+    pub struct ListAuditLogsPaginate {
+        pub start_after: Option<AuditLogId>,
+        pub limit: Option<usize>,
+        pub cursor: Option<ListCursor>,
+    }
+
+    #[derive(Default, CandidType, Deserialize, Clone)]
+    pub struct ListAuditLogsOrder {
+        pub desc: bool,
+    }
+
+    #[derive(Default, CandidType, Deserialize, Clone)]
+    pub struct ListAuditLogsResults {
+        pub items: Vec<(AuditLogId, AuditLog)>,
+        pub items_length: usize,
+        pub next_cursor: Option<ListCursor>,
+    }
+}
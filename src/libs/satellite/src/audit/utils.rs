@@ -0,0 +1,38 @@
+use crate::audit::constants::AUDIT_LOG_MAX_SUMMARY_LENGTH;
+use serde::Serialize;
+
+/// Serializes a value to JSON, truncated to `AUDIT_LOG_MAX_SUMMARY_LENGTH` bytes.
+pub fn summarize<T: Serialize>(value: &T) -> String {
+    let mut summary = serde_json::to_string(value).unwrap_or_default();
+
+    if summary.len() > AUDIT_LOG_MAX_SUMMARY_LENGTH {
+        let end = (0..=AUDIT_LOG_MAX_SUMMARY_LENGTH)
+            .rev()
+            .find(|index| summary.is_char_boundary(*index))
+            .unwrap_or(0);
+
+        summary.truncate(end);
+    }
+
+    summary
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_summarize() {
+        assert_eq!(summarize(&vec!["admin", "editor"]), r#"["admin","editor"]"#);
+    }
+
+    #[test]
+    fn test_summarize_truncates_on_char_boundary() {
+        let value = "é".repeat(AUDIT_LOG_MAX_SUMMARY_LENGTH);
+
+        let summary = summarize(&value);
+
+        assert!(summary.len() <= AUDIT_LOG_MAX_SUMMARY_LENGTH);
+        assert!(summary.starts_with("\"é"));
+    }
+}
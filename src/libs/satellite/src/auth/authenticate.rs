@@ -3,6 +3,8 @@ use crate::auth::delegation;
 use crate::auth::register::register_user;
 use crate::auth::strategy_impls::AuthHeap;
 use crate::types::interface::{Authentication, AuthenticationError, AuthenticationResult};
+use crate::user::sessions::store::record_user_session;
+use candid::Principal;
 use junobuild_auth::delegation::types::{
     GetDelegationResult, OpenIdGetDelegationArgs, OpenIdPrepareDelegationArgs,
 };
@@ -23,9 +25,14 @@ pub async fn openid_authenticate(
     let result = match prepared_delegation {
         Ok((delegation, provider, credential)) => {
             let key = &delegation.user_key;
+            let user_id = Principal::self_authenticating(key);
 
             register_user(key, &provider, &credential)
-                .map(|doc| Authentication { delegation, doc })
+                .map(|doc| {
+                    record_user_session(&user_id, delegation.expiration);
+
+                    Authentication { delegation, doc }
+                })
                 .map_err(AuthenticationError::RegisterUser)
         }
         Err(err) => Err(AuthenticationError::PrepareDelegation(err)),
@@ -4,6 +4,7 @@ use crate::db::types::store::AssertSetDocOptions;
 use crate::errors::user::JUNO_DATASTORE_ERROR_USER_REGISTER_PROVIDER_INVALID_DATA;
 use crate::rules::store::get_rule_db;
 use crate::user::core::types::state::{OpenIdData, ProviderData, UserData};
+use crate::user::sessions::assert::assert_user_sessions_not_revoked;
 use crate::Doc;
 use candid::Principal;
 use junobuild_auth::delegation::types::UserKey;
@@ -25,6 +26,10 @@ pub fn register_user(
         .ok_or_else(|| msg_db_collection_not_found(&user_collection))?;
 
     let user_id = Principal::self_authenticating(public_key);
+
+    // A delegation issued while the sessions are revoked could not be told apart from those that were revoked.
+    assert_user_sessions_not_revoked(&user_id)?;
+
     let user_key = user_id.to_text();
 
     let current_user = unsafe_get_doc(&user_collection.to_string(), &user_key, &rule)?;
@@ -44,6 +49,15 @@ pub fn register_user(
         .as_ref()
         .and_then(|user_data| user_data.banned.clone());
 
+    let ban = current_user_data
+        .as_ref()
+        .and_then(|user_data| user_data.ban.clone());
+
+    // The roles are granted by the administrators and must be preserved when the provider data are updated.
+    let roles = current_user_data
+        .as_ref()
+        .and_then(|user_data| user_data.roles.clone());
+
     let existing_provider_data: Option<&OpenIdData> = match current_user_data.as_ref() {
         None => None, // A new user
         Some(user_data) => match user_data.provider_data.as_ref() {
@@ -85,6 +99,8 @@ pub fn register_user(
         banned,
         provider: Some(provider.into()),
         provider_data: Some(ProviderData::OpenId(provider_data)),
+        roles,
+        ban,
     };
 
     let user_data = UserData::prepare_set_doc(&user_data, &current_user)?;
@@ -1,5 +1,8 @@
 use crate::access_keys::store::set_access_keys;
+use crate::audit::store::record_audit_log;
+use crate::audit::types::state::AuditLogEndpoint;
 use crate::automation::workflow::build_automation_workflow_key;
+use crate::get_access_keys;
 use junobuild_auth::automation::types::PreparedAutomation;
 use junobuild_auth::openid::credentials::automation::types::interface::OpenIdAutomationCredential;
 use junobuild_auth::openid::types::provider::OpenIdAutomationProvider;
@@ -25,9 +28,19 @@ pub fn register_controller(
         metadata,
         expires_at: Some(controller.expires_at),
         kind: Some(AccessKeyKind::Automation),
+        restrictions: None,
     };
 
     set_access_keys(&controllers, &controller);
 
+    let access_key = get_access_keys().get(controller_id).cloned();
+
+    record_audit_log(
+        AuditLogEndpoint::AuthenticateAutomation,
+        Some(controller_id.to_text()),
+        None,
+        access_key.as_ref(),
+    );
+
     Ok(())
 }
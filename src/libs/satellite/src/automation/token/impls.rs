@@ -27,6 +27,7 @@ impl AutomationTokenData {
             data,
             description: None,
             version: current_doc.as_ref().and_then(|d| d.version),
+            expires_at: None,
         };
 
         Ok(set_doc)
@@ -28,6 +28,7 @@ impl AutomationWorkflowData {
             data,
             description: None,
             version: current_doc.as_ref().and_then(|d| d.version),
+            expires_at: None,
         };
 
         Ok(set_doc)
@@ -1,32 +1,45 @@
 use crate::auth::assert::assert_caller_is_allowed;
 use crate::automation::{assert_automation_token_caller, assert_automation_workflow_caller};
+use crate::db::data::decode_data;
 use crate::db::runtime::increment_and_assert_rate;
+use crate::db::schema::{parse_schema, validate_data};
 use crate::db::types::config::DbConfig;
-use crate::db::types::interface::SetDbConfig;
+use crate::db::types::interface::{CommitDocOperation, SetDbConfig};
 use crate::db::types::state::{DocAssertDelete, DocAssertSet, DocContext};
 use crate::db::types::store::AssertSetDocOptions;
-use crate::errors::db::{JUNO_DATASTORE_ERROR_CANNOT_READ, JUNO_DATASTORE_ERROR_CANNOT_WRITE};
+use crate::errors::db::{
+    JUNO_DATASTORE_ERROR_CANNOT_READ, JUNO_DATASTORE_ERROR_CANNOT_WRITE,
+    JUNO_DATASTORE_ERROR_COMMIT_DUPLICATE_DOC, JUNO_DATASTORE_ERROR_EXPIRY_NOT_SUPPORTED,
+    JUNO_DATASTORE_ERROR_INVALID_DATA, JUNO_DATASTORE_ERROR_INVALID_SCHEMA,
+};
 use crate::hooks::db::{invoke_assert_delete_doc, invoke_assert_set_doc};
 use crate::types::store::{AssertContext, StoreContext};
 use crate::user::core::assert::{
-    assert_user_collection_caller_key, assert_user_collection_data,
+    assert_user_collection_caller_key, assert_user_collection_data, assert_user_collection_roles,
     assert_user_collection_write_permission, assert_user_is_not_banned,
 };
+use crate::user::roles::assert::{
+    assert_create_permission_with_user_roles, assert_permission_with_user_roles,
+};
 use crate::user::usage::assert::{
     assert_user_usage_collection_data, increment_and_assert_db_usage,
+    update_and_assert_db_bytes_usage,
 };
 use crate::user::webauthn::assert::{
     assert_user_webauthn_collection_data, assert_user_webauthn_collection_write_permission,
 };
 use crate::{DelDoc, Doc, SetDoc};
 use candid::Principal;
-use junobuild_collections::assert::stores::{
-    assert_create_permission, assert_permission, public_permission,
+use junobuild_collections::assert::collection::is_system_collection;
+use junobuild_collections::assert::stores::public_permission;
+use junobuild_collections::types::core::CollectionKey;
+use junobuild_collections::types::rules::{Permission, Rule};
+use junobuild_shared::assert::{
+    assert_description_length, assert_expires_at, assert_max_memory_size, assert_version,
 };
-use junobuild_collections::types::rules::Permission;
-use junobuild_shared::assert::{assert_description_length, assert_max_memory_size, assert_version};
 use junobuild_shared::types::core::Key;
-use junobuild_shared::types::state::{AccessKeys, Version};
+use junobuild_shared::types::state::{AccessKeys, Timestamp, Version};
+use std::collections::HashSet;
 
 pub fn assert_get_doc(
     &StoreContext {
@@ -63,23 +76,43 @@ pub fn assert_get_docs(
 }
 
 pub fn assert_set_doc(
-    &StoreContext {
-        caller,
-        controllers,
-        collection,
-    }: &StoreContext,
-    &AssertContext { rule, auth_config }: &AssertContext,
+    context: &StoreContext,
+    assert_context: &AssertContext,
     config: &Option<DbConfig>,
     options: &AssertSetDocOptions,
     key: &Key,
     value: &SetDoc,
     current_doc: &Option<Doc>,
 ) -> Result<(), String> {
+    assert_set_doc_rules(context, assert_context, config, key, value, current_doc)?;
+
+    update_and_assert_set_doc_usage(context, assert_context, options, value, current_doc)
+}
+
+/// Asserts the rules, the permissions and the `assert_set_doc` hook for setting a document.
+///
+/// Unlike `assert_set_doc`, the usage and rate counters are neither asserted nor incremented,
+/// therefore the state is not modified.
+pub fn assert_set_doc_rules(
+    context: &StoreContext,
+    &AssertContext { rule, auth_config }: &AssertContext,
+    config: &Option<DbConfig>,
+    key: &Key,
+    value: &SetDoc,
+    current_doc: &Option<Doc>,
+) -> Result<(), String> {
+    let &StoreContext {
+        caller,
+        controllers,
+        collection,
+    } = context;
+
     assert_caller_is_allowed(caller, controllers, auth_config)?;
     assert_user_is_not_banned(caller, controllers)?;
 
     assert_user_collection_caller_key(caller, collection, key, current_doc)?;
     assert_user_collection_data(collection, value)?;
+    assert_user_collection_roles(caller, controllers, collection, value)?;
     assert_user_collection_write_permission(caller, controllers, collection, current_doc)?;
 
     assert_user_webauthn_collection_data(caller, collection, value)?;
@@ -97,7 +130,11 @@ pub fn assert_set_doc(
 
     assert_description_length(&value.description)?;
 
-    assert_user_usage_collection_data(collection, value)?;
+    assert_doc_expiry(collection, &value.expires_at)?;
+
+    assert_doc_schema(collection, key, rule, value)?;
+
+    assert_user_usage_collection_data(collection, key, value)?;
 
     invoke_assert_set_doc(
         &caller,
@@ -111,8 +148,40 @@ pub fn assert_set_doc(
         },
     )?;
 
+    Ok(())
+}
+
+/// Increments and asserts the usage and rate counters of setting a document.
+pub fn update_and_assert_set_doc_usage(
+    context: &StoreContext,
+    &AssertContext {
+        rule,
+        auth_config: _,
+    }: &AssertContext,
+    options: &AssertSetDocOptions,
+    value: &SetDoc,
+    current_doc: &Option<Doc>,
+) -> Result<(), String> {
+    let &StoreContext {
+        caller,
+        controllers,
+        collection,
+    } = context;
+
     increment_and_assert_db_usage(caller, controllers, collection, rule.max_changes_per_user)?;
 
+    let (owner, current_size) = current_doc.as_ref().map_or((caller, 0), |current_doc| {
+        (current_doc.owner, current_doc.size())
+    });
+
+    update_and_assert_db_bytes_usage(
+        context,
+        owner,
+        current_size,
+        value.size(),
+        rule.max_bytes_per_user,
+    )?;
+
     if options.with_assert_rate {
         increment_and_assert_rate(collection, &rule.rate_config)?;
     }
@@ -121,16 +190,32 @@ pub fn assert_set_doc(
 }
 
 pub fn assert_delete_doc(
-    &StoreContext {
-        caller,
-        controllers,
-        collection,
-    }: &StoreContext,
+    context: &StoreContext,
+    assert_context: &AssertContext,
+    key: &Key,
+    value: &DelDoc,
+    current_doc: &Option<Doc>,
+) -> Result<(), String> {
+    assert_delete_doc_rules(context, assert_context, key, value, current_doc)?;
+
+    update_and_assert_delete_doc_usage(context, assert_context, current_doc)
+}
+
+/// Asserts the rules, the permissions and the `assert_delete_doc` hook for deleting a document,
+/// without modifying the state.
+pub fn assert_delete_doc_rules(
+    context: &StoreContext,
     &AssertContext { rule, auth_config }: &AssertContext,
     key: &Key,
     value: &DelDoc,
     current_doc: &Option<Doc>,
 ) -> Result<(), String> {
+    let &StoreContext {
+        caller,
+        controllers,
+        collection,
+    } = context;
+
     assert_caller_is_allowed(caller, controllers, auth_config)?;
     assert_user_is_not_banned(caller, controllers)?;
 
@@ -153,8 +238,36 @@ pub fn assert_delete_doc(
         },
     )?;
 
+    Ok(())
+}
+
+/// Increments and asserts the usage and rate counters of deleting a document.
+pub fn update_and_assert_delete_doc_usage(
+    context: &StoreContext,
+    &AssertContext {
+        rule,
+        auth_config: _,
+    }: &AssertContext,
+    current_doc: &Option<Doc>,
+) -> Result<(), String> {
+    let &StoreContext {
+        caller,
+        controllers,
+        collection,
+    } = context;
+
     increment_and_assert_db_usage(caller, controllers, collection, rule.max_changes_per_user)?;
 
+    if let Some(current_doc) = current_doc {
+        update_and_assert_db_bytes_usage(
+            context,
+            current_doc.owner,
+            current_doc.size(),
+            0,
+            rule.max_bytes_per_user,
+        )?;
+    }
+
     increment_and_assert_rate(collection, &rule.rate_config)?;
 
     Ok(())
@@ -173,7 +286,7 @@ fn assert_read_permission(
     current_doc: &Doc,
     rule: &Permission,
 ) -> Result<(), String> {
-    if !assert_permission(rule, current_doc.owner, caller, controllers) {
+    if !assert_permission_with_user_roles(rule, current_doc.owner, caller, controllers) {
         return Err(JUNO_DATASTORE_ERROR_CANNOT_READ.to_string());
     }
 
@@ -190,12 +303,13 @@ fn assert_write_permission(
     if !public_permission(rule) {
         match current_doc {
             None => {
-                if !assert_create_permission(rule, caller, controllers) {
+                if !assert_create_permission_with_user_roles(rule, caller, controllers) {
                     return Err(JUNO_DATASTORE_ERROR_CANNOT_WRITE.to_string());
                 }
             }
             Some(current_doc) => {
-                if !assert_permission(rule, current_doc.owner, caller, controllers) {
+                if !assert_permission_with_user_roles(rule, current_doc.owner, caller, controllers)
+                {
                     return Err(JUNO_DATASTORE_ERROR_CANNOT_WRITE.to_string());
                 }
             }
@@ -223,6 +337,60 @@ fn assert_write_version(
     Ok(())
 }
 
+fn assert_doc_expiry(
+    collection: &CollectionKey,
+    expires_at: &Option<Timestamp>,
+) -> Result<(), String> {
+    // Documents of the system collections - e.g. users - are not meant to vanish on their own.
+    if expires_at.is_some() && is_system_collection(collection) {
+        return Err(format!(
+            "{JUNO_DATASTORE_ERROR_EXPIRY_NOT_SUPPORTED} ({collection})"
+        ));
+    }
+
+    assert_expires_at(expires_at)
+}
+
+/// Asserts the JSON Schema of a collection can be used to validate the data of its documents.
+pub fn assert_collection_schema(schema: &Option<String>) -> Result<(), String> {
+    if let Some(schema) = schema {
+        parse_schema(schema)
+            .map_err(|error| format!("{JUNO_DATASTORE_ERROR_INVALID_SCHEMA} ({error})"))?;
+    }
+
+    Ok(())
+}
+
+fn assert_doc_schema(
+    collection: &CollectionKey,
+    key: &Key,
+    rule: &Rule,
+    value: &SetDoc,
+) -> Result<(), String> {
+    let schema = match &rule.schema {
+        None => return Ok(()),
+        Some(schema) => parse_schema(schema)
+            .map_err(|error| format!("{JUNO_DATASTORE_ERROR_INVALID_SCHEMA} ({error})"))?,
+    };
+
+    let errors = match decode_data(&value.data) {
+        None => vec!["data: must be JSON".to_string()],
+        Some(data) => validate_data(&schema, &data)
+            .iter()
+            .map(|error| error.to_string())
+            .collect(),
+    };
+
+    if !errors.is_empty() {
+        return Err(format!(
+            "{JUNO_DATASTORE_ERROR_INVALID_DATA} ({collection} - {key}): {}",
+            errors.join("; ")
+        ));
+    }
+
+    Ok(())
+}
+
 pub fn assert_set_config(
     proposed_config: &SetDbConfig,
     current_config: &Option<DbConfig>,
@@ -242,3 +410,22 @@ fn assert_config_version(
 
     Ok(())
 }
+
+pub fn assert_commit_docs_unique(operations: &[CommitDocOperation]) -> Result<(), String> {
+    let mut targets = HashSet::new();
+
+    for operation in operations {
+        let (collection, key) = match operation {
+            CommitDocOperation::Set(collection, key, _) => (collection, key),
+            CommitDocOperation::Delete(collection, key, _) => (collection, key),
+        };
+
+        if !targets.insert((collection, key)) {
+            return Err(format!(
+                "{JUNO_DATASTORE_ERROR_COMMIT_DUPLICATE_DOC} ({collection} - {key})"
+            ));
+        }
+    }
+
+    Ok(())
+}
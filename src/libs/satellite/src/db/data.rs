@@ -0,0 +1,189 @@
+use junobuild_shared::types::list::{DataMatcher, DataValueMatcher, IndexValue};
+use junobuild_utils::decode_doc_data;
+use serde_json::{Map, Value};
+
+/// Decodes the data of a document as JSON, the encoding used by `junobuild_utils::decode_doc_data`.
+///
+/// Returns `None` if the data is not JSON.
+pub fn decode_data(data: &[u8]) -> Option<Value> {
+    decode_doc_data::<Value>(data).ok()
+}
+
+/// Resolves a dot-separated path - e.g. `address.city` - within the JSON data of a document.
+pub fn get_data_field<'a>(json: &'a Value, field: &str) -> Option<&'a Value> {
+    field
+        .split('.')
+        .try_fold(json, |value, segment| value.get(segment))
+}
+
+/// Converts a JSON value to a scalar.
+///
+/// Bigints and principals - encoded as `{"__bigint__": "..."}` and `{"__principal__": "..."}` -
+/// are respectively converted to numbers and texts. Other arrays and objects have no scalar value.
+pub fn to_data_value(value: &Value) -> Option<IndexValue> {
+    match value {
+        Value::Null => Some(IndexValue::Null),
+        Value::Bool(value) => Some(IndexValue::Bool(*value)),
+        // Adding zero normalizes -0.0 which would otherwise be ordered before 0.0.
+        Value::Number(value) => value.as_f64().map(|value| IndexValue::Number(value + 0.0)),
+        Value::String(value) => Some(IndexValue::Text(value.clone())),
+        Value::Object(object) => to_marker_value(object),
+        Value::Array(_) => None,
+    }
+}
+
+fn to_marker_value(object: &Map<String, Value>) -> Option<IndexValue> {
+    if object.len() != 1 {
+        return None;
+    }
+
+    match object.iter().next() {
+        Some((marker, Value::String(value))) if marker == "__bigint__" => value
+            .parse::<f64>()
+            .ok()
+            .map(|value| IndexValue::Number(value + 0.0)),
+        Some((marker, Value::String(value))) if marker == "__principal__" => {
+            Some(IndexValue::Text(value.clone()))
+        }
+        _ => None,
+    }
+}
+
+// ---------------------------------------------------------
+// Matcher
+// ---------------------------------------------------------
+
+pub fn match_data(json: &Value, matcher: &DataMatcher) -> bool {
+    match matcher {
+        DataMatcher::Field(field_matcher) => {
+            let value = get_data_field(json, &field_matcher.field);
+            match_data_value(value, &field_matcher.value)
+        }
+        DataMatcher::And(matchers) => matchers.iter().all(|matcher| match_data(json, matcher)),
+        DataMatcher::Or(matchers) => matchers.iter().any(|matcher| match_data(json, matcher)),
+        DataMatcher::Not(matcher) => !match_data(json, matcher),
+    }
+}
+
+fn match_data_value(value: Option<&Value>, filter: &DataValueMatcher) -> bool {
+    if let DataValueMatcher::Exists = filter {
+        return value.is_some();
+    }
+
+    let value = match value.and_then(to_data_value) {
+        None => return false,
+        Some(value) => value,
+    };
+
+    match filter {
+        DataValueMatcher::Equal(expected) => value == *expected,
+        DataValueMatcher::In(candidates) => candidates.contains(&value),
+        DataValueMatcher::GreaterThan(start) => to_number(&value).is_some_and(|n| n > *start),
+        DataValueMatcher::LessThan(end) => to_number(&value).is_some_and(|n| n < *end),
+        DataValueMatcher::Between(start, end) => {
+            to_number(&value).is_some_and(|n| n >= *start && n <= *end)
+        }
+        DataValueMatcher::Exists => true,
+    }
+}
+
+fn to_number(value: &IndexValue) -> Option<f64> {
+    match value {
+        IndexValue::Number(value) => Some(*value),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use junobuild_shared::types::list::DataFieldMatcher;
+
+    fn field(field: &str, value: DataValueMatcher) -> DataMatcher {
+        DataMatcher::Field(DataFieldMatcher {
+            field: field.to_string(),
+            value,
+        })
+    }
+
+    fn json() -> Value {
+        decode_data(
+            br#"{"name":"Juno","age":3,"address":{"city":"Zurich"},"owner":{"__principal__":"aaaaa-aa"},"balance":{"__bigint__":"12000"},"note":null}"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn matches_equality_and_in_lists() {
+        let json = json();
+
+        assert!(match_data(
+            &json,
+            &field(
+                "address.city",
+                DataValueMatcher::Equal(IndexValue::Text("Zurich".to_string()))
+            )
+        ));
+        assert!(match_data(
+            &json,
+            &field(
+                "owner",
+                DataValueMatcher::Equal(IndexValue::Text("aaaaa-aa".to_string()))
+            )
+        ));
+        assert!(match_data(
+            &json,
+            &field(
+                "age",
+                DataValueMatcher::In(vec![IndexValue::Number(1.0), IndexValue::Number(3.0)])
+            )
+        ));
+        assert!(!match_data(
+            &json,
+            &field(
+                "age",
+                DataValueMatcher::Equal(IndexValue::Text("3".to_string()))
+            )
+        ));
+    }
+
+    #[test]
+    fn matches_numeric_ranges() {
+        let json = json();
+
+        assert!(match_data(
+            &json,
+            &field("age", DataValueMatcher::Between(3.0, 5.0))
+        ));
+        assert!(match_data(
+            &json,
+            &field("balance", DataValueMatcher::GreaterThan(10000.0))
+        ));
+        assert!(!match_data(
+            &json,
+            &field("name", DataValueMatcher::LessThan(10.0))
+        ));
+    }
+
+    #[test]
+    fn matches_exists_and_combinators() {
+        let json = json();
+
+        assert!(match_data(&json, &field("note", DataValueMatcher::Exists)));
+        assert!(!match_data(
+            &json,
+            &field("address.zip", DataValueMatcher::Exists)
+        ));
+
+        let matcher = DataMatcher::And(vec![
+            field("age", DataValueMatcher::GreaterThan(1.0)),
+            DataMatcher::Not(Box::new(field("missing", DataValueMatcher::Exists))),
+            DataMatcher::Or(vec![
+                field("name", DataValueMatcher::Equal(IndexValue::Null)),
+                field("note", DataValueMatcher::Equal(IndexValue::Null)),
+            ]),
+        ]);
+
+        assert!(match_data(&json, &matcher));
+    }
+}
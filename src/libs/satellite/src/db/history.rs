@@ -0,0 +1,263 @@
+use crate::db::state::is_history_deletion_pending;
+use crate::db::types::state::{DbHistoryStable, Doc, DocRevision, RevisionStableKey};
+use crate::expiry::state::insert_expiry;
+use crate::expiry::types::state::ExpiryTarget;
+use crate::memory::state::STATE;
+use junobuild_collections::types::core::CollectionKey;
+use junobuild_collections::types::rules::{HistoryConfig, Rule};
+use junobuild_collections::utils::range_collection_end;
+use junobuild_shared::ic::api::time;
+use junobuild_shared::types::core::Key;
+use junobuild_shared::types::state::Timestamp;
+use std::ops::RangeBounds;
+
+// ---------------------------------------------------------
+// Update
+// ---------------------------------------------------------
+
+/// Keeps the state of a document that is about to be replaced or deleted as a revision, if the
+/// history of the collection is enabled, and removes the revisions that exceed its limits.
+///
+/// If the history has a retention, the expiry of the revision is scheduled as well so that it is
+/// removed even if the document is deleted or never updated again.
+pub fn archive_doc_revision(collection: &CollectionKey, key: &Key, doc: &Doc, rule: &Rule) {
+    let history = match &rule.history {
+        None => return,
+        Some(history) => history,
+    };
+
+    // The previous revisions of the collection are still being removed
+    if is_history_deletion_pending(collection) {
+        return;
+    }
+
+    let now = time();
+
+    STATE.with(|state| {
+        let revisions = &mut state.borrow_mut().stable.db_history;
+
+        revisions.insert(
+            revision_key(collection, key, doc.updated_at),
+            DocRevision {
+                revision: doc.updated_at,
+                doc: doc.clone(),
+                archived_at: now,
+            },
+        );
+
+        prune_doc_revisions(collection, key, history, now, revisions);
+    });
+
+    if let Some(retention) = history.retention.filter(|retention| *retention > 0) {
+        schedule_doc_revisions_expiry(collection, key, now.saturating_add(retention));
+    }
+}
+
+/// Removes the revisions of a document that exceed the limits of the history of its collection.
+///
+/// Used by the purge of the expired entries. If revisions remain - e.g. because the retention was
+/// extended in the meantime - another expiry is scheduled for the oldest one.
+pub fn prune_expired_doc_revisions(
+    collection: &CollectionKey,
+    key: &Key,
+    rule: &Rule,
+    now: Timestamp,
+) {
+    let history = match &rule.history {
+        None => return,
+        Some(history) => history,
+    };
+
+    let oldest_archived_at = STATE.with(|state| {
+        let revisions = &mut state.borrow_mut().stable.db_history;
+
+        prune_doc_revisions(collection, key, history, now, revisions);
+
+        revisions
+            .range(filter_doc_revisions_range(collection, key))
+            .map(|entry| entry.value().archived_at)
+            .min()
+    });
+
+    let retention = history.retention.filter(|retention| *retention > 0);
+
+    if let (Some(archived_at), Some(retention)) = (oldest_archived_at, retention) {
+        schedule_doc_revisions_expiry(collection, key, archived_at.saturating_add(retention));
+    }
+}
+
+fn schedule_doc_revisions_expiry(collection: &CollectionKey, key: &Key, expires_at: Timestamp) {
+    insert_expiry(
+        ExpiryTarget::DocRevisions(collection.clone(), key.clone()),
+        expires_at,
+    );
+}
+
+/// Removes up to `limit` revisions of the documents of a collection and returns how many were
+/// removed.
+///
+/// Used - in batches - when the history of a collection is disabled or when the collection is
+/// deleted.
+pub fn delete_collection_history(collection: &CollectionKey, limit: usize) -> usize {
+    STATE.with(|state| {
+        let revisions = &mut state.borrow_mut().stable.db_history;
+
+        let keys: Vec<RevisionStableKey> = revisions
+            .range(filter_collection_revisions_range(collection))
+            .take(limit)
+            .map(|entry| entry.key().clone())
+            .collect();
+
+        for key in &keys {
+            revisions.remove(key);
+        }
+
+        keys.len()
+    })
+}
+
+fn prune_doc_revisions(
+    collection: &CollectionKey,
+    key: &Key,
+    history: &HistoryConfig,
+    now: Timestamp,
+    revisions: &mut DbHistoryStable,
+) {
+    // Newest revisions first
+    let entries: Vec<(RevisionStableKey, Timestamp)> = revisions
+        .range(filter_doc_revisions_range(collection, key))
+        .map(|entry| (entry.key().clone(), entry.value().archived_at))
+        .collect::<Vec<_>>()
+        .into_iter()
+        .rev()
+        .collect();
+
+    let max_revisions = history
+        .max_revisions
+        .filter(|max| *max > 0)
+        .map(|max| max as usize);
+
+    for (index, (revision_key, archived_at)) in entries.into_iter().enumerate() {
+        let exceeds_max = max_revisions.is_some_and(|max| index >= max);
+
+        if exceeds_max || is_expired(archived_at, history, now) {
+            revisions.remove(&revision_key);
+        }
+    }
+}
+
+fn is_expired(archived_at: Timestamp, history: &HistoryConfig, now: Timestamp) -> bool {
+    match history.retention.filter(|retention| *retention > 0) {
+        None => false,
+        Some(retention) => archived_at.saturating_add(retention) < now,
+    }
+}
+
+// ---------------------------------------------------------
+// Get
+// ---------------------------------------------------------
+
+/// Returns the revisions of a document that are still retained, the newest first.
+pub fn get_doc_revisions(collection: &CollectionKey, key: &Key, rule: &Rule) -> Vec<DocRevision> {
+    let history = match &rule.history {
+        None => return Vec::new(),
+        Some(history) => history,
+    };
+
+    let now = time();
+
+    STATE.with(|state| {
+        state
+            .borrow()
+            .stable
+            .db_history
+            .range(filter_doc_revisions_range(collection, key))
+            .map(|entry| entry.value())
+            .filter(|revision| !is_expired(revision.archived_at, history, now))
+            .collect::<Vec<DocRevision>>()
+            .into_iter()
+            .rev()
+            .collect()
+    })
+}
+
+pub fn get_doc_revision(
+    collection: &CollectionKey,
+    key: &Key,
+    revision: Timestamp,
+    rule: &Rule,
+) -> Option<DocRevision> {
+    let history = rule.history.as_ref()?;
+
+    let value = STATE.with(|state| {
+        state
+            .borrow()
+            .stable
+            .db_history
+            .get(&revision_key(collection, key, revision))
+    })?;
+
+    if is_expired(value.archived_at, history, time()) {
+        return None;
+    }
+
+    Some(value)
+}
+
+// ---------------------------------------------------------
+// Range
+// ---------------------------------------------------------
+
+fn revision_key(collection: &CollectionKey, key: &Key, revision: Timestamp) -> RevisionStableKey {
+    RevisionStableKey {
+        collection: collection.clone(),
+        key: key.clone(),
+        revision,
+    }
+}
+
+fn filter_doc_revisions_range(
+    collection: &CollectionKey,
+    key: &Key,
+) -> impl RangeBounds<RevisionStableKey> {
+    revision_key(collection, key, Timestamp::MIN)..=revision_key(collection, key, Timestamp::MAX)
+}
+
+fn filter_collection_revisions_range(
+    collection: &CollectionKey,
+) -> impl RangeBounds<RevisionStableKey> {
+    let start_key = revision_key(collection, &"".to_string(), Timestamp::MIN);
+    let end_key = revision_key(
+        &range_collection_end(collection),
+        &"".to_string(),
+        Timestamp::MIN,
+    );
+
+    start_key..end_key
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expires_revisions_after_retention() {
+        let history = HistoryConfig {
+            max_revisions: None,
+            retention: Some(10),
+        };
+
+        assert!(!is_expired(100, &history, 110));
+        assert!(is_expired(100, &history, 111));
+    }
+
+    #[test]
+    fn keeps_revisions_without_retention() {
+        let history = HistoryConfig {
+            max_revisions: Some(5),
+            retention: None,
+        };
+
+        assert!(!is_expired(0, &history, u64::MAX));
+    }
+}
@@ -0,0 +1,46 @@
+use crate::db::history::delete_collection_history;
+use crate::db::state::{
+    delete_history_deletion, get_next_history_deletion, insert_history_deletion,
+};
+use ic_cdk_timers::set_timer;
+use junobuild_collections::types::core::CollectionKey;
+use std::time::Duration;
+
+// The number of revisions removed per batch.
+const HISTORY_DELETION_BATCH_SIZE: usize = 500;
+
+/// Registers the removal of the revisions of a collection and processes it in batches.
+pub fn schedule_history_deletion(collection: &CollectionKey) {
+    insert_history_deletion(collection);
+
+    defer_history_deletion();
+}
+
+/// Resumes the removals that were pending when the Satellite was upgraded.
+pub fn init_history_deletion_timer() {
+    if get_next_history_deletion().is_some() {
+        defer_history_deletion();
+    }
+}
+
+fn defer_history_deletion() {
+    set_timer(Duration::ZERO, async { delete_history_batch() });
+}
+
+/// Removes a batch of revisions of the next pending collection. Another batch is scheduled as
+/// long as removals are pending.
+fn delete_history_batch() {
+    let Some(collection) = get_next_history_deletion() else {
+        return;
+    };
+
+    let removed = delete_collection_history(&collection, HISTORY_DELETION_BATCH_SIZE);
+
+    if removed < HISTORY_DELETION_BATCH_SIZE {
+        delete_history_deletion(&collection);
+    }
+
+    if get_next_history_deletion().is_some() {
+        defer_history_deletion();
+    }
+}
@@ -1,19 +1,21 @@
 use crate::db::types::config::DbConfig;
 use crate::db::types::interface::SetDbConfig;
-use crate::db::types::state::{DbHeapState, Doc, StableKey};
+use crate::db::types::state::{
+    DbHeapState, Doc, DocRevision, IndexStableKey, RevisionStableKey, StableKey,
+};
 use crate::SetDoc;
 use candid::Principal;
-use ic_cdk::api::time;
 use ic_stable_structures::storable::Bound;
 use ic_stable_structures::Storable;
 use junobuild_collections::constants::db::DEFAULT_DB_COLLECTIONS;
 use junobuild_collections::types::rules::{Memory, Rule};
 use junobuild_shared::data::version::next_version;
+use junobuild_shared::ic::api::time;
 use junobuild_shared::memory::serializers::{
     deserialize_from_bytes, serialize_into_bytes, serialize_to_bytes,
 };
+use junobuild_shared::types::state::{Expirable, Timestamped, Versioned};
 use junobuild_shared::types::state::{Timestamp, UserId, Version};
-use junobuild_shared::types::state::{Timestamped, Versioned};
 use std::borrow::Cow;
 use std::cmp::Ordering;
 use std::collections::{BTreeMap, HashMap};
@@ -38,14 +40,23 @@ impl Default for DbHeapState {
                         max_size: rule.max_size,
                         max_capacity: rule.max_capacity,
                         max_changes_per_user: rule.max_changes_per_user,
+                        max_bytes_per_user: rule.max_bytes_per_user,
                         created_at: now,
                         updated_at: now,
                         version: rule.version,
                         rate_config: rule.rate_config,
+                        indexes: rule.indexes,
+                        history: rule.history,
+                        ttl: rule.ttl,
+                        schema: rule.schema,
+                        variants: rule.variants,
+                        content_validation: rule.content_validation,
                     },
                 )
             })),
             config: None,
+            indexes_rebuilds: None,
+            history_deletions: None,
         }
     }
 }
@@ -100,8 +111,61 @@ impl Storable for StableKey {
     const BOUND: Bound = Bound::Unbounded;
 }
 
+impl Storable for IndexStableKey {
+    fn to_bytes(&self) -> Cow<'_, [u8]> {
+        serialize_to_bytes(self)
+    }
+
+    fn into_bytes(self) -> Vec<u8> {
+        serialize_into_bytes(&self)
+    }
+
+    fn from_bytes(bytes: Cow<[u8]>) -> Self {
+        deserialize_from_bytes(bytes)
+    }
+
+    const BOUND: Bound = Bound::Unbounded;
+}
+
+impl Storable for RevisionStableKey {
+    fn to_bytes(&self) -> Cow<'_, [u8]> {
+        serialize_to_bytes(self)
+    }
+
+    fn into_bytes(self) -> Vec<u8> {
+        serialize_into_bytes(&self)
+    }
+
+    fn from_bytes(bytes: Cow<[u8]>) -> Self {
+        deserialize_from_bytes(bytes)
+    }
+
+    const BOUND: Bound = Bound::Unbounded;
+}
+
+impl Storable for DocRevision {
+    fn to_bytes(&self) -> Cow<'_, [u8]> {
+        serialize_to_bytes(self)
+    }
+
+    fn into_bytes(self) -> Vec<u8> {
+        serialize_into_bytes(&self)
+    }
+
+    fn from_bytes(bytes: Cow<[u8]>) -> Self {
+        deserialize_from_bytes(bytes)
+    }
+
+    const BOUND: Bound = Bound::Unbounded;
+}
+
 impl Doc {
-    pub fn prepare(caller: Principal, current_doc: &Option<Doc>, user_doc: SetDoc) -> Self {
+    pub fn prepare(
+        caller: Principal,
+        current_doc: &Option<Doc>,
+        user_doc: SetDoc,
+        rule: &Rule,
+    ) -> Self {
         let now = time();
 
         let created_at: Timestamp = match current_doc {
@@ -125,6 +189,7 @@ impl Doc {
             created_at,
             updated_at,
             version: Some(version),
+            expires_at: rule.expires_at(&user_doc.expires_at, now),
         }
     }
 }
@@ -135,6 +200,34 @@ impl Versioned for Doc {
     }
 }
 
+impl Expirable for Doc {
+    fn expires_at(&self) -> Option<Timestamp> {
+        self.expires_at
+    }
+}
+
+impl Doc {
+    /// The size in bytes accounted to the owner of the document - i.e. its data and description.
+    pub fn size(&self) -> u64 {
+        doc_size(&self.data, &self.description)
+    }
+}
+
+impl SetDoc {
+    /// The size in bytes the document will be accounted for once set.
+    pub fn size(&self) -> u64 {
+        doc_size(&self.data, &self.description)
+    }
+}
+
+fn doc_size(data: &[u8], description: &Option<String>) -> u64 {
+    let description_length = description
+        .as_ref()
+        .map_or(0, |description| description.len());
+
+    data.len().saturating_add(description_length) as u64
+}
+
 impl DbConfig {
     pub fn prepare(current_config: &Option<DbConfig>, user_config: &SetDbConfig) -> Self {
         let now = time();
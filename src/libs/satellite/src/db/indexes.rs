@@ -0,0 +1,342 @@
+use crate::db::data::{decode_data, get_data_field, to_data_value};
+use crate::db::state::is_indexes_rebuild_pending;
+use crate::db::types::state::{DbIndexesStable, Doc, IndexStableKey};
+use crate::errors::db::{
+    JUNO_DATASTORE_ERROR_INDEX_NOT_FOUND, JUNO_DATASTORE_ERROR_INDEX_REBUILDING,
+};
+use crate::memory::state::STATE;
+use junobuild_collections::types::core::CollectionKey;
+use junobuild_collections::types::rules::{IndexField, Rule};
+use junobuild_collections::utils::range_collection_end;
+use junobuild_shared::data::list::match_index_value;
+use junobuild_shared::types::core::Key;
+use junobuild_shared::types::list::{IndexMatcher, IndexValue, IndexValueMatcher};
+use std::ops::RangeBounds;
+
+// ---------------------------------------------------------
+// Update
+// ---------------------------------------------------------
+
+pub fn insert_doc_indexes(collection: &CollectionKey, key: &Key, doc: &Doc, rule: &Rule) {
+    let entries = index_entries(collection, key, doc, rule);
+
+    if entries.is_empty() {
+        return;
+    }
+
+    STATE.with(|state| {
+        let indexes = &mut state.borrow_mut().stable.db_indexes;
+
+        for entry in entries {
+            indexes.insert(entry, ());
+        }
+    })
+}
+
+pub fn delete_doc_indexes(collection: &CollectionKey, key: &Key, doc: &Doc, rule: &Rule) {
+    let entries = index_entries(collection, key, doc, rule);
+
+    if entries.is_empty() {
+        return;
+    }
+
+    STATE.with(|state| {
+        let indexes = &mut state.borrow_mut().stable.db_indexes;
+
+        for entry in entries {
+            indexes.remove(&entry);
+        }
+    })
+}
+
+/// Removes up to `limit` index entries of a collection.
+///
+/// Used to clear the previous indexes when the indexes declared on the rule of a collection are
+/// modified. Returns the number of removed entries.
+pub fn delete_collection_indexes(collection: &CollectionKey, limit: usize) -> usize {
+    STATE.with(|state| {
+        delete_collection_indexes_impl(collection, limit, &mut state.borrow_mut().stable.db_indexes)
+    })
+}
+
+fn delete_collection_indexes_impl(
+    collection: &CollectionKey,
+    limit: usize,
+    indexes: &mut DbIndexesStable,
+) -> usize {
+    let entries: Vec<IndexStableKey> = indexes
+        .range(filter_indexes_range(collection))
+        .take(limit)
+        .map(|entry| entry.key().clone())
+        .collect();
+
+    for entry in &entries {
+        indexes.remove(entry);
+    }
+
+    entries.len()
+}
+
+fn filter_indexes_range(collection: &CollectionKey) -> impl RangeBounds<IndexStableKey> {
+    let start_key = IndexStableKey {
+        collection: collection.clone(),
+        field: "".to_string(),
+        value: IndexValue::Null,
+        key: "".to_string(),
+    };
+
+    let end_key = IndexStableKey {
+        collection: range_collection_end(collection).clone(),
+        field: "".to_string(),
+        value: IndexValue::Null,
+        key: "".to_string(),
+    };
+
+    start_key..end_key
+}
+
+fn index_entries(
+    collection: &CollectionKey,
+    key: &Key,
+    doc: &Doc,
+    rule: &Rule,
+) -> Vec<IndexStableKey> {
+    let fields = match &rule.indexes {
+        None => return Vec::new(),
+        Some(fields) if fields.is_empty() => return Vec::new(),
+        Some(fields) => fields,
+    };
+
+    extract_index_values(&doc.data, fields)
+        .into_iter()
+        .map(|(field, value)| IndexStableKey {
+            collection: collection.clone(),
+            field,
+            value,
+            key: key.clone(),
+        })
+        .collect()
+}
+
+/// Decodes the JSON data of a document and extracts the scalar values of the indexed fields.
+///
+/// Fields that are missing, that resolve to an array or an object, or data that is not JSON are not indexed.
+fn extract_index_values(data: &[u8], fields: &[IndexField]) -> Vec<(IndexField, IndexValue)> {
+    let json = match decode_data(data) {
+        Some(json) => json,
+        None => return Vec::new(),
+    };
+
+    fields
+        .iter()
+        .filter_map(|field| {
+            get_data_field(&json, field)
+                .and_then(to_data_value)
+                .map(|value| (field.clone(), value))
+        })
+        .collect()
+}
+
+// ---------------------------------------------------------
+// Lookup
+// ---------------------------------------------------------
+
+/// Resolves the keys of the documents of a collection whose indexed value matches the filter.
+///
+/// The lookup is a range scan over the index and does not read the documents.
+pub fn get_doc_keys_by_index(
+    collection: &CollectionKey,
+    IndexMatcher { field, value }: &IndexMatcher,
+    rule: &Rule,
+) -> Result<Vec<Key>, String> {
+    let declared = rule
+        .indexes
+        .as_ref()
+        .is_some_and(|indexes| indexes.contains(field));
+
+    if !declared {
+        return Err(format!(
+            "{JUNO_DATASTORE_ERROR_INDEX_NOT_FOUND} ({collection} - {field})"
+        ));
+    }
+
+    if is_indexes_rebuild_pending(collection) {
+        return Err(format!(
+            "{JUNO_DATASTORE_ERROR_INDEX_REBUILDING} ({collection})"
+        ));
+    }
+
+    let start_key = IndexStableKey {
+        collection: collection.clone(),
+        field: field.clone(),
+        value: lower_bound(value),
+        key: "".to_string(),
+    };
+
+    let keys = STATE.with(|state| {
+        state
+            .borrow()
+            .stable
+            .db_indexes
+            .range(start_key..)
+            .map(|entry| entry.key().clone())
+            .take_while(|entry| {
+                entry.collection == *collection
+                    && entry.field == *field
+                    && !is_past_upper_bound(&entry.value, value)
+            })
+            .filter(|entry| match_index_value(&entry.value, value))
+            .map(|entry| entry.key)
+            .collect()
+    });
+
+    Ok(keys)
+}
+
+fn lower_bound(filter: &IndexValueMatcher) -> IndexValue {
+    match filter {
+        IndexValueMatcher::Equal(value)
+        | IndexValueMatcher::GreaterThan(value)
+        | IndexValueMatcher::Between(value, _) => value.clone(),
+        IndexValueMatcher::LessThan(value) => min_value_of_kind(value),
+    }
+}
+
+fn min_value_of_kind(value: &IndexValue) -> IndexValue {
+    match value {
+        IndexValue::Null => IndexValue::Null,
+        IndexValue::Bool(_) => IndexValue::Bool(false),
+        IndexValue::Number(_) => IndexValue::Number(f64::NEG_INFINITY),
+        IndexValue::Text(_) => IndexValue::Text("".to_string()),
+    }
+}
+
+fn is_past_upper_bound(value: &IndexValue, filter: &IndexValueMatcher) -> bool {
+    match filter {
+        IndexValueMatcher::Equal(expected) => value > expected,
+        IndexValueMatcher::GreaterThan(start) => !value.same_kind(start),
+        IndexValueMatcher::LessThan(end) => value >= end,
+        IndexValueMatcher::Between(_, end) => value > end,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ic_stable_structures::memory_manager::{MemoryId, MemoryManager};
+    use ic_stable_structures::{DefaultMemoryImpl, StableBTreeMap};
+
+    fn fields(fields: &[&str]) -> Vec<IndexField> {
+        fields.iter().map(|field| field.to_string()).collect()
+    }
+
+    #[test]
+    fn extracts_scalar_values_with_json_paths() {
+        let data =
+            br#"{"name":"Juno","age":3,"active":true,"address":{"city":"Zurich"},"tags":["a"]}"#;
+
+        let values = extract_index_values(
+            data,
+            &fields(&["name", "age", "active", "address.city", "tags", "missing"]),
+        );
+
+        assert_eq!(
+            values,
+            vec![
+                ("name".to_string(), IndexValue::Text("Juno".to_string())),
+                ("age".to_string(), IndexValue::Number(3.0)),
+                ("active".to_string(), IndexValue::Bool(true)),
+                (
+                    "address.city".to_string(),
+                    IndexValue::Text("Zurich".to_string())
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn ignores_data_that_is_not_json() {
+        let values = extract_index_values(&[0, 1, 2], &fields(&["name"]));
+        assert!(values.is_empty());
+    }
+
+    #[test]
+    fn orders_values_by_kind_then_value() {
+        assert!(IndexValue::Null < IndexValue::Bool(false));
+        assert!(IndexValue::Bool(true) < IndexValue::Number(-10.0));
+        assert!(IndexValue::Number(2.0) < IndexValue::Number(10.0));
+        assert!(IndexValue::Number(1e9) < IndexValue::Text("".to_string()));
+        assert_eq!(IndexValue::Number(-0.0 + 0.0), IndexValue::Number(0.0));
+    }
+
+    #[test]
+    fn bounds_range_to_the_kind_of_the_filter() {
+        let filter = IndexValueMatcher::GreaterThan(IndexValue::Number(5.0));
+
+        assert_eq!(lower_bound(&filter), IndexValue::Number(5.0));
+        assert!(!is_past_upper_bound(&IndexValue::Number(100.0), &filter));
+        assert!(is_past_upper_bound(
+            &IndexValue::Text("6".to_string()),
+            &filter
+        ));
+        assert!(!match_index_value(&IndexValue::Number(5.0), &filter));
+
+        let filter = IndexValueMatcher::LessThan(IndexValue::Text("m".to_string()));
+
+        assert_eq!(lower_bound(&filter), IndexValue::Text("".to_string()));
+        assert!(is_past_upper_bound(
+            &IndexValue::Text("m".to_string()),
+            &filter
+        ));
+        assert!(match_index_value(
+            &IndexValue::Text("a".to_string()),
+            &filter
+        ));
+    }
+
+    #[test]
+    fn between_is_inclusive() {
+        let filter = IndexValueMatcher::Between(IndexValue::Number(1.0), IndexValue::Number(3.0));
+
+        assert!(match_index_value(&IndexValue::Number(1.0), &filter));
+        assert!(match_index_value(&IndexValue::Number(3.0), &filter));
+        assert!(!is_past_upper_bound(&IndexValue::Number(3.0), &filter));
+        assert!(is_past_upper_bound(&IndexValue::Number(3.5), &filter));
+    }
+
+    #[test]
+    fn deletes_collection_indexes_in_batches() {
+        let memory = MemoryManager::init(DefaultMemoryImpl::default()).get(MemoryId::new(0));
+        let mut indexes: DbIndexesStable = StableBTreeMap::init(memory);
+
+        let entry = |collection: &str, key: usize| IndexStableKey {
+            collection: collection.to_string(),
+            field: "name".to_string(),
+            value: IndexValue::Number(key as f64),
+            key: key.to_string(),
+        };
+
+        for key in 0..5 {
+            indexes.insert(entry("a", key), ());
+            indexes.insert(entry("b", key), ());
+        }
+
+        let collection = "a".to_string();
+
+        assert_eq!(
+            delete_collection_indexes_impl(&collection, 3, &mut indexes),
+            3
+        );
+        assert_eq!(
+            delete_collection_indexes_impl(&collection, 3, &mut indexes),
+            2
+        );
+        assert_eq!(
+            delete_collection_indexes_impl(&collection, 3, &mut indexes),
+            0
+        );
+
+        assert_eq!(indexes.len(), 5);
+        assert!(indexes.iter().all(|entry| entry.key().collection == "b"));
+    }
+}
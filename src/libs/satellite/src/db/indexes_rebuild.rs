@@ -0,0 +1,94 @@
+use crate::db::indexes::{delete_collection_indexes, insert_doc_indexes};
+use crate::db::state::{
+    delete_indexes_rebuild, get_docs_after, get_next_indexes_rebuild, insert_indexes_rebuild,
+};
+use crate::db::types::state::IndexesRebuild;
+use crate::rules::store::get_rule_db;
+use ic_cdk_timers::set_timer;
+use junobuild_collections::types::core::CollectionKey;
+use std::time::Duration;
+
+// The number of index entries removed, or of documents indexed again, per batch.
+const INDEXES_REBUILD_BATCH_SIZE: usize = 500;
+
+/// Registers - or restarts - the rebuild of the indexes of a collection and processes it in batches.
+pub fn schedule_indexes_rebuild(collection: &CollectionKey) {
+    insert_indexes_rebuild(collection, &IndexesRebuild::default());
+
+    defer_indexes_rebuild();
+}
+
+/// Resumes the rebuilds that were pending when the Satellite was upgraded.
+pub fn init_indexes_rebuild_timer() {
+    if get_next_indexes_rebuild().is_some() {
+        defer_indexes_rebuild();
+    }
+}
+
+fn defer_indexes_rebuild() {
+    set_timer(Duration::ZERO, async { rebuild_indexes_batch() });
+}
+
+/// Processes a batch of the next pending rebuild: first the entries of the previous indexes are
+/// removed, then the documents are indexed again following the key order. Another batch is
+/// scheduled as long as rebuilds are pending.
+fn rebuild_indexes_batch() {
+    let Some((collection, rebuild)) = get_next_indexes_rebuild() else {
+        return;
+    };
+
+    match get_rule_db(&collection) {
+        // The collection was deleted in the meantime.
+        None => delete_indexes_rebuild(&collection),
+        Some(rule) if !rebuild.cleared => {
+            let removed = delete_collection_indexes(&collection, INDEXES_REBUILD_BATCH_SIZE);
+
+            insert_indexes_rebuild(
+                &collection,
+                &IndexesRebuild {
+                    cleared: removed < INDEXES_REBUILD_BATCH_SIZE,
+                    cursor: None,
+                },
+            );
+
+            let indexed = rule
+                .indexes
+                .as_ref()
+                .is_some_and(|indexes| !indexes.is_empty());
+
+            // Nothing to index again
+            if removed < INDEXES_REBUILD_BATCH_SIZE && !indexed {
+                delete_indexes_rebuild(&collection);
+            }
+        }
+        Some(rule) => {
+            let docs = get_docs_after(
+                &collection,
+                &rebuild.cursor,
+                INDEXES_REBUILD_BATCH_SIZE,
+                &rule,
+            )
+            .unwrap_or_default();
+
+            for (key, doc) in &docs {
+                insert_doc_indexes(&collection, key, doc, &rule);
+            }
+
+            if docs.len() < INDEXES_REBUILD_BATCH_SIZE {
+                delete_indexes_rebuild(&collection);
+            } else {
+                insert_indexes_rebuild(
+                    &collection,
+                    &IndexesRebuild {
+                        cleared: true,
+                        cursor: docs.last().map(|(key, _)| key.clone()),
+                    },
+                );
+            }
+        }
+    }
+
+    if get_next_indexes_rebuild().is_some() {
+        defer_indexes_rebuild();
+    }
+}
@@ -1,5 +1,6 @@
 use crate::db::state::{
-    delete_doc as delete_state_doc, get_doc as get_state_doc, insert_doc as insert_state_doc,
+    delete_doc as delete_state_doc, get_doc as get_state_doc, get_docs_after, get_owner_docs_size,
+    insert_doc as insert_state_doc,
 };
 use crate::db::types::state::{Doc, DocUpsert};
 use crate::SetDoc;
@@ -20,6 +21,20 @@ pub fn unsafe_get_doc(
     get_state_doc(collection, key, rule)
 }
 
+/// Retrieves up to `limit` documents of a collection following the `cursor` key, sorted by key,
+/// directly from the state.
+///
+/// ⚠️ **Warning:** This function is for internal use only and does not perform any assertions.
+///
+pub fn unsafe_get_docs_after(
+    collection: &CollectionKey,
+    cursor: &Option<Key>,
+    limit: usize,
+    rule: &Rule,
+) -> Result<Vec<(Key, Doc)>, String> {
+    get_docs_after(collection, cursor, limit, rule)
+}
+
 /// Inserts or updates a document directly in the state.
 ///
 /// ⚠️ **Warning:** This function is for internal use only and does not perform any assertions.
@@ -33,7 +48,7 @@ pub fn unsafe_set_doc(
 ) -> Result<DocUpsert, String> {
     let current_doc = get_state_doc(collection, key, rule)?;
 
-    let doc: Doc = Doc::prepare(caller, &current_doc, value);
+    let doc: Doc = Doc::prepare(caller, &current_doc, value, rule);
 
     let (_evicted_doc, after) = insert_state_doc(collection, key, &doc, rule)?;
 
@@ -54,3 +69,15 @@ pub fn unsafe_delete_doc(
 ) -> Result<Option<Doc>, String> {
     delete_state_doc(collection, key, rule)
 }
+
+/// Computes the total size of the documents of a collection owned by a user directly from the state.
+///
+/// ⚠️ **Warning:** This function is for internal use only and does not perform any assertions.
+///
+pub fn unsafe_get_owner_docs_size(
+    collection: &CollectionKey,
+    owner: &UserId,
+    rule: &Rule,
+) -> Result<u64, String> {
+    get_owner_docs_size(collection, owner, rule)
+}
@@ -1,7 +1,13 @@
 mod assert;
+mod data;
+mod history;
+pub mod history_deletion;
 pub mod impls;
+mod indexes;
+pub mod indexes_rebuild;
 pub mod internal;
 pub mod runtime;
+mod schema;
 mod state;
 pub mod store;
 pub mod types;
@@ -0,0 +1,461 @@
+use crate::db::data::to_data_value;
+use junobuild_shared::types::list::IndexValue;
+use regex::Regex;
+use serde_json::{Map, Number, Value};
+use std::fmt;
+
+/// A violation of the schema of a collection, located by the dot-separated path of the failing
+/// value within the data of the document - e.g. `data.address.city` or `data.tags.0`.
+pub struct SchemaError {
+    pub path: String,
+    pub message: String,
+}
+
+impl fmt::Display for SchemaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+const ROOT_PATH: &str = "data";
+
+const TYPES: [&str; 7] = [
+    "null", "boolean", "integer", "number", "string", "array", "object",
+];
+
+// Keywords that carry no constraint
+const ANNOTATIONS: [&str; 7] = [
+    "$schema",
+    "$id",
+    "$comment",
+    "title",
+    "description",
+    "default",
+    "examples",
+];
+
+// ---------------------------------------------------------
+// Schema
+// ---------------------------------------------------------
+
+/// Parses the JSON Schema of a collection and asserts it only uses supported keywords.
+///
+/// The supported subset is: `type`, `enum`, `const`, `properties`, `required`,
+/// `additionalProperties`, `items`, `minItems`, `maxItems`, `minLength`, `maxLength`,
+/// `pattern`, `minimum`, `maximum`, `exclusiveMinimum` and `exclusiveMaximum`. Annotations such as
+/// `title` or `description` are accepted and ignored.
+pub fn parse_schema(schema: &str) -> Result<Value, SchemaError> {
+    let schema: Value = serde_json::from_str(schema).map_err(|err| SchemaError {
+        path: "".to_string(),
+        message: err.to_string(),
+    })?;
+
+    assert_schema(&schema, "")?;
+
+    Ok(schema)
+}
+
+fn assert_schema(schema: &Value, path: &str) -> Result<(), SchemaError> {
+    let keywords = match schema {
+        Value::Bool(_) => return Ok(()),
+        Value::Object(keywords) => keywords,
+        _ => return Err(schema_error(path, "must be an object or a boolean")),
+    };
+
+    for (keyword, value) in keywords {
+        let keyword_path = join_path(path, keyword);
+
+        let valid = match keyword.as_str() {
+            "type" => match value {
+                Value::String(kind) => TYPES.contains(&kind.as_str()),
+                Value::Array(kinds) => kinds
+                    .iter()
+                    .all(|kind| kind.as_str().is_some_and(|kind| TYPES.contains(&kind))),
+                _ => false,
+            },
+            "enum" => value.is_array(),
+            "const" => true,
+            "required" => value
+                .as_array()
+                .is_some_and(|fields| fields.iter().all(Value::is_string)),
+            "properties" => match value {
+                Value::Object(properties) => {
+                    for (field, schema) in properties {
+                        assert_schema(schema, &join_path(&keyword_path, field))?;
+                    }
+                    true
+                }
+                _ => false,
+            },
+            "additionalProperties" | "items" => {
+                assert_schema(value, &keyword_path)?;
+                true
+            }
+            "minItems" | "maxItems" | "minLength" | "maxLength" => value.is_u64(),
+            "minimum" | "maximum" | "exclusiveMinimum" | "exclusiveMaximum" => value.is_number(),
+            "pattern" => value
+                .as_str()
+                .is_some_and(|pattern| Regex::new(pattern).is_ok()),
+            keyword if ANNOTATIONS.contains(&keyword) => true,
+            _ => return Err(schema_error(&keyword_path, "unsupported keyword")),
+        };
+
+        if !valid {
+            return Err(schema_error(&keyword_path, "invalid value"));
+        }
+    }
+
+    Ok(())
+}
+
+fn schema_error(path: &str, message: &str) -> SchemaError {
+    SchemaError {
+        path: path.to_string(),
+        message: message.to_string(),
+    }
+}
+
+// ---------------------------------------------------------
+// Validation
+// ---------------------------------------------------------
+
+/// Validates the decoded data of a document against the schema of its collection and returns
+/// every violation.
+pub fn validate_data(schema: &Value, data: &Value) -> Vec<SchemaError> {
+    let mut errors = Vec::new();
+    validate_value(schema, data, ROOT_PATH, &mut errors);
+    errors
+}
+
+fn validate_value(schema: &Value, value: &Value, path: &str, errors: &mut Vec<SchemaError>) {
+    let keywords = match schema {
+        Value::Bool(true) => return,
+        Value::Bool(false) => {
+            errors.push(schema_error(path, "is not allowed"));
+            return;
+        }
+        Value::Object(keywords) => keywords,
+        _ => return,
+    };
+
+    // Bigints and principals are validated as numbers and strings.
+    let value = to_scalar_value(value).unwrap_or_else(|| value.clone());
+
+    if let Some(kinds) = keywords.get("type") {
+        if !match_type(kinds, &value) {
+            errors.push(schema_error(
+                path,
+                &format!("must be of type {}", type_names(kinds)),
+            ));
+            return;
+        }
+    }
+
+    if let Some(Value::Array(candidates)) = keywords.get("enum") {
+        if !candidates
+            .iter()
+            .any(|candidate| equal_values(candidate, &value))
+        {
+            errors.push(schema_error(path, "must be one of the allowed values"));
+        }
+    }
+
+    if let Some(expected) = keywords.get("const") {
+        if !equal_values(expected, &value) {
+            errors.push(schema_error(path, "must be equal to the constant value"));
+        }
+    }
+
+    match &value {
+        Value::Number(number) => validate_number(keywords, number, path, errors),
+        Value::String(text) => validate_string(keywords, text, path, errors),
+        Value::Array(items) => validate_array(keywords, items, path, errors),
+        Value::Object(fields) => validate_object(keywords, fields, path, errors),
+        Value::Null | Value::Bool(_) => (),
+    }
+}
+
+fn validate_number(
+    keywords: &Map<String, Value>,
+    number: &Number,
+    path: &str,
+    errors: &mut Vec<SchemaError>,
+) {
+    let value = match number.as_f64() {
+        None => return,
+        Some(value) => value,
+    };
+
+    let bounds = [
+        ("minimum", ">="),
+        ("maximum", "<="),
+        ("exclusiveMinimum", ">"),
+        ("exclusiveMaximum", "<"),
+    ];
+
+    for (keyword, operator) in bounds {
+        let bound = match keywords.get(keyword).and_then(Value::as_f64) {
+            None => continue,
+            Some(bound) => bound,
+        };
+
+        let valid = match operator {
+            ">=" => value >= bound,
+            "<=" => value <= bound,
+            ">" => value > bound,
+            _ => value < bound,
+        };
+
+        if !valid {
+            errors.push(schema_error(path, &format!("must be {operator} {bound}")));
+        }
+    }
+}
+
+fn validate_string(
+    keywords: &Map<String, Value>,
+    text: &str,
+    path: &str,
+    errors: &mut Vec<SchemaError>,
+) {
+    let length = text.chars().count() as u64;
+
+    if let Some(min) = keywords.get("minLength").and_then(Value::as_u64) {
+        if length < min {
+            errors.push(schema_error(
+                path,
+                &format!("must be at least {min} characters long"),
+            ));
+        }
+    }
+
+    if let Some(max) = keywords.get("maxLength").and_then(Value::as_u64) {
+        if length > max {
+            errors.push(schema_error(
+                path,
+                &format!("must be at most {max} characters long"),
+            ));
+        }
+    }
+
+    if let Some(pattern) = keywords.get("pattern").and_then(Value::as_str) {
+        let matches = Regex::new(pattern).is_ok_and(|re| re.is_match(text));
+
+        if !matches {
+            errors.push(schema_error(
+                path,
+                &format!("must match the pattern {pattern}"),
+            ));
+        }
+    }
+}
+
+fn validate_array(
+    keywords: &Map<String, Value>,
+    items: &[Value],
+    path: &str,
+    errors: &mut Vec<SchemaError>,
+) {
+    let length = items.len() as u64;
+
+    if let Some(min) = keywords.get("minItems").and_then(Value::as_u64) {
+        if length < min {
+            errors.push(schema_error(
+                path,
+                &format!("must contain at least {min} items"),
+            ));
+        }
+    }
+
+    if let Some(max) = keywords.get("maxItems").and_then(Value::as_u64) {
+        if length > max {
+            errors.push(schema_error(
+                path,
+                &format!("must contain at most {max} items"),
+            ));
+        }
+    }
+
+    if let Some(schema) = keywords.get("items") {
+        for (index, item) in items.iter().enumerate() {
+            validate_value(schema, item, &join_path(path, &index.to_string()), errors);
+        }
+    }
+}
+
+fn validate_object(
+    keywords: &Map<String, Value>,
+    fields: &Map<String, Value>,
+    path: &str,
+    errors: &mut Vec<SchemaError>,
+) {
+    if let Some(Value::Array(required)) = keywords.get("required") {
+        for field in required.iter().filter_map(Value::as_str) {
+            if !fields.contains_key(field) {
+                errors.push(schema_error(&join_path(path, field), "is required"));
+            }
+        }
+    }
+
+    let properties = keywords.get("properties").and_then(Value::as_object);
+
+    for (field, value) in fields {
+        let field_path = join_path(path, field);
+
+        match properties.and_then(|properties| properties.get(field)) {
+            Some(schema) => validate_value(schema, value, &field_path, errors),
+            None => {
+                if let Some(schema) = keywords.get("additionalProperties") {
+                    validate_value(schema, value, &field_path, errors);
+                }
+            }
+        }
+    }
+}
+
+// ---------------------------------------------------------
+// Utils
+// ---------------------------------------------------------
+
+fn to_scalar_value(value: &Value) -> Option<Value> {
+    if !value.is_object() {
+        return None;
+    }
+
+    match to_data_value(value)? {
+        IndexValue::Number(number) => Number::from_f64(number).map(Value::Number),
+        IndexValue::Text(text) => Some(Value::String(text)),
+        IndexValue::Null | IndexValue::Bool(_) => None,
+    }
+}
+
+fn match_type(kinds: &Value, value: &Value) -> bool {
+    match kinds {
+        Value::String(kind) => match_kind(kind, value),
+        Value::Array(kinds) => kinds
+            .iter()
+            .filter_map(Value::as_str)
+            .any(|kind| match_kind(kind, value)),
+        _ => true,
+    }
+}
+
+fn match_kind(kind: &str, value: &Value) -> bool {
+    match kind {
+        "null" => value.is_null(),
+        "boolean" => value.is_boolean(),
+        "integer" => value.as_f64().is_some_and(|number| number.fract() == 0.0),
+        "number" => value.is_number(),
+        "string" => value.is_string(),
+        "array" => value.is_array(),
+        "object" => value.is_object(),
+        _ => false,
+    }
+}
+
+fn type_names(kinds: &Value) -> String {
+    match kinds {
+        Value::Array(kinds) => kinds
+            .iter()
+            .filter_map(Value::as_str)
+            .collect::<Vec<&str>>()
+            .join(" or "),
+        kind => kind.as_str().unwrap_or_default().to_string(),
+    }
+}
+
+fn equal_values(expected: &Value, value: &Value) -> bool {
+    match (expected, value) {
+        // 1 and 1.0 are the same number
+        (Value::Number(expected), Value::Number(value)) => expected.as_f64() == value.as_f64(),
+        _ => expected == value,
+    }
+}
+
+fn join_path(path: &str, segment: &str) -> String {
+    if path.is_empty() {
+        return segment.to_string();
+    }
+
+    format!("{path}.{segment}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::data::decode_data;
+
+    fn schema() -> Value {
+        parse_schema(
+            r#"{
+                "title": "Profile",
+                "type": "object",
+                "required": ["name", "age"],
+                "additionalProperties": false,
+                "properties": {
+                    "name": { "type": "string", "minLength": 1, "maxLength": 10 },
+                    "age": { "type": "integer", "minimum": 0 },
+                    "balance": { "type": "number", "exclusiveMinimum": 0 },
+                    "owner": { "type": "string", "pattern": "^[a-z0-9-]+$" },
+                    "tags": { "type": "array", "maxItems": 2, "items": { "enum": ["a", "b", "c"] } },
+                    "address": {
+                        "type": "object",
+                        "properties": { "city": { "type": "string" } }
+                    }
+                }
+            }"#,
+        )
+        .ok()
+        .unwrap()
+    }
+
+    fn errors(data: &[u8]) -> Vec<String> {
+        validate_data(&schema(), &decode_data(data).unwrap())
+            .iter()
+            .map(|error| error.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn accepts_valid_data_including_bigints_and_principals() {
+        assert!(errors(
+            br#"{"name":"Juno","age":3,"balance":{"__bigint__":"12000"},"owner":{"__principal__":"aaaaa-aa"},"tags":["a"],"address":{"city":"Zurich"}}"#
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn names_the_failing_paths() {
+        assert_eq!(
+            errors(
+                br#"{"name":"","age":1.5,"tags":["a","d","c"],"address":{"city":3},"extra":true}"#
+            ),
+            vec![
+                "data.address.city: must be of type string",
+                "data.age: must be of type integer",
+                "data.extra: is not allowed",
+                "data.name: must be at least 1 characters long",
+                "data.tags: must contain at most 2 items",
+                "data.tags.1: must be one of the allowed values",
+            ]
+        );
+
+        assert_eq!(errors(br#"{"name":"Juno"}"#), vec!["data.age: is required"]);
+    }
+
+    #[test]
+    fn rejects_unsupported_keywords() {
+        let error = parse_schema(r#"{"properties":{"name":{"$ref":"/name"}}}"#)
+            .err()
+            .unwrap();
+
+        assert_eq!(
+            error.to_string(),
+            "properties.name.$ref: unsupported keyword"
+        );
+
+        assert!(parse_schema(r#"{"type":"text"}"#).is_err());
+        assert!(parse_schema(r#"{"pattern":"("}"#).is_err());
+        assert!(parse_schema("[]").is_err());
+    }
+}
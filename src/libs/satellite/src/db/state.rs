@@ -1,5 +1,11 @@
+use crate::db::history::archive_doc_revision;
+use crate::db::indexes::{delete_doc_indexes, insert_doc_indexes};
 use crate::db::types::config::DbConfig;
-use crate::db::types::state::{Collection, DbHeap, DbHeapState, DbStable, Doc, StableKey};
+use crate::db::types::state::{
+    Collection, DbHeap, DbHeapState, DbStable, Doc, IndexesRebuild, StableKey,
+};
+use crate::expiry::state::insert_expiry;
+use crate::expiry::types::state::ExpiryTarget;
 use crate::memory::state::STATE;
 use junobuild_collections::msg::msg_db_collection_not_found;
 use junobuild_collections::types::core::CollectionKey;
@@ -7,8 +13,9 @@ use junobuild_collections::types::rules::{Memory, Rule};
 use junobuild_collections::utils::range_collection_end;
 use junobuild_shared::data::collect::collect_stable_vec;
 use junobuild_shared::types::core::Key;
-use std::collections::BTreeMap;
-use std::ops::RangeBounds;
+use junobuild_shared::types::state::UserId;
+use std::collections::{BTreeMap, BTreeSet};
+use std::ops::{Bound, RangeBounds};
 // ---------------------------------------------------------
 // Collections
 // ---------------------------------------------------------
@@ -112,7 +119,7 @@ pub fn insert_doc(
     doc: &Doc,
     rule: &Rule,
 ) -> Result<(Option<(Key, Doc)>, Doc), String> {
-    match rule.mem() {
+    let (evicted_doc, replaced_doc, after) = match rule.mem() {
         Memory::Heap => STATE.with(|state| {
             insert_doc_heap(
                 collection,
@@ -131,7 +138,27 @@ pub fn insert_doc(
                 &mut state.borrow_mut().stable.db,
             )
         }),
+    }?;
+
+    if let Some((evicted_key, evicted_doc)) = &evicted_doc {
+        delete_doc_indexes(collection, evicted_key, evicted_doc, rule);
+    }
+
+    if let Some(replaced_doc) = &replaced_doc {
+        delete_doc_indexes(collection, key, replaced_doc, rule);
+        archive_doc_revision(collection, key, replaced_doc, rule);
+    }
+
+    insert_doc_indexes(collection, key, &after, rule);
+
+    if let Some(expires_at) = after.expires_at {
+        insert_expiry(
+            ExpiryTarget::Doc(collection.clone(), key.clone()),
+            expires_at,
+        );
     }
+
+    Ok((evicted_doc, after))
 }
 
 pub fn delete_doc(
@@ -139,13 +166,20 @@ pub fn delete_doc(
     key: &Key,
     rule: &Rule,
 ) -> Result<Option<Doc>, String> {
-    match rule.mem() {
+    let deleted_doc = match rule.mem() {
         Memory::Heap => {
             STATE.with(|state| delete_doc_heap(collection, key, &mut state.borrow_mut().heap.db.db))
         }
         Memory::Stable => STATE
             .with(|state| delete_doc_stable(collection, key, &mut state.borrow_mut().stable.db)),
+    }?;
+
+    if let Some(deleted_doc) = &deleted_doc {
+        delete_doc_indexes(collection, key, deleted_doc, rule);
+        archive_doc_revision(collection, key, deleted_doc, rule);
     }
+
+    Ok(deleted_doc)
 }
 
 // Get
@@ -190,6 +224,43 @@ pub fn get_docs_stable(
     Ok(items)
 }
 
+/// The documents of a collection that follow a key - or precede it, in descending order - read
+/// lazily by seeking in the stable memory.
+pub fn iter_docs_stable_after<'a>(
+    collection: &CollectionKey,
+    start_after: &Key,
+    desc: bool,
+    db: &'a DbStable,
+) -> Box<dyn Iterator<Item = (StableKey, Doc)> + 'a> {
+    let after = StableKey {
+        collection: collection.clone(),
+        key: start_after.clone(),
+    };
+
+    if desc {
+        let start_key = StableKey {
+            collection: collection.clone(),
+            key: "".to_string(),
+        };
+
+        return Box::new(
+            db.range((Bound::Included(start_key), Bound::Excluded(after)))
+                .rev()
+                .map(|entry| (entry.key().clone(), entry.value().clone())),
+        );
+    }
+
+    let end_key = StableKey {
+        collection: range_collection_end(collection).clone(),
+        key: "".to_string(),
+    };
+
+    Box::new(
+        db.range((Bound::Excluded(after), Bound::Excluded(end_key)))
+            .map(|entry| (entry.key().clone(), entry.value().clone())),
+    )
+}
+
 pub fn get_docs_heap<'a>(
     collection: &CollectionKey,
     db: &'a DbHeap,
@@ -205,6 +276,89 @@ pub fn get_docs_heap<'a>(
     }
 }
 
+/// Returns up to `limit` documents of a collection following the `cursor` key, sorted by key.
+pub fn get_docs_after(
+    collection: &CollectionKey,
+    cursor: &Option<Key>,
+    limit: usize,
+    rule: &Rule,
+) -> Result<Vec<(Key, Doc)>, String> {
+    let start = match cursor {
+        None => Bound::Unbounded,
+        Some(cursor) => Bound::Excluded(cursor.clone()),
+    };
+
+    match rule.mem() {
+        Memory::Heap => STATE.with(|state| {
+            let state = state.borrow();
+            let col = state
+                .heap
+                .db
+                .db
+                .get(collection)
+                .ok_or_else(|| msg_db_collection_not_found(collection))?;
+
+            Ok(col
+                .range((start, Bound::Unbounded))
+                .take(limit)
+                .map(|(key, doc)| (key.clone(), doc.clone()))
+                .collect())
+        }),
+        Memory::Stable => STATE.with(|state| {
+            let start = match start {
+                Bound::Excluded(key) => Bound::Excluded(stable_key(collection, &key)),
+                _ => Bound::Included(stable_key(collection, &"".to_string())),
+            };
+
+            let end = Bound::Excluded(stable_key(
+                &range_collection_end(collection),
+                &"".to_string(),
+            ));
+
+            Ok(state
+                .borrow()
+                .stable
+                .db
+                .range((start, end))
+                .take(limit)
+                .map(|entry| (entry.key().key.clone(), entry.value()))
+                .collect())
+        }),
+    }
+}
+
+pub fn get_docs_stable_by_keys(
+    collection: &CollectionKey,
+    keys: &[Key],
+    db: &DbStable,
+) -> Vec<(StableKey, Doc)> {
+    keys.iter()
+        .filter_map(|key| {
+            let stable_key = stable_key(collection, key);
+            db.get(&stable_key).map(|doc| (stable_key, doc))
+        })
+        .collect()
+}
+
+pub fn get_docs_heap_by_keys<'a>(
+    collection: &CollectionKey,
+    keys: &[Key],
+    db: &'a DbHeap,
+) -> Result<Vec<(&'a Key, &'a Doc)>, String> {
+    let col = db.get(collection);
+
+    match col {
+        None => Err(msg_db_collection_not_found(collection)),
+        Some(col) => {
+            let items = keys
+                .iter()
+                .filter_map(|key| col.get_key_value(key))
+                .collect();
+            Ok(items)
+        }
+    }
+}
+
 pub fn count_docs_heap(collection: &CollectionKey, db: &DbHeap) -> Result<usize, String> {
     let col = db.get(collection);
 
@@ -220,6 +374,35 @@ pub fn count_docs_stable(collection: &CollectionKey, db: &DbStable) -> Result<us
     Ok(length)
 }
 
+/// The total size of the documents of a collection owned by a user.
+pub fn get_owner_docs_size(
+    collection: &CollectionKey,
+    owner: &UserId,
+    rule: &Rule,
+) -> Result<u64, String> {
+    match rule.mem() {
+        Memory::Heap => STATE.with(|state| {
+            let state = state.borrow();
+            let docs = get_docs_heap(collection, &state.heap.db.db)?;
+
+            Ok(docs
+                .iter()
+                .filter(|(_, doc)| doc.owner == *owner)
+                .fold(0u64, |size, (_, doc)| size.saturating_add(doc.size())))
+        }),
+        Memory::Stable => STATE.with(|state| {
+            Ok(state
+                .borrow()
+                .stable
+                .db
+                .range(filter_docs_range(collection))
+                .map(|entry| entry.value())
+                .filter(|doc| doc.owner == *owner)
+                .fold(0u64, |size, doc| size.saturating_add(doc.size())))
+        }),
+    }
+}
+
 fn filter_docs_range(collection: &CollectionKey) -> impl RangeBounds<StableKey> {
     let start_key = StableKey {
         collection: collection.clone(),
@@ -236,18 +419,21 @@ fn filter_docs_range(collection: &CollectionKey) -> impl RangeBounds<StableKey>
 
 // Insert
 
+/// The evicted document - if the capacity was reached, the replaced document and the inserted document.
+type InsertDocResult = (Option<(Key, Doc)>, Option<Doc>, Doc);
+
 fn insert_doc_stable(
     collection: &CollectionKey,
     key: &Key,
     doc: &Doc,
     max_capacity: Option<u32>,
     db: &mut DbStable,
-) -> Result<(Option<(Key, Doc)>, Doc), String> {
+) -> Result<InsertDocResult, String> {
     let evicted_doc = limit_docs_stable_capacity(collection, max_capacity, db)?;
 
-    db.insert(stable_key(collection, key), doc.clone());
+    let replaced_doc = db.insert(stable_key(collection, key), doc.clone());
 
-    Ok((evicted_doc.clone(), doc.clone()))
+    Ok((evicted_doc.clone(), replaced_doc, doc.clone()))
 }
 
 fn limit_docs_stable_capacity(
@@ -279,7 +465,7 @@ fn insert_doc_heap(
     doc: &Doc,
     max_capacity: Option<u32>,
     db: &mut DbHeap,
-) -> Result<(Option<(Key, Doc)>, Doc), String> {
+) -> Result<InsertDocResult, String> {
     let col = db.get_mut(collection);
 
     match col {
@@ -287,8 +473,8 @@ fn insert_doc_heap(
         Some(col) => {
             let evicted_doc = limit_docs_heap_capacity(max_capacity, col);
 
-            col.insert(key.clone(), doc.clone());
-            Ok((evicted_doc.clone(), doc.clone()))
+            let replaced_doc = col.insert(key.clone(), doc.clone());
+            Ok((evicted_doc.clone(), replaced_doc, doc.clone()))
         }
     }
 }
@@ -372,3 +558,112 @@ pub fn insert_config(config: &DbConfig) {
 fn insert_config_impl(config: &DbConfig, state: &mut DbHeapState) {
     state.config = Some(config.clone());
 }
+
+// ---------------------------------------------------------
+// Indexes rebuilds
+// ---------------------------------------------------------
+
+pub fn is_indexes_rebuild_pending(collection: &CollectionKey) -> bool {
+    STATE.with(|state| {
+        state
+            .borrow()
+            .heap
+            .db
+            .indexes_rebuilds
+            .as_ref()
+            .is_some_and(|rebuilds| rebuilds.contains_key(collection))
+    })
+}
+
+pub fn get_next_indexes_rebuild() -> Option<(CollectionKey, IndexesRebuild)> {
+    STATE.with(|state| {
+        state
+            .borrow()
+            .heap
+            .db
+            .indexes_rebuilds
+            .as_ref()
+            .and_then(|rebuilds| rebuilds.first_key_value())
+            .map(|(collection, rebuild)| (collection.clone(), rebuild.clone()))
+    })
+}
+
+pub fn insert_indexes_rebuild(collection: &CollectionKey, rebuild: &IndexesRebuild) {
+    STATE.with(|state| {
+        state
+            .borrow_mut()
+            .heap
+            .db
+            .indexes_rebuilds
+            .get_or_insert_with(BTreeMap::new)
+            .insert(collection.clone(), rebuild.clone());
+    })
+}
+
+pub fn delete_indexes_rebuild(collection: &CollectionKey) {
+    STATE.with(|state| {
+        let db = &mut state.borrow_mut().heap.db;
+
+        if let Some(rebuilds) = db.indexes_rebuilds.as_mut() {
+            rebuilds.remove(collection);
+
+            if rebuilds.is_empty() {
+                db.indexes_rebuilds = None;
+            }
+        }
+    })
+}
+
+// ---------------------------------------------------------
+// History deletions
+// ---------------------------------------------------------
+
+pub fn is_history_deletion_pending(collection: &CollectionKey) -> bool {
+    STATE.with(|state| {
+        state
+            .borrow()
+            .heap
+            .db
+            .history_deletions
+            .as_ref()
+            .is_some_and(|deletions| deletions.contains(collection))
+    })
+}
+
+pub fn get_next_history_deletion() -> Option<CollectionKey> {
+    STATE.with(|state| {
+        state
+            .borrow()
+            .heap
+            .db
+            .history_deletions
+            .as_ref()
+            .and_then(|deletions| deletions.first().cloned())
+    })
+}
+
+pub fn insert_history_deletion(collection: &CollectionKey) {
+    STATE.with(|state| {
+        state
+            .borrow_mut()
+            .heap
+            .db
+            .history_deletions
+            .get_or_insert_with(BTreeSet::new)
+            .insert(collection.clone());
+    })
+}
+
+pub fn delete_history_deletion(collection: &CollectionKey) {
+    STATE.with(|state| {
+        let db = &mut state.borrow_mut().heap.db;
+
+        if let Some(deletions) = db.history_deletions.as_mut() {
+            deletions.remove(collection);
+
+            if deletions.is_empty() {
+                db.history_deletions = None;
+            }
+        }
+    })
+}
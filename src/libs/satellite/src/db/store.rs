@@ -1,30 +1,48 @@
-use crate::access_keys::store::get_access_keys;
+use crate::access_keys::store::get_db_access_keys;
 use crate::auth::store::get_config as get_auth_config;
 use crate::db::assert::{
-    assert_delete_doc, assert_get_doc, assert_get_docs, assert_set_config, assert_set_doc,
+    assert_collection_schema, assert_commit_docs_unique, assert_delete_doc,
+    assert_delete_doc_rules, assert_get_doc, assert_get_docs, assert_set_config, assert_set_doc,
+    assert_set_doc_rules, update_and_assert_delete_doc_usage, update_and_assert_set_doc_usage,
 };
+use crate::db::history::{get_doc_revision, get_doc_revisions, prune_expired_doc_revisions};
+use crate::db::history_deletion::schedule_history_deletion;
+use crate::db::indexes::get_doc_keys_by_index;
+use crate::db::indexes_rebuild::schedule_indexes_rebuild;
 use crate::db::state::{
     count_docs_heap, count_docs_stable, delete_collection as delete_state_collection,
     delete_doc as delete_state_doc, get_config, get_doc as get_state_doc, get_docs_heap,
-    get_docs_stable, get_rule as get_state_rule, init_collection as init_state_collection,
-    insert_config, insert_doc as insert_state_doc,
-    is_collection_empty as is_state_collection_empty,
+    get_docs_heap_by_keys, get_docs_stable, get_docs_stable_by_keys, get_rule as get_state_rule,
+    init_collection as init_state_collection, insert_config, insert_doc as insert_state_doc,
+    is_collection_empty as is_state_collection_empty, iter_docs_stable_after,
 };
 use crate::db::types::config::DbConfig;
-use crate::db::types::interface::{DelDoc, SetDbConfig, SetDoc};
-use crate::db::types::state::{Doc, DocContext, DocUpsert};
+use crate::db::types::interface::{
+    CommitDocOperation, DelDoc, RestoreDocRevision, SetDbConfig, SetDoc,
+};
+use crate::db::types::state::{
+    CommitDocsContext, DbStable, Doc, DocContext, DocRevision, DocUpsert, StableKey,
+};
 use crate::db::types::store::AssertSetDocOptions;
 use crate::db::utils::filter_values;
+use crate::errors::db::JUNO_DATASTORE_ERROR_REVISION_NOT_FOUND;
 use crate::memory::state::STATE;
+use crate::types::state::CollectionType;
 use crate::types::store::{AssertContext, StoreContext};
+use crate::user::usage::store::release_bytes_usage;
 use candid::Principal;
+use junobuild_auth::state::types::config::AuthenticationConfig;
 use junobuild_collections::msg::msg_db_collection_not_empty;
 use junobuild_collections::types::core::CollectionKey;
 use junobuild_collections::types::rules::{Memory, Rule};
-use junobuild_shared::data::list::list_values;
+use junobuild_shared::data::list::{list_seek, list_values, list_values_after_seek};
+use junobuild_shared::ic::api::time;
+use junobuild_shared::ic::UnwrapOrTrap;
 use junobuild_shared::types::core::Key;
-use junobuild_shared::types::list::{ListParams, ListResults};
-use junobuild_shared::types::state::{AccessKeys, UserId};
+use junobuild_shared::types::list::{ListParams, ListResults, ListSeek};
+use junobuild_shared::types::state::{
+    AccessKeyOperation, AccessKeys, Expirable, Timestamp, UserId,
+};
 // ---------------------------------------------------------
 // Collection
 // ---------------------------------------------------------
@@ -54,9 +72,32 @@ fn delete_collection_impl(
 
     delete_state_collection(collection, memory)?;
 
+    schedule_history_deletion(collection);
+
     Ok(())
 }
 
+/// Schedules the removal of the revisions of a collection.
+///
+/// The revisions are removed in batches from a timer, so that disabling the history of a large
+/// collection does not exceed the instruction limit. No revision is archived until it completes.
+pub fn delete_collection_history_store(collection: &CollectionKey) {
+    schedule_history_deletion(collection);
+}
+
+pub fn assert_collection_schema_store(schema: &Option<String>) -> Result<(), String> {
+    assert_collection_schema(schema)
+}
+
+/// Schedules the rebuild of the indexes of a collection.
+///
+/// The previous entries are cleared and the documents are indexed again in batches from a timer,
+/// so that modifying the indexes of a large collection does not exceed the instruction limit.
+/// Index lookups on the collection are rejected until the rebuild completes.
+pub fn rebuild_collection_indexes_store(collection: &CollectionKey) {
+    schedule_indexes_rebuild(collection);
+}
+
 // ---------------------------------------------------------
 // Get
 // ---------------------------------------------------------
@@ -84,7 +125,7 @@ pub fn get_doc_store(
     collection: CollectionKey,
     key: Key,
 ) -> Result<Option<Doc>, String> {
-    let controllers: AccessKeys = get_access_keys();
+    let controllers: AccessKeys = get_db_access_keys(&collection, AccessKeyOperation::Read);
 
     let context = StoreContext {
         caller,
@@ -112,7 +153,7 @@ fn get_doc_impl(
     assert_context: &AssertContext,
     key: Key,
 ) -> Result<Option<Doc>, String> {
-    let value = get_state_doc(context.collection, &key, assert_context.rule)?;
+    let value = get_unexpired_state_doc(context.collection, &key, assert_context.rule)?;
 
     match value {
         None => Ok(None),
@@ -126,6 +167,17 @@ fn get_doc_impl(
     }
 }
 
+/// An expired document is considered deleted even if it was not yet purged from the state.
+fn get_unexpired_state_doc(
+    collection: &CollectionKey,
+    key: &Key,
+    rule: &Rule,
+) -> Result<Option<Doc>, String> {
+    let doc = get_state_doc(collection, key, rule)?;
+
+    Ok(doc.filter(|doc| !doc.is_expired(time())))
+}
+
 // ---------------------------------------------------------
 // Insert
 // ---------------------------------------------------------
@@ -154,7 +206,7 @@ pub fn set_doc_store(
     key: Key,
     value: SetDoc,
 ) -> Result<DocContext<DocUpsert>, String> {
-    let controllers: AccessKeys = get_access_keys();
+    let controllers: AccessKeys = get_db_access_keys(&collection, AccessKeyOperation::Write);
     let config = get_config();
 
     let context = StoreContext {
@@ -189,7 +241,7 @@ pub fn internal_set_doc_store(
     value: SetDoc,
     assert_options: &AssertSetDocOptions,
 ) -> Result<DocContext<DocUpsert>, String> {
-    let controllers: AccessKeys = get_access_keys();
+    let controllers: AccessKeys = get_db_access_keys(&collection, AccessKeyOperation::Write);
     let config = get_config();
 
     let context = StoreContext {
@@ -233,19 +285,49 @@ fn set_doc_impl(
     key: Key,
     value: SetDoc,
 ) -> Result<DocUpsert, String> {
-    let current_doc = get_state_doc(context.collection, &key, assert_context.rule)?;
-
-    assert_set_doc(
+    let current_doc = assert_set_doc_impl(
         context,
         assert_context,
         config,
         assert_options,
         &key,
         &value,
+    )?;
+
+    apply_set_doc_impl(context, assert_context, key, value, current_doc)
+}
+
+fn assert_set_doc_impl(
+    context: &StoreContext,
+    assert_context: &AssertContext,
+    config: &Option<DbConfig>,
+    assert_options: &AssertSetDocOptions,
+    key: &Key,
+    value: &SetDoc,
+) -> Result<Option<Doc>, String> {
+    let current_doc = get_unexpired_state_doc(context.collection, key, assert_context.rule)?;
+
+    assert_set_doc(
+        context,
+        assert_context,
+        config,
+        assert_options,
+        key,
+        value,
         &current_doc,
     )?;
 
-    let doc: Doc = Doc::prepare(context.caller, &current_doc, value);
+    Ok(current_doc)
+}
+
+fn apply_set_doc_impl(
+    context: &StoreContext,
+    assert_context: &AssertContext,
+    key: Key,
+    value: SetDoc,
+    current_doc: Option<Doc>,
+) -> Result<DocUpsert, String> {
+    let doc: Doc = Doc::prepare(context.caller, &current_doc, value, assert_context.rule);
 
     let (_evicted_doc, after) =
         insert_state_doc(context.collection, &key, &doc, assert_context.rule)?;
@@ -281,7 +363,7 @@ pub fn list_docs_store(
     collection: CollectionKey,
     filter: &ListParams,
 ) -> Result<ListResults<Doc>, String> {
-    let controllers: AccessKeys = get_access_keys();
+    let controllers: AccessKeys = get_db_access_keys(&collection, AccessKeyOperation::Read);
 
     secure_get_docs(caller, &controllers, collection, filter)
 }
@@ -336,14 +418,41 @@ fn secure_get_docs(
 
     assert_get_docs(&context, &assert_context)?;
 
+    // With an index matcher, only the documents resolved through the index are read.
+    let index_keys = filter
+        .matcher
+        .as_ref()
+        .and_then(|matcher| matcher.index.as_ref())
+        .map(|index| get_doc_keys_by_index(&collection, index, &rule))
+        .transpose()?;
+
     match rule.mem() {
         Memory::Heap => STATE.with(|state| {
             let state_ref = state.borrow();
-            let docs = get_docs_heap(&collection, &state_ref.heap.db.db)?;
+            let docs = match &index_keys {
+                None => get_docs_heap(&collection, &state_ref.heap.db.db)?,
+                Some(keys) => get_docs_heap_by_keys(&collection, keys, &state_ref.heap.db.db)?,
+            };
             get_docs_impl(&docs, caller, controllers, filter, &rule)
         }),
         Memory::Stable => STATE.with(|state| {
-            let stable = get_docs_stable(&collection, &state.borrow().stable.db)?;
+            // A page ordered by keys that resumes from a cursor is read by seeking to it.
+            if let (None, Some(seek)) = (&index_keys, list_seek(filter)?) {
+                return get_docs_stable_after_impl(
+                    &collection,
+                    &seek,
+                    caller,
+                    controllers,
+                    filter,
+                    &rule,
+                    &state.borrow().stable.db,
+                );
+            }
+
+            let stable = match &index_keys {
+                None => get_docs_stable(&collection, &state.borrow().stable.db)?,
+                Some(keys) => get_docs_stable_by_keys(&collection, keys, &state.borrow().stable.db),
+            };
             let docs: Vec<(&Key, &Doc)> = stable.iter().map(|(key, doc)| (&key.key, doc)).collect();
             get_docs_impl(&docs, caller, controllers, filter, &rule)
         }),
@@ -359,9 +468,50 @@ fn get_docs_impl<'a>(
 ) -> Result<ListResults<Doc>, String> {
     let matches = filter_values(caller, controllers, &rule.read, docs, filters)?;
 
-    let results = list_values(&matches, filters);
+    list_values(&matches, filters)
+}
 
-    Ok(results)
+/// Reads the page that follows a cursor in a stable collection. The documents are filtered in
+/// batches, until one beyond the limit is found, to know whether more documents follow.
+fn get_docs_stable_after_impl(
+    collection: &CollectionKey,
+    seek: &ListSeek,
+    caller: Principal,
+    controllers: &AccessKeys,
+    filters: &ListParams,
+    rule: &Rule,
+    db: &DbStable,
+) -> Result<ListResults<Doc>, String> {
+    let wanted = seek.limit.map(|limit| limit.saturating_add(1));
+
+    let mut entries = iter_docs_stable_after(collection, &seek.start_after, seek.desc, db);
+    let mut matches: Vec<(Key, Doc)> = Vec::new();
+
+    loop {
+        let missing = wanted.map_or(usize::MAX, |wanted| wanted.saturating_sub(matches.len()));
+
+        if missing == 0 {
+            break;
+        }
+
+        let batch: Vec<(StableKey, Doc)> = entries.by_ref().take(missing).collect();
+
+        if batch.is_empty() {
+            break;
+        }
+
+        let docs: Vec<(&Key, &Doc)> = batch.iter().map(|(key, doc)| (&key.key, doc)).collect();
+
+        let filtered = filter_values(caller, controllers, &rule.read, &docs, filters)?;
+
+        matches.extend(
+            filtered
+                .into_iter()
+                .map(|(key, doc)| (key.clone(), doc.clone())),
+        );
+    }
+
+    Ok(list_values_after_seek(matches, seek))
 }
 
 // ---------------------------------------------------------
@@ -395,7 +545,7 @@ pub fn delete_doc_store(
     key: Key,
     value: DelDoc,
 ) -> Result<DocContext<Option<Doc>>, String> {
-    let controllers: AccessKeys = get_access_keys();
+    let controllers: AccessKeys = get_db_access_keys(&collection, AccessKeyOperation::Write);
 
     let context = StoreContext {
         caller,
@@ -434,13 +584,22 @@ fn delete_doc_impl(
     key: Key,
     value: DelDoc,
 ) -> Result<Option<Doc>, String> {
-    let current_doc = get_state_doc(context.collection, &key, assert_context.rule)?;
-
-    assert_delete_doc(context, assert_context, &key, &value, &current_doc)?;
+    assert_delete_doc_impl(context, assert_context, &key, &value)?;
 
     delete_state_doc(context.collection, &key, assert_context.rule)
 }
 
+fn assert_delete_doc_impl(
+    context: &StoreContext,
+    assert_context: &AssertContext,
+    key: &Key,
+    value: &DelDoc,
+) -> Result<(), String> {
+    let current_doc = get_unexpired_state_doc(context.collection, key, assert_context.rule)?;
+
+    assert_delete_doc(context, assert_context, key, value, &current_doc)
+}
+
 /// Delete multiple documents from a collection's store.
 ///
 /// This function deletes multiple documents from a collection's store based on the specified collection key.
@@ -478,7 +637,16 @@ fn delete_docs_impl(
     rule: &Rule,
 ) -> Result<(), String> {
     for key in keys {
-        delete_state_doc(collection, key, rule)?;
+        let deleted_doc = delete_state_doc(collection, key, rule)?;
+
+        if let Some(deleted_doc) = deleted_doc {
+            release_bytes_usage(
+                collection,
+                &CollectionType::Db,
+                &deleted_doc.owner,
+                deleted_doc.size(),
+            )?;
+        }
     }
 
     Ok(())
@@ -540,7 +708,7 @@ pub fn delete_filtered_docs_store(
     collection: CollectionKey,
     filter: &ListParams,
 ) -> Result<Vec<DocContext<Option<Doc>>>, String> {
-    let controllers: AccessKeys = get_access_keys();
+    let controllers: AccessKeys = get_db_access_keys(&collection, AccessKeyOperation::Write);
 
     let docs = secure_get_docs(caller, &controllers, collection.clone(), filter)?;
 
@@ -586,6 +754,342 @@ fn delete_filtered_docs_store_impl(
     Ok(results)
 }
 
+/// Deletes a document whose expiration is due.
+///
+/// The document is only deleted if `expires_at` is still its expiration - i.e. it was not updated
+/// with another expiration since. No assertion is performed, this function is used by the purge
+/// of the expired entries, `now` being the time of the purge.
+///
+/// # Returns
+/// - `Ok(Some(DocContext<Option<Doc>>))`: The context of the deleted document.
+/// - `Ok(None)`: The document does not exist anymore or is not expired.
+/// - `Err(String)`: An error message if the collection does not exist or the deletion fails.
+pub fn delete_expired_doc_store(
+    collection: &CollectionKey,
+    key: &Key,
+    expires_at: Timestamp,
+    now: Timestamp,
+) -> Result<Option<DocContext<Option<Doc>>>, String> {
+    let rule = get_state_rule(collection)?;
+
+    let current_doc = get_state_doc(collection, key, &rule)?;
+
+    let expired = current_doc
+        .as_ref()
+        .is_some_and(|doc| doc.expires_at == Some(expires_at) && doc.is_expired(now));
+
+    if !expired {
+        return Ok(None);
+    }
+
+    let deleted_doc = delete_state_doc(collection, key, &rule)?;
+
+    if let Some(deleted_doc) = &deleted_doc {
+        release_bytes_usage(
+            collection,
+            &CollectionType::Db,
+            &deleted_doc.owner,
+            deleted_doc.size(),
+        )?;
+    }
+
+    Ok(Some(DocContext {
+        key: key.clone(),
+        collection: collection.clone(),
+        data: deleted_doc,
+    }))
+}
+
+// ---------------------------------------------------------
+// History
+// ---------------------------------------------------------
+
+/// List the revisions of a document.
+///
+/// Revisions are the previous states of a document, kept when the history of its collection is
+/// enabled. Only the revisions the caller is allowed to read - according to the read permission of
+/// the collection - are returned, the newest first.
+///
+/// # Parameters
+/// - `caller`: The `UserId` representing the caller requesting the revisions.
+/// - `collection`: A `CollectionKey` representing the collection of the document.
+/// - `key`: A `Key` identifying the document.
+///
+/// # Returns
+/// - `Ok(Vec<DocRevision>)`: The revisions of the document. Empty if the history is not enabled.
+/// - `Err(String)`: An error message if the collection does not exist.
+pub fn list_doc_revisions_store(
+    caller: UserId,
+    collection: CollectionKey,
+    key: Key,
+) -> Result<Vec<DocRevision>, String> {
+    let controllers: AccessKeys = get_db_access_keys(&collection, AccessKeyOperation::Read);
+    let rule = get_state_rule(&collection)?;
+    let auth_config = get_auth_config();
+
+    let context = StoreContext {
+        caller,
+        controllers: &controllers,
+        collection: &collection,
+    };
+
+    let assert_context = AssertContext {
+        rule: &rule,
+        auth_config: &auth_config,
+    };
+
+    let revisions = get_doc_revisions(&collection, &key, &rule)
+        .into_iter()
+        .filter(|revision| assert_get_doc(&context, &assert_context, &revision.doc).is_ok())
+        .collect();
+
+    Ok(revisions)
+}
+
+/// Restore a revision of a document.
+///
+/// The data and description of the revision are set as a new version of the document. The restore
+/// is therefore subject to the same permissions, version check and assertions as `set_doc_store`,
+/// and the state it replaces is itself kept as a revision.
+///
+/// # Parameters
+/// - `caller`: The `UserId` representing the caller restoring the revision.
+/// - `collection`: A `CollectionKey` representing the collection of the document.
+/// - `key`: A `Key` identifying the document.
+/// - `value`: The `RestoreDocRevision` identifying the revision and the current version of the document.
+///
+/// # Returns
+/// - `Ok(DocContext<DocUpsert>)`: The document before and after the restore.
+/// - `Err(String)`: An error message if the revision is not found or the document cannot be set.
+pub fn restore_doc_revision_store(
+    caller: UserId,
+    collection: CollectionKey,
+    key: Key,
+    value: RestoreDocRevision,
+) -> Result<DocContext<DocUpsert>, String> {
+    let controllers: AccessKeys = get_db_access_keys(&collection, AccessKeyOperation::Write);
+    let rule = get_state_rule(&collection)?;
+    let auth_config = get_auth_config();
+
+    let context = StoreContext {
+        caller,
+        controllers: &controllers,
+        collection: &collection,
+    };
+
+    let assert_context = AssertContext {
+        rule: &rule,
+        auth_config: &auth_config,
+    };
+
+    let revision = get_doc_revision(&collection, &key, value.revision, &rule)
+        .filter(|revision| assert_get_doc(&context, &assert_context, &revision.doc).is_ok())
+        .ok_or_else(|| {
+            format!(
+                "{JUNO_DATASTORE_ERROR_REVISION_NOT_FOUND} ({collection} - {key} - {})",
+                value.revision
+            )
+        })?;
+
+    set_doc_store(
+        caller,
+        collection,
+        key,
+        SetDoc {
+            data: revision.doc.data,
+            description: revision.doc.description,
+            version: value.version,
+            expires_at: None,
+        },
+    )
+}
+
+/// Removes the revisions of a document that exceed the retention of the history of its collection.
+///
+/// No assertion is performed, this function is used by the purge of the expired entries to sweep
+/// the revisions of documents that were deleted or are not updated anymore.
+///
+/// # Returns
+/// - `Ok(())`: The revisions were pruned, if any.
+/// - `Err(String)`: An error message if the collection does not exist anymore.
+pub fn prune_expired_doc_revisions_store(
+    collection: &CollectionKey,
+    key: &Key,
+    now: Timestamp,
+) -> Result<(), String> {
+    let rule = get_state_rule(collection)?;
+
+    prune_expired_doc_revisions(collection, key, &rule, now);
+
+    Ok(())
+}
+
+// ---------------------------------------------------------
+// Commit
+// ---------------------------------------------------------
+
+/// Commit a batch of set and delete operations on documents - all of them or none.
+///
+/// This function first asserts every operation - rules, version preconditions and `assert_set_doc`
+/// or `assert_delete_doc` hooks - without modifying the state. If any assertion fails, an error is
+/// returned and nothing is written. Only then are the usage and rate counters incremented and the
+/// operations applied. An operation can target a document only once per commit.
+///
+/// # Parameters
+/// - `caller`: The `UserId` representing the caller initiating the commit.
+/// - `operations`: The list of `CommitDocOperation` to apply, across one or multiple collections.
+///
+/// # Returns
+/// - `Ok(CommitDocsContext)`: The context of the documents that were set and deleted.
+/// - `Err(String)`: An error message if an operation was rejected.
+///
+/// # Traps
+/// If an operation fails once the state started to be modified - e.g. a usage quota is reached
+/// by the batch - the function traps, which reverts every change, so that a batch is never
+/// partially applied.
+pub fn commit_docs_store(
+    caller: UserId,
+    operations: Vec<CommitDocOperation>,
+) -> Result<CommitDocsContext, String> {
+    let config = get_config();
+    let auth_config = get_auth_config();
+
+    assert_commit_docs_unique(&operations)?;
+
+    let prepared = operations
+        .into_iter()
+        .map(|operation| assert_commit_doc_operation(caller, &config, &auth_config, operation))
+        .collect::<Result<Vec<PreparedCommitDocOperation>, String>>()?;
+
+    let mut results = CommitDocsContext {
+        set: Vec::new(),
+        deleted: Vec::new(),
+    };
+
+    for prepared in prepared {
+        apply_commit_doc_operation(caller, &auth_config, prepared, &mut results).unwrap_or_trap();
+    }
+
+    Ok(results)
+}
+
+type PreparedCommitDocOperation = (CommitDocOperation, Rule, Option<Doc>);
+
+fn assert_commit_doc_operation(
+    caller: UserId,
+    config: &Option<DbConfig>,
+    auth_config: &Option<AuthenticationConfig>,
+    operation: CommitDocOperation,
+) -> Result<PreparedCommitDocOperation, String> {
+    let collection = match &operation {
+        CommitDocOperation::Set(collection, _, _) => collection,
+        CommitDocOperation::Delete(collection, _, _) => collection,
+    };
+
+    let rule = get_state_rule(collection)?;
+
+    let controllers: AccessKeys = get_db_access_keys(collection, AccessKeyOperation::Write);
+
+    let context = StoreContext {
+        caller,
+        controllers: &controllers,
+        collection,
+    };
+
+    let assert_context = AssertContext {
+        rule: &rule,
+        auth_config,
+    };
+
+    let current_doc = get_unexpired_state_doc(collection, operation_key(&operation), &rule)?;
+
+    match &operation {
+        CommitDocOperation::Set(_, key, value) => {
+            assert_set_doc_rules(&context, &assert_context, config, key, value, &current_doc)?
+        }
+        CommitDocOperation::Delete(_, key, value) => {
+            assert_delete_doc_rules(&context, &assert_context, key, value, &current_doc)?
+        }
+    };
+
+    Ok((operation, rule, current_doc))
+}
+
+fn apply_commit_doc_operation(
+    caller: UserId,
+    auth_config: &Option<AuthenticationConfig>,
+    (operation, rule, current_doc): PreparedCommitDocOperation,
+    results: &mut CommitDocsContext,
+) -> Result<(), String> {
+    let assert_context = AssertContext {
+        rule: &rule,
+        auth_config,
+    };
+
+    let assert_options = AssertSetDocOptions {
+        with_assert_rate: true,
+    };
+
+    match operation {
+        CommitDocOperation::Set(collection, key, value) => {
+            let controllers: AccessKeys =
+                get_db_access_keys(&collection, AccessKeyOperation::Write);
+
+            let context = StoreContext {
+                caller,
+                controllers: &controllers,
+                collection: &collection,
+            };
+
+            update_and_assert_set_doc_usage(
+                &context,
+                &assert_context,
+                &assert_options,
+                &value,
+                &current_doc,
+            )?;
+
+            let data =
+                apply_set_doc_impl(&context, &assert_context, key.clone(), value, current_doc)?;
+
+            results.set.push(DocContext {
+                key,
+                collection,
+                data,
+            });
+        }
+        CommitDocOperation::Delete(collection, key, _) => {
+            let controllers: AccessKeys =
+                get_db_access_keys(&collection, AccessKeyOperation::Write);
+
+            let context = StoreContext {
+                caller,
+                controllers: &controllers,
+                collection: &collection,
+            };
+
+            update_and_assert_delete_doc_usage(&context, &assert_context, &current_doc)?;
+
+            let data = delete_state_doc(&collection, &key, &rule)?;
+
+            results.deleted.push(DocContext {
+                key,
+                collection,
+                data,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+fn operation_key(operation: &CommitDocOperation) -> &Key {
+    match operation {
+        CommitDocOperation::Set(_, key, _) => key,
+        CommitDocOperation::Delete(_, key, _) => key,
+    }
+}
+
 // ---------------------------------------------------------
 // Config
 // ---------------------------------------------------------
@@ -605,3 +1109,227 @@ pub fn set_config_store(proposed_config: &SetDbConfig) -> Result<DbConfig, Strin
 pub fn get_config_store() -> Option<DbConfig> {
     get_config()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expiry::state::get_expired_entries;
+    use crate::expiry::types::state::ExpiryTarget;
+    use crate::memory::state::STATE;
+    use crate::rules::store::set_rule_db;
+    use crate::rules::test_utils::public_rule;
+    use junobuild_collections::types::interface::SetRule;
+    use junobuild_collections::types::rules::HistoryConfig;
+    use junobuild_shared::ic::api::id;
+    use junobuild_shared::types::list::{ListCursor, ListOrder, ListOrderField, ListPaginate};
+
+    fn init_collection(collection: &str) {
+        init_collection_with_history(collection, None);
+    }
+
+    fn init_collection_with_history(collection: &str, history: Option<HistoryConfig>) {
+        set_rule_db(
+            collection.to_string(),
+            SetRule {
+                history,
+                ..public_rule(Memory::Heap)
+            },
+        )
+        .unwrap();
+    }
+
+    fn set(collection: &str, key: &str, version: Option<u64>) -> CommitDocOperation {
+        CommitDocOperation::Set(
+            collection.to_string(),
+            key.to_string(),
+            SetDoc {
+                data: b"{}".to_vec(),
+                version,
+                ..Default::default()
+            },
+        )
+    }
+
+    fn get(collection: &str, key: &str) -> Option<Doc> {
+        let rule = get_state_rule(&collection.to_string()).unwrap();
+        get_state_doc(&collection.to_string(), &key.to_string(), &rule).unwrap()
+    }
+
+    #[test]
+    fn commits_all_operations() {
+        init_collection("orders");
+        init_collection("inventory");
+
+        let result = commit_docs_store(
+            id(),
+            vec![
+                set("orders", "order-1", None),
+                set("inventory", "item-1", None),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(result.set.len(), 2);
+        assert!(get("orders", "order-1").is_some());
+        assert!(get("inventory", "item-1").is_some());
+
+        let result = commit_docs_store(
+            id(),
+            vec![
+                set("orders", "order-1", Some(1)),
+                CommitDocOperation::Delete(
+                    "inventory".to_string(),
+                    "item-1".to_string(),
+                    DelDoc { version: Some(1) },
+                ),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(result.set.len(), 1);
+        assert_eq!(result.deleted.len(), 1);
+        assert_eq!(get("orders", "order-1").unwrap().version, Some(2));
+        assert!(get("inventory", "item-1").is_none());
+    }
+
+    #[test]
+    fn failing_batch_writes_nothing() {
+        init_collection("orders");
+        init_collection("inventory");
+
+        commit_docs_store(id(), vec![set("inventory", "item-1", None)]).unwrap();
+
+        // The second operation expects an outdated version of the document.
+        let result = commit_docs_store(
+            id(),
+            vec![
+                set("orders", "order-1", None),
+                set("inventory", "item-1", Some(3)),
+            ],
+        );
+
+        assert!(result.is_err());
+        assert!(get("orders", "order-1").is_none());
+        assert_eq!(get("inventory", "item-1").unwrap().version, Some(1));
+    }
+
+    #[test]
+    fn prunes_revisions_of_deleted_docs() {
+        init_collection_with_history(
+            "drafts",
+            Some(HistoryConfig {
+                max_revisions: None,
+                retention: Some(1),
+            }),
+        );
+
+        commit_docs_store(id(), vec![set("drafts", "draft-1", None)]).unwrap();
+        commit_docs_store(
+            id(),
+            vec![CommitDocOperation::Delete(
+                "drafts".to_string(),
+                "draft-1".to_string(),
+                DelDoc { version: Some(1) },
+            )],
+        )
+        .unwrap();
+
+        let count_revisions = || STATE.with(|state| state.borrow().stable.db_history.len());
+
+        assert_eq!(count_revisions(), 1);
+
+        let scheduled = get_expired_entries(u64::MAX, 10).into_iter().any(|entry| {
+            entry.target == ExpiryTarget::DocRevisions("drafts".to_string(), "draft-1".to_string())
+        });
+
+        assert!(scheduled);
+
+        let archived_at = STATE.with(|state| {
+            state
+                .borrow()
+                .stable
+                .db_history
+                .iter()
+                .next()
+                .map(|entry| entry.value().archived_at)
+                .unwrap()
+        });
+
+        let prune = |now: Timestamp| {
+            prune_expired_doc_revisions_store(&"drafts".to_string(), &"draft-1".to_string(), now)
+                .unwrap()
+        };
+
+        // Within the retention of the revision
+        prune(archived_at + 1);
+
+        assert_eq!(count_revisions(), 1);
+
+        // Once the retention of the revision elapsed
+        prune(archived_at + 2);
+
+        assert_eq!(count_revisions(), 0);
+    }
+
+    #[test]
+    fn lists_stable_docs_by_seeking_after_a_cursor() {
+        set_rule_db("notes".to_string(), public_rule(Memory::Stable)).unwrap();
+
+        for key in ["a", "b", "c", "d", "e"] {
+            set_doc_store(
+                id(),
+                "notes".to_string(),
+                key.to_string(),
+                SetDoc {
+                    data: b"{}".to_vec(),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        }
+
+        let list = |cursor: Option<ListCursor>, desc: bool| {
+            list_docs_store(
+                id(),
+                "notes".to_string(),
+                &ListParams {
+                    order: Some(ListOrder {
+                        desc,
+                        field: ListOrderField::Keys,
+                    }),
+                    paginate: Some(ListPaginate {
+                        start_after: None,
+                        limit: Some(2),
+                        cursor,
+                    }),
+                    ..Default::default()
+                },
+            )
+            .unwrap()
+        };
+
+        let keys = |results: &ListResults<Doc>| {
+            results
+                .items
+                .iter()
+                .map(|(key, _)| key.clone())
+                .collect::<Vec<_>>()
+        };
+
+        let first = list(None, false);
+        assert_eq!(keys(&first), vec!["a", "b"]);
+
+        let second = list(first.next_cursor, false);
+        assert_eq!(keys(&second), vec!["c", "d"]);
+
+        let third = list(second.next_cursor, false);
+        assert_eq!(keys(&third), vec!["e"]);
+        assert!(third.next_cursor.is_none());
+
+        let first = list(None, true);
+        assert_eq!(keys(&first), vec!["e", "d"]);
+
+        let second = list(first.next_cursor, true);
+        assert_eq!(keys(&second), vec!["c", "b"]);
+    }
+}
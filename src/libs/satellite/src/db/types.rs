@@ -4,13 +4,14 @@ pub mod state {
     use candid::CandidType;
     use ic_stable_structures::StableBTreeMap;
     use junobuild_collections::types::core::CollectionKey;
-    use junobuild_collections::types::rules::Rules;
+    use junobuild_collections::types::rules::{IndexField, Rules};
     use junobuild_shared::rate::types::RateTokenStore;
     use junobuild_shared::types::core::{Blob, Key};
+    use junobuild_shared::types::list::IndexValue;
     use junobuild_shared::types::memory::Memory;
     use junobuild_shared::types::state::{Timestamp, UserId, Version};
     use serde::{Deserialize, Serialize};
-    use std::collections::{BTreeMap, HashMap};
+    use std::collections::{BTreeMap, BTreeSet, HashMap};
 
     pub type Collection = BTreeMap<Key, Doc>;
     pub type DbHeap = HashMap<CollectionKey, Collection>;
@@ -23,13 +24,66 @@ pub mod state {
         pub key: Key,
     }
 
+    pub type DbIndexesStable = StableBTreeMap<IndexStableKey, (), Memory>;
+
+    /// An entry of a secondary index. The ordering of the fields matters: entries of a collection's
+    /// field are sorted by value and then by key, which lets lookups be resolved with a range.
+    #[derive(CandidType, Serialize, Deserialize, Clone, PartialEq, Eq, PartialOrd, Ord)]
+    pub struct IndexStableKey {
+        pub collection: CollectionKey,
+        pub field: IndexField,
+        pub value: IndexValue,
+        pub key: Key,
+    }
+
+    pub type DbHistoryStable = StableBTreeMap<RevisionStableKey, DocRevision, Memory>;
+
+    /// The key of a revision of a document. Revisions of a document are sorted by the `updated_at`
+    /// of the state they keep, which also identifies them.
+    #[derive(CandidType, Serialize, Deserialize, Clone, PartialEq, Eq, PartialOrd, Ord)]
+    pub struct RevisionStableKey {
+        pub collection: CollectionKey,
+        pub key: Key,
+        pub revision: Timestamp,
+    }
+
+    /// A previous state of a document, kept when the history of its collection is enabled.
+    ///
+    /// - `revision`: The identifier of the revision - the `updated_at` of the kept state.
+    /// - `doc`: The state of the document before it was updated or deleted.
+    /// - `archived_at`: When the state was replaced. Used to apply the retention period.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct DocRevision {
+        pub revision: Timestamp,
+        pub doc: Doc,
+        pub archived_at: Timestamp,
+    }
+
     #[derive(CandidType, Serialize, Deserialize, Clone)]
     pub struct DbHeapState {
         pub db: DbHeap,
         pub rules: Rules,
         pub config: Option<DbConfig>,
+        pub indexes_rebuilds: Option<IndexesRebuilds>,
+        pub history_deletions: Option<HistoryDeletions>,
+    }
+
+    pub type IndexesRebuilds = BTreeMap<CollectionKey, IndexesRebuild>;
+
+    /// The progress of the rebuild of the indexes of a collection, performed in batches.
+    ///
+    /// - `cleared`: Whether all the entries of the previous indexes have been removed.
+    /// - `cursor`: The key of the last document indexed again.
+    #[derive(Default, CandidType, Serialize, Deserialize, Clone)]
+    pub struct IndexesRebuild {
+        pub cleared: bool,
+        pub cursor: Option<Key>,
     }
 
+    /// The collections whose revisions are being removed in batches, because their history was
+    /// disabled or the collection was deleted.
+    pub type HistoryDeletions = BTreeSet<CollectionKey>;
+
     #[derive(Default, Clone)]
     pub struct DbRuntimeState {
         pub rate_tokens: RateTokenStore,
@@ -44,6 +98,7 @@ pub mod state {
     /// - `created_at`: A `u64` timestamp for the document's creation.
     /// - `updated_at`: A `u64` timestamp for the document's last update.
     /// - `version`: A `u64` number for the document's version. The field is optional for backwards compatibility but, will be populated to 1 on the first create or update.
+    /// - `expires_at`: An optional `u64` timestamp after which the document is considered deleted.
     ///
     /// This struct is used to store and manage documents within a collection's store.
     #[derive(CandidType, Serialize, Deserialize, Clone)]
@@ -54,6 +109,7 @@ pub mod state {
         pub created_at: Timestamp,
         pub updated_at: Timestamp,
         pub version: Option<Version>,
+        pub expires_at: Option<Timestamp>,
     }
 
     #[derive(CandidType, Serialize, Deserialize, Clone)]
@@ -69,6 +125,13 @@ pub mod state {
         pub after: Doc,
     }
 
+    /// The documents set and deleted by a commit, applied together.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct CommitDocsContext {
+        pub set: Vec<DocContext<DocUpsert>>,
+        pub deleted: Vec<DocContext<Option<Doc>>>,
+    }
+
     #[derive(CandidType, Serialize, Deserialize, Clone)]
     pub struct DocAssertSet {
         pub current: Option<Doc>,
@@ -102,8 +165,9 @@ pub mod config {
 pub mod interface {
     use crate::db::types::config::DbConfigMaxMemorySize;
     use candid::CandidType;
-    use junobuild_shared::types::core::Blob;
-    use junobuild_shared::types::state::Version;
+    use junobuild_collections::types::core::CollectionKey;
+    use junobuild_shared::types::core::{Blob, Key};
+    use junobuild_shared::types::state::{Timestamp, Version};
     use serde::{Deserialize, Serialize};
 
     /// Parameters for setting a document.
@@ -114,6 +178,7 @@ pub mod interface {
     /// - `description`: An optional `String` providing additional description for the document. This field is optional.
     /// - `version`: An optional `u64` version representing the last version of the document to ensure
     ///   update consistency. This field is optional - i.e. first time a document is saved, it can be left empty but following updates require the current version to be passed.
+    /// - `expires_at`: An optional `u64` timestamp after which the document is deleted. If left empty, the default time-to-live of the collection - if any - applies.
     ///
     /// `SetDoc` is used to provide parameters for setting or updating a document in the collection's store.
     #[derive(Default, CandidType, Serialize, Deserialize, Clone)]
@@ -121,6 +186,7 @@ pub mod interface {
         pub data: Blob,
         pub description: Option<String>,
         pub version: Option<Version>,
+        pub expires_at: Option<Timestamp>,
     }
 
     /// Parameters for deleting a document.
@@ -136,6 +202,31 @@ pub mod interface {
         pub version: Option<Version>,
     }
 
+    /// An operation of a commit of documents.
+    ///
+    /// - `Set`: Inserts or updates the document `key` of the `collection`.
+    /// - `Delete`: Deletes the document `key` of the `collection`.
+    ///
+    /// The `version` of the provided `SetDoc` or `DelDoc` acts as precondition: the operation - and
+    /// therefore the whole commit - is rejected if it does not match the current version of the document.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub enum CommitDocOperation {
+        Set(CollectionKey, Key, SetDoc),
+        Delete(CollectionKey, Key, DelDoc),
+    }
+
+    /// Parameters for restoring a revision of a document.
+    ///
+    /// - `revision`: The identifier of the revision to restore.
+    /// - `version`: The current version of the document, if any, as for `SetDoc`.
+    ///
+    /// The revision is restored as a new version of the document.
+    #[derive(Default, CandidType, Serialize, Deserialize, Clone)]
+    pub struct RestoreDocRevision {
+        pub revision: Timestamp,
+        pub version: Option<Version>,
+    }
+
     /// Parameters for setting the datastore configuration.
     ///
     /// This struct includes the following fields:
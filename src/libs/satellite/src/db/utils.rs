@@ -1,11 +1,13 @@
+use crate::db::data::{decode_data, match_data};
 use crate::db::types::state::Doc;
+use crate::user::roles::assert::assert_permission_with_user_roles;
 use candid::Principal;
-use junobuild_collections::assert::stores::assert_permission;
 use junobuild_collections::types::rules::Permission;
 use junobuild_shared::data::list::{filter_timestamps, matcher_regex};
+use junobuild_shared::ic::api::time;
 use junobuild_shared::types::core::Key;
-use junobuild_shared::types::list::ListParams;
-use junobuild_shared::types::state::{AccessKeys, UserId};
+use junobuild_shared::types::list::{DataMatcher, ListParams};
+use junobuild_shared::types::state::{AccessKeys, Expirable, UserId};
 use regex::Regex;
 
 pub fn filter_values<'a>(
@@ -22,14 +24,20 @@ pub fn filter_values<'a>(
 ) -> Result<Vec<(&'a Key, &'a Doc)>, String> {
     let (regex_key, regex_description) = matcher_regex(matcher)?;
 
+    let data_matcher = matcher.as_ref().and_then(|matcher| matcher.data.as_ref());
+
+    let now = time();
+
     let result = col
         .iter()
         .filter_map(|(key, doc)| {
-            if filter_key_matcher(&regex_key, key)
+            if !doc.is_expired(now)
+                && filter_key_matcher(&regex_key, key)
                 && filter_description_matcher(&regex_description, &doc.description)
                 && filter_owner(owner, &doc.owner)
                 && filter_timestamps(matcher, *doc)
-                && assert_permission(rule, doc.owner, caller, controllers)
+                && filter_data_matcher(data_matcher, &doc.data)
+                && assert_permission_with_user_roles(rule, doc.owner, caller, controllers)
             {
                 Some((*key, *doc))
             } else {
@@ -58,6 +66,16 @@ fn filter_description_matcher(regex: &Option<Regex>, description: &Option<String
     }
 }
 
+fn filter_data_matcher(matcher: Option<&DataMatcher>, data: &[u8]) -> bool {
+    match matcher {
+        None => true,
+        Some(matcher) => match decode_data(data) {
+            None => false,
+            Some(json) => match_data(&json, matcher),
+        },
+    }
+}
+
 fn filter_owner(owner: &Option<UserId>, doc_owner: &UserId) -> bool {
     match owner {
         None => true,
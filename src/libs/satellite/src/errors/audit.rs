@@ -0,0 +1,4 @@
+// The audit trail cannot be configured to keep fewer entries than the minimum - e.g. to erase the changes just made.
+pub const JUNO_AUDIT_ERROR_MAX_ENTRIES_TOO_LOW: &str = "juno.audit.error.max_entries_too_low";
+// The audit trail cannot be configured to keep its entries for less than the minimum retention.
+pub const JUNO_AUDIT_ERROR_RETENTION_TOO_SHORT: &str = "juno.audit.error.retention_too_short";
@@ -1,2 +1,18 @@
 pub const JUNO_DATASTORE_ERROR_CANNOT_WRITE: &str = "juno.datastore.error.cannot_write";
 pub const JUNO_DATASTORE_ERROR_CANNOT_READ: &str = "juno.datastore.error.cannot_read";
+// The index matcher targets a field that is not declared as index in the collection's rule.
+pub const JUNO_DATASTORE_ERROR_INDEX_NOT_FOUND: &str = "juno.datastore.error.index_not_found";
+// The indexes of the collection are being rebuilt after its rule was modified. Lookups are available once completed.
+pub const JUNO_DATASTORE_ERROR_INDEX_REBUILDING: &str = "juno.datastore.error.index_rebuilding";
+// A document is targeted by more than one operation of the same commit.
+pub const JUNO_DATASTORE_ERROR_COMMIT_DUPLICATE_DOC: &str =
+    "juno.datastore.error.commit_duplicate_doc";
+// The revision of the document does not exist or is no longer retained.
+pub const JUNO_DATASTORE_ERROR_REVISION_NOT_FOUND: &str = "juno.datastore.error.revision_not_found";
+// Documents of system collections cannot expire.
+pub const JUNO_DATASTORE_ERROR_EXPIRY_NOT_SUPPORTED: &str =
+    "juno.datastore.error.expiry_not_supported";
+// The JSON Schema declared on the collection's rule is malformed or uses unsupported keywords.
+pub const JUNO_DATASTORE_ERROR_INVALID_SCHEMA: &str = "juno.datastore.error.invalid_schema";
+// The data of the document does not satisfy the JSON Schema of the collection.
+pub const JUNO_DATASTORE_ERROR_INVALID_DATA: &str = "juno.datastore.error.invalid_data";
@@ -0,0 +1,2 @@
+// No custom HTTP endpoint serves the requested url.
+pub const JUNO_HTTP_ERROR_NO_ENDPOINT: &str = "juno.http.error.no_endpoint";
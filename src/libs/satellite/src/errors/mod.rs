@@ -1,4 +1,7 @@
+pub mod audit;
 pub mod auth;
 pub mod automation;
 pub mod db;
+pub mod http;
+pub mod storage;
 pub mod user;
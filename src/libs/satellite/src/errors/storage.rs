@@ -0,0 +1,25 @@
+// The uploaded image cannot be decoded, or one of the variants of the collection cannot be encoded.
+#[cfg(feature = "image")]
+pub const JUNO_STORAGE_ERROR_INVALID_IMAGE: &str = "juno.storage.error.invalid_image";
+// Signed URLs are only issued for assets protected by a token, the others are public.
+pub const JUNO_STORAGE_ERROR_ASSET_NOT_PROTECTED: &str = "juno.storage.error.asset_not_protected";
+// The expiry of a signed URL must be in the future.
+pub const JUNO_STORAGE_ERROR_SIGNED_URL_EXPIRED: &str = "juno.storage.error.signed_url_expired";
+// The signing key cannot be generated before the random number generator is initialized.
+pub const JUNO_STORAGE_ERROR_NO_SIGNING_KEY: &str = "juno.storage.error.no_signing_key";
+// The uploaded content does not match the content-type it is uploaded with - e.g. HTML uploaded as an image.
+pub const JUNO_STORAGE_ERROR_CONTENT_TYPE_MISMATCH: &str =
+    "juno.storage.error.content_type_mismatch";
+// Executables cannot be uploaded to a collection that validates its content.
+pub const JUNO_STORAGE_ERROR_EXECUTABLE_NOT_ALLOWED: &str =
+    "juno.storage.error.executable_not_allowed";
+// SVG images with scripts - e.g. an onload attribute - cannot be uploaded to a collection that validates its content.
+pub const JUNO_STORAGE_ERROR_SVG_SCRIPT_NOT_ALLOWED: &str =
+    "juno.storage.error.svg_script_not_allowed";
+// The uploaded image exceeds the bounds of the content validation of the collection.
+pub const JUNO_STORAGE_ERROR_IMAGE_DIMENSIONS_EXCEEDED: &str =
+    "juno.storage.error.image_dimensions_exceeded";
+// Images cannot be processed - e.g. to generate variants - by a Satellite built without the image feature.
+#[cfg(not(feature = "image"))]
+pub const JUNO_STORAGE_ERROR_IMAGE_PROCESSING_DISABLED: &str =
+    "juno.storage.error.image_processing_disabled";
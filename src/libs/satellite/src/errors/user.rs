@@ -7,6 +7,21 @@ pub const JUNO_DATASTORE_ERROR_USER_KEY_NO_PRINCIPAL: &str =
     "juno.datastore.error.user.key_no_principal";
 // Banned
 pub const JUNO_DATASTORE_ERROR_USER_NOT_ALLOWED: &str = "juno.datastore.error.user.not_allowed";
+// A temporary ban must provide an expiration.
+pub const JUNO_DATASTORE_ERROR_USER_BAN_NO_EXPIRATION: &str =
+    "juno.datastore.error.user.ban_no_expiration";
+// The expiration of a ban must be in the future.
+pub const JUNO_DATASTORE_ERROR_USER_BAN_EXPIRED: &str = "juno.datastore.error.user.ban_expired";
+// Sessions revoked
+pub const JUNO_DATASTORE_ERROR_USER_SESSIONS_REVOKED: &str =
+    "juno.datastore.error.user.sessions_revoked";
+// No user registered with the principal {}.
+pub const JUNO_DATASTORE_ERROR_USER_NOT_FOUND: &str = "juno.datastore.error.user.not_found";
+// Roles must be unique and made of alphanumeric characters, dashes or underscores.
+pub const JUNO_DATASTORE_ERROR_USER_INVALID_ROLES: &str = "juno.datastore.error.user.invalid_roles";
+// Only a controller can grant roles to a user.
+pub const JUNO_DATASTORE_ERROR_USER_ROLES_NOT_ALLOWED: &str =
+    "juno.datastore.error.user.roles_not_allowed";
 // The AAGUID (Authenticator Attestation GUID) must be exactly 16 bytes.
 pub const JUNO_DATASTORE_ERROR_USER_AAGUID_INVALID_LENGTH: &str =
     "juno.datastore.error.user.webauthn.aaguid_invalid_length";
@@ -21,6 +36,11 @@ pub const JUNO_DATASTORE_ERROR_USER_REGISTER_PROVIDER_INVALID_DATA: &str =
 // Change limit reached.
 pub const JUNO_DATASTORE_ERROR_USER_USAGE_CHANGE_LIMIT_REACHED: &str =
     "juno.datastore.error.user.usage.change_limit_reached";
+// Bytes limit reached, in the collection or in the whole satellite.
+pub const JUNO_DATASTORE_ERROR_USER_USAGE_BYTES_LIMIT_REACHED: &str =
+    "juno.datastore.error.user.usage.bytes_limit_reached";
+pub const JUNO_DATASTORE_ERROR_USER_USAGE_TOTAL_BYTES_LIMIT_REACHED: &str =
+    "juno.datastore.error.user.usage.total_bytes_limit_reached";
 pub const JUNO_DATASTORE_ERROR_USER_USAGE_INVALID_DATA: &str =
     "juno.datastore.error.user.usage.invalid_data";
 
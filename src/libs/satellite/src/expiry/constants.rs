@@ -0,0 +1,2 @@
+// The maximum number of entries processed per purge to keep its cost bounded.
+pub const EXPIRY_PURGE_MAX_ENTRIES: usize = 100;
@@ -0,0 +1,5 @@
+mod constants;
+mod impls;
+pub mod purge;
+pub mod state;
+pub mod types;
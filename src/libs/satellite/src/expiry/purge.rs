@@ -0,0 +1,199 @@
+use crate::assets::storage::store::{
+    delete_expired_asset_store, delete_expired_upload_batch_store,
+};
+use crate::db::store::{delete_expired_doc_store, prune_expired_doc_revisions_store};
+use crate::db::types::state::DocContext;
+use crate::expiry::constants::EXPIRY_PURGE_MAX_ENTRIES;
+use crate::expiry::state::{
+    delete_expiry, get_expired_entries, get_expiry_purge_timer, get_next_expiry,
+    set_expiry_purge_timer,
+};
+use crate::expiry::types::runtime::ExpiryPurgeTimer;
+use crate::expiry::types::state::ExpiryTarget;
+use crate::hooks::db::invoke_on_delete_many_docs;
+use crate::hooks::storage::invoke_on_delete_many_assets;
+use crate::Doc;
+use ic_cdk_timers::{clear_timer, TimerId};
+use junobuild_shared::ic::api::id;
+use junobuild_shared::ic::api::time;
+use junobuild_shared::types::state::Timestamp;
+use junobuild_storage::types::store::Asset;
+use std::time::Duration;
+
+/// Arms the purge for the oldest entry of the schedule - if any - since timers do not survive an
+/// upgrade.
+pub fn init_expiry_purge_timer() {
+    if let Some(deadline) = get_next_expiry() {
+        schedule_expiry_purge(deadline);
+    }
+}
+
+/// Arms a one-shot timer that purges the entries expired at `deadline`, unless a purge is already
+/// armed for an earlier deadline. No timer is running as long as nothing is scheduled to expire.
+pub fn schedule_expiry_purge(deadline: Timestamp) {
+    let armed = get_expiry_purge_timer();
+
+    if armed
+        .as_ref()
+        .is_some_and(|armed| armed.deadline <= deadline)
+    {
+        return;
+    }
+
+    if let Some(armed) = armed {
+        clear_timer(armed.timer_id);
+    }
+
+    // Entries are purged once strictly expired, hence one nanosecond after their deadline.
+    let delay = deadline.saturating_add(1).saturating_sub(time());
+
+    let timer_id = set_purge_timer(Duration::from_nanos(delay), purge_expired_entries);
+
+    set_expiry_purge_timer(Some(ExpiryPurgeTimer { deadline, timer_id }));
+}
+
+#[cfg(target_arch = "wasm32")]
+fn set_purge_timer(delay: Duration, purge: fn()) -> TimerId {
+    ic_cdk_timers::set_timer(delay, async move { purge() })
+}
+
+// Timers are only available in canisters.
+#[cfg(not(target_arch = "wasm32"))]
+fn set_purge_timer(_delay: Duration, _purge: fn()) -> TimerId {
+    TimerId::default()
+}
+
+fn purge_expired_entries() {
+    purge_expired_entries_impl(time());
+}
+
+/// Deletes the documents and assets that are expired at `now` and fires the related hooks, the
+/// Satellite being the caller.
+///
+/// At most `EXPIRY_PURGE_MAX_ENTRIES` entries are processed. The purge is then armed again for the
+/// oldest remaining entry - right away if more are already due.
+fn purge_expired_entries_impl(now: Timestamp) {
+    // The timer that triggered this purge has fired
+    set_expiry_purge_timer(None);
+
+    let entries = get_expired_entries(now, EXPIRY_PURGE_MAX_ENTRIES);
+
+    let mut docs: Vec<DocContext<Option<Doc>>> = Vec::new();
+    let mut assets: Vec<Option<Asset>> = Vec::new();
+
+    for entry in &entries {
+        delete_expiry(entry);
+
+        // Errors - e.g. the collection was deleted in the meantime - only mean there is nothing left to purge.
+        match &entry.target {
+            ExpiryTarget::Doc(collection, key) => {
+                if let Ok(Some(doc)) =
+                    delete_expired_doc_store(collection, key, entry.expires_at, now)
+                {
+                    docs.push(doc);
+                }
+            }
+            ExpiryTarget::Asset(collection, full_path) => {
+                if let Ok(Some(asset)) =
+                    delete_expired_asset_store(collection, full_path, entry.expires_at, now)
+                {
+                    assets.push(Some(asset));
+                }
+            }
+            ExpiryTarget::DocRevisions(collection, key) => {
+                let _ = prune_expired_doc_revisions_store(collection, key, now);
+            }
+            ExpiryTarget::UploadBatch(batch_id) => {
+                delete_expired_upload_batch_store(batch_id, entry.expires_at, now);
+            }
+        }
+    }
+
+    let caller = id();
+
+    if !docs.is_empty() {
+        invoke_on_delete_many_docs(&caller, &docs);
+    }
+
+    if !assets.is_empty() {
+        invoke_on_delete_many_assets(&caller, &assets);
+    }
+
+    init_expiry_purge_timer();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::store::{get_doc_store, set_doc_store};
+    use crate::db::types::interface::SetDoc;
+    use crate::expiry::state::insert_expiry;
+    use crate::rules::store::set_rule_db;
+    use crate::rules::test_utils::public_rule;
+    use junobuild_collections::types::rules::Memory;
+
+    fn init_collection(collection: &str) {
+        set_rule_db(collection.to_string(), public_rule(Memory::Heap)).unwrap();
+    }
+
+    fn armed_deadline() -> Option<Timestamp> {
+        get_expiry_purge_timer().map(|timer| timer.deadline)
+    }
+
+    #[test]
+    fn arms_no_purge_without_expiries() {
+        init_expiry_purge_timer();
+
+        assert!(armed_deadline().is_none());
+    }
+
+    #[test]
+    fn arms_purge_for_the_earliest_deadline() {
+        let target = |key: &str| ExpiryTarget::Doc("sessions".to_string(), key.to_string());
+
+        insert_expiry(target("a"), 200);
+        assert_eq!(armed_deadline(), Some(200));
+
+        insert_expiry(target("b"), 100);
+        assert_eq!(armed_deadline(), Some(100));
+
+        insert_expiry(target("c"), 300);
+        assert_eq!(armed_deadline(), Some(100));
+    }
+
+    #[test]
+    fn purges_expired_docs_and_arms_the_next_deadline() {
+        init_collection("sessions");
+
+        let caller = id();
+        let now = time();
+        let later = now.saturating_add(3_600_000_000_000);
+
+        for (key, expires_at) in [
+            ("expired", now.saturating_add(50_000_000)),
+            ("pending", later),
+        ] {
+            set_doc_store(
+                caller,
+                "sessions".to_string(),
+                key.to_string(),
+                SetDoc {
+                    data: b"{}".to_vec(),
+                    expires_at: Some(expires_at),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        }
+
+        // Once the first document expired
+        purge_expired_entries_impl(now.saturating_add(100_000_000));
+
+        let get = |key: &str| get_doc_store(caller, "sessions".to_string(), key.to_string());
+
+        assert!(get("expired").unwrap().is_none());
+        assert!(get("pending").unwrap().is_some());
+        assert_eq!(get_next_expiry(), Some(later));
+        assert_eq!(armed_deadline(), Some(later));
+    }
+}
@@ -0,0 +1,65 @@
+use crate::expiry::purge::schedule_expiry_purge;
+use crate::expiry::types::runtime::ExpiryPurgeTimer;
+use crate::expiry::types::state::{ExpiryStableKey, ExpiryTarget};
+use crate::memory::state::STATE;
+use junobuild_shared::types::state::Timestamp;
+
+// ---------------------------------------------------------
+// Schedule
+// ---------------------------------------------------------
+
+/// Adds an entry to the expiration schedule and arms the purge for its deadline if no purge is
+/// armed for an earlier one.
+pub fn insert_expiry(target: ExpiryTarget, expires_at: Timestamp) {
+    STATE.with(|state| {
+        state
+            .borrow_mut()
+            .stable
+            .expiries
+            .insert(ExpiryStableKey { expires_at, target }, ())
+    });
+
+    schedule_expiry_purge(expires_at);
+}
+
+pub fn delete_expiry(entry: &ExpiryStableKey) {
+    STATE.with(|state| state.borrow_mut().stable.expiries.remove(entry));
+}
+
+/// Returns the entries of the schedule that are expired, the oldest first, up to `limit`.
+pub fn get_expired_entries(now: Timestamp, limit: usize) -> Vec<ExpiryStableKey> {
+    STATE.with(|state| {
+        state
+            .borrow()
+            .stable
+            .expiries
+            .keys()
+            .take_while(|entry| entry.expires_at < now)
+            .take(limit)
+            .collect()
+    })
+}
+
+/// Returns the deadline of the oldest entry of the schedule, if any.
+pub fn get_next_expiry() -> Option<Timestamp> {
+    STATE.with(|state| {
+        state
+            .borrow()
+            .stable
+            .expiries
+            .first_key_value()
+            .map(|(entry, _)| entry.expires_at)
+    })
+}
+
+// ---------------------------------------------------------
+// Purge timer
+// ---------------------------------------------------------
+
+pub fn get_expiry_purge_timer() -> Option<ExpiryPurgeTimer> {
+    STATE.with(|state| state.borrow().runtime.expiry.purge.clone())
+}
+
+pub fn set_expiry_purge_timer(timer: Option<ExpiryPurgeTimer>) {
+    STATE.with(|state| state.borrow_mut().runtime.expiry.purge = timer);
+}
@@ -0,0 +1,50 @@
+pub mod state {
+    use candid::CandidType;
+    use ic_stable_structures::StableBTreeMap;
+    use junobuild_collections::types::core::CollectionKey;
+    use junobuild_shared::types::core::Key;
+    use junobuild_shared::types::memory::Memory;
+    use junobuild_shared::types::state::Timestamp;
+    use junobuild_storage::types::runtime_state::BatchId;
+    use junobuild_storage::types::state::FullPath;
+    use serde::{Deserialize, Serialize};
+
+    pub type ExpiriesStable = StableBTreeMap<ExpiryStableKey, (), Memory>;
+
+    /// An entry of the expiration schedule. The ordering of the fields matters: entries are sorted
+    /// by expiration first, which lets the purge collect the entries that are due from the start.
+    ///
+    /// The schedule is not updated when a document or an asset is replaced or deleted. Instead,
+    /// the purge compares the entry with the current expiration of its target before deleting it.
+    #[derive(CandidType, Serialize, Deserialize, Clone, PartialEq, Eq, PartialOrd, Ord)]
+    pub struct ExpiryStableKey {
+        pub expires_at: Timestamp,
+        pub target: ExpiryTarget,
+    }
+
+    #[derive(CandidType, Serialize, Deserialize, Clone, PartialEq, Eq, PartialOrd, Ord)]
+    pub enum ExpiryTarget {
+        Doc(CollectionKey, Key),
+        Asset(CollectionKey, FullPath),
+        DocRevisions(CollectionKey, Key),
+        UploadBatch(BatchId),
+    }
+}
+
+pub mod runtime {
+    use ic_cdk_timers::TimerId;
+    use junobuild_shared::types::state::Timestamp;
+
+    #[derive(Default, Clone)]
+    pub struct ExpiryRuntimeState {
+        pub purge: Option<ExpiryPurgeTimer>,
+    }
+
+    /// The one-shot timer of the next purge and the deadline it was armed for. Timers do not
+    /// survive an upgrade, therefore it is kept in the runtime state.
+    #[derive(Clone)]
+    pub struct ExpiryPurgeTimer {
+        pub deadline: Timestamp,
+        pub timer_id: TimerId,
+    }
+}
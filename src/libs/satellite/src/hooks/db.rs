@@ -226,3 +226,40 @@ fn is_log_collection(collection: &CollectionKey) -> bool {
 fn is_not_log_collection(collection: &CollectionKey) -> bool {
     !is_log_collection(collection)
 }
+
+// The hooks are implemented by the Satellite that includes the crate. Unit tests provide
+// hooks and assertions that accept any change and that apply to no collection.
+#[cfg(test)]
+mod test_hooks {
+    use crate::types::hooks::{
+        AssertDeleteDocContext, AssertSetDocContext, OnDeleteManyDocsContext,
+    };
+
+    #[no_mangle]
+    fn juno_on_delete_many_docs(_context: OnDeleteManyDocsContext) {}
+
+    #[no_mangle]
+    fn juno_on_delete_many_docs_collections() -> Option<Vec<String>> {
+        Some(Vec::new())
+    }
+
+    #[no_mangle]
+    fn juno_assert_set_doc(_context: AssertSetDocContext) -> Result<(), String> {
+        Ok(())
+    }
+
+    #[no_mangle]
+    fn juno_assert_delete_doc(_context: AssertDeleteDocContext) -> Result<(), String> {
+        Ok(())
+    }
+
+    #[no_mangle]
+    fn juno_assert_set_doc_collections() -> Option<Vec<String>> {
+        Some(Vec::new())
+    }
+
+    #[no_mangle]
+    fn juno_assert_delete_doc_collections() -> Option<Vec<String>> {
+        Some(Vec::new())
+    }
+}
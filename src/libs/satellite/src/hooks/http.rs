@@ -0,0 +1,112 @@
+#![allow(dead_code)]
+
+#[cfg(not(feature = "on_http_request"))]
+use crate::errors::http::JUNO_HTTP_ERROR_NO_ENDPOINT;
+use crate::types::hooks::{OnHttpRequestContext, OnHttpRequestResponse};
+use junobuild_storage::types::state::FullPath;
+
+extern "Rust" {
+    fn juno_on_http_request(context: OnHttpRequestContext)
+        -> Result<OnHttpRequestResponse, String>;
+
+    fn juno_on_http_request_paths() -> Option<Vec<String>>;
+}
+
+/// The path prefixes served by the custom HTTP endpoints, without trailing slash. The root cannot be
+/// an endpoint given that it would shadow all the assets.
+pub fn invoke_http_endpoints() -> Vec<FullPath> {
+    #[cfg(feature = "on_http_request")]
+    {
+        let paths = unsafe { juno_on_http_request_paths() };
+
+        if let Some(paths) = paths {
+            return http_endpoints(&paths);
+        }
+    }
+
+    Vec::new()
+}
+
+fn http_endpoints(paths: &[String]) -> Vec<FullPath> {
+    paths
+        .iter()
+        .filter(|path| path.starts_with('/'))
+        .map(|path| path.trim_end_matches('/').to_string())
+        .filter(|path| !path.is_empty())
+        .collect()
+}
+
+#[cfg(feature = "on_http_request")]
+pub fn invoke_on_http_request(
+    context: OnHttpRequestContext,
+) -> Result<OnHttpRequestResponse, String> {
+    unsafe { juno_on_http_request(context) }
+}
+
+// Not reachable without endpoints, which are only served when the hook is enabled.
+#[cfg(not(feature = "on_http_request"))]
+pub fn invoke_on_http_request(
+    _context: OnHttpRequestContext,
+) -> Result<OnHttpRequestResponse, String> {
+    Err(JUNO_HTTP_ERROR_NO_ENDPOINT.to_string())
+}
+
+// The hooks are implemented by the Satellite that includes the crate. Unit tests provide
+// hooks that serve no endpoint.
+#[cfg(all(test, feature = "on_http_request"))]
+mod test_hooks {
+    use crate::errors::http::JUNO_HTTP_ERROR_NO_ENDPOINT;
+    use crate::types::hooks::{OnHttpRequestContext, OnHttpRequestResponse};
+
+    #[no_mangle]
+    fn juno_on_http_request(
+        _context: OnHttpRequestContext,
+    ) -> Result<OnHttpRequestResponse, String> {
+        Err(JUNO_HTTP_ERROR_NO_ENDPOINT.to_string())
+    }
+
+    #[no_mangle]
+    fn juno_on_http_request_paths() -> Option<Vec<String>> {
+        Some(Vec::new())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_the_paths_of_the_endpoints() {
+        let paths = ["/api/webhooks/", "/api/health", "relative", "/", "//", ""].map(String::from);
+
+        assert_eq!(
+            http_endpoints(&paths),
+            vec!["/api/webhooks".to_string(), "/api/health".to_string()]
+        );
+    }
+
+    #[test]
+    fn serves_no_endpoint_without_paths() {
+        use crate::errors::http::JUNO_HTTP_ERROR_NO_ENDPOINT;
+        use candid::Principal;
+        use junobuild_storage::http::types::HttpRequest;
+
+        assert!(invoke_http_endpoints().is_empty());
+
+        let context = OnHttpRequestContext {
+            caller: Principal::anonymous(),
+            data: HttpRequest {
+                url: "/api/webhooks".to_string(),
+                method: "POST".to_string(),
+                headers: Vec::new(),
+                body: Vec::new(),
+                certificate_version: None,
+            },
+        };
+
+        assert_eq!(
+            invoke_on_http_request(context).err(),
+            Some(JUNO_HTTP_ERROR_NO_ENDPOINT.to_string())
+        );
+    }
+}
@@ -1,4 +1,5 @@
 pub mod db;
+pub mod http;
 pub mod lifecycle;
 pub mod random;
 pub mod storage;
@@ -222,3 +222,18 @@ fn is_system_collection(collection: &CollectionKey) -> bool {
 fn is_not_system_collection(collection: &CollectionKey) -> bool {
     !is_system_collection(collection)
 }
+
+// The hooks are implemented by the Satellite that includes the crate. Unit tests provide
+// hooks that apply to no collection.
+#[cfg(test)]
+mod test_hooks {
+    use crate::types::hooks::OnDeleteManyAssetsContext;
+
+    #[no_mangle]
+    fn juno_on_delete_many_assets(_context: OnDeleteManyAssetsContext) {}
+
+    #[no_mangle]
+    fn juno_on_delete_many_assets_collections() -> Option<Vec<String>> {
+        Some(Vec::new())
+    }
+}
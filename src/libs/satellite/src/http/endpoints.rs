@@ -0,0 +1,173 @@
+use crate::assets::storage::strategy_impls::StorageState;
+use crate::caller;
+use crate::certification::strategy_impls::StorageCertificate;
+use crate::errors::http::JUNO_HTTP_ERROR_NO_ENDPOINT;
+use crate::hooks::http::invoke_on_http_request;
+use crate::types::hooks::{OnHttpRequestContext, OnHttpRequestResponse};
+use junobuild_storage::http::response::{build_endpoint_response, build_upgrade_response};
+use junobuild_storage::http::types::{HttpRequest, HttpResponse};
+use junobuild_storage::routing::get_http_endpoint;
+use junobuild_storage::runtime::is_certification_skipped;
+use junobuild_storage::types::state::FullPath;
+
+/// Returns the custom HTTP endpoint that serves the request, if any.
+pub fn find_http_endpoint(request: &HttpRequest) -> Option<FullPath> {
+    get_http_endpoint(&request.url, &StorageState)
+}
+
+/// Serves a request to a custom HTTP endpoint in a query.
+///
+/// Only GET and HEAD requests are answered in the query, with a response which certification is
+/// skipped. The other requests - or those which certification cannot be skipped, e.g. in v1 or as
+/// long as the endpoint is not yet part of the certified tree - are upgraded to an update call.
+pub fn http_request_endpoint(
+    request: HttpRequest,
+    endpoint: &FullPath,
+) -> Result<HttpResponse, String> {
+    let query = matches!(request.method.as_str(), "GET" | "HEAD")
+        && request.certificate_version == Some(2)
+        && is_certification_skipped(endpoint);
+
+    if !query {
+        return Ok(build_upgrade_response());
+    }
+
+    let url = request.url.clone();
+    let certificate_version = request.certificate_version;
+
+    let OnHttpRequestResponse {
+        status_code,
+        headers,
+        body,
+    } = handle_http_request(request)?;
+
+    Ok(build_endpoint_response(
+        url,
+        certificate_version,
+        endpoint,
+        status_code,
+        headers,
+        body,
+        &StorageCertificate,
+    ))
+}
+
+/// Serves a request to a custom HTTP endpoint that was upgraded to an update call.
+pub fn http_request_update_endpoint(request: HttpRequest) -> Result<HttpResponse, String> {
+    if find_http_endpoint(&request).is_none() {
+        return Err(JUNO_HTTP_ERROR_NO_ENDPOINT.to_string());
+    }
+
+    let OnHttpRequestResponse {
+        status_code,
+        headers,
+        body,
+    } = handle_http_request(request)?;
+
+    Ok(HttpResponse {
+        body,
+        headers,
+        status_code,
+        streaming_strategy: None,
+        upgrade: None,
+    })
+}
+
+fn handle_http_request(request: HttpRequest) -> Result<OnHttpRequestResponse, String> {
+    let context: OnHttpRequestContext = OnHttpRequestContext {
+        caller: caller(),
+        data: request,
+    };
+
+    invoke_on_http_request(context)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::http::JUNO_HTTP_ERROR_NO_ENDPOINT;
+    use junobuild_storage::certification::types::certified::CertifiedAssetHashes;
+    use junobuild_storage::runtime::init_certified_assets;
+
+    const ENDPOINT: &str = "/api/webhooks";
+
+    fn request(method: &str, certificate_version: Option<u16>) -> HttpRequest {
+        HttpRequest {
+            url: format!("{ENDPOINT}/stripe"),
+            method: method.to_string(),
+            headers: Vec::new(),
+            body: Vec::new(),
+            certificate_version,
+        }
+    }
+
+    fn skip_certification() {
+        let mut asset_hashes = CertifiedAssetHashes::default();
+        asset_hashes.insert_skip_v2(&ENDPOINT.to_string());
+
+        init_certified_assets(&asset_hashes);
+    }
+
+    fn is_upgrade(response: Result<HttpResponse, String>) -> bool {
+        matches!(
+            response,
+            Ok(HttpResponse {
+                upgrade: Some(true),
+                ..
+            })
+        )
+    }
+
+    #[test]
+    fn upgrades_requests_that_cannot_be_answered_in_a_query() {
+        skip_certification();
+
+        let endpoint = ENDPOINT.to_string();
+
+        assert!(is_upgrade(http_request_endpoint(
+            request("POST", Some(2)),
+            &endpoint
+        )));
+        assert!(is_upgrade(http_request_endpoint(
+            request("GET", Some(1)),
+            &endpoint
+        )));
+        assert!(is_upgrade(http_request_endpoint(
+            request("GET", None),
+            &endpoint
+        )));
+    }
+
+    #[test]
+    fn upgrades_requests_as_long_as_the_certification_is_not_skipped() {
+        init_certified_assets(&CertifiedAssetHashes::default());
+
+        assert!(is_upgrade(http_request_endpoint(
+            request("GET", Some(2)),
+            &ENDPOINT.to_string()
+        )));
+    }
+
+    #[test]
+    fn answers_in_a_query_when_the_certification_is_skipped() {
+        skip_certification();
+
+        // The request reaches the hook, which serves no endpoint in the tests
+        let response = http_request_endpoint(request("GET", Some(2)), &ENDPOINT.to_string());
+
+        assert_eq!(
+            response.err(),
+            Some(JUNO_HTTP_ERROR_NO_ENDPOINT.to_string())
+        );
+    }
+
+    #[test]
+    fn rejects_updates_without_endpoint() {
+        let response = http_request_update_endpoint(request("POST", Some(2)));
+
+        assert_eq!(
+            response.err(),
+            Some(JUNO_HTTP_ERROR_NO_ENDPOINT.to_string())
+        );
+    }
+}
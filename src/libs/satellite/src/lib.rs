@@ -3,12 +3,15 @@
 mod access_keys;
 mod api;
 mod assets;
+mod audit;
 mod auth;
 mod automation;
 mod certification;
 mod db;
 mod errors;
+mod expiry;
 mod hooks;
+mod http;
 mod impls;
 mod logs;
 mod memory;
@@ -18,6 +21,8 @@ mod sdk;
 mod types;
 mod user;
 
+use crate::audit::types::config::AuditConfig;
+use crate::audit::types::interface::{ListAuditLogsParams, ListAuditLogsResults, SetAuditConfig};
 use crate::db::types::config::DbConfig;
 use crate::db::types::interface::SetDbConfig;
 use crate::types::interface::{
@@ -26,6 +31,13 @@ use crate::types::interface::{
     GetDelegationResultResponse,
 };
 use crate::types::state::CollectionType;
+use crate::user::bans::types::interface::{
+    BanUserArgs, BannedUser, ListBannedUsersParams, ListBannedUsersResults,
+};
+use crate::user::roles::types::interface::UserRolesArgs;
+use crate::user::sessions::types::state::UserSessions;
+use crate::user::usage::types::config::UserUsageConfig;
+use crate::user::usage::types::interface::{SetUserUsageConfig, UserUsage};
 use automation::types::AuthenticateAutomationArgs;
 use ic_cdk_macros::{init, post_upgrade, pre_upgrade, query, update};
 use junobuild_auth::state::types::automation::AutomationConfig;
@@ -39,7 +51,7 @@ use junobuild_collections::types::core::CollectionKey;
 use junobuild_collections::types::interface::{
     DelRule, ListRulesParams, ListRulesResults, SetRule,
 };
-use junobuild_collections::types::rules::Rule;
+use junobuild_collections::types::rules::{RoleName, Rule};
 use junobuild_shared::ic::response::ManualReply;
 use junobuild_shared::ic::UnwrapOrTrap;
 use junobuild_shared::types::core::DomainName;
@@ -50,15 +62,16 @@ use junobuild_shared::types::interface::{
 };
 use junobuild_shared::types::list::ListParams;
 use junobuild_shared::types::list::ListResults;
-use junobuild_shared::types::state::AccessKeys;
+use junobuild_shared::types::state::{AccessKeys, Timestamp, UserId};
 use junobuild_storage::http::types::{
     HttpRequest, HttpResponse, StreamingCallbackHttpResponse, StreamingCallbackToken,
 };
 use junobuild_storage::types::config::StorageConfig;
 use junobuild_storage::types::interface::{
     AssetNoContent, CommitBatch, InitAssetKey, InitUploadResult, SetStorageConfig,
-    SetStorageConfigWithOptions, UploadChunk, UploadChunkResult,
+    SetStorageConfigWithOptions, UploadChunk, UploadChunkResult, UploadSession,
 };
+use junobuild_storage::types::runtime_state::BatchId;
 use junobuild_storage::types::state::{AssetAccessToken, FullPath};
 use memory::lifecycle;
 
@@ -142,6 +155,28 @@ pub fn del_many_docs(docs: Vec<(CollectionKey, Key, DelDoc)>) {
     api::db::del_many_docs(docs)
 }
 
+#[doc(hidden)]
+#[update]
+pub fn commit_docs(operations: Vec<CommitDocOperation>) -> Vec<(Key, Doc)> {
+    api::db::commit_docs(operations)
+}
+
+#[doc(hidden)]
+#[query]
+pub fn list_doc_revisions(collection: CollectionKey, key: Key) -> Vec<DocRevision> {
+    api::db::list_doc_revisions(collection, key)
+}
+
+#[doc(hidden)]
+#[update]
+pub fn restore_doc_revision(
+    collection: CollectionKey,
+    key: Key,
+    revision: RestoreDocRevision,
+) -> Doc {
+    api::db::restore_doc_revision(collection, key, revision)
+}
+
 #[doc(hidden)]
 #[update]
 pub fn del_filtered_docs(collection: CollectionKey, filter: ListParams) {
@@ -218,6 +253,76 @@ pub fn switch_storage_system_memory() {
     api::rules::switch_storage_system_memory()
 }
 
+// ---------------------------------------------------------
+// User usage
+// ---------------------------------------------------------
+
+#[doc(hidden)]
+#[query]
+pub fn get_user_usage() -> UserUsage {
+    api::user::get_user_usage()
+}
+
+// ---------------------------------------------------------
+// User roles
+// ---------------------------------------------------------
+
+#[doc(hidden)]
+#[update(guard = "caller_is_admin")]
+pub fn assign_user_roles(args: UserRolesArgs) -> Vec<RoleName> {
+    api::user::assign_user_roles(args)
+}
+
+#[doc(hidden)]
+#[update(guard = "caller_is_admin")]
+pub fn revoke_user_roles(args: UserRolesArgs) -> Vec<RoleName> {
+    api::user::revoke_user_roles(args)
+}
+
+// ---------------------------------------------------------
+// User sessions
+// ---------------------------------------------------------
+
+#[doc(hidden)]
+#[query(guard = "caller_is_admin")]
+pub fn get_user_sessions(user: UserId) -> Option<UserSessions> {
+    api::user::get_user_sessions(user)
+}
+
+#[doc(hidden)]
+#[update(guard = "caller_is_admin")]
+pub fn revoke_user_sessions(user: UserId) -> UserSessions {
+    api::user::revoke_user_sessions(user)
+}
+
+#[doc(hidden)]
+#[update(guard = "caller_is_admin")]
+pub fn clear_user_sessions_revocation(user: UserId) -> Option<UserSessions> {
+    api::user::clear_user_sessions_revocation(user)
+}
+
+// ---------------------------------------------------------
+// User bans
+// ---------------------------------------------------------
+
+#[doc(hidden)]
+#[update(guard = "caller_is_admin")]
+pub fn ban_user(args: BanUserArgs) -> BannedUser {
+    api::user::ban_user(args)
+}
+
+#[doc(hidden)]
+#[update(guard = "caller_is_admin")]
+pub fn unban_user(user: UserId) {
+    api::user::unban_user(user)
+}
+
+#[doc(hidden)]
+#[query(guard = "caller_is_admin")]
+pub fn list_banned_users(params: ListBannedUsersParams) -> ListBannedUsersResults {
+    api::user::list_banned_users(params)
+}
+
 // ---------------------------------------------------------
 // Controllers
 // ---------------------------------------------------------
@@ -437,6 +542,44 @@ pub fn get_storage_config() -> StorageConfig {
     api::config::get_storage_config()
 }
 
+// ---------------------------------------------------------
+// Audit
+// ---------------------------------------------------------
+
+#[doc(hidden)]
+#[update(guard = "caller_is_admin")]
+pub fn set_audit_config(config: SetAuditConfig) -> AuditConfig {
+    api::config::set_audit_config(config)
+}
+
+#[doc(hidden)]
+#[query(guard = "caller_is_admin")]
+pub fn get_audit_config() -> Option<AuditConfig> {
+    api::config::get_audit_config()
+}
+
+#[doc(hidden)]
+#[query(guard = "caller_is_admin")]
+pub fn list_audit_logs(params: ListAuditLogsParams) -> ListAuditLogsResults {
+    api::audit::list_audit_logs(params)
+}
+
+// ---------------------------------------------------------
+// User usage config
+// ---------------------------------------------------------
+
+#[doc(hidden)]
+#[update(guard = "caller_is_admin")]
+pub fn set_user_usage_config(config: SetUserUsageConfig) -> UserUsageConfig {
+    api::config::set_user_usage_config(config)
+}
+
+#[doc(hidden)]
+#[query(guard = "caller_is_admin")]
+pub fn get_user_usage_config() -> Option<UserUsageConfig> {
+    api::config::get_user_usage_config()
+}
+
 // ---------------------------------------------------------
 // Http
 // ---------------------------------------------------------
@@ -447,6 +590,12 @@ pub fn http_request(request: HttpRequest) -> HttpResponse {
     api::http::http_request(request)
 }
 
+#[doc(hidden)]
+#[update]
+pub fn http_request_update(request: HttpRequest) -> HttpResponse {
+    api::http::http_request_update(request)
+}
+
 #[doc(hidden)]
 #[query]
 pub fn http_request_streaming_callback(
@@ -471,6 +620,12 @@ pub fn upload_asset_chunk(chunk: UploadChunk) -> UploadChunkResult {
     api::storage::upload_asset_chunk(chunk)
 }
 
+#[doc(hidden)]
+#[query]
+pub fn get_upload_session(batch_id: BatchId) -> UploadSession {
+    api::storage::get_upload_session(batch_id)
+}
+
 #[doc(hidden)]
 #[update]
 pub fn commit_asset_upload(commit: CommitBatch) {
@@ -519,6 +674,16 @@ pub fn set_asset_token(collection: CollectionKey, full_path: FullPath, token: As
     api::storage::set_asset_token(collection, full_path, token);
 }
 
+#[doc(hidden)]
+#[update]
+pub fn sign_asset_url(
+    collection: CollectionKey,
+    full_path: FullPath,
+    expires_at: Timestamp,
+) -> String {
+    api::storage::sign_asset_url(collection, full_path, expires_at)
+}
+
 #[doc(hidden)]
 #[query(guard = "caller_has_write_permission")]
 pub fn count_collection_assets(collection: CollectionKey) -> usize {
@@ -582,21 +747,26 @@ pub fn memory_size() -> MemorySize {
 macro_rules! include_satellite {
     () => {
         use junobuild_satellite::{
-            authenticate, authenticate_automation, certify_assets_chunk, commit_asset_upload,
+            assign_user_roles, authenticate, authenticate_automation, ban_user,
+            certify_assets_chunk, clear_user_sessions_revocation, commit_asset_upload, commit_docs,
             commit_proposal, commit_proposal_asset_upload, commit_proposal_many_assets_upload,
             count_assets, count_collection_assets, count_collection_docs, count_docs,
             count_proposals, del_asset, del_assets, del_controller_self, del_controllers,
             del_custom_domain, del_doc, del_docs, del_filtered_assets, del_filtered_docs,
             del_many_assets, del_many_docs, del_rule, delete_proposal_assets, deposit_cycles,
-            get_asset, get_auth_config, get_automation_config, get_config, get_db_config,
-            get_delegation, get_doc, get_many_assets, get_many_docs, get_proposal,
-            get_storage_config, http_request, http_request_streaming_callback, init,
-            init_asset_upload, init_proposal, init_proposal_asset_upload,
-            init_proposal_many_assets_upload, list_assets, list_controllers, list_custom_domains,
-            list_docs, list_proposals, list_rules, post_upgrade, pre_upgrade, reject_proposal,
-            set_asset_token, set_auth_config, set_automation_config, set_controllers,
-            set_custom_domain, set_db_config, set_doc, set_many_docs, set_rule, set_storage_config,
-            set_storage_config_with_options, submit_proposal, switch_storage_system_memory,
+            get_asset, get_audit_config, get_auth_config, get_automation_config, get_config,
+            get_db_config, get_delegation, get_doc, get_many_assets, get_many_docs, get_proposal,
+            get_storage_config, get_upload_session, get_user_sessions, get_user_usage,
+            get_user_usage_config, http_request, http_request_streaming_callback,
+            http_request_update, init, init_asset_upload, init_proposal,
+            init_proposal_asset_upload, init_proposal_many_assets_upload, list_assets,
+            list_audit_logs, list_banned_users, list_controllers, list_custom_domains,
+            list_doc_revisions, list_docs, list_proposals, list_rules, post_upgrade, pre_upgrade,
+            reject_proposal, restore_doc_revision, revoke_user_roles, revoke_user_sessions,
+            set_asset_token, set_audit_config, set_auth_config, set_automation_config,
+            set_controllers, set_custom_domain, set_db_config, set_doc, set_many_docs, set_rule,
+            set_storage_config, set_storage_config_with_options, set_user_usage_config,
+            sign_asset_url, submit_proposal, switch_storage_system_memory, unban_user,
             upload_asset_chunk, upload_proposal_asset_chunk,
         };
 
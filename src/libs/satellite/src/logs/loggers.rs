@@ -1,9 +1,9 @@
 use crate::logs::types::logs::{Log, LogLevel};
 use crate::random::runtime::random;
 use crate::{set_doc_store, SetDoc};
-use ic_cdk::api::time;
 use junobuild_collections::constants::db::COLLECTION_LOG_KEY;
 use junobuild_shared::ic::api::id;
+use junobuild_shared::ic::api::time;
 use junobuild_shared::types::core::Key;
 use junobuild_utils::encode_doc_data;
 use serde::Serialize;
@@ -94,6 +94,7 @@ fn set_log<T: Serialize>(level: LogLevel, message: String, data: Option<&T>) ->
         description: None,
         data: encode_doc_data(&log)?,
         version: None,
+        expires_at: None,
     };
 
     set_doc_store(id(), COLLECTION_LOG_KEY.to_string(), key, doc)?;
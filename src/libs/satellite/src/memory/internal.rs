@@ -1,7 +1,9 @@
 use crate::memory::manager::{
-    get_memory_assets, get_memory_content_chunks, get_memory_db, get_memory_polyfill,
+    get_memory_assets, get_memory_audit_logs, get_memory_content_chunks, get_memory_db,
+    get_memory_db_history, get_memory_db_indexes, get_memory_expiries, get_memory_polyfill,
     get_memory_proposal_assets, get_memory_proposal_content_chunks, get_memory_proposals,
-    get_memory_upgrades,
+    get_memory_upgrades, get_memory_upload_batches, get_memory_upload_chunks,
+    get_memory_user_sessions,
 };
 use crate::types::state::StableState;
 use ic_stable_structures::StableBTreeMap;
@@ -19,6 +21,13 @@ pub fn init_stable_state() -> StableState {
         proposals_assets: StableBTreeMap::init(get_memory_proposal_assets()),
         proposals_content_chunks: StableBTreeMap::init(get_memory_proposal_content_chunks()),
         proposals: StableBTreeMap::init(get_memory_proposals()),
+        db_indexes: StableBTreeMap::init(get_memory_db_indexes()),
+        db_history: StableBTreeMap::init(get_memory_db_history()),
+        expiries: StableBTreeMap::init(get_memory_expiries()),
+        upload_batches: StableBTreeMap::init(get_memory_upload_batches()),
+        upload_chunks: StableBTreeMap::init(get_memory_upload_chunks()),
+        audit_logs: StableBTreeMap::init(get_memory_audit_logs()),
+        user_sessions: StableBTreeMap::init(get_memory_user_sessions()),
     }
 }
 
@@ -1,4 +1,7 @@
 use crate::assets::storage::certified_assets::upgrade::defer_init_certified_assets;
+use crate::db::history_deletion::init_history_deletion_timer;
+use crate::db::indexes_rebuild::init_indexes_rebuild_timer;
+use crate::expiry::purge::init_expiry_purge_timer;
 use crate::hooks::lifecycle::{
     invoke_on_init, invoke_on_init_sync, invoke_on_post_upgrade, invoke_on_post_upgrade_sync,
 };
@@ -58,6 +61,9 @@ pub fn post_upgrade() {
 
     defer_init_certified_assets();
     defer_init_random_seed();
+    init_expiry_purge_timer();
+    init_indexes_rebuild_timer();
+    init_history_deletion_timer();
 
     invoke_on_post_upgrade_sync();
 
@@ -11,6 +11,13 @@ const POLYFILL: MemoryId = MemoryId::new(4);
 const PROPOSAL_ASSETS: MemoryId = MemoryId::new(5);
 const PROPOSAL_CONTENT_CHUNKS: MemoryId = MemoryId::new(6);
 const PROPOSALS: MemoryId = MemoryId::new(7);
+const DB_INDEXES: MemoryId = MemoryId::new(8);
+const DB_HISTORY: MemoryId = MemoryId::new(9);
+const EXPIRIES: MemoryId = MemoryId::new(10);
+const UPLOAD_BATCHES: MemoryId = MemoryId::new(11);
+const UPLOAD_CHUNKS: MemoryId = MemoryId::new(12);
+const AUDIT_LOGS: MemoryId = MemoryId::new(13);
+const USER_SESSIONS: MemoryId = MemoryId::new(14);
 
 thread_local! {
     static MEMORY_MANAGER: RefCell<MemoryManager<DefaultMemoryImpl>> =
@@ -48,3 +55,31 @@ pub fn get_memory_proposal_content_chunks() -> Memory {
 pub fn get_memory_proposals() -> Memory {
     MEMORY_MANAGER.with(|m| m.borrow().get(PROPOSALS))
 }
+
+pub fn get_memory_db_indexes() -> Memory {
+    MEMORY_MANAGER.with(|m| m.borrow().get(DB_INDEXES))
+}
+
+pub fn get_memory_db_history() -> Memory {
+    MEMORY_MANAGER.with(|m| m.borrow().get(DB_HISTORY))
+}
+
+pub fn get_memory_expiries() -> Memory {
+    MEMORY_MANAGER.with(|m| m.borrow().get(EXPIRIES))
+}
+
+pub fn get_memory_upload_batches() -> Memory {
+    MEMORY_MANAGER.with(|m| m.borrow().get(UPLOAD_BATCHES))
+}
+
+pub fn get_memory_upload_chunks() -> Memory {
+    MEMORY_MANAGER.with(|m| m.borrow().get(UPLOAD_CHUNKS))
+}
+
+pub fn get_memory_audit_logs() -> Memory {
+    MEMORY_MANAGER.with(|m| m.borrow().get(AUDIT_LOGS))
+}
+
+pub fn get_memory_user_sessions() -> Memory {
+    MEMORY_MANAGER.with(|m| m.borrow().get(USER_SESSIONS))
+}
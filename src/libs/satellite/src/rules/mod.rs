@@ -1,4 +1,6 @@
 mod internal;
 pub mod store;
 pub mod switch_memory;
+#[cfg(test)]
+pub mod test_utils;
 pub mod upgrade;
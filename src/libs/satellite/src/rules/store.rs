@@ -1,5 +1,8 @@
 use crate::assets::storage::store::assert_assets_collection_empty_store;
-use crate::db::store::{delete_collection_store, init_collection_store};
+use crate::db::store::{
+    assert_collection_schema_store, delete_collection_history_store, delete_collection_store,
+    init_collection_store, rebuild_collection_indexes_store,
+};
 use crate::memory::state::STATE;
 use junobuild_collections::store::{del_rule, filter_rules, list_rules, set_rule};
 use junobuild_collections::types::core::CollectionKey;
@@ -37,6 +40,12 @@ pub fn list_rules_storage(filter: &ListRulesParams) -> ListRulesResults {
 }
 
 pub fn set_rule_db(collection: CollectionKey, rule: SetRule) -> Result<Rule, String> {
+    assert_collection_schema_store(&rule.schema)?;
+
+    let current_rule = get_rule_db(&collection);
+    let current_indexes = current_rule.as_ref().and_then(|rule| rule.indexes.clone());
+    let current_history = current_rule.and_then(|rule| rule.history);
+
     let rule = STATE.with(|state| {
         set_rule(
             collection.clone(),
@@ -49,6 +58,16 @@ pub fn set_rule_db(collection: CollectionKey, rule: SetRule) -> Result<Rule, Str
     // If the collection does not exist yet we initialize it
     init_collection_store(&collection, &rule.memory.clone().unwrap_or(Memory::Stable));
 
+    // The existing documents are indexed again if the declared indexes changed
+    if current_indexes != rule.indexes {
+        rebuild_collection_indexes_store(&collection);
+    }
+
+    // The revisions are dropped if the history is disabled
+    if current_history.is_some() && rule.history.is_none() {
+        delete_collection_history_store(&collection);
+    }
+
     Ok(rule)
 }
 
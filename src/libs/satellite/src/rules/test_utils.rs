@@ -0,0 +1,24 @@
+use junobuild_collections::types::interface::SetRule;
+use junobuild_collections::types::rules::{Memory, Permission};
+
+/// A public rule without any option, to be completed with struct update syntax in the tests.
+pub fn public_rule(memory: Memory) -> SetRule {
+    SetRule {
+        read: Permission::Public,
+        write: Permission::Public,
+        mutable_permissions: None,
+        memory: Some(memory),
+        max_size: None,
+        max_capacity: None,
+        max_changes_per_user: None,
+        max_bytes_per_user: None,
+        version: None,
+        rate_config: None,
+        indexes: None,
+        history: None,
+        ttl: None,
+        schema: None,
+        variants: None,
+        content_validation: None,
+    }
+}
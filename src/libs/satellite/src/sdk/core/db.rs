@@ -1,10 +1,13 @@
 pub use crate::db::store::{
-    count_collection_docs_store, count_docs_store, delete_doc_store, delete_docs_store,
-    delete_filtered_docs_store, get_doc_store, list_docs_store, set_doc_store,
+    commit_docs_store, count_collection_docs_store, count_docs_store, delete_doc_store,
+    delete_docs_store, delete_filtered_docs_store, get_doc_store, list_doc_revisions_store,
+    list_docs_store, restore_doc_revision_store, set_doc_store,
 };
-pub use crate::db::types::interface::{DelDoc, SetDoc};
+pub use crate::db::types::interface::{CommitDocOperation, DelDoc, RestoreDocRevision, SetDoc};
+pub use crate::db::types::state::CommitDocsContext;
 pub use crate::db::types::state::Doc;
 pub use crate::db::types::state::DocAssertDelete;
 pub use crate::db::types::state::DocAssertSet;
 pub use crate::db::types::state::DocContext;
+pub use crate::db::types::state::DocRevision;
 pub use crate::db::types::state::DocUpsert;
@@ -38,6 +38,7 @@ pub fn caller_is_admin() -> Result<(), String> {
 ///
 /// Grants access to admin and editor access keys. Submitter access keys
 /// do not satisfy this guard. Non-admin access keys must not be expired.
+/// Access keys restricted to particular collections do not satisfy this guard either.
 ///
 /// # Note
 /// When called from a serverless functions hook, the caller is the satellite itself,
@@ -60,6 +61,7 @@ pub fn caller_has_write_permission() -> Result<(), String> {
 ///
 /// This is the least restrictive access check — it accepts admin, editor,
 /// and submitter access keys alike. Non-admin access keys must not be expired.
+/// Access keys restricted to particular collections do not satisfy this guard.
 ///
 /// # Note
 /// When called from a serverless functions hook, the caller is the satellite itself,
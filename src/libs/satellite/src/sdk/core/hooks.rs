@@ -2,5 +2,6 @@ pub use crate::types::hooks::{
     AssertDeleteAssetContext, AssertDeleteDocContext, AssertSetDocContext,
     AssertUploadAssetContext, HookContext, OnDeleteAssetContext, OnDeleteDocContext,
     OnDeleteFilteredAssetsContext, OnDeleteFilteredDocsContext, OnDeleteManyAssetsContext,
-    OnDeleteManyDocsContext, OnSetDocContext, OnSetManyDocsContext, OnUploadAssetContext,
+    OnDeleteManyDocsContext, OnHttpRequestContext, OnHttpRequestResponse, OnSetDocContext,
+    OnSetManyDocsContext, OnUploadAssetContext,
 };
@@ -1,6 +1,6 @@
 pub use crate::assets::storage::handlers::set_asset_handler;
 pub use crate::assets::storage::store::{
     count_assets_store, count_collection_assets_store, delete_asset_store, delete_assets_store,
-    delete_filtered_assets_store, get_asset_store, get_content_chunks_store, list_assets_store,
-    set_asset_token_store,
+    delete_filtered_assets_store, get_asset_store, get_asset_upload_chunk_store,
+    get_content_chunks_store, list_assets_store, set_asset_token_store, sign_asset_url_store,
 };
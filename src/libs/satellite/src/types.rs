@@ -1,7 +1,16 @@
 pub mod state {
-    use crate::assets::storage::types::state::{AssetsStable, ContentChunksStable};
-    use crate::db::types::state::{DbHeapState, DbRuntimeState, DbStable};
+    use crate::assets::storage::types::state::{
+        AssetsStable, ContentChunksStable, UploadBatchesStable, UploadChunksStable,
+    };
+    use crate::audit::types::state::{AuditHeapState, AuditLogsStable};
+    use crate::db::types::state::{
+        DbHeapState, DbHistoryStable, DbIndexesStable, DbRuntimeState, DbStable,
+    };
+    use crate::expiry::types::runtime::ExpiryRuntimeState;
+    use crate::expiry::types::state::ExpiriesStable;
     use crate::memory::internal::init_stable_state;
+    use crate::user::sessions::types::state::UserSessionsStable;
+    use crate::user::usage::types::state::UserUsageHeapState;
     use candid::CandidType;
     use junobuild_auth::state::types::state::AuthenticationHeapState;
     use junobuild_cdn::proposals::ProposalsStable;
@@ -32,6 +41,13 @@ pub mod state {
         pub proposals_assets: ProposalAssetsStable,
         pub proposals_content_chunks: ProposalContentChunksStable,
         pub proposals: ProposalsStable,
+        pub db_indexes: DbIndexesStable,
+        pub db_history: DbHistoryStable,
+        pub expiries: ExpiriesStable,
+        pub upload_batches: UploadBatchesStable,
+        pub upload_chunks: UploadChunksStable,
+        pub audit_logs: AuditLogsStable,
+        pub user_sessions: UserSessionsStable,
     }
 
     #[derive(Default, CandidType, Serialize, Deserialize, Clone)]
@@ -40,12 +56,15 @@ pub mod state {
         pub db: DbHeapState,
         pub storage: StorageHeapState,
         pub authentication: Option<AuthenticationHeapState>,
+        pub audit: Option<AuditHeapState>,
+        pub user_usage: Option<UserUsageHeapState>,
     }
 
     #[derive(Default, Clone)]
     pub struct RuntimeState {
         pub rng: Option<StdRng>, // rng = Random Number Generator
         pub db: DbRuntimeState,
+        pub expiry: ExpiryRuntimeState,
     }
 
     #[derive(CandidType, Deserialize, Serialize, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -165,7 +184,9 @@ pub mod hooks {
     use crate::db::types::state::{DocAssertDelete, DocAssertSet, DocContext, DocUpsert};
     use crate::Doc;
     use candid::{CandidType, Deserialize};
+    use junobuild_shared::types::core::Blob;
     use junobuild_shared::types::state::UserId;
+    use junobuild_storage::http::types::{HeaderField, HttpRequest, StatusCode};
     use junobuild_storage::types::store::{Asset, AssetAssertUpload};
 
     /// A generic context struct used in Juno satellite hooks.
@@ -230,4 +251,20 @@ pub mod hooks {
 
     /// A type alias for the context used in the `assert_delete_asset` satellite hook.
     pub type AssertDeleteAssetContext = HookContext<Asset>;
+
+    /// A type alias for the context used in the `on_http_request` satellite hook.
+    pub type OnHttpRequestContext = HookContext<HttpRequest>;
+
+    /// The response returned by the `on_http_request` satellite hook.
+    ///
+    /// # Fields
+    /// - `status_code`: The HTTP status code of the response.
+    /// - `headers`: The headers of the response.
+    /// - `body`: The body of the response.
+    #[derive(CandidType, Deserialize, Clone)]
+    pub struct OnHttpRequestResponse {
+        pub status_code: StatusCode,
+        pub headers: Vec<HeaderField>,
+        pub body: Blob,
+    }
 }
@@ -0,0 +1,13 @@
+use crate::errors::user::JUNO_DATASTORE_ERROR_USER_BAN_EXPIRED;
+use crate::user::bans::types::interface::BanUserArgs;
+use junobuild_shared::types::state::Timestamp;
+
+pub fn assert_ban_user(args: &BanUserArgs, now: Timestamp) -> Result<(), String> {
+    if let Some(expires_at) = args.expires_at {
+        if expires_at <= now {
+            return Err(JUNO_DATASTORE_ERROR_USER_BAN_EXPIRED.to_string());
+        }
+    }
+
+    Ok(())
+}
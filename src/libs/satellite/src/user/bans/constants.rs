@@ -0,0 +1,2 @@
+// The number of users inspected per page when listing the banned users if no limit is provided.
+pub const USER_BANS_DEFAULT_LIST_LIMIT: usize = 500;
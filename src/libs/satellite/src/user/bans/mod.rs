@@ -0,0 +1,4 @@
+mod assert;
+mod constants;
+pub mod store;
+pub mod types;
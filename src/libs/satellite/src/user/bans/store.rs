@@ -0,0 +1,228 @@
+use crate::user::bans::assert::assert_ban_user;
+use crate::user::bans::constants::USER_BANS_DEFAULT_LIST_LIMIT;
+use crate::user::bans::types::interface::{
+    BanUserArgs, BannedUser, ListBannedUsersParams, ListBannedUsersResults,
+};
+use crate::user::core::store::{get_user, get_users_after, update_user_data};
+use crate::user::core::types::state::{BannedReason, UserBan, UserData};
+use candid::Principal;
+use junobuild_shared::data::list::{decode_list_cursor, encode_list_cursor};
+use junobuild_shared::ic::api::caller;
+use junobuild_shared::ic::api::time;
+use junobuild_shared::types::core::Key;
+use junobuild_shared::types::state::{Timestamp, UserId};
+use junobuild_utils::decode_doc_data;
+
+/// Returns the ban of the user, if the user is currently banned.
+pub fn get_user_ban(user_id: &UserId) -> Result<Option<BannedUser>, String> {
+    let Some(user) = get_user(user_id)? else {
+        return Ok(None);
+    };
+
+    let user_data = decode_doc_data::<UserData>(&user.data)?;
+
+    Ok(banned_user(user_id, &user_data, time()))
+}
+
+/// Bans a user on behalf of the caller - an administrator.
+///
+/// The ban is lifted automatically once expired, or never if no expiration is provided.
+///
+/// # Returns
+/// - `Ok(BannedUser)`: The ban.
+/// - `Err(String)`: If the expiration is not in the future or the user does not exist.
+pub fn ban_user(args: &BanUserArgs) -> Result<BannedUser, String> {
+    let now = time();
+
+    assert_ban_user(args, now)?;
+
+    let user_data = update_user_data(&args.user, |user_data| {
+        user_data.banned = Some(match args.expires_at {
+            None => BannedReason::Indefinite,
+            Some(_) => BannedReason::Temporary,
+        });
+
+        user_data.ban = Some(UserBan {
+            code: args.code.clone(),
+            reason: args.reason.clone(),
+            banned_by: Some(caller()),
+            banned_at: Some(now),
+            expires_at: args.expires_at,
+        });
+    })?;
+
+    Ok(into_banned_user(&args.user, &user_data))
+}
+
+pub fn unban_user(user_id: &UserId) -> Result<(), String> {
+    update_user_data(user_id, |user_data| {
+        user_data.banned = None;
+        user_data.ban = None;
+    })?;
+
+    Ok(())
+}
+
+/// Lists the users that are currently banned. Expired bans are omitted.
+///
+/// The users are inspected one page at a time - see `ListBannedUsersPaginate` - so that listing
+/// the banned users of a large Satellite never exceeds the instruction limit.
+pub fn list_banned_users(
+    ListBannedUsersParams { paginate }: &ListBannedUsersParams,
+) -> Result<ListBannedUsersResults, String> {
+    let now = time();
+
+    let limit = paginate
+        .as_ref()
+        .and_then(|paginate| paginate.limit)
+        .unwrap_or(USER_BANS_DEFAULT_LIST_LIMIT);
+
+    let start_after: Option<Key> = match paginate {
+        None => None,
+        Some(paginate) => match &paginate.cursor {
+            Some(cursor) => Some(decode_list_cursor::<Key>(cursor)?),
+            None => paginate.start_after.map(|user_id| user_id.to_text()),
+        },
+    };
+
+    // One more user than the limit is read to know whether another page follows.
+    let users = get_users_after(&start_after, limit.saturating_add(1))?;
+
+    let has_more = users.len() > limit;
+
+    let users = &users[..users.len().min(limit)];
+
+    let items: Vec<BannedUser> = users
+        .iter()
+        .filter_map(|(key, doc)| {
+            let user_id = Principal::from_text(key).ok()?;
+            let user_data = decode_doc_data::<UserData>(&doc.data).ok()?;
+
+            banned_user(&user_id, &user_data, now)
+        })
+        .collect();
+
+    let next_cursor = users
+        .last()
+        .filter(|_| has_more)
+        .map(|(key, _)| encode_list_cursor(key));
+
+    Ok(ListBannedUsersResults {
+        items_length: items.len(),
+        items,
+        next_cursor,
+    })
+}
+
+fn banned_user(user_id: &UserId, user_data: &UserData, now: Timestamp) -> Option<BannedUser> {
+    if !user_data.is_banned(now) {
+        return None;
+    }
+
+    Some(into_banned_user(user_id, user_data))
+}
+
+fn into_banned_user(user_id: &UserId, user_data: &UserData) -> BannedUser {
+    let ban = user_data.ban.clone().unwrap_or_default();
+
+    BannedUser {
+        user: *user_id,
+        code: ban.code,
+        reason: ban.reason,
+        banned_by: ban.banned_by,
+        banned_at: ban.banned_at,
+        expires_at: ban.expires_at,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::internal::unsafe_set_doc;
+    use crate::rules::store::get_rule_db;
+    use crate::rules::upgrade::init_system_collections;
+    use crate::user::bans::types::interface::ListBannedUsersPaginate;
+    use crate::SetDoc;
+    use junobuild_collections::constants::db::COLLECTION_USER_KEY;
+    use junobuild_shared::types::list::ListCursor;
+
+    fn insert_user(id: u8, banned: bool) {
+        let collection = COLLECTION_USER_KEY.to_string();
+        let rule = get_rule_db(&collection).unwrap();
+
+        let user_id = Principal::from_slice(&[id; 29]);
+
+        let data = if banned {
+            br#"{"banned":"indefinite"}"#.to_vec()
+        } else {
+            b"{}".to_vec()
+        };
+
+        unsafe_set_doc(
+            user_id,
+            &collection,
+            &user_id.to_text(),
+            SetDoc {
+                data,
+                ..Default::default()
+            },
+            &rule,
+        )
+        .unwrap();
+    }
+
+    fn params(limit: usize, cursor: Option<ListCursor>) -> ListBannedUsersParams {
+        ListBannedUsersParams {
+            paginate: Some(ListBannedUsersPaginate {
+                start_after: None,
+                limit: Some(limit),
+                cursor,
+            }),
+        }
+    }
+
+    #[test]
+    fn lists_banned_users_page_by_page() {
+        init_system_collections();
+
+        for id in 1..=5 {
+            insert_user(id, id == 1 || id == 4);
+        }
+
+        let mut banned = Vec::new();
+        let mut cursor = None;
+        let mut pages = 0;
+
+        loop {
+            let results = list_banned_users(&params(2, cursor)).unwrap();
+
+            assert!(results.items_length <= 2);
+            banned.extend(results.items.into_iter().map(|ban| ban.user));
+            pages += 1;
+
+            match results.next_cursor {
+                None => break,
+                Some(next_cursor) => cursor = Some(next_cursor),
+            }
+        }
+
+        assert_eq!(pages, 3);
+        assert_eq!(banned.len(), 2);
+        assert!(banned.contains(&Principal::from_slice(&[1; 29])));
+        assert!(banned.contains(&Principal::from_slice(&[4; 29])));
+    }
+
+    #[test]
+    fn lists_all_banned_users_within_a_page() {
+        init_system_collections();
+
+        for id in 1..=3 {
+            insert_user(id, id == 2);
+        }
+
+        let results = list_banned_users(&ListBannedUsersParams::default()).unwrap();
+
+        assert_eq!(results.items_length, 1);
+        assert!(results.next_cursor.is_none());
+    }
+}
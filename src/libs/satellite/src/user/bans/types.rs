@@ -0,0 +1,49 @@
+pub mod interface {
+    use candid::{CandidType, Principal};
+    use junobuild_shared::types::list::ListCursor;
+    use junobuild_shared::types::state::{Timestamp, UserId};
+    use serde::{Deserialize, Serialize};
+
+    /// Bans a user. The ban is temporary if an expiration is provided, indefinite otherwise.
+    #[derive(CandidType, Deserialize, Clone)]
+    pub struct BanUserArgs {
+        pub user: UserId,
+        pub code: Option<String>,
+        pub reason: Option<String>,
+        pub expires_at: Option<Timestamp>,
+    }
+
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct BannedUser {
+        pub user: UserId,
+        pub code: Option<String>,
+        pub reason: Option<String>,
+        pub banned_by: Option<Principal>,
+        pub banned_at: Option<Timestamp>,
+        // None if the user is banned indefinitely.
+        pub expires_at: Option<Timestamp>,
+    }
+
+    #[derive(Default, CandidType, Deserialize, Clone)]
+    pub struct ListBannedUsersParams {
+        pub paginate: Option<ListBannedUsersPaginate>,
+    }
+
+    /// The banned users are found by inspecting the users one page at a time. The limit bounds
+    /// the number of users inspected - not the number of banned users returned - so that a page
+    /// never exceeds the instruction limit. A page may therefore contain few or no banned users
+    /// while more remain; the listing is complete once no next cursor is returned.
+    #[derive(Default, CandidType, Deserialize, Clone)]
+    pub struct ListBannedUsersPaginate {
+        pub start_after: Option<UserId>,
+        pub limit: Option<usize>,
+        pub cursor: Option<ListCursor>,
+    }
+
+    #[derive(Default, CandidType, Serialize, Deserialize, Clone)]
+    pub struct ListBannedUsersResults {
+        pub items: Vec<BannedUser>,
+        pub items_length: usize,
+        pub next_cursor: Option<ListCursor>,
+    }
+}
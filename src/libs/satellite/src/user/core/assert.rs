@@ -1,14 +1,16 @@
 use crate::errors::user::{
     JUNO_DATASTORE_ERROR_USER_CALLER_KEY, JUNO_DATASTORE_ERROR_USER_CANNOT_UPDATE,
     JUNO_DATASTORE_ERROR_USER_INVALID_DATA, JUNO_DATASTORE_ERROR_USER_KEY_NO_PRINCIPAL,
-    JUNO_DATASTORE_ERROR_USER_NOT_ALLOWED,
+    JUNO_DATASTORE_ERROR_USER_NOT_ALLOWED, JUNO_DATASTORE_ERROR_USER_ROLES_NOT_ALLOWED,
 };
-use crate::user::core::types::state::{BannedReason, UserData};
+use crate::user::core::types::state::UserData;
+use crate::user::sessions::assert::assert_user_sessions_not_revoked;
 use crate::{get_doc_store, Doc, SetDoc};
 use candid::Principal;
 use junobuild_collections::constants::db::COLLECTION_USER_KEY;
 use junobuild_collections::types::core::CollectionKey;
 use junobuild_shared::ic::api::id;
+use junobuild_shared::ic::api::time;
 use junobuild_shared::segments::access_keys::is_write_access_key;
 use junobuild_shared::types::core::Key;
 use junobuild_shared::types::state::AccessKeys;
@@ -54,7 +56,34 @@ pub fn assert_user_collection_data(collection: &CollectionKey, doc: &SetDoc) ->
     let user_data = decode_doc_data::<UserData>(&doc.data)
         .map_err(|err| format!("{JUNO_DATASTORE_ERROR_USER_INVALID_DATA}: {err}"))?;
 
-    user_data.assert_provider_data()
+    user_data.assert_provider_data()?;
+    user_data.assert_roles()?;
+    user_data.assert_ban()
+}
+
+pub fn assert_user_collection_roles(
+    caller: Principal,
+    controllers: &AccessKeys,
+    collection: &CollectionKey,
+    doc: &SetDoc,
+) -> Result<(), String> {
+    if collection != COLLECTION_USER_KEY {
+        return Ok(());
+    }
+
+    if is_write_access_key(caller, controllers) {
+        return Ok(());
+    }
+
+    let user_data = decode_doc_data::<UserData>(&doc.data)
+        .map_err(|err| format!("{JUNO_DATASTORE_ERROR_USER_INVALID_DATA}: {err}"))?;
+
+    // A user creating their own entry cannot grant themselves roles.
+    if user_data.roles.is_some() {
+        return Err(JUNO_DATASTORE_ERROR_USER_ROLES_NOT_ALLOWED.to_string());
+    }
+
+    Ok(())
 }
 
 pub fn assert_user_collection_write_permission(
@@ -91,6 +120,10 @@ pub fn assert_user_is_not_banned(
         return Ok(());
     }
 
+    // Sessions are revoked when an account is compromised, the user is rejected until the
+    // delegations issued before the revocation have expired.
+    assert_user_sessions_not_revoked(&caller)?;
+
     let user_key = caller.to_text();
 
     let user = get_doc_store(id(), COLLECTION_USER_KEY.to_string(), user_key)?;
@@ -98,7 +131,8 @@ pub fn assert_user_is_not_banned(
     if let Some(user) = user {
         let user_data = decode_doc_data::<UserData>(&user.data)?;
 
-        if let Some(BannedReason::Indefinite) = user_data.banned {
+        // Temporary bans are lifted once expired.
+        if user_data.is_banned(time()) {
             return Err(JUNO_DATASTORE_ERROR_USER_NOT_ALLOWED.to_string());
         }
     }
@@ -1,18 +1,21 @@
 use crate::errors::user::{
-    JUNO_DATASTORE_ERROR_USER_AAGUID_INVALID_LENGTH,
-    JUNO_DATASTORE_ERROR_USER_PROVIDER_INVALID_DATA,
+    JUNO_DATASTORE_ERROR_USER_AAGUID_INVALID_LENGTH, JUNO_DATASTORE_ERROR_USER_BAN_NO_EXPIRATION,
+    JUNO_DATASTORE_ERROR_USER_INVALID_ROLES, JUNO_DATASTORE_ERROR_USER_PROVIDER_INVALID_DATA,
     JUNO_DATASTORE_ERROR_USER_PROVIDER_WEBAUTHN_INVALID_DATA,
     JUNO_DATASTORE_ERROR_USER_REGISTER_PROVIDER_INVALID_DATA,
 };
 use crate::user::core::constants::AAGUID_LENGTH;
 use crate::user::core::types::state::{
-    AuthProvider, OpenIdData, ProviderData, UserData, WebAuthnData,
+    AuthProvider, BannedReason, OpenIdData, ProviderData, UserData, WebAuthnData,
 };
 use crate::{Doc, SetDoc};
 use junobuild_auth::openid::credentials::delegation::types::interface::OpenIdDelegationCredential;
 use junobuild_auth::openid::types::provider::OpenIdDelegationProvider;
 use junobuild_auth::profile::types::{OpenIdProfile, Validated};
+use junobuild_collections::assert::rules::is_valid_role_name;
+use junobuild_shared::types::state::Timestamp;
 use junobuild_utils::encode_doc_data;
+use std::collections::HashSet;
 
 impl Validated for WebAuthnData {
     fn validate(&self) -> Result<(), String> {
@@ -66,6 +69,7 @@ impl ProviderData {
             (ProviderData::WebAuthn(_), &AuthProvider::WebAuthn) => true,
             (ProviderData::OpenId(_), &AuthProvider::Google) => true,
             (ProviderData::OpenId(_), &AuthProvider::GitHub) => true,
+            (ProviderData::OpenId(_), &AuthProvider::OpenId) => true,
             _ => false,
         }
     }
@@ -85,7 +89,9 @@ impl UserData {
 
                 provider_data.validate()
             }
-            Some(ref provider @ (AuthProvider::Google | AuthProvider::GitHub)) => {
+            Some(
+                ref provider @ (AuthProvider::Google | AuthProvider::GitHub | AuthProvider::OpenId),
+            ) => {
                 let provider_data = self.provider_data.as_ref().ok_or_else(|| {
                     JUNO_DATASTORE_ERROR_USER_REGISTER_PROVIDER_INVALID_DATA.to_string()
                 })?;
@@ -107,6 +113,54 @@ impl UserData {
         }
     }
 
+    pub fn assert_roles(&self) -> Result<(), String> {
+        let Some(roles) = self.roles.as_ref() else {
+            return Ok(());
+        };
+
+        let mut names = HashSet::new();
+
+        let valid = roles
+            .iter()
+            .all(|role| is_valid_role_name(role) && names.insert(role));
+
+        if !valid {
+            return Err(format!(
+                "{JUNO_DATASTORE_ERROR_USER_INVALID_ROLES} ({})",
+                roles.join(", ")
+            ));
+        }
+
+        Ok(())
+    }
+
+    pub fn assert_ban(&self) -> Result<(), String> {
+        let Some(BannedReason::Temporary) = self.banned else {
+            return Ok(());
+        };
+
+        let expires_at = self.ban.as_ref().and_then(|ban| ban.expires_at);
+
+        if expires_at.is_none() {
+            return Err(JUNO_DATASTORE_ERROR_USER_BAN_NO_EXPIRATION.to_string());
+        }
+
+        Ok(())
+    }
+
+    /// Returns true if the user is banned indefinitely or temporarily and the ban has not expired yet.
+    pub fn is_banned(&self, now: Timestamp) -> bool {
+        match self.banned {
+            None => false,
+            Some(BannedReason::Indefinite) => true,
+            Some(BannedReason::Temporary) => self
+                .ban
+                .as_ref()
+                .and_then(|ban| ban.expires_at)
+                .is_some_and(|expires_at| expires_at > now),
+        }
+    }
+
     pub fn prepare_set_doc(
         user_data: &UserData,
         current_doc: &Option<Doc>,
@@ -117,6 +171,7 @@ impl UserData {
             data,
             description: None,
             version: current_doc.as_ref().and_then(|d| d.version),
+            expires_at: None,
         };
 
         Ok(set_doc)
@@ -165,6 +220,7 @@ impl From<&OpenIdDelegationProvider> for AuthProvider {
         match provider {
             OpenIdDelegationProvider::Google => AuthProvider::Google,
             OpenIdDelegationProvider::GitHub => AuthProvider::GitHub,
+            OpenIdDelegationProvider::Generic(_) => AuthProvider::OpenId,
         }
     }
 }
@@ -173,7 +229,7 @@ impl From<&OpenIdDelegationProvider> for AuthProvider {
 mod tests {
     use super::*;
     use crate::user::core::types::state::{
-        AuthProvider, OpenIdData, ProviderData, UserData, WebAuthnData,
+        AuthProvider, OpenIdData, ProviderData, UserBan, UserData, WebAuthnData,
     };
 
     // ------------------------
@@ -209,6 +265,8 @@ mod tests {
             provider_data: Some(ProviderData::WebAuthn(WebAuthnData {
                 aaguid: Some(vec![0; 16]),
             })),
+            roles: None,
+            ban: None,
         };
         assert!(user.assert_provider_data().is_ok());
     }
@@ -219,6 +277,8 @@ mod tests {
             provider: Some(AuthProvider::WebAuthn),
             banned: None,
             provider_data: None,
+            roles: None,
+            ban: None,
         };
         assert!(user.assert_provider_data().is_err());
     }
@@ -239,6 +299,8 @@ mod tests {
             provider: Some(AuthProvider::Google),
             banned: None,
             provider_data: Some(provider_data),
+            roles: None,
+            ban: None,
         };
 
         assert!(user.assert_provider_data().is_ok());
@@ -260,6 +322,8 @@ mod tests {
             provider: Some(AuthProvider::Google),
             banned: None,
             provider_data: Some(provider_data),
+            roles: None,
+            ban: None,
         };
 
         assert!(user.assert_provider_data().is_err());
@@ -271,6 +335,8 @@ mod tests {
             provider: Some(AuthProvider::Nfid),
             banned: None,
             provider_data: None,
+            roles: None,
+            ban: None,
         };
         assert!(user.assert_provider_data().is_ok());
     }
@@ -281,6 +347,8 @@ mod tests {
             provider: Some(AuthProvider::Nfid),
             banned: None,
             provider_data: Some(ProviderData::WebAuthn(WebAuthnData { aaguid: None })),
+            roles: None,
+            ban: None,
         };
         assert!(user.assert_provider_data().is_err());
     }
@@ -301,6 +369,8 @@ mod tests {
             provider: Some(AuthProvider::GitHub),
             banned: None,
             provider_data: Some(provider_data),
+            roles: None,
+            ban: None,
         };
 
         assert!(user.assert_provider_data().is_ok());
@@ -312,6 +382,8 @@ mod tests {
             provider: Some(AuthProvider::GitHub),
             banned: None,
             provider_data: None,
+            roles: None,
+            ban: None,
         };
         assert!(user.assert_provider_data().is_err());
     }
@@ -332,11 +404,99 @@ mod tests {
             provider: Some(AuthProvider::GitHub),
             banned: None,
             provider_data: Some(provider_data),
+            roles: None,
+            ban: None,
         };
 
         assert!(user.assert_provider_data().is_err());
     }
 
+    #[test]
+    fn test_userdata_roles() {
+        let with_roles = |roles: Option<Vec<&str>>| UserData {
+            provider: Some(AuthProvider::InternetIdentity),
+            banned: None,
+            provider_data: None,
+            roles: roles.map(|roles| roles.into_iter().map(String::from).collect()),
+            ban: None,
+        };
+
+        assert!(with_roles(None).assert_roles().is_ok());
+        assert!(with_roles(Some(vec!["editor", "moderator_2"]))
+            .assert_roles()
+            .is_ok());
+        assert!(with_roles(Some(vec!["editor", "editor"]))
+            .assert_roles()
+            .is_err());
+        assert!(with_roles(Some(vec![""])).assert_roles().is_err());
+        assert!(with_roles(Some(vec!["edit or"])).assert_roles().is_err());
+    }
+
+    // ------------------------
+    // UserData::assert_ban and is_banned
+    // ------------------------
+
+    fn banned_user(banned: Option<BannedReason>, expires_at: Option<Timestamp>) -> UserData {
+        UserData {
+            provider: Some(AuthProvider::InternetIdentity),
+            banned,
+            provider_data: None,
+            roles: None,
+            ban: Some(UserBan {
+                expires_at,
+                ..Default::default()
+            }),
+        }
+    }
+
+    #[test]
+    fn test_userdata_temporary_ban_requires_expiration() {
+        let err = banned_user(Some(BannedReason::Temporary), None)
+            .assert_ban()
+            .unwrap_err();
+        assert_eq!(err, JUNO_DATASTORE_ERROR_USER_BAN_NO_EXPIRATION);
+
+        assert!(banned_user(Some(BannedReason::Temporary), Some(100))
+            .assert_ban()
+            .is_ok());
+        assert!(banned_user(Some(BannedReason::Indefinite), None)
+            .assert_ban()
+            .is_ok());
+    }
+
+    #[test]
+    fn test_userdata_is_banned() {
+        assert!(!banned_user(None, Some(100)).is_banned(50));
+        assert!(banned_user(Some(BannedReason::Indefinite), None).is_banned(50));
+
+        let temporary = banned_user(Some(BannedReason::Temporary), Some(100));
+        assert!(temporary.is_banned(99));
+        assert!(!temporary.is_banned(100));
+    }
+
+    #[test]
+    fn test_userdata_ban_serialization() {
+        let json = r#"{"provider":null,"banned":"temporary","providerData":null,"roles":null,"ban":{"code":"spam","reason":null,"bannedBy":{"__principal__":"aaaaa-aa"},"bannedAt":{"__bigint__":"1"},"expiresAt":{"__bigint__":"100"}}}"#;
+
+        let user: UserData = serde_json::from_str(json).unwrap();
+        let ban = user.ban.as_ref().unwrap();
+
+        assert!(matches!(user.banned, Some(BannedReason::Temporary)));
+        assert_eq!(ban.code.as_deref(), Some("spam"));
+        assert_eq!(
+            ban.banned_by,
+            Some(candid::Principal::management_canister())
+        );
+        assert_eq!(ban.expires_at, Some(100));
+
+        assert_eq!(serde_json::to_string(&user).unwrap(), json);
+
+        // Users banned before the details were introduced
+        let legacy: UserData = serde_json::from_str(r#"{"banned":"indefinite"}"#).unwrap();
+        assert!(legacy.ban.is_none());
+        assert!(legacy.is_banned(0));
+    }
+
     #[test]
     fn test_openid_provider_to_auth_provider() {
         assert!(matches!(
@@ -347,5 +507,9 @@ mod tests {
             AuthProvider::from(&OpenIdDelegationProvider::GitHub),
             AuthProvider::GitHub
         ));
+        assert!(matches!(
+            AuthProvider::from(&OpenIdDelegationProvider::Generic("auth0".to_string())),
+            AuthProvider::OpenId
+        ));
     }
 }
@@ -1,4 +1,5 @@
 pub mod assert;
 mod constants;
 mod impls;
+pub mod store;
 pub mod types;
@@ -0,0 +1,67 @@
+use crate::db::internal::{unsafe_get_doc, unsafe_get_docs_after};
+use crate::db::store::internal_set_doc_store;
+use crate::db::types::store::AssertSetDocOptions;
+use crate::errors::user::JUNO_DATASTORE_ERROR_USER_NOT_FOUND;
+use crate::rules::store::get_rule_db;
+use crate::user::core::types::state::UserData;
+use crate::Doc;
+use junobuild_collections::constants::db::COLLECTION_USER_KEY;
+use junobuild_collections::msg::msg_db_collection_not_found;
+use junobuild_shared::ic::api::id;
+use junobuild_shared::types::core::Key;
+use junobuild_shared::types::state::UserId;
+use junobuild_utils::decode_doc_data;
+
+pub fn get_user(user_id: &UserId) -> Result<Option<Doc>, String> {
+    let user_collection = COLLECTION_USER_KEY.to_string();
+
+    let rule = get_rule_db(&user_collection)
+        .ok_or_else(|| msg_db_collection_not_found(&user_collection))?;
+
+    unsafe_get_doc(&user_collection, &user_id.to_text(), &rule)
+}
+
+/// Returns up to `limit` users following the `start_after` key, sorted by key.
+pub fn get_users_after(start_after: &Option<Key>, limit: usize) -> Result<Vec<(Key, Doc)>, String> {
+    let user_collection = COLLECTION_USER_KEY.to_string();
+
+    let rule = get_rule_db(&user_collection)
+        .ok_or_else(|| msg_db_collection_not_found(&user_collection))?;
+
+    unsafe_get_docs_after(&user_collection, start_after, limit, &rule)
+}
+
+/// Updates the data of an existing user on behalf of the administrators.
+///
+/// # Returns
+/// - `Ok(UserData)`: The updated data.
+/// - `Err(String)`: If the user does not exist or the data cannot be decoded or saved.
+pub fn update_user_data(
+    user_id: &UserId,
+    update: impl FnOnce(&mut UserData),
+) -> Result<UserData, String> {
+    let current_user = get_user(user_id)?
+        .ok_or_else(|| format!("{JUNO_DATASTORE_ERROR_USER_NOT_FOUND} ({user_id})"))?;
+
+    let mut user_data = decode_doc_data::<UserData>(&current_user.data)?;
+
+    update(&mut user_data);
+
+    let set_doc = UserData::prepare_set_doc(&user_data, &Some(current_user))?;
+
+    let assert_options = AssertSetDocOptions {
+        with_assert_rate: false,
+    };
+
+    // Only an administrator - the satellite itself in this case - can update user data.
+    // See `assert_user_collection_write_permission` for details.
+    internal_set_doc_store(
+        id(),
+        COLLECTION_USER_KEY.to_string(),
+        user_id.to_text(),
+        set_doc,
+        &assert_options,
+    )?;
+
+    Ok(user_data)
+}
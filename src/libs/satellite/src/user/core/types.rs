@@ -1,4 +1,7 @@
 pub mod state {
+    use candid::Principal;
+    use junobuild_collections::types::rules::RoleName;
+    use junobuild_shared::types::state::Timestamp;
     use serde::{Deserialize, Serialize};
 
     #[derive(Serialize, Deserialize)]
@@ -7,6 +10,9 @@ pub mod state {
         pub provider: Option<AuthProvider>,
         pub banned: Option<BannedReason>,
         pub provider_data: Option<ProviderData>,
+        pub roles: Option<Vec<RoleName>>,
+        // The details of the ban - why, by whom and until when the user is banned.
+        pub ban: Option<UserBan>,
     }
 
     #[derive(Serialize, Deserialize)]
@@ -20,12 +26,32 @@ pub mod state {
         Google,
         #[serde(rename = "github")]
         GitHub,
+        // A generic OpenID Connect provider configured by the developer.
+        #[serde(rename = "openid")]
+        OpenId,
     }
 
     #[derive(Serialize, Deserialize, Clone)]
     #[serde(rename_all = "snake_case")]
     pub enum BannedReason {
         Indefinite,
+        // Lifted automatically once the `expires_at` of the ban details is reached.
+        Temporary,
+    }
+
+    #[derive(Serialize, Deserialize, Clone, Default)]
+    #[serde(rename_all = "camelCase", deny_unknown_fields)]
+    pub struct UserBan {
+        // A coded reason, e.g. "spam" or "harassment", for moderation tooling.
+        pub code: Option<String>,
+        // A free-text reason.
+        pub reason: Option<String>,
+        #[serde(default, with = "junobuild_utils::with::principal_opt")]
+        pub banned_by: Option<Principal>,
+        #[serde(default, with = "junobuild_utils::with::bigint_opt")]
+        pub banned_at: Option<Timestamp>,
+        #[serde(default, with = "junobuild_utils::with::bigint_opt")]
+        pub expires_at: Option<Timestamp>,
     }
 
     #[derive(Serialize, Deserialize)]
@@ -1,4 +1,7 @@
+pub mod bans;
 pub mod core;
 pub mod internal_hooks;
+pub mod roles;
+pub mod sessions;
 pub mod usage;
 pub mod webauthn;
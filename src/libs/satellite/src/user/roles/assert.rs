@@ -0,0 +1,40 @@
+use crate::user::roles::store::has_user_role;
+use candid::Principal;
+use junobuild_collections::assert::stores::{
+    assert_create_permission_with_roles, assert_permission_with_roles,
+};
+use junobuild_collections::types::rules::Permission;
+use junobuild_shared::segments::access_keys::is_write_access_key;
+use junobuild_shared::types::state::AccessKeys;
+
+/// Asserts the permission of a collection, resolving role-based permissions with the roles of the users.
+pub fn assert_permission_with_user_roles(
+    permission: &Permission,
+    owner: Principal,
+    caller: Principal,
+    controllers: &AccessKeys,
+) -> bool {
+    assert_permission_with_roles(
+        permission,
+        owner,
+        caller,
+        controllers,
+        is_write_access_key,
+        has_user_role,
+    )
+}
+
+/// Asserts the creation of a document or asset, resolving role-based permissions with the roles of the users.
+pub fn assert_create_permission_with_user_roles(
+    permission: &Permission,
+    caller: Principal,
+    controllers: &AccessKeys,
+) -> bool {
+    assert_create_permission_with_roles(
+        permission,
+        caller,
+        controllers,
+        is_write_access_key,
+        has_user_role,
+    )
+}
@@ -0,0 +1,3 @@
+pub mod assert;
+pub mod store;
+pub mod types;
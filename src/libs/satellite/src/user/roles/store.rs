@@ -0,0 +1,61 @@
+use crate::user::core::store::{get_user, update_user_data};
+use crate::user::core::types::state::UserData;
+use crate::user::roles::types::interface::UserRolesArgs;
+use junobuild_collections::types::rules::RoleName;
+use junobuild_shared::types::state::UserId;
+use junobuild_utils::decode_doc_data;
+
+/// Returns true if the user holds at least one of the roles.
+pub fn has_user_role(user_id: UserId, roles: &[RoleName]) -> bool {
+    let user_roles = get_user_roles(&user_id).unwrap_or_default();
+
+    user_roles.iter().any(|role| roles.contains(role))
+}
+
+pub fn get_user_roles(user_id: &UserId) -> Result<Vec<RoleName>, String> {
+    let user = get_user(user_id)?;
+
+    let roles = match user {
+        None => Vec::new(),
+        Some(user) => decode_doc_data::<UserData>(&user.data)?
+            .roles
+            .unwrap_or_default(),
+    };
+
+    Ok(roles)
+}
+
+pub fn assign_user_roles(
+    UserRolesArgs { user, roles }: &UserRolesArgs,
+) -> Result<Vec<RoleName>, String> {
+    update_user_roles(user, |current_roles| {
+        for role in roles {
+            if !current_roles.contains(role) {
+                current_roles.push(role.clone());
+            }
+        }
+    })
+}
+
+pub fn revoke_user_roles(
+    UserRolesArgs { user, roles }: &UserRolesArgs,
+) -> Result<Vec<RoleName>, String> {
+    update_user_roles(user, |current_roles| {
+        current_roles.retain(|role| !roles.contains(role))
+    })
+}
+
+fn update_user_roles(
+    user_id: &UserId,
+    update: impl FnOnce(&mut Vec<RoleName>),
+) -> Result<Vec<RoleName>, String> {
+    let user_data = update_user_data(user_id, |user_data| {
+        let mut roles = user_data.roles.take().unwrap_or_default();
+
+        update(&mut roles);
+
+        user_data.roles = if roles.is_empty() { None } else { Some(roles) };
+    })?;
+
+    Ok(user_data.roles.unwrap_or_default())
+}
@@ -0,0 +1,13 @@
+pub mod interface {
+    use candid::CandidType;
+    use junobuild_collections::types::rules::RoleName;
+    use junobuild_shared::types::state::UserId;
+    use serde::Deserialize;
+
+    /// The roles to assign to, or to revoke from, a user.
+    #[derive(CandidType, Deserialize, Clone)]
+    pub struct UserRolesArgs {
+        pub user: UserId,
+        pub roles: Vec<RoleName>,
+    }
+}
@@ -0,0 +1,17 @@
+use crate::errors::user::JUNO_DATASTORE_ERROR_USER_SESSIONS_REVOKED;
+use crate::user::sessions::state::get_user_sessions;
+use junobuild_shared::ic::api::time;
+use junobuild_shared::types::state::UserId;
+
+/// Rejects the user as long as the sessions issued before their revocation may still be in use.
+pub fn assert_user_sessions_not_revoked(user_id: &UserId) -> Result<(), String> {
+    let Some(sessions) = get_user_sessions(user_id) else {
+        return Ok(());
+    };
+
+    if sessions.is_revoked(time()) {
+        return Err(JUNO_DATASTORE_ERROR_USER_SESSIONS_REVOKED.to_string());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,2 @@
+// The maximum number of sessions tracked per user. The oldest are dropped first.
+pub const USER_SESSIONS_MAX_LENGTH: usize = 100;
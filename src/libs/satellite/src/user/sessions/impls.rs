@@ -0,0 +1,169 @@
+use crate::user::sessions::constants::USER_SESSIONS_MAX_LENGTH;
+use crate::user::sessions::types::state::{UserSession, UserSessions};
+use ic_stable_structures::storable::Bound;
+use ic_stable_structures::Storable;
+use junobuild_shared::memory::serializers::{
+    deserialize_from_bytes, serialize_into_bytes, serialize_to_bytes,
+};
+use junobuild_shared::types::state::Timestamp;
+use std::borrow::Cow;
+
+impl Storable for UserSessions {
+    fn to_bytes(&self) -> Cow<'_, [u8]> {
+        serialize_to_bytes(self)
+    }
+
+    fn into_bytes(self) -> Vec<u8> {
+        serialize_into_bytes(&self)
+    }
+
+    fn from_bytes(bytes: Cow<[u8]>) -> Self {
+        deserialize_from_bytes(bytes)
+    }
+
+    const BOUND: Bound = Bound::Unbounded;
+}
+
+impl UserSessions {
+    /// Tracks a new session and drops those that have expired.
+    pub fn add_session(&self, session: UserSession, now: Timestamp) -> Self {
+        let mut sessions: Vec<UserSession> = self
+            .sessions
+            .iter()
+            .filter(|session| session.expires_at > now)
+            .cloned()
+            .collect();
+
+        sessions.push(session);
+
+        let overflow = sessions.len().saturating_sub(USER_SESSIONS_MAX_LENGTH);
+        sessions.drain(..overflow);
+
+        UserSessions {
+            sessions,
+            ..self.clone()
+        }
+    }
+
+    /// Revokes all sessions issued until now.
+    ///
+    /// The user remains locked out until the latest expiration of the tracked sessions or, if
+    /// none is tracked - e.g. the user signed in before the sessions were tracked - until the
+    /// provided fallback.
+    pub fn revoke(&self, now: Timestamp, untracked_until: Timestamp) -> Self {
+        let latest_expiration = self
+            .sessions
+            .iter()
+            .filter(|session| session.expires_at > now)
+            .map(|session| session.expires_at)
+            .max();
+
+        let revoked_until = latest_expiration
+            .unwrap_or(untracked_until)
+            .max(self.revoked_until.unwrap_or(now))
+            .max(now);
+
+        UserSessions {
+            sessions: Vec::new(),
+            revoked_at: Some(now),
+            revoked_until: Some(revoked_until),
+        }
+    }
+
+    /// Lifts the revocation. The delegations issued before it can be used again.
+    pub fn lift(&self) -> Self {
+        UserSessions {
+            revoked_until: None,
+            ..self.clone()
+        }
+    }
+
+    pub fn is_revoked(&self, now: Timestamp) -> bool {
+        self.revoked_until.is_some_and(|until| until > now)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn session(created_at: Timestamp, expires_at: Timestamp) -> UserSession {
+        UserSession {
+            created_at,
+            expires_at,
+        }
+    }
+
+    #[test]
+    fn add_session_drops_expired_sessions() {
+        let sessions = UserSessions {
+            sessions: vec![session(0, 10), session(5, 50)],
+            ..Default::default()
+        };
+
+        let result = sessions.add_session(session(20, 100), 20);
+
+        assert_eq!(result.sessions, vec![session(5, 50), session(20, 100)]);
+    }
+
+    #[test]
+    fn add_session_keeps_the_most_recent_sessions() {
+        let sessions = UserSessions {
+            sessions: (0..USER_SESSIONS_MAX_LENGTH as u64)
+                .map(|i| session(i, 1_000 + i))
+                .collect(),
+            ..Default::default()
+        };
+
+        let result = sessions.add_session(session(500, 5_000), 0);
+
+        assert_eq!(result.sessions.len(), USER_SESSIONS_MAX_LENGTH);
+        assert_eq!(result.sessions.first(), Some(&session(1, 1_001)));
+        assert_eq!(result.sessions.last(), Some(&session(500, 5_000)));
+    }
+
+    #[test]
+    fn revoke_locks_until_latest_expiration() {
+        let sessions = UserSessions {
+            sessions: vec![session(0, 200), session(10, 150), session(0, 50)],
+            ..Default::default()
+        };
+
+        let result = sessions.revoke(100, 10_000);
+
+        assert!(result.sessions.is_empty());
+        assert_eq!(result.revoked_at, Some(100));
+        assert_eq!(result.revoked_until, Some(200));
+        assert!(result.is_revoked(199));
+        assert!(!result.is_revoked(200));
+    }
+
+    #[test]
+    fn revoke_untracked_sessions_uses_fallback() {
+        let result = UserSessions::default().revoke(100, 10_000);
+
+        assert_eq!(result.revoked_until, Some(10_000));
+    }
+
+    #[test]
+    fn revoke_does_not_shorten_a_previous_revocation() {
+        let sessions = UserSessions {
+            sessions: vec![session(150, 300)],
+            revoked_at: Some(100),
+            revoked_until: Some(1_000),
+        };
+
+        let result = sessions.revoke(200, 10_000);
+
+        assert_eq!(result.revoked_at, Some(200));
+        assert_eq!(result.revoked_until, Some(1_000));
+    }
+
+    #[test]
+    fn lift_clears_the_revocation_only() {
+        let result = UserSessions::default().revoke(100, 10_000).lift();
+
+        assert!(!result.is_revoked(100));
+        assert_eq!(result.revoked_at, Some(100));
+    }
+}
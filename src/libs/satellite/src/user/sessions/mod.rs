@@ -0,0 +1,6 @@
+pub mod assert;
+mod constants;
+mod impls;
+mod state;
+pub mod store;
+pub mod types;
@@ -0,0 +1,17 @@
+use crate::memory::state::STATE;
+use crate::user::sessions::types::state::UserSessions;
+use junobuild_shared::types::state::UserId;
+
+pub fn get_user_sessions(user_id: &UserId) -> Option<UserSessions> {
+    STATE.with(|state| state.borrow().stable.user_sessions.get(user_id))
+}
+
+pub fn insert_user_sessions(user_id: &UserId, sessions: &UserSessions) {
+    STATE.with(|state| {
+        state
+            .borrow_mut()
+            .stable
+            .user_sessions
+            .insert(*user_id, sessions.clone())
+    });
+}
@@ -0,0 +1,104 @@
+use crate::user::sessions::state::{
+    get_user_sessions as get_state_user_sessions, insert_user_sessions,
+};
+use crate::user::sessions::types::state::{UserSession, UserSessions};
+use junobuild_auth::delegation::MAX_EXPIRATION_PERIOD_NS;
+use junobuild_shared::ic::api::time;
+use junobuild_shared::types::state::{Timestamp, UserId};
+
+pub fn get_user_sessions(user_id: &UserId) -> Option<UserSessions> {
+    get_state_user_sessions(user_id)
+}
+
+/// Tracks a session - a delegation - issued to the user.
+///
+/// # Arguments
+/// - `user_id`: The user to whom the delegation was issued.
+/// - `expires_at`: The expiration of the delegation.
+pub fn record_user_session(user_id: &UserId, expires_at: Timestamp) {
+    let now = time();
+
+    let current_sessions = get_state_user_sessions(user_id).unwrap_or_default();
+
+    let sessions = current_sessions.add_session(
+        UserSession {
+            created_at: now,
+            expires_at,
+        },
+        now,
+    );
+
+    insert_user_sessions(user_id, &sessions);
+}
+
+/// Revokes all sessions issued to the user until now.
+///
+/// Because issued delegations cannot be invalidated, the calls of the user are rejected until the
+/// revoked delegations have expired. Sessions that were not tracked - such as those of users who
+/// signed in with Internet Identity or with a passkey - are assumed to last the maximum delegation
+/// period.
+///
+/// Note that the revocation applies to this Satellite only and not to the other canisters that
+/// were targeted by the delegations.
+///
+/// # Returns
+/// - The sessions of the user after revocation.
+pub fn revoke_user_sessions(user_id: &UserId) -> UserSessions {
+    let now = time();
+
+    let current_sessions = get_state_user_sessions(user_id).unwrap_or_default();
+
+    let sessions = current_sessions.revoke(now, now.saturating_add(MAX_EXPIRATION_PERIOD_NS));
+
+    insert_user_sessions(user_id, &sessions);
+
+    sessions
+}
+
+/// Lifts the revocation of the sessions of the user - e.g. to undo a revocation made by mistake.
+///
+/// The delegations issued before the revocation can be used again.
+///
+/// # Returns
+/// - The sessions of the user after the revocation was lifted, if any were recorded.
+pub fn clear_user_sessions_revocation(user_id: &UserId) -> Option<UserSessions> {
+    let current_sessions = get_state_user_sessions(user_id)?;
+
+    let sessions = current_sessions.lift();
+
+    insert_user_sessions(user_id, &sessions);
+
+    Some(sessions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use candid::Principal;
+
+    fn user(id: u8) -> UserId {
+        Principal::from_slice(&[id; 29])
+    }
+
+    #[test]
+    fn revoke_and_clear_the_revocation() {
+        let user_id = user(1);
+
+        record_user_session(&user_id, time() + 1_000);
+
+        let sessions = revoke_user_sessions(&user_id);
+
+        assert!(sessions.is_revoked(time()));
+        assert!(get_user_sessions(&user_id).unwrap().is_revoked(time()));
+
+        let sessions = clear_user_sessions_revocation(&user_id).unwrap();
+
+        assert!(!sessions.is_revoked(time()));
+        assert!(!get_user_sessions(&user_id).unwrap().is_revoked(time()));
+    }
+
+    #[test]
+    fn clear_revocation_of_unknown_user() {
+        assert_eq!(clear_user_sessions_revocation(&user(2)), None);
+    }
+}
@@ -0,0 +1,29 @@
+pub mod state {
+    use candid::CandidType;
+    use ic_stable_structures::StableBTreeMap;
+    use junobuild_shared::types::memory::Memory;
+    use junobuild_shared::types::state::{Timestamp, UserId};
+    use serde::{Deserialize, Serialize};
+
+    pub type UserSessionsStable = StableBTreeMap<UserId, UserSessions, Memory>;
+
+    /// The sessions - i.e. the delegations - issued by the Satellite to a user.
+    ///
+    /// A delegation cannot be invalidated before it expires and the IC does not tell which
+    /// delegation signed a call. That is why revoking the sessions records a not-before
+    /// timestamp and locks the user out until all the delegations issued before it have expired.
+    #[derive(Default, CandidType, Serialize, Deserialize, Clone, Debug, PartialEq)]
+    pub struct UserSessions {
+        pub sessions: Vec<UserSession>,
+        // When the sessions were last revoked
+        pub revoked_at: Option<Timestamp>,
+        // Until when the calls of the user are rejected - the latest expiration of the revoked delegations
+        pub revoked_until: Option<Timestamp>,
+    }
+
+    #[derive(CandidType, Serialize, Deserialize, Clone, Debug, PartialEq)]
+    pub struct UserSession {
+        pub created_at: Timestamp,
+        pub expires_at: Timestamp,
+    }
+}
@@ -1,17 +1,27 @@
 use crate::errors::user::{
+    JUNO_DATASTORE_ERROR_USER_USAGE_BYTES_LIMIT_REACHED,
     JUNO_DATASTORE_ERROR_USER_USAGE_CHANGE_LIMIT_REACHED,
     JUNO_DATASTORE_ERROR_USER_USAGE_INVALID_DATA,
+    JUNO_DATASTORE_ERROR_USER_USAGE_TOTAL_BYTES_LIMIT_REACHED,
 };
 use crate::types::state::CollectionType;
-use crate::user::usage::store::increment_usage;
-use crate::user::usage::types::state::UserUsageData;
+use crate::types::store::StoreContext;
+use crate::user::usage::store::{
+    get_config_store, get_total_bytes_usage, increment_usage, update_bytes_usage,
+};
+use crate::user::usage::types::config::UserUsageConfig;
+use crate::user::usage::types::interface::SetUserUsageConfig;
+use crate::user::usage::types::state::{UserTotalUsageData, UserUsageData};
 use crate::SetDoc;
 use junobuild_collections::assert::collection::is_system_collection;
 use junobuild_collections::constants::db::COLLECTION_USER_USAGE_KEY;
 use junobuild_collections::types::core::CollectionKey;
+use junobuild_shared::assert::assert_version;
 use junobuild_shared::segments::access_keys::is_write_access_key;
+use junobuild_shared::types::core::Key;
 use junobuild_shared::types::state::{AccessKeys, UserId};
 use junobuild_utils::decode_doc_data;
+
 // ---------------------------------------------------------
 // Increment user usage - i.e. when a user edit, create or delete
 // ---------------------------------------------------------
@@ -74,12 +84,112 @@ fn increment_and_assert_usage(
     Ok(())
 }
 
+// ---------------------------------------------------------
+// Update the bytes owned by a user - i.e. when a document or an asset is written or deleted
+// ---------------------------------------------------------
+
+pub fn update_and_assert_db_bytes_usage(
+    context: &StoreContext,
+    owner: UserId,
+    previous_size: u64,
+    size: u64,
+    max_bytes_per_user: Option<u64>,
+) -> Result<(), String> {
+    update_and_assert_bytes_usage(
+        context,
+        &CollectionType::Db,
+        owner,
+        previous_size,
+        size,
+        max_bytes_per_user,
+    )
+}
+
+pub fn update_and_assert_storage_bytes_usage(
+    context: &StoreContext,
+    owner: UserId,
+    previous_size: u64,
+    size: u64,
+    max_bytes_per_user: Option<u64>,
+) -> Result<(), String> {
+    update_and_assert_bytes_usage(
+        context,
+        &CollectionType::Storage,
+        owner,
+        previous_size,
+        size,
+        max_bytes_per_user,
+    )
+}
+
+fn update_and_assert_bytes_usage(
+    &StoreContext {
+        caller,
+        controllers,
+        collection,
+    }: &StoreContext,
+    collection_type: &CollectionType,
+    owner: UserId,
+    previous_size: u64,
+    size: u64,
+    max_bytes_per_user: Option<u64>,
+) -> Result<(), String> {
+    if is_system_collection(collection) || previous_size == size {
+        return Ok(());
+    }
+
+    // We only collect the bytes owned by users
+    if is_write_access_key(owner, controllers) {
+        return Ok(());
+    }
+
+    let user_usage = update_bytes_usage(collection, collection_type, &owner, previous_size, size)?;
+
+    // Releasing bytes is always allowed and the controllers are not limited.
+    if size < previous_size || is_write_access_key(caller, controllers) {
+        return Ok(());
+    }
+
+    // The error is returned - not trapped - when the write is performed by a serverless function,
+    // therefore the bytes that are not written are released.
+    if let Err(err) = assert_bytes_usage(&owner, &user_usage, max_bytes_per_user) {
+        update_bytes_usage(collection, collection_type, &owner, size, previous_size)?;
+
+        return Err(err);
+    }
+
+    Ok(())
+}
+
+fn assert_bytes_usage(
+    owner: &UserId,
+    user_usage: &Option<UserUsageData>,
+    max_bytes_per_user: Option<u64>,
+) -> Result<(), String> {
+    if let (Some(user_usage), Some(max_bytes_per_user)) = (user_usage, max_bytes_per_user) {
+        if user_usage.bytes.unwrap_or_default() > max_bytes_per_user {
+            return Err(JUNO_DATASTORE_ERROR_USER_USAGE_BYTES_LIMIT_REACHED.to_string());
+        }
+    }
+
+    if let Some(max_bytes_per_user) =
+        get_config_store().and_then(|config| config.max_bytes_per_user)
+    {
+        if get_total_bytes_usage(owner)? > max_bytes_per_user {
+            return Err(JUNO_DATASTORE_ERROR_USER_USAGE_TOTAL_BYTES_LIMIT_REACHED.to_string());
+        }
+    }
+
+    Ok(())
+}
+
 // ---------------------------------------------------------
 // Assert struct - useful when an admit set imperatively a user usage
 // ---------------------------------------------------------
 
 pub fn assert_user_usage_collection_data(
     collection: &CollectionKey,
+    key: &Key,
     doc: &SetDoc,
 ) -> Result<(), String> {
     let user_usage_collection = COLLECTION_USER_USAGE_KEY;
@@ -88,8 +198,85 @@ pub fn assert_user_usage_collection_data(
         return Ok(());
     }
 
-    decode_doc_data::<UserUsageData>(&doc.data)
-        .map_err(|err| format!("{JUNO_DATASTORE_ERROR_USER_USAGE_INVALID_DATA}: {err}"))?;
+    // The total of a user is keyed with its id only, the usage of a collection with its id, type and collection
+    let decoded = match key.contains('#') {
+        true => decode_doc_data::<UserUsageData>(&doc.data).map(|_| ()),
+        false => decode_doc_data::<UserTotalUsageData>(&doc.data).map(|_| ()),
+    };
+
+    decoded.map_err(|err| format!("{JUNO_DATASTORE_ERROR_USER_USAGE_INVALID_DATA}: {err}"))?;
+
+    Ok(())
+}
+
+// ---------------------------------------------------------
+// Config
+// ---------------------------------------------------------
+
+pub fn assert_set_config(
+    proposed_config: &SetUserUsageConfig,
+    current_config: &Option<UserUsageConfig>,
+) -> Result<(), String> {
+    if let Some(cfg) = current_config {
+        assert_version(proposed_config.version, cfg.version)?
+    }
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::store::set_rule_db;
+    use crate::rules::test_utils::public_rule;
+    use crate::rules::upgrade::init_system_collections;
+    use crate::user::usage::store::set_config_store;
+    use candid::Principal;
+    use junobuild_collections::types::rules::Memory;
+
+    fn user() -> Principal {
+        Principal::from_slice(&[1, 2, 3])
+    }
+
+    fn write(collection: &str, previous_size: u64, size: u64) -> Result<(), String> {
+        let controllers = AccessKeys::new();
+        let collection = collection.to_string();
+
+        let context = StoreContext {
+            caller: user(),
+            controllers: &controllers,
+            collection: &collection,
+        };
+
+        update_and_assert_db_bytes_usage(&context, user(), previous_size, size, None)
+    }
+
+    #[test]
+    fn asserts_the_total_quota_across_collections() {
+        init_system_collections();
+
+        for collection in ["total-a", "total-b"] {
+            set_rule_db(collection.to_string(), public_rule(Memory::Heap)).unwrap();
+        }
+
+        set_config_store(&SetUserUsageConfig {
+            max_bytes_per_user: Some(10),
+            version: None,
+        })
+        .unwrap();
+
+        assert!(write("total-a", 0, 6).is_ok());
+        assert!(write("total-b", 0, 6).is_err());
+
+        // The bytes of the rejected write are released
+        assert_eq!(get_total_bytes_usage(&user()).unwrap(), 6);
+
+        // The total follows the content that is replaced or deleted
+        assert!(write("total-a", 6, 2).is_ok());
+        assert!(write("total-b", 0, 6).is_ok());
+        assert_eq!(get_total_bytes_usage(&user()).unwrap(), 8);
+
+        assert!(write("total-b", 6, 0).is_ok());
+        assert_eq!(get_total_bytes_usage(&user()).unwrap(), 2);
+    }
+}
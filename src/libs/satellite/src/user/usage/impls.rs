@@ -1,7 +1,11 @@
 use crate::types::state::CollectionType;
-use crate::user::usage::types::state::{UserUsageData, UserUsageKey};
+use crate::user::usage::types::config::UserUsageConfig;
+use crate::user::usage::types::interface::SetUserUsageConfig;
+use crate::user::usage::types::state::{UserTotalUsageData, UserUsageData, UserUsageKey};
 use junobuild_collections::types::core::CollectionKey;
-use junobuild_shared::types::state::UserId;
+use junobuild_shared::data::version::next_version;
+use junobuild_shared::ic::api::time;
+use junobuild_shared::types::state::{Timestamp, UserId, Version, Versioned};
 
 impl UserUsageData {
     pub fn increment(current_user_usage: &Option<UserUsageData>) -> Self {
@@ -12,8 +16,48 @@ impl UserUsageData {
             Some(current_user_usage) => current_user_usage.changes_count.saturating_add(count),
         };
 
+        // A new usage has no bytes yet, an existing one keeps its - possibly not yet computed - bytes.
+        let bytes: Option<u64> = current_user_usage
+            .as_ref()
+            .map_or(Some(0), |current_user_usage| current_user_usage.bytes);
+
         UserUsageData {
             changes_count: items_count,
+            bytes,
+        }
+    }
+
+    pub fn update_bytes(
+        current_user_usage: &Option<UserUsageData>,
+        previous_size: u64,
+        size: u64,
+    ) -> Self {
+        let (changes_count, current_bytes) = match current_user_usage {
+            None => (0, 0),
+            Some(current_user_usage) => (
+                current_user_usage.changes_count,
+                current_user_usage.bytes.unwrap_or_default(),
+            ),
+        };
+
+        UserUsageData {
+            changes_count,
+            bytes: Some(
+                current_bytes
+                    .saturating_sub(previous_size)
+                    .saturating_add(size),
+            ),
+        }
+    }
+}
+
+impl UserTotalUsageData {
+    pub fn update_bytes(&self, previous_size: u64, size: u64) -> Self {
+        UserTotalUsageData {
+            bytes: self
+                .bytes
+                .saturating_sub(previous_size)
+                .saturating_add(size),
         }
     }
 }
@@ -39,4 +83,128 @@ impl UserUsageKey {
             self.collection_key
         )
     }
+
+    pub fn to_total_key(user_id: &UserId) -> String {
+        user_id.to_text()
+    }
+}
+
+impl UserUsageConfig {
+    pub fn prepare(
+        current_config: &Option<UserUsageConfig>,
+        user_config: &SetUserUsageConfig,
+    ) -> Self {
+        let now = time();
+
+        let created_at: Timestamp = match current_config {
+            None => now,
+            Some(current_config) => current_config.created_at.unwrap_or(now),
+        };
+
+        let version = next_version(current_config);
+
+        let updated_at: Timestamp = now;
+
+        UserUsageConfig {
+            max_bytes_per_user: user_config.max_bytes_per_user,
+            created_at: Some(created_at),
+            updated_at: Some(updated_at),
+            version: Some(version),
+        }
+    }
+}
+
+impl Versioned for UserUsageConfig {
+    fn version(&self) -> Option<Version> {
+        self.version
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ------------------------
+    // UserUsageData::increment
+    // ------------------------
+
+    #[test]
+    fn test_increment_keeps_bytes() {
+        let current = Some(UserUsageData {
+            changes_count: 2,
+            bytes: Some(1_024),
+        });
+
+        let usage = UserUsageData::increment(&current);
+        assert_eq!(usage.changes_count, 3);
+        assert_eq!(usage.bytes, Some(1_024));
+    }
+
+    // ------------------------
+    // UserUsageData::update_bytes
+    // ------------------------
+
+    #[test]
+    fn test_update_bytes_new_usage() {
+        let usage = UserUsageData::update_bytes(&None, 0, 512);
+        assert_eq!(usage.changes_count, 0);
+        assert_eq!(usage.bytes, Some(512));
+    }
+
+    #[test]
+    fn test_update_bytes_replaces_previous_size() {
+        let current = Some(UserUsageData {
+            changes_count: 5,
+            bytes: Some(1_000),
+        });
+
+        let usage = UserUsageData::update_bytes(&current, 300, 100);
+        assert_eq!(usage.changes_count, 5);
+        assert_eq!(usage.bytes, Some(800));
+    }
+
+    #[test]
+    fn test_update_bytes_does_not_underflow() {
+        let current = Some(UserUsageData {
+            changes_count: 1,
+            bytes: Some(100),
+        });
+
+        let usage = UserUsageData::update_bytes(&current, 500, 0);
+        assert_eq!(usage.bytes, Some(0));
+    }
+
+    #[test]
+    fn test_update_total_bytes_does_not_underflow() {
+        let total = UserTotalUsageData { bytes: 1_000 };
+
+        assert_eq!(total.update_bytes(300, 100).bytes, 800);
+        assert_eq!(total.update_bytes(5_000, 0).bytes, 0);
+    }
+
+    #[test]
+    fn test_deserialize_usage_without_bytes() {
+        let usage: UserUsageData = serde_json::from_str(r#"{"changes_count":3}"#).unwrap();
+        assert_eq!(usage.changes_count, 3);
+        assert_eq!(usage.bytes, None);
+    }
+
+    #[test]
+    fn test_increment_new_usage_owns_no_bytes() {
+        let usage = UserUsageData::increment(&None);
+        assert_eq!(usage.changes_count, 1);
+        assert_eq!(usage.bytes, Some(0));
+    }
+
+    #[test]
+    fn test_increment_keeps_bytes_to_compute() {
+        let current = Some(UserUsageData {
+            changes_count: 2,
+            bytes: None,
+        });
+
+        let usage = UserUsageData::increment(&current);
+        assert_eq!(usage.changes_count, 3);
+        assert_eq!(usage.bytes, None);
+    }
 }
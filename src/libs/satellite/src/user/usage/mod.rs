@@ -1,5 +1,6 @@
 pub mod assert;
 mod impls;
 pub mod internal_hooks;
-mod store;
-mod types;
+mod state;
+pub mod store;
+pub mod types;
@@ -0,0 +1,22 @@
+use crate::memory::state::STATE;
+use crate::user::usage::types::config::UserUsageConfig;
+use crate::user::usage::types::state::UserUsageHeapState;
+
+pub fn get_config() -> Option<UserUsageConfig> {
+    STATE.with(|state| {
+        state
+            .borrow()
+            .heap
+            .user_usage
+            .as_ref()
+            .map(|user_usage| user_usage.config.clone())
+    })
+}
+
+pub fn insert_config(config: &UserUsageConfig) {
+    STATE.with(|state| {
+        state.borrow_mut().heap.user_usage = Some(UserUsageHeapState {
+            config: config.clone(),
+        })
+    })
+}
@@ -1,15 +1,29 @@
-use crate::db::internal::{unsafe_delete_doc, unsafe_get_doc, unsafe_set_doc};
-use crate::rules::store::{get_non_system_rules_db, get_non_system_rules_storage, get_rule_db};
+use crate::assets::storage::internal::unsafe_get_owner_assets_size;
+use crate::db::internal::{
+    unsafe_delete_doc, unsafe_get_doc, unsafe_get_owner_docs_size, unsafe_set_doc,
+};
+use crate::db::types::state::Doc;
+use crate::rules::store::{
+    get_non_system_rules_db, get_non_system_rules_storage, get_rule_db, get_rule_storage,
+};
 use crate::types::state::CollectionType;
-use crate::user::usage::types::state::{UserUsageData, UserUsageKey};
+use crate::user::usage::assert::assert_set_config;
+use crate::user::usage::state::{get_config, insert_config};
+use crate::user::usage::types::config::UserUsageConfig;
+use crate::user::usage::types::interface::{SetUserUsageConfig, UserCollectionUsage, UserUsage};
+use crate::user::usage::types::state::{UserTotalUsageData, UserUsageData, UserUsageKey};
 use crate::SetDoc;
+use junobuild_collections::assert::collection::is_system_collection;
 use junobuild_collections::constants::db::COLLECTION_USER_USAGE_KEY;
-use junobuild_collections::msg::msg_db_collection_not_found;
+use junobuild_collections::msg::{msg_db_collection_not_found, msg_storage_collection_not_found};
 use junobuild_collections::types::core::CollectionKey;
 use junobuild_collections::types::rules::Rule;
 use junobuild_shared::ic::api::id;
+use junobuild_shared::types::core::Key;
 use junobuild_shared::types::state::UserId;
 use junobuild_utils::{decode_doc_data, encode_doc_data};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
 
 pub fn increment_usage(
     collection_key: &CollectionKey,
@@ -18,42 +32,204 @@ pub fn increment_usage(
 ) -> Result<UserUsageData, String> {
     let user_usage_key = UserUsageKey::create(user_id, collection_key, collection_type).to_key();
 
-    let user_usage_collection = COLLECTION_USER_USAGE_KEY.to_string();
-
-    let rule = get_rule_db(&user_usage_collection)
-        .ok_or_else(|| msg_db_collection_not_found(&user_usage_collection))?;
+    let (user_usage_collection, rule) = get_user_usage_rule()?;
 
-    let doc = unsafe_get_doc(&user_usage_collection.to_string(), &user_usage_key, &rule)?;
+    let (doc, current_usage) = get_usage(&user_usage_collection, &user_usage_key, &rule)?;
 
-    let current_usage = doc
-        .as_ref()
-        .map(|doc| decode_doc_data(&doc.data))
-        .transpose()?;
+    let current_usage = backfill_bytes(collection_key, collection_type, user_id, current_usage)?;
 
     let update_usage = UserUsageData::increment(&current_usage);
 
-    let update_doc = SetDoc {
-        data: encode_doc_data(&update_usage)?,
-        description: doc.as_ref().and_then(|d| d.description.clone()),
-        version: doc.as_ref().and_then(|d| d.version),
-    };
-
-    unsafe_set_doc(
-        id(),
-        &user_usage_collection.to_string(),
+    set_usage(
+        &user_usage_collection,
         &user_usage_key,
-        update_doc,
+        &doc,
+        &update_usage,
         &rule,
     )?;
 
     Ok(update_usage)
 }
 
-pub fn delete_user_usage(user_id: &UserId) -> Result<(), String> {
-    let user_usage_collection = COLLECTION_USER_USAGE_KEY.to_string();
+pub fn update_bytes_usage(
+    collection_key: &CollectionKey,
+    collection_type: &CollectionType,
+    user_id: &UserId,
+    previous_size: u64,
+    size: u64,
+) -> Result<Option<UserUsageData>, String> {
+    let user_usage_key = UserUsageKey::create(user_id, collection_key, collection_type).to_key();
 
-    let rule = get_rule_db(&user_usage_collection)
-        .ok_or_else(|| msg_db_collection_not_found(&user_usage_collection))?;
+    let (user_usage_collection, rule) = get_user_usage_rule()?;
+
+    let (doc, current_usage) = get_usage(&user_usage_collection, &user_usage_key, &rule)?;
+
+    // Nothing was accounted for the user - e.g. the content was written by a controller - therefore there is nothing to decrease.
+    if current_usage.is_none() && size <= previous_size {
+        return Ok(None);
+    }
+
+    let current_usage = backfill_bytes(collection_key, collection_type, user_id, current_usage)?;
+
+    let update_usage = UserUsageData::update_bytes(&current_usage, previous_size, size);
+
+    set_usage(
+        &user_usage_collection,
+        &user_usage_key,
+        &doc,
+        &update_usage,
+        &rule,
+    )?;
+
+    update_total_bytes_usage(user_id, previous_size, size, &user_usage_collection, &rule)?;
+
+    Ok(Some(update_usage))
+}
+
+fn update_total_bytes_usage(
+    user_id: &UserId,
+    previous_size: u64,
+    size: u64,
+    user_usage_collection: &CollectionKey,
+    user_usage_rule: &Rule,
+) -> Result<(), String> {
+    let (doc, current_total) = get_total_usage(user_usage_collection, user_id, user_usage_rule)?;
+
+    let update_total = match current_total {
+        Some(current_total) => current_total.update_bytes(previous_size, size),
+        // The usage of the collection is already updated, therefore the computed total includes the change.
+        None => UserTotalUsageData {
+            bytes: get_user_usage(user_id)?.bytes,
+        },
+    };
+
+    set_usage(
+        user_usage_collection,
+        &UserUsageKey::to_total_key(user_id),
+        &doc,
+        &update_total,
+        user_usage_rule,
+    )
+}
+
+/// Returns the total size in bytes of the documents and assets a user owns across all the collections.
+pub fn get_total_bytes_usage(user_id: &UserId) -> Result<u64, String> {
+    let (user_usage_collection, rule) = get_user_usage_rule()?;
+
+    let (_, total) = get_total_usage(&user_usage_collection, user_id, &rule)?;
+
+    match total {
+        Some(total) => Ok(total.bytes),
+        None => Ok(get_user_usage(user_id)?.bytes),
+    }
+}
+
+/// Releases the bytes of a document or an asset deleted without assertion - e.g. when it expired.
+pub fn release_bytes_usage(
+    collection_key: &CollectionKey,
+    collection_type: &CollectionType,
+    user_id: &UserId,
+    size: u64,
+) -> Result<(), String> {
+    if is_system_collection(collection_key) {
+        return Ok(());
+    }
+
+    let user_usage_key = UserUsageKey::create(user_id, collection_key, collection_type).to_key();
+
+    let (user_usage_collection, rule) = get_user_usage_rule()?;
+
+    let (_, current_usage) = get_usage(&user_usage_collection, &user_usage_key, &rule)?;
+
+    // The content is already deleted, the bytes computed for the usage will not include it.
+    if current_usage.is_some_and(|usage| usage.bytes.is_none()) {
+        return Ok(());
+    }
+
+    update_bytes_usage(collection_key, collection_type, user_id, size, 0)?;
+
+    Ok(())
+}
+
+pub fn get_user_usage(user_id: &UserId) -> Result<UserUsage, String> {
+    let (user_usage_collection, rule) = get_user_usage_rule()?;
+
+    let mut collections = get_user_usage_for_collections(
+        user_id,
+        &CollectionType::Db,
+        &user_usage_collection,
+        &rule,
+    )?;
+    collections.extend(get_user_usage_for_collections(
+        user_id,
+        &CollectionType::Storage,
+        &user_usage_collection,
+        &rule,
+    )?);
+
+    let bytes = collections
+        .iter()
+        .fold(0u64, |bytes, usage| bytes.saturating_add(usage.bytes));
+
+    let max_bytes = get_config().and_then(|config| config.max_bytes_per_user);
+
+    Ok(UserUsage {
+        bytes,
+        max_bytes,
+        collections,
+    })
+}
+
+fn get_user_usage_for_collections(
+    user_id: &UserId,
+    collection_type: &CollectionType,
+    user_usage_collection: &CollectionKey,
+    user_usage_rule: &Rule,
+) -> Result<Vec<UserCollectionUsage>, String> {
+    let rules = match collection_type {
+        CollectionType::Db => get_non_system_rules_db(),
+        CollectionType::Storage => get_non_system_rules_storage(),
+    };
+
+    let mut usages: Vec<UserCollectionUsage> = Vec::new();
+
+    for (collection_key, rule) in rules {
+        let user_usage_key =
+            UserUsageKey::create(user_id, &collection_key, collection_type).to_key();
+
+        let (doc, usage) = get_usage(user_usage_collection, &user_usage_key, user_usage_rule)?;
+
+        let computed = usage.as_ref().is_some_and(|usage| usage.bytes.is_none());
+
+        let usage = backfill_bytes(&collection_key, collection_type, user_id, usage)?;
+
+        // Saved so that the bytes are computed once - i.e. when the usage is asserted in an update.
+        if let (true, Some(usage)) = (computed, &usage) {
+            set_usage(
+                user_usage_collection,
+                &user_usage_key,
+                &doc,
+                usage,
+                user_usage_rule,
+            )?;
+        }
+
+        if let Some(usage) = usage {
+            usages.push(UserCollectionUsage {
+                collection: collection_key,
+                collection_type: collection_type.clone(),
+                changes_count: usage.changes_count,
+                bytes: usage.bytes.unwrap_or_default(),
+                max_bytes: rule.max_bytes_per_user,
+            });
+        }
+    }
+
+    Ok(usages)
+}
+
+pub fn delete_user_usage(user_id: &UserId) -> Result<(), String> {
+    let (user_usage_collection, rule) = get_user_usage_rule()?;
 
     delete_user_usage_for_collections(user_id, &CollectionType::Db, &user_usage_collection, &rule)?;
     delete_user_usage_for_collections(
@@ -63,6 +239,12 @@ pub fn delete_user_usage(user_id: &UserId) -> Result<(), String> {
         &rule,
     )?;
 
+    unsafe_delete_doc(
+        &user_usage_collection,
+        &UserUsageKey::to_total_key(user_id),
+        &rule,
+    )?;
+
     Ok(())
 }
 
@@ -86,3 +268,164 @@ fn delete_user_usage_for_collections(
 
     Ok(())
 }
+
+/// Computes and saves the bytes of a usage recorded before the sizes were tracked. Useful when the
+/// content is written before its size is accounted for - e.g. when an upload is committed.
+pub fn backfill_bytes_usage(
+    collection_key: &CollectionKey,
+    collection_type: &CollectionType,
+    user_id: &UserId,
+) -> Result<(), String> {
+    if is_system_collection(collection_key) {
+        return Ok(());
+    }
+
+    let user_usage_key = UserUsageKey::create(user_id, collection_key, collection_type).to_key();
+
+    let (user_usage_collection, rule) = get_user_usage_rule()?;
+
+    let (doc, current_usage) = get_usage(&user_usage_collection, &user_usage_key, &rule)?;
+
+    if !current_usage
+        .as_ref()
+        .is_some_and(|usage| usage.bytes.is_none())
+    {
+        return Ok(());
+    }
+
+    if let Some(usage) = backfill_bytes(collection_key, collection_type, user_id, current_usage)? {
+        set_usage(&user_usage_collection, &user_usage_key, &doc, &usage, &rule)?;
+    }
+
+    Ok(())
+}
+
+/// Computes the bytes of a usage recorded before the sizes were tracked from the documents or assets
+/// the user owns in the collection. Must be called before the content is modified.
+fn backfill_bytes(
+    collection_key: &CollectionKey,
+    collection_type: &CollectionType,
+    user_id: &UserId,
+    usage: Option<UserUsageData>,
+) -> Result<Option<UserUsageData>, String> {
+    match usage {
+        Some(UserUsageData {
+            changes_count,
+            bytes: None,
+        }) => Ok(Some(UserUsageData {
+            changes_count,
+            bytes: Some(get_owned_bytes(collection_key, collection_type, user_id)?),
+        })),
+        usage => Ok(usage),
+    }
+}
+
+fn get_owned_bytes(
+    collection_key: &CollectionKey,
+    collection_type: &CollectionType,
+    user_id: &UserId,
+) -> Result<u64, String> {
+    match collection_type {
+        CollectionType::Db => {
+            let rule = get_rule_db(collection_key)
+                .ok_or_else(|| msg_db_collection_not_found(collection_key))?;
+
+            unsafe_get_owner_docs_size(collection_key, user_id, &rule)
+        }
+        CollectionType::Storage => {
+            let rule = get_rule_storage(collection_key)
+                .ok_or_else(|| msg_storage_collection_not_found(collection_key))?;
+
+            Ok(unsafe_get_owner_assets_size(collection_key, user_id, &rule))
+        }
+    }
+}
+
+fn get_user_usage_rule() -> Result<(CollectionKey, Rule), String> {
+    let user_usage_collection = COLLECTION_USER_USAGE_KEY.to_string();
+
+    let rule = get_rule_db(&user_usage_collection)
+        .ok_or_else(|| msg_db_collection_not_found(&user_usage_collection))?;
+
+    Ok((user_usage_collection, rule))
+}
+
+fn get_usage(
+    user_usage_collection: &CollectionKey,
+    user_usage_key: &Key,
+    rule: &Rule,
+) -> Result<(Option<Doc>, Option<UserUsageData>), String> {
+    get_usage_data(user_usage_collection, user_usage_key, rule)
+}
+
+fn get_total_usage(
+    user_usage_collection: &CollectionKey,
+    user_id: &UserId,
+    rule: &Rule,
+) -> Result<(Option<Doc>, Option<UserTotalUsageData>), String> {
+    get_usage_data(
+        user_usage_collection,
+        &UserUsageKey::to_total_key(user_id),
+        rule,
+    )
+}
+
+fn get_usage_data<T: DeserializeOwned>(
+    user_usage_collection: &CollectionKey,
+    user_usage_key: &Key,
+    rule: &Rule,
+) -> Result<(Option<Doc>, Option<T>), String> {
+    let doc = unsafe_get_doc(user_usage_collection, user_usage_key, rule)?;
+
+    let usage = doc
+        .as_ref()
+        .map(|doc| decode_doc_data(&doc.data))
+        .transpose()?;
+
+    Ok((doc, usage))
+}
+
+fn set_usage<T: Serialize>(
+    user_usage_collection: &CollectionKey,
+    user_usage_key: &Key,
+    doc: &Option<Doc>,
+    usage: &T,
+    rule: &Rule,
+) -> Result<(), String> {
+    let update_doc = SetDoc {
+        data: encode_doc_data(usage)?,
+        description: doc.as_ref().and_then(|d| d.description.clone()),
+        version: doc.as_ref().and_then(|d| d.version),
+        expires_at: None,
+    };
+
+    unsafe_set_doc(
+        id(),
+        user_usage_collection,
+        user_usage_key,
+        update_doc,
+        rule,
+    )?;
+
+    Ok(())
+}
+
+// ---------------------------------------------------------
+// Config
+// ---------------------------------------------------------
+
+pub fn set_config_store(proposed_config: &SetUserUsageConfig) -> Result<UserUsageConfig, String> {
+    let current_config = get_config();
+
+    assert_set_config(proposed_config, &current_config)?;
+
+    let config = UserUsageConfig::prepare(&current_config, proposed_config);
+
+    insert_config(&config);
+
+    Ok(config)
+}
+
+pub fn get_config_store() -> Option<UserUsageConfig> {
+    get_config()
+}
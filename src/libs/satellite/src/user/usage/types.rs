@@ -1,9 +1,16 @@
 pub mod state {
     use crate::types::state::CollectionType;
+    use crate::user::usage::types::config::UserUsageConfig;
+    use candid::CandidType;
     use junobuild_collections::types::core::CollectionKey;
     use junobuild_shared::types::state::UserId;
     use serde::{Deserialize, Serialize};
 
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct UserUsageHeapState {
+        pub config: UserUsageConfig,
+    }
+
     /// A unique key for identifying user usage within a collection.
     /// The key will be parsed to `user-id#db|storage#collection`.
     #[derive(Serialize, Deserialize)]
@@ -13,10 +20,81 @@ pub mod state {
         pub collection_type: CollectionType,
     }
 
-    /// Tracks the usage (create, set and delete) of a user in a collection.
+    /// Tracks the usage (create, set and delete) of a user in a collection,
+    /// and the total size in bytes of the documents or assets the user owns in it.
+    ///
+    /// The bytes are `None` for the usages recorded before the sizes were tracked. They are computed
+    /// from the content owned by the user the first time the usage is updated.
     #[derive(Serialize, Deserialize)]
     #[serde(deny_unknown_fields)]
     pub struct UserUsageData {
         pub changes_count: u32,
+        #[serde(default)]
+        pub bytes: Option<u64>,
+    }
+
+    /// Tracks the total size in bytes of the documents and assets a user owns across all the collections
+    /// of the Datastore and Storage. Its key is the user id.
+    ///
+    /// The total is updated alongside the bytes of the collections, so that the global quota is asserted
+    /// without reading the usages of every collection. It is computed from these the first time it is updated.
+    #[derive(Serialize, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    pub struct UserTotalUsageData {
+        pub bytes: u64,
+    }
+}
+
+pub mod config {
+    use candid::CandidType;
+    use junobuild_shared::types::state::{Timestamp, Version};
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Default, CandidType, Serialize, Deserialize, Clone)]
+    pub struct UserUsageConfig {
+        // The total size in bytes of the documents and assets a user can own across all the collections of the Datastore and Storage
+        pub max_bytes_per_user: Option<u64>,
+        pub version: Option<Version>,
+        pub created_at: Option<Timestamp>,
+        pub updated_at: Option<Timestamp>,
+    }
+}
+
+pub mod interface {
+    use crate::types::state::CollectionType;
+    use candid::CandidType;
+    use junobuild_collections::types::core::CollectionKey;
+    use junobuild_shared::types::state::Version;
+    use serde::{Deserialize, Serialize};
+
+    /// Parameters for setting the configuration of the usage of the users.
+    ///
+    /// - `max_bytes_per_user`: An optional total size in bytes of the documents and assets a user can own across all the collections of the Datastore and Storage.
+    /// - `version`: An optional `Version` used for version control to ensure update consistency. If specified, it must match the current configuration version to apply the update.
+    #[derive(Default, CandidType, Serialize, Deserialize, Clone)]
+    pub struct SetUserUsageConfig {
+        pub max_bytes_per_user: Option<u64>,
+        pub version: Option<Version>,
+    }
+
+    /// The consumption of a user across the collections of the Datastore and Storage.
+    ///
+    /// - `bytes`: The total size in bytes of the documents and assets owned by the user.
+    /// - `max_bytes`: The total size the user can own, if a limit is configured.
+    /// - `collections`: The consumption per collection.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct UserUsage {
+        pub bytes: u64,
+        pub max_bytes: Option<u64>,
+        pub collections: Vec<UserCollectionUsage>,
+    }
+
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct UserCollectionUsage {
+        pub collection: CollectionKey,
+        pub collection_type: CollectionType,
+        pub changes_count: u32,
+        pub bytes: u64,
+        pub max_bytes: Option<u64>,
     }
 }
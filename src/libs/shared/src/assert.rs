@@ -1,12 +1,13 @@
 use crate::errors::{
-    JUNO_ERROR_MEMORY_HEAP_EXCEEDED, JUNO_ERROR_MEMORY_STABLE_EXCEEDED, JUNO_ERROR_NO_TIMESTAMP,
-    JUNO_ERROR_NO_VERSION, JUNO_ERROR_TIMESTAMP_OUTDATED_OR_FUTURE,
+    JUNO_ERROR_EXPIRY_IN_PAST, JUNO_ERROR_MEMORY_HEAP_EXCEEDED, JUNO_ERROR_MEMORY_STABLE_EXCEEDED,
+    JUNO_ERROR_NO_TIMESTAMP, JUNO_ERROR_NO_VERSION, JUNO_ERROR_TIMESTAMP_OUTDATED_OR_FUTURE,
     JUNO_ERROR_VERSION_OUTDATED_OR_FUTURE,
 };
+use crate::ic::api::time;
 use crate::segments::utils::memory_size;
 use crate::types::config::ConfigMaxMemorySize;
 use crate::types::interface::MemorySize;
-use crate::types::state::Version;
+use crate::types::state::{Timestamp, Version};
 
 /// Asserts the validity of a given user timestamp against the current timestamp.
 /// e.g. the timestamp of an existing entity persisted in a smart contract.
@@ -165,6 +166,28 @@ pub fn assert_description_length(description: &Option<String>) -> Result<(), Str
     Ok(())
 }
 
+/// Asserts that an optional expiration - e.g. of a document or an asset - is not in the past.
+///
+/// # Parameters
+/// - `expires_at`: An `Option<Timestamp>` representing when the entity expires, if ever.
+///
+/// # Returns
+/// - `Ok(())` if no expiration is provided or if it is in the future.
+/// - `Err(String)` if the expiration is in the past.
+pub fn assert_expires_at(expires_at: &Option<Timestamp>) -> Result<(), String> {
+    if let Some(expires_at) = expires_at {
+        let now = time();
+
+        if *expires_at < now {
+            return Err(format!(
+                "{JUNO_ERROR_EXPIRY_IN_PAST} ({expires_at} - {now})"
+            ));
+        }
+    }
+
+    Ok(())
+}
+
 pub fn assert_max_memory_size(
     config_max_memory_size: &Option<ConfigMaxMemorySize>,
 ) -> Result<(), String> {
@@ -1,33 +1,56 @@
+use crate::errors::JUNO_ERROR_INVALID_LIST_CURSOR;
+use crate::memory::serializers::serialize_into_bytes;
 use crate::regex::build_regex;
 use crate::types::core::Key;
 use crate::types::list::{
-    ListMatcher, ListOrder, ListOrderField, ListPaginate, ListParams, ListResults, TimestampMatcher,
+    IndexValue, IndexValueMatcher, ListCursor, ListCursorPosition, ListMatcher, ListOrder,
+    ListOrderField, ListPaginate, ListParams, ListResults, ListSeek, TimestampMatcher,
 };
 use crate::types::state::Timestamp;
 use crate::types::state::Timestamped;
+use ciborium::from_reader;
 use regex::Regex;
-
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::cmp::Ordering;
+
+/// Orders and paginates values that match a list filter.
+///
+/// Pagination either starts after a `start_after` key or resumes from a `cursor` returned with a
+/// previous page. A cursor encodes the sort field value and the key of the last item of that page,
+/// so resuming is a binary search over the ordered values that remains correct if values were
+/// inserted or deleted in between.
+///
+/// `matches` has to contain every value that matches the filter. Stores ordered by key should
+/// rather seek to the page with [`list_seek`] when a list ordered by keys resumes from a cursor.
+///
+/// # Returns
+/// - `Ok(ListResults<T>)`: The page of values and a `next_cursor` if more values follow.
+/// - `Err(String)`: If the cursor cannot be decoded or does not match the order of the list.
 pub fn list_values<'a, T: Clone + Timestamped>(
     matches: &'a [(&'a Key, &'a T)],
     filters: &'a ListParams,
-) -> ListResults<T> {
+) -> Result<ListResults<T>, String> {
     let matches_length = matches.len();
 
     let ordered = order_values(matches, filters);
 
-    let start = start_at(&ordered, filters);
+    let start = start_at(&ordered, filters)?;
 
-    let paginated = paginate_values(ordered, filters, &start);
+    let paginated = paginate_values(&ordered, filters, &start);
 
     let length = paginated.len();
 
-    ListResults {
+    let next_cursor = next_cursor(&ordered, filters, start, length);
+
+    Ok(ListResults {
         items: paginated,
         items_length: length,
         matches_length,
         items_page: current_page(start, filters),
         matches_pages: total_pages(matches_length, filters),
-    }
+        next_cursor,
+    })
 }
 
 fn current_page(start_at: Option<usize>, filters: &ListParams) -> Option<usize> {
@@ -43,25 +66,185 @@ fn current_page(start_at: Option<usize>, filters: &ListParams) -> Option<usize>
 fn total_pages(matches_length: usize, filters: &ListParams) -> Option<usize> {
     match filters.clone().paginate {
         None => None,
-        Some(paginate) => paginate.limit.map(|limit| matches_length / limit),
+        Some(paginate) => paginate.limit.map(|limit| matches_length.div_ceil(limit)),
     }
 }
 
-fn start_at<T: Clone + Timestamped>(matches: &[(&Key, &T)], filters: &ListParams) -> Option<usize> {
+fn start_at<T: Clone + Timestamped>(
+    matches: &[(&Key, &T)],
+    filters: &ListParams,
+) -> Result<Option<usize>, String> {
     match filters.clone().paginate {
-        None => None,
-        Some(paginate) => match paginate.start_after {
-            None => Some(0),
-            Some(start_after) => {
+        None => Ok(None),
+        Some(paginate) => match (paginate.cursor, paginate.start_after) {
+            (Some(cursor), _) => {
+                let position = decode_cursor(&cursor, filters)?;
+
+                // Ordered values are strictly sorted by (field, key) therefore the values that
+                // precede or equal the cursor form a prefix of the list.
+                let index = matches.partition_point(|(key, value)| {
+                    cmp_cursor_position(key, *value, &position, filters) != Ordering::Greater
+                });
+
+                Ok(Some(index))
+            }
+            (None, None) => Ok(Some(0)),
+            (None, Some(start_after)) => {
                 let index = matches
                     .iter()
                     .position(|(key, _)| (*key).clone().eq(&start_after));
-                index.map(|index| index + 1)
+                Ok(index.map(|index| index + 1))
             }
         },
     }
 }
 
+fn next_cursor<T: Clone + Timestamped>(
+    matches: &[(&Key, &T)],
+    filters: &ListParams,
+    start_at: Option<usize>,
+    length: usize,
+) -> Option<ListCursor> {
+    filters.paginate.as_ref()?;
+
+    let start = start_at?;
+
+    if length == 0 || start.saturating_add(length) >= matches.len() {
+        return None;
+    }
+
+    let (key, value) = matches.get(start + length - 1)?;
+
+    let position = ListCursorPosition {
+        timestamp: order_timestamp(*value, filters),
+        key: (*key).clone(),
+    };
+
+    Some(encode_list_cursor(&position))
+}
+
+/// Returns where a page resumes when a list ordered by keys is continued from a cursor.
+///
+/// Stores ordered by key can then read the page from that position - seeking in logarithmic time -
+/// instead of loading and sorting every match. Lists ordered by timestamps, or not continued from a
+/// cursor, return `None` and are listed with [`list_values`].
+///
+/// # Returns
+/// - `Ok(Some(ListSeek))`: The key after which the page starts, the direction and the limit.
+/// - `Ok(None)`: The list cannot be resumed by seeking.
+/// - `Err(String)`: If the cursor cannot be decoded or does not match the order of the list.
+pub fn list_seek(filters: &ListParams) -> Result<Option<ListSeek>, String> {
+    let Some(ListPaginate {
+        cursor: Some(cursor),
+        limit,
+        start_after: _,
+    }) = &filters.paginate
+    else {
+        return Ok(None);
+    };
+
+    let desc = match &filters.order {
+        None => false,
+        Some(ListOrder {
+            field: ListOrderField::Keys,
+            desc,
+        }) => *desc,
+        Some(_) => return Ok(None),
+    };
+
+    let position = decode_cursor(cursor, filters)?;
+
+    Ok(Some(ListSeek {
+        start_after: position.key,
+        desc,
+        limit: *limit,
+    }))
+}
+
+/// Builds the page of a list resumed by seeking after a cursor.
+///
+/// `values` are the matches that follow the cursor in the order of the list - one beyond the limit
+/// if more follow. Only the page is read, therefore the number of matches is the length of the
+/// page and no page index is provided.
+pub fn list_values_after_seek<T>(mut values: Vec<(Key, T)>, seek: &ListSeek) -> ListResults<T> {
+    let has_more = seek.limit.is_some_and(|limit| values.len() > limit);
+
+    if let Some(limit) = seek.limit {
+        values.truncate(limit);
+    }
+
+    let next_cursor = values.last().filter(|_| has_more).map(|(key, _)| {
+        encode_list_cursor(&ListCursorPosition {
+            timestamp: None,
+            key: key.clone(),
+        })
+    });
+
+    let length = values.len();
+
+    ListResults {
+        items: values,
+        items_length: length,
+        matches_length: length,
+        items_page: None,
+        matches_pages: None,
+        next_cursor,
+    }
+}
+
+/// Encodes a position into an opaque cursor.
+pub fn encode_list_cursor<T: Serialize>(position: &T) -> ListCursor {
+    serialize_into_bytes(position)
+}
+
+/// Decodes the position of an opaque cursor.
+pub fn decode_list_cursor<T: DeserializeOwned>(cursor: &ListCursor) -> Result<T, String> {
+    from_reader(cursor.as_slice()).map_err(|_| JUNO_ERROR_INVALID_LIST_CURSOR.to_string())
+}
+
+fn decode_cursor(cursor: &ListCursor, filters: &ListParams) -> Result<ListCursorPosition, String> {
+    let position: ListCursorPosition = decode_list_cursor(cursor)?;
+
+    let ordered_by_timestamp = order_field(filters).is_some_and(|field| {
+        matches!(field, ListOrderField::CreatedAt | ListOrderField::UpdatedAt)
+    });
+
+    if position.timestamp.is_some() != ordered_by_timestamp {
+        return Err(JUNO_ERROR_INVALID_LIST_CURSOR.to_string());
+    }
+
+    Ok(position)
+}
+
+fn order_field(filters: &ListParams) -> Option<&ListOrderField> {
+    filters.order.as_ref().map(|order| &order.field)
+}
+
+fn order_timestamp<T: Timestamped>(value: &T, filters: &ListParams) -> Option<Timestamp> {
+    match order_field(filters) {
+        Some(ListOrderField::CreatedAt) => Some(value.created_at()),
+        Some(ListOrderField::UpdatedAt) => Some(value.updated_at()),
+        Some(ListOrderField::Keys) | None => None,
+    }
+}
+
+/// Compares a value with the position of a cursor, following the order of the list.
+fn cmp_cursor_position<T: Timestamped>(
+    key: &Key,
+    value: &T,
+    position: &ListCursorPosition,
+    filters: &ListParams,
+) -> Ordering {
+    let ordering = order_timestamp(value, filters)
+        .cmp(&position.timestamp)
+        .then_with(|| key.cmp(&position.key));
+
+    match &filters.order {
+        Some(ListOrder { desc: true, .. }) => ordering.reverse(),
+        _ => ordering,
+    }
+}
+
 fn order_values<'a, T: Clone + Timestamped>(
     matches: &'a [(&'a Key, &'a T)],
     ListParams {
@@ -72,7 +255,8 @@ fn order_values<'a, T: Clone + Timestamped>(
     }: &'a ListParams,
 ) -> Vec<(&'a Key, &'a T)> {
     match order {
-        None => matches.to_vec(),
+        // Without explicit order values are listed by key, the order cursors expect.
+        None => order_values_with_keys(matches, &false),
         Some(ListOrder { desc, field }) => match field {
             ListOrderField::Keys => order_values_with_keys(matches, desc),
             ListOrderField::UpdatedAt => order_values_with_updated_at(matches, desc),
@@ -81,6 +265,8 @@ fn order_values<'a, T: Clone + Timestamped>(
     }
 }
 
+// Values with equal timestamps are ordered by key, which makes the order total and a cursor
+// position unambiguous.
 fn order_values_with_updated_at<'a, T: Clone + Timestamped>(
     matches: &'a [(&'a Key, &'a T)],
     desc: &bool,
@@ -88,11 +274,19 @@ fn order_values_with_updated_at<'a, T: Clone + Timestamped>(
     let mut sorted_matches = matches.to_vec();
 
     if *desc {
-        sorted_matches.sort_by(|(_, value_a), (_, value_b)| value_b.cmp_updated_at(value_a));
+        sorted_matches.sort_by(|(key_a, value_a), (key_b, value_b)| {
+            value_b
+                .cmp_updated_at(value_a)
+                .then_with(|| key_b.cmp(key_a))
+        });
         return sorted_matches;
     }
 
-    sorted_matches.sort_by(|(_, value_a), (_, value_b)| value_a.cmp_updated_at(value_b));
+    sorted_matches.sort_by(|(key_a, value_a), (key_b, value_b)| {
+        value_a
+            .cmp_updated_at(value_b)
+            .then_with(|| key_a.cmp(key_b))
+    });
     sorted_matches
 }
 
@@ -103,11 +297,19 @@ fn order_values_with_created_at<'a, T: Clone + Timestamped>(
     let mut sorted_matches = matches.to_vec();
 
     if *desc {
-        sorted_matches.sort_by(|(_, value_a), (_, value_b)| value_b.cmp_created_at(value_a));
+        sorted_matches.sort_by(|(key_a, value_a), (key_b, value_b)| {
+            value_b
+                .cmp_created_at(value_a)
+                .then_with(|| key_b.cmp(key_a))
+        });
         return sorted_matches;
     }
 
-    sorted_matches.sort_by(|(_, value_a), (_, value_b)| value_a.cmp_created_at(value_b));
+    sorted_matches.sort_by(|(key_a, value_a), (key_b, value_b)| {
+        value_a
+            .cmp_created_at(value_b)
+            .then_with(|| key_a.cmp(key_b))
+    });
     sorted_matches
 }
 
@@ -127,7 +329,7 @@ fn order_values_with_keys<'a, T: Clone + Timestamped>(
 }
 
 fn paginate_values<T: Clone + Timestamped>(
-    matches: Vec<(&Key, &T)>,
+    matches: &[(&Key, &T)],
     ListParams {
         matcher: _,
         order: _,
@@ -144,6 +346,7 @@ fn paginate_values<T: Clone + Timestamped>(
         Some(ListPaginate {
             start_after: _,
             limit,
+            cursor: _,
         }) => {
             let max: usize = matches.len();
 
@@ -238,3 +441,187 @@ fn match_timestamp(timestamp: Timestamp, filter: &TimestampMatcher) -> bool {
         TimestampMatcher::Between(start, end) => timestamp >= *start && timestamp <= *end,
     }
 }
+
+pub fn match_index_value(value: &IndexValue, filter: &IndexValueMatcher) -> bool {
+    match filter {
+        IndexValueMatcher::Equal(expected) => value == expected,
+        IndexValueMatcher::GreaterThan(start) => value.same_kind(start) && value > start,
+        IndexValueMatcher::LessThan(end) => value.same_kind(end) && value < end,
+        IndexValueMatcher::Between(start, end) => {
+            value.same_kind(start) && value.same_kind(end) && value >= start && value <= end
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone)]
+    struct Item {
+        timestamp: Timestamp,
+    }
+
+    impl Timestamped for Item {
+        fn created_at(&self) -> Timestamp {
+            self.timestamp
+        }
+
+        fn updated_at(&self) -> Timestamp {
+            self.timestamp
+        }
+
+        fn cmp_updated_at(&self, other: &Self) -> Ordering {
+            self.timestamp.cmp(&other.timestamp)
+        }
+
+        fn cmp_created_at(&self, other: &Self) -> Ordering {
+            self.timestamp.cmp(&other.timestamp)
+        }
+    }
+
+    fn items(values: &[(&str, Timestamp)]) -> Vec<(Key, Item)> {
+        values
+            .iter()
+            .map(|(key, timestamp)| {
+                (
+                    key.to_string(),
+                    Item {
+                        timestamp: *timestamp,
+                    },
+                )
+            })
+            .collect()
+    }
+
+    fn params(cursor: Option<ListCursor>, field: ListOrderField, desc: bool) -> ListParams {
+        ListParams {
+            matcher: None,
+            paginate: Some(ListPaginate {
+                start_after: None,
+                limit: Some(2),
+                cursor,
+            }),
+            order: Some(ListOrder { desc, field }),
+            owner: None,
+        }
+    }
+
+    fn list(values: &[(Key, Item)], filters: &ListParams) -> ListResults<Item> {
+        let matches: Vec<(&Key, &Item)> = values.iter().map(|(key, item)| (key, item)).collect();
+        list_values(&matches, filters).unwrap()
+    }
+
+    fn keys(results: &ListResults<Item>) -> Vec<&str> {
+        results.items.iter().map(|(key, _)| key.as_str()).collect()
+    }
+
+    #[test]
+    fn resumes_from_cursor_with_equal_timestamps() {
+        let values = items(&[("a", 3), ("b", 1), ("c", 1), ("d", 2), ("e", 3)]);
+
+        let first = list(&values, &params(None, ListOrderField::UpdatedAt, false));
+        assert_eq!(keys(&first), vec!["b", "c"]);
+        assert_eq!(first.matches_pages, Some(3));
+
+        let second = list(
+            &values,
+            &params(first.next_cursor, ListOrderField::UpdatedAt, false),
+        );
+        assert_eq!(keys(&second), vec!["d", "a"]);
+
+        let third = list(
+            &values,
+            &params(second.next_cursor, ListOrderField::UpdatedAt, false),
+        );
+        assert_eq!(keys(&third), vec!["e"]);
+        assert!(third.next_cursor.is_none());
+    }
+
+    #[test]
+    fn resumes_after_concurrent_inserts() {
+        let values = items(&[("a", 1), ("c", 2), ("e", 3)]);
+
+        let first = list(&values, &params(None, ListOrderField::Keys, true));
+        assert_eq!(keys(&first), vec!["e", "c"]);
+
+        let values = items(&[("a", 1), ("b", 4), ("c", 2), ("d", 5), ("e", 3)]);
+
+        let second = list(
+            &values,
+            &params(first.next_cursor, ListOrderField::Keys, true),
+        );
+        assert_eq!(keys(&second), vec!["b", "a"]);
+    }
+
+    #[test]
+    fn rejects_cursor_of_another_order() {
+        let values = items(&[("a", 1), ("b", 2), ("c", 3)]);
+
+        let first = list(&values, &params(None, ListOrderField::Keys, false));
+
+        let matches: Vec<(&Key, &Item)> = values.iter().map(|(key, item)| (key, item)).collect();
+        let result = list_values(
+            &matches,
+            &params(first.next_cursor, ListOrderField::CreatedAt, false),
+        );
+
+        assert!(result.is_err());
+        assert!(list_values(
+            &matches,
+            &params(Some(vec![0xff]), ListOrderField::Keys, false)
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn seeks_only_lists_ordered_by_keys() {
+        let values = items(&[("a", 1), ("b", 2), ("c", 3)]);
+
+        let first = list(&values, &params(None, ListOrderField::Keys, false));
+
+        assert!(list_seek(&params(None, ListOrderField::Keys, false))
+            .unwrap()
+            .is_none());
+
+        let seek = list_seek(&params(
+            first.next_cursor.clone(),
+            ListOrderField::Keys,
+            false,
+        ))
+        .unwrap()
+        .unwrap();
+        assert_eq!(seek.start_after, "b");
+        assert!(!seek.desc);
+
+        let timestamps = list(&values, &params(None, ListOrderField::UpdatedAt, false));
+        assert!(list_seek(&params(
+            timestamps.next_cursor,
+            ListOrderField::UpdatedAt,
+            false
+        ))
+        .unwrap()
+        .is_none());
+    }
+
+    #[test]
+    fn continues_the_pages_read_by_seeking() {
+        let seek = ListSeek {
+            start_after: "a".to_string(),
+            desc: false,
+            limit: Some(2),
+        };
+
+        let page = list_values_after_seek(items(&[("b", 1), ("c", 2), ("d", 3)]), &seek);
+        assert_eq!(keys(&page), vec!["b", "c"]);
+
+        let next = list_seek(&params(page.next_cursor, ListOrderField::Keys, false))
+            .unwrap()
+            .unwrap();
+        assert_eq!(next.start_after, "c");
+
+        let last = list_values_after_seek(items(&[("d", 3)]), &next);
+        assert_eq!(keys(&last), vec!["d"]);
+        assert!(last.next_cursor.is_none());
+    }
+}
@@ -18,6 +18,15 @@ pub const JUNO_ERROR_CONTROLLERS_REVOKED_NOT_ALLOWED: &str =
 pub const JUNO_ERROR_CONTROLLERS_ADMIN_NO_EXPIRY: &str = "juno.error.controllers.admin_no_expire";
 // New controller cannot expire in the past.
 pub const JUNO_ERROR_CONTROLLERS_EXPIRY_IN_PAST: &str = "juno.error.controllers.expiry_in_past";
+// Only controllers with write scope can be restricted.
+pub const JUNO_ERROR_CONTROLLERS_RESTRICTIONS_WRITE_ONLY: &str =
+    "juno.error.controllers.restrictions_write_only";
+// Restricted controller must be scoped to at least one collection.
+pub const JUNO_ERROR_CONTROLLERS_RESTRICTIONS_NO_COLLECTIONS: &str =
+    "juno.error.controllers.restrictions_no_collections";
+// Invalid or duplicate restricted collection: {}
+pub const JUNO_ERROR_CONTROLLERS_RESTRICTIONS_INVALID_COLLECTION: &str =
+    "juno.error.controllers.restrictions_invalid_collection";
 // Stable memory usage exceeded: {} bytes used, {} bytes allowed.
 pub const JUNO_ERROR_MEMORY_STABLE_EXCEEDED: &str = "juno.error.memory.stable_exceeded";
 // Heap memory usage exceeded: {} bytes used, {} bytes allowed.
@@ -47,3 +56,7 @@ pub const JUNO_ERROR_CMC_CREATE_CANISTER_FAILED: &str = "juno.error.cmc.create_c
 pub const JUNO_ERROR_CMC_INSTALL_CODE_FAILED: &str = "juno.error.cmc.install_code_failed";
 // Invalid regex
 pub const JUNO_ERROR_INVALID_REGEX: &str = "juno.error.invalid_regex";
+// Invalid list cursor
+pub const JUNO_ERROR_INVALID_LIST_CURSOR: &str = "juno.error.invalid_list_cursor";
+// Expiry cannot be set in the past.
+pub const JUNO_ERROR_EXPIRY_IN_PAST: &str = "juno.error.expiry_in_past";
@@ -1,6 +1,8 @@
 use crate::types::domain::CustomDomain;
+use crate::types::list::IndexValue;
 use crate::types::state::{OrbiterSatelliteConfig, SegmentKind, Version, Versioned};
 use crate::types::utils::CalendarDate;
+use std::cmp::Ordering;
 use std::fmt::{Display, Formatter, Result};
 use time::Month;
 
@@ -36,3 +38,44 @@ impl Versioned for &OrbiterSatelliteConfig {
         self.version
     }
 }
+
+impl IndexValue {
+    fn rank(&self) -> u8 {
+        match self {
+            IndexValue::Null => 0,
+            IndexValue::Bool(_) => 1,
+            IndexValue::Number(_) => 2,
+            IndexValue::Text(_) => 3,
+        }
+    }
+
+    /// Whether both values are of the same kind - e.g. both numbers.
+    pub fn same_kind(&self, other: &Self) -> bool {
+        self.rank() == other.rank()
+    }
+}
+
+impl PartialEq for IndexValue {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for IndexValue {}
+
+impl PartialOrd for IndexValue {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for IndexValue {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (IndexValue::Bool(a), IndexValue::Bool(b)) => a.cmp(b),
+            (IndexValue::Number(a), IndexValue::Number(b)) => a.total_cmp(b),
+            (IndexValue::Text(a), IndexValue::Text(b)) => a.cmp(b),
+            _ => self.rank().cmp(&other.rank()),
+        }
+    }
+}
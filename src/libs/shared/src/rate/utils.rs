@@ -1,6 +1,6 @@
+use crate::ic::api::time;
 use crate::rate::types::{RateConfig, RateTokenStore, RateTokens};
 use crate::types::state::Timestamp;
-use ic_cdk::api::time;
 use std::cmp::min;
 
 pub fn increment_and_assert_rate_store(
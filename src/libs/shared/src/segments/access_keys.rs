@@ -4,14 +4,18 @@ use crate::env::{CONSOLE, OBSERVATORY};
 use crate::errors::{
     JUNO_ERROR_CONTROLLERS_ADMIN_NO_EXPIRY, JUNO_ERROR_CONTROLLERS_ANONYMOUS_NOT_ALLOWED,
     JUNO_ERROR_CONTROLLERS_EXPIRY_IN_PAST, JUNO_ERROR_CONTROLLERS_MAX_NUMBER,
-    JUNO_ERROR_CONTROLLERS_REVOKED_NOT_ALLOWED,
+    JUNO_ERROR_CONTROLLERS_RESTRICTIONS_INVALID_COLLECTION,
+    JUNO_ERROR_CONTROLLERS_RESTRICTIONS_NO_COLLECTIONS,
+    JUNO_ERROR_CONTROLLERS_RESTRICTIONS_WRITE_ONLY, JUNO_ERROR_CONTROLLERS_REVOKED_NOT_ALLOWED,
 };
 use crate::ic::api::{id, is_canister_controller, time};
 use crate::types::interface::SetAccessKey;
-use crate::types::state::{AccessKey, AccessKeyId, AccessKeyScope, AccessKeys};
+use crate::types::state::{
+    AccessKey, AccessKeyCollection, AccessKeyId, AccessKeyOperation, AccessKeyScope, AccessKeys,
+};
 use crate::utils::{principal_anonymous, principal_equal, principal_not_anonymous};
 use candid::Principal;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 /// Initializes a set of access keys with default administrative scope.
 ///
@@ -28,6 +32,7 @@ pub fn init_admin_access_keys(new_access_keys: &[AccessKeyId]) -> AccessKeys {
         expires_at: None,
         scope: AccessKeyScope::Admin,
         kind: None,
+        restrictions: None,
     };
 
     set_access_keys(new_access_keys, &access_key_data, &mut access_keys);
@@ -65,6 +70,7 @@ pub fn set_access_keys(
             expires_at: access_key_data.expires_at,
             scope: access_key_data.scope.clone(),
             kind: access_key_data.kind.clone(),
+            restrictions: access_key_data.restrictions.clone(),
         };
 
         access_keys.insert(*access_key_id, access_key);
@@ -82,7 +88,9 @@ pub fn delete_access_keys(remove_access_key_ids: &[AccessKeyId], access_keys: &m
     }
 }
 
-/// Checks if an id is a non-expired access key with admin or write scope (permissions).
+/// Checks if an id is a non-expired and unrestricted access key with admin or write scope (permissions).
+///
+/// Restricted access keys are not considered. Use [`scope_access_keys`] to resolve them for a particular collection.
 ///
 /// # Arguments
 /// - `id`: `Principal` of e.g. the caller.
@@ -98,12 +106,14 @@ pub fn is_write_access_key(id: Principal, access_keys: &AccessKeys) -> bool {
                 .any(|(&access_key_id, access_key)| match access_key.scope {
                     AccessKeyScope::Submit => false,
                     _ => {
-                        principal_equal(access_key_id, id) && is_access_key_not_expired(access_key)
+                        principal_equal(access_key_id, id)
+                            && is_access_key_not_expired(access_key)
+                            && is_access_key_unrestricted(access_key)
                     }
                 }))
 }
 
-/// Checks if an id is a non-expired access key regardless of scope (admin, write, or submit).
+/// Checks if an id is a non-expired and unrestricted access key regardless of scope (admin, write, or submit).
 ///
 /// # Arguments
 /// - `id`: `Principal` e.g. of the caller.
@@ -115,10 +125,72 @@ pub fn is_valid_access_key(id: Principal, access_keys: &AccessKeys) -> bool {
     principal_not_anonymous(id)
         && (is_self(id)
             || access_keys.iter().any(|(&access_key_id, access_key)| {
-                principal_equal(access_key_id, id) && is_access_key_not_expired(access_key)
+                principal_equal(access_key_id, id)
+                    && is_access_key_not_expired(access_key)
+                    && is_access_key_unrestricted(access_key)
             }))
 }
 
+/// Resolves the access keys that apply to a particular collection and operation.
+///
+/// Unrestricted access keys are returned as is. Restricted access keys are returned - without
+/// their restrictions - only if they cover the collection and, when read-only, if the operation
+/// is a read. Other restricted access keys are omitted.
+///
+/// # Arguments
+/// - `access_keys`: Reference to the current set of access keys.
+/// - `collection`: The collection that is accessed.
+/// - `operation`: Whether the collection is read or written.
+///
+/// # Returns
+/// An `AccessKeys` collection that can be used with the usual access key checks.
+pub fn scope_access_keys(
+    access_keys: &AccessKeys,
+    collection: &AccessKeyCollection,
+    operation: AccessKeyOperation,
+) -> AccessKeys {
+    access_keys
+        .iter()
+        .filter_map(|(access_key_id, access_key)| {
+            let Some(restrictions) = &access_key.restrictions else {
+                return Some((*access_key_id, access_key.clone()));
+            };
+
+            if restrictions.read_only && operation == AccessKeyOperation::Write {
+                return None;
+            }
+
+            let in_scope = restrictions
+                .collections
+                .as_ref()
+                .is_none_or(|collections| collections.contains(collection));
+
+            if !in_scope {
+                return None;
+            }
+
+            Some((
+                *access_key_id,
+                AccessKey {
+                    restrictions: None,
+                    ..access_key.clone()
+                },
+            ))
+        })
+        .collect()
+}
+
+/// Checks if an access key applies to every collection and operation.
+///
+/// # Arguments
+/// - `access_key`: The access key to check
+///
+/// # Returns
+/// `true` if the access key has no restrictions, `false` otherwise.
+fn is_access_key_unrestricted(access_key: &AccessKey) -> bool {
+    access_key.restrictions.is_none()
+}
+
 /// Checks if an access key has not expired.
 ///
 /// Admin access keys never expire. Other access keys are considered not expired if:
@@ -321,6 +393,49 @@ pub fn assert_access_key_expiration(access_key: &SetAccessKey) -> Result<(), Str
     Ok(())
 }
 
+/// Validates access key restrictions.
+///
+/// Ensures that:
+/// - Only access keys with write scope are restricted
+/// - If a list of collections is provided, it is not empty and contains no blank or duplicate collection
+///
+/// # Arguments
+/// - `access_key`: The access key configuration to validate
+///
+/// # Returns
+/// `Ok(())` if validation passes, or `Err(String)` with error message if validation fails
+pub fn assert_access_key_restrictions(access_key: &SetAccessKey) -> Result<(), String> {
+    let Some(restrictions) = &access_key.restrictions else {
+        return Ok(());
+    };
+
+    if !matches!(access_key.scope, AccessKeyScope::Write) {
+        return Err(JUNO_ERROR_CONTROLLERS_RESTRICTIONS_WRITE_ONLY.to_string());
+    }
+
+    if let Some(collections) = &restrictions.collections {
+        if collections.is_empty() {
+            return Err(JUNO_ERROR_CONTROLLERS_RESTRICTIONS_NO_COLLECTIONS.to_string());
+        }
+
+        let mut seen = HashSet::new();
+
+        for collection in collections {
+            let name = match collection {
+                AccessKeyCollection::Db(name) | AccessKeyCollection::Storage(name) => name,
+            };
+
+            if name.trim().is_empty() || !seen.insert(collection) {
+                return Err(format!(
+                    "{JUNO_ERROR_CONTROLLERS_RESTRICTIONS_INVALID_COLLECTION} ({name})"
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
 /// Checks if the id is the console.
 ///
 /// # Arguments
@@ -404,7 +519,9 @@ fn controller_revoked(access_key_id: &AccessKeyId) -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::types::state::{AccessKey, AccessKeyKind, AccessKeyScope, AccessKeys};
+    use crate::types::state::{
+        AccessKey, AccessKeyKind, AccessKeyRestrictions, AccessKeyScope, AccessKeys,
+    };
     use candid::Principal;
     use std::collections::HashMap;
 
@@ -428,6 +545,7 @@ mod tests {
             expires_at,
             scope,
             kind,
+            restrictions: None,
         }
     }
 
@@ -617,6 +735,7 @@ mod tests {
             expires_at: Some(mock_time() + 1000),
             scope: AccessKeyScope::Write,
             kind: Some(AccessKeyKind::Automation),
+            restrictions: None,
         };
 
         set_access_keys(&principals, &access_key_data, &mut access_keys);
@@ -639,6 +758,7 @@ mod tests {
             expires_at: None,
             scope: AccessKeyScope::Write,
             kind: None,
+            restrictions: None,
         };
         set_access_keys(&[principal], &initial_data, &mut access_keys);
         let original_created_at = access_keys.get(&principal).unwrap().created_at;
@@ -649,6 +769,7 @@ mod tests {
             expires_at: Some(mock_time() + 1000),
             scope: AccessKeyScope::Admin,
             kind: Some(AccessKeyKind::Automation),
+            restrictions: None,
         };
         set_access_keys(&[principal], &update_data, &mut access_keys);
 
@@ -782,6 +903,7 @@ mod tests {
             expires_at: Some(time() + 1000),
             scope: AccessKeyScope::Admin,
             kind: None,
+            restrictions: None,
         };
 
         let result = assert_access_key_expiration(&access_key);
@@ -799,6 +921,7 @@ mod tests {
             expires_at: None,
             scope: AccessKeyScope::Admin,
             kind: None,
+            restrictions: None,
         };
 
         let result = assert_access_key_expiration(&access_key);
@@ -812,6 +935,7 @@ mod tests {
             expires_at: Some(time() - 1000),
             scope: AccessKeyScope::Write,
             kind: None,
+            restrictions: None,
         };
 
         let result = assert_access_key_expiration(&access_key);
@@ -829,6 +953,7 @@ mod tests {
             expires_at: Some(time() + 1000),
             scope: AccessKeyScope::Write,
             kind: None,
+            restrictions: None,
         };
 
         let result = assert_access_key_expiration(&access_key);
@@ -842,6 +967,7 @@ mod tests {
             expires_at: None,
             scope: AccessKeyScope::Write,
             kind: None,
+            restrictions: None,
         };
 
         let result = assert_access_key_expiration(&access_key);
@@ -855,6 +981,7 @@ mod tests {
             expires_at: Some(time() + 1000),
             scope: AccessKeyScope::Submit,
             kind: None,
+            restrictions: None,
         };
 
         let result = assert_access_key_expiration(&access_key);
@@ -1000,4 +1127,139 @@ mod tests {
         );
         assert!(result.is_ok());
     }
+
+    fn restricted_access_key(
+        collections: Option<Vec<AccessKeyCollection>>,
+        read_only: bool,
+    ) -> AccessKey {
+        AccessKey {
+            restrictions: Some(AccessKeyRestrictions {
+                collections,
+                read_only,
+            }),
+            ..create_access_key(AccessKeyScope::Write, None, None)
+        }
+    }
+
+    #[test]
+    fn test_restricted_access_key_not_write_nor_valid() {
+        let mut access_keys = AccessKeys::new();
+        let restricted = test_principal(10);
+
+        access_keys.insert(restricted, restricted_access_key(None, false));
+
+        assert!(!is_write_access_key(restricted, &access_keys));
+        assert!(!is_valid_access_key(restricted, &access_keys));
+    }
+
+    #[test]
+    fn test_scope_access_keys_in_scope_collection() {
+        let mut access_keys = AccessKeys::new();
+        let restricted = test_principal(10);
+        let unrestricted = test_principal(11);
+
+        access_keys.insert(
+            restricted,
+            restricted_access_key(
+                Some(vec![AccessKeyCollection::Db("orders".to_string())]),
+                false,
+            ),
+        );
+        access_keys.insert(
+            unrestricted,
+            create_access_key(AccessKeyScope::Write, None, None),
+        );
+
+        let orders = scope_access_keys(
+            &access_keys,
+            &AccessKeyCollection::Db("orders".to_string()),
+            AccessKeyOperation::Write,
+        );
+
+        assert!(is_write_access_key(restricted, &orders));
+        assert!(is_write_access_key(unrestricted, &orders));
+
+        let profiles = scope_access_keys(
+            &access_keys,
+            &AccessKeyCollection::Db("profiles".to_string()),
+            AccessKeyOperation::Read,
+        );
+
+        assert!(!is_write_access_key(restricted, &profiles));
+        assert!(is_write_access_key(unrestricted, &profiles));
+
+        let storage_orders = scope_access_keys(
+            &access_keys,
+            &AccessKeyCollection::Storage("orders".to_string()),
+            AccessKeyOperation::Read,
+        );
+
+        assert!(!is_write_access_key(restricted, &storage_orders));
+    }
+
+    #[test]
+    fn test_scope_access_keys_read_only() {
+        let mut access_keys = AccessKeys::new();
+        let restricted = test_principal(10);
+
+        access_keys.insert(restricted, restricted_access_key(None, true));
+
+        let collection = AccessKeyCollection::Storage("images".to_string());
+
+        let read = scope_access_keys(&access_keys, &collection, AccessKeyOperation::Read);
+        assert!(is_write_access_key(restricted, &read));
+
+        let write = scope_access_keys(&access_keys, &collection, AccessKeyOperation::Write);
+        assert!(!is_write_access_key(restricted, &write));
+    }
+
+    #[test]
+    fn test_assert_access_key_restrictions() {
+        let set_access_key =
+            |scope: AccessKeyScope, collections: Option<Vec<AccessKeyCollection>>| SetAccessKey {
+                metadata: HashMap::new(),
+                expires_at: None,
+                scope,
+                kind: None,
+                restrictions: Some(AccessKeyRestrictions {
+                    collections,
+                    read_only: false,
+                }),
+            };
+
+        let orders = AccessKeyCollection::Db("orders".to_string());
+
+        assert!(assert_access_key_restrictions(&set_access_key(
+            AccessKeyScope::Write,
+            Some(vec![
+                orders.clone(),
+                AccessKeyCollection::Storage("orders".to_string())
+            ])
+        ))
+        .is_ok());
+        assert!(
+            assert_access_key_restrictions(&set_access_key(AccessKeyScope::Write, None)).is_ok()
+        );
+
+        assert_eq!(
+            assert_access_key_restrictions(&set_access_key(AccessKeyScope::Admin, None))
+                .unwrap_err(),
+            JUNO_ERROR_CONTROLLERS_RESTRICTIONS_WRITE_ONLY.to_string()
+        );
+        assert_eq!(
+            assert_access_key_restrictions(&set_access_key(AccessKeyScope::Write, Some(vec![])))
+                .unwrap_err(),
+            JUNO_ERROR_CONTROLLERS_RESTRICTIONS_NO_COLLECTIONS.to_string()
+        );
+        assert!(assert_access_key_restrictions(&set_access_key(
+            AccessKeyScope::Write,
+            Some(vec![orders.clone(), orders])
+        ))
+        .is_err());
+        assert!(assert_access_key_restrictions(&set_access_key(
+            AccessKeyScope::Write,
+            Some(vec![AccessKeyCollection::Db(" ".to_string())])
+        ))
+        .is_err());
+    }
 }
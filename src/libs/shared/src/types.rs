@@ -36,6 +36,14 @@ pub mod state {
         fn version(&self) -> Option<Version>;
     }
 
+    pub trait Expirable {
+        fn expires_at(&self) -> Option<Timestamp>;
+
+        fn is_expired(&self, now: Timestamp) -> bool {
+            self.expires_at().is_some_and(|expires_at| expires_at < now)
+        }
+    }
+
     #[derive(CandidType, Serialize, Deserialize, Clone)]
     pub struct AccessKey {
         pub metadata: Metadata,
@@ -44,6 +52,7 @@ pub mod state {
         pub expires_at: Option<Timestamp>,
         pub scope: AccessKeyScope,
         pub kind: Option<AccessKeyKind>,
+        pub restrictions: Option<AccessKeyRestrictions>,
     }
 
     #[derive(CandidType, Serialize, Deserialize, Clone)]
@@ -59,6 +68,27 @@ pub mod state {
         Emulator,
     }
 
+    /// Limits a write access key to particular collections and, optionally, to read-only use.
+    /// A restricted key is not granted any satellite-wide privilege.
+    #[derive(CandidType, Serialize, Deserialize, Clone, Default)]
+    pub struct AccessKeyRestrictions {
+        // None: every collection. Some: only the listed collections.
+        pub collections: Option<Vec<AccessKeyCollection>>,
+        pub read_only: bool,
+    }
+
+    #[derive(CandidType, Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+    pub enum AccessKeyCollection {
+        Db(String),
+        Storage(String),
+    }
+
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    pub enum AccessKeyOperation {
+        Read,
+        Write,
+    }
+
     #[derive(CandidType, Serialize, Deserialize, Copy, Clone, Debug, PartialEq, Eq, Hash)]
     pub enum SegmentKind {
         Satellite,
@@ -112,8 +142,8 @@ pub mod state {
 pub mod interface {
     use crate::mgmt::types::cmc::SubnetId;
     use crate::types::state::{
-        AccessKeyId, AccessKeyKind, AccessKeyScope, Metadata, MissionControlId, NotificationKind,
-        Segment, Timestamp, UserId,
+        AccessKeyId, AccessKeyKind, AccessKeyRestrictions, AccessKeyScope, Metadata,
+        MissionControlId, NotificationKind, Segment, Timestamp, UserId,
     };
     use candid::{CandidType, Principal};
     use ic_ledger_types::BlockIndex;
@@ -193,6 +223,7 @@ pub mod interface {
         pub expires_at: Option<Timestamp>,
         pub scope: AccessKeyScope,
         pub kind: Option<AccessKeyKind>,
+        pub restrictions: Option<AccessKeyRestrictions>,
     }
 
     #[derive(CandidType, Deserialize)]
@@ -304,15 +335,36 @@ pub mod core {
 }
 
 pub mod list {
-    use crate::types::core::Key;
+    use crate::types::core::{Blob, Key};
     use crate::types::state::{Timestamp, UserId};
     use candid::CandidType;
-    use serde::Deserialize;
+    use serde::{Deserialize, Serialize};
+
+    /// An opaque continuation cursor returned with a page of results.
+    ///
+    /// Passing it back in `ListPaginate` resumes the listing right after the last item of that page.
+    pub type ListCursor = Blob;
+
+    /// The position a cursor encodes: the value of the sort field - if the list is ordered by a
+    /// timestamp - and the key of the last item of a page.
+    #[derive(Serialize, Deserialize)]
+    pub struct ListCursorPosition {
+        pub timestamp: Option<Timestamp>,
+        pub key: Key,
+    }
+
+    /// Where a page ordered by keys resumes after a cursor - for stores that can seek by key.
+    pub struct ListSeek {
+        pub start_after: Key,
+        pub desc: bool,
+        pub limit: Option<usize>,
+    }
 
     #[derive(Default, CandidType, Deserialize, Clone)]
     pub struct ListPaginate {
         pub start_after: Option<Key>,
         pub limit: Option<usize>,
+        pub cursor: Option<ListCursor>,
     }
 
     #[derive(Default, CandidType, Deserialize, Clone)]
@@ -337,12 +389,74 @@ pub mod list {
         Between(Timestamp, Timestamp),
     }
 
+    /// A scalar value of the JSON data of a document, kept in secondary indexes and used by data matchers.
+    ///
+    /// Values of different kinds are ordered `Null` < `Bool` < `Number` < `Text`.
+    #[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+    pub enum IndexValue {
+        Null,
+        Bool(bool),
+        Number(f64),
+        Text(String),
+    }
+
+    #[derive(CandidType, Deserialize, Clone)]
+    pub enum IndexValueMatcher {
+        Equal(IndexValue),
+        GreaterThan(IndexValue),
+        LessThan(IndexValue),
+        Between(IndexValue, IndexValue),
+    }
+
+    /// Looks up documents through a secondary index declared on the collection's rule.
+    ///
+    /// - `field`: The indexed field, as declared in the rule - e.g. `address.city`.
+    /// - `value`: The condition the indexed value must satisfy.
+    #[derive(CandidType, Deserialize, Clone)]
+    pub struct IndexMatcher {
+        pub field: String,
+        pub value: IndexValueMatcher,
+    }
+
+    #[derive(CandidType, Deserialize, Clone)]
+    pub enum DataValueMatcher {
+        Equal(IndexValue),
+        GreaterThan(f64),
+        LessThan(f64),
+        Between(f64, f64),
+        In(Vec<IndexValue>),
+        Exists,
+    }
+
+    /// Matches a field of the JSON data of a document.
+    ///
+    /// - `field`: The dot-separated path of the field - e.g. `address.city`.
+    /// - `value`: The condition the value of the field must satisfy.
+    #[derive(CandidType, Deserialize, Clone)]
+    pub struct DataFieldMatcher {
+        pub field: String,
+        pub value: DataValueMatcher,
+    }
+
+    /// Filters documents on the content of their JSON data.
+    ///
+    /// Field conditions can be combined with `And`, `Or` and `Not`. Documents whose data is not JSON never match.
+    #[derive(CandidType, Deserialize, Clone)]
+    pub enum DataMatcher {
+        Field(DataFieldMatcher),
+        And(Vec<DataMatcher>),
+        Or(Vec<DataMatcher>),
+        Not(Box<DataMatcher>),
+    }
+
     #[derive(Default, CandidType, Deserialize, Clone)]
     pub struct ListMatcher {
         pub key: Option<Key>,
         pub description: Option<String>,
         pub created_at: Option<TimestampMatcher>,
         pub updated_at: Option<TimestampMatcher>,
+        pub index: Option<IndexMatcher>,
+        pub data: Option<DataMatcher>,
     }
 
     #[derive(Default, CandidType, Deserialize, Clone)]
@@ -360,6 +474,7 @@ pub mod list {
         pub items_page: Option<usize>,
         pub matches_length: usize,
         pub matches_pages: Option<usize>,
+        pub next_cursor: Option<ListCursor>,
     }
 }
 
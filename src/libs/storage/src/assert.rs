@@ -1,21 +1,25 @@
 use crate::errors::{
     JUNO_STORAGE_ERROR_CANNOT_COMMIT_BATCH, JUNO_STORAGE_ERROR_CANNOT_COMMIT_INVALID_COLLECTION,
-    JUNO_STORAGE_ERROR_UPLOAD_NOT_ALLOWED, JUNO_STORAGE_ERROR_UPLOAD_PATH_COLLECTION_PREFIX,
+    JUNO_STORAGE_ERROR_CONTENT_HASH_MISMATCH, JUNO_STORAGE_ERROR_UPLOAD_NOT_ALLOWED,
+    JUNO_STORAGE_ERROR_UPLOAD_PATH_COLLECTION_PREFIX,
 };
 use crate::runtime::increment_and_assert_rate;
 use crate::strategies::{StorageAssertionsStrategy, StorageStateStrategy};
 use crate::types::config::StorageConfig;
 use crate::types::interface::{CommitBatch, InitAssetKey};
 use crate::types::state::FullPath;
-use crate::types::store::{Asset, AssetAssertUpload, Batch};
+use crate::types::store::{Asset, AssetAssertUpload, AssetEncoding, Batch};
 use crate::well_known::assert::assert_not_well_known_asset;
 use candid::Principal;
+use ic_certification::Hash;
 use junobuild_collections::assert::collection::is_system_collection;
 use junobuild_collections::constants::assets::COLLECTION_ASSET_KEY;
 use junobuild_collections::constants::core::SYS_COLLECTION_PREFIX;
 use junobuild_collections::types::core::CollectionKey;
 use junobuild_collections::types::rules::Rule;
-use junobuild_shared::assert::{assert_description_length, assert_max_memory_size};
+use junobuild_shared::assert::{
+    assert_description_length, assert_expires_at, assert_max_memory_size,
+};
 use junobuild_shared::types::state::AccessKeys;
 use junobuild_shared::utils::principal_not_equal;
 
@@ -40,6 +44,8 @@ pub fn assert_create_batch(
 
     assert_description_length(&init.description)?;
 
+    assert_expires_at(&init.expires_at)?;
+
     let rule = storage_state.get_rule(&init.collection)?;
 
     increment_and_assert_rate(&init.collection, &rule.rate_config)?;
@@ -166,6 +172,17 @@ pub fn assert_commit_chunks(
     Ok(())
 }
 
+/// Asserts that the content assembled from the chunks matches the hash expected by the client, if
+/// any was provided - e.g. a chunk was corrupted or is missing.
+pub fn assert_content_hash(sha256: &Option<Hash>, encoding: &AssetEncoding) -> Result<(), String> {
+    match sha256 {
+        Some(sha256) if *sha256 != encoding.sha256 => {
+            Err(JUNO_STORAGE_ERROR_CONTENT_HASH_MISMATCH.to_string())
+        }
+        _ => Ok(()),
+    }
+}
+
 fn assert_memory_size(config: &StorageConfig) -> Result<(), String> {
     assert_max_memory_size(&config.max_memory_size)
 }
@@ -237,3 +254,39 @@ fn assert_key(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use junobuild_shared::types::core::Blob;
+
+    fn encoding(chunks: &[&[u8]]) -> AssetEncoding {
+        let chunks: Vec<Blob> = chunks.iter().map(|chunk| chunk.to_vec()).collect();
+        AssetEncoding::from(&chunks)
+    }
+
+    #[test]
+    fn accepts_content_matching_the_hash() {
+        let expected = encoding(&[b"hello ", b"world"]).sha256;
+
+        assert!(assert_content_hash(&Some(expected), &encoding(&[b"hello ", b"world"])).is_ok());
+        assert!(assert_content_hash(&None, &encoding(&[b"anything"])).is_ok());
+    }
+
+    #[test]
+    fn rejects_content_not_matching_the_hash() {
+        let expected = encoding(&[b"hello ", b"world"]).sha256;
+
+        // A corrupted chunk
+        assert_eq!(
+            assert_content_hash(&Some(expected), &encoding(&[b"hello ", b"w0rld"])),
+            Err(JUNO_STORAGE_ERROR_CONTENT_HASH_MISMATCH.to_string())
+        );
+
+        // A missing chunk
+        assert!(assert_content_hash(&Some(expected), &encoding(&[b"hello "])).is_err());
+
+        // Chunks assembled in another order
+        assert!(assert_content_hash(&Some(expected), &encoding(&[b"world", b"hello "])).is_err());
+    }
+}
@@ -1,5 +1,9 @@
-use crate::certification::constants::{IC_CERTIFICATE_EXPRESSION_HEADER, IC_CERTIFICATE_HEADER};
-use crate::certification::tree_utils::response_headers_expression;
+use crate::certification::constants::{
+    IC_CERTIFICATE_EXPRESSION_HEADER, IC_CERTIFICATE_HEADER, IC_CERTIFICATE_SKIP_EXPRESSION,
+};
+use crate::certification::tree_utils::{
+    host_response_headers_expression, response_headers_expression,
+};
 use crate::certification::types::certified::CertifiedAssetHashes;
 use crate::http::types::HeaderField;
 use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
@@ -36,20 +40,32 @@ pub fn build_asset_certificate_header(
     }
 }
 
+/// The responses served from the root of a custom domain - `certified_host` - are certified with the Host of the request.
 pub fn build_certified_expression(
     asset_headers: &[HeaderField],
     certificate_version: &Option<u16>,
+    certified_host: bool,
 ) -> Result<Option<HeaderField>, &'static str> {
     match certificate_version {
         None | Some(1) => Ok(None),
         Some(2) => Ok(Some(HeaderField(
             IC_CERTIFICATE_EXPRESSION_HEADER.to_string(),
-            response_headers_expression(asset_headers),
+            match certified_host {
+                true => host_response_headers_expression(asset_headers),
+                false => response_headers_expression(asset_headers),
+            },
         ))),
         _ => Err("Unsupported certificate version to certify expression."),
     }
 }
 
+pub fn build_skip_certification_expression() -> HeaderField {
+    HeaderField(
+        IC_CERTIFICATE_EXPRESSION_HEADER.to_string(),
+        IC_CERTIFICATE_SKIP_EXPRESSION.to_string(),
+    )
+}
+
 fn build_asset_certificate_header_v1_impl(
     certificate: &Blob,
     asset_hashes: &CertifiedAssetHashes,
@@ -7,4 +7,10 @@ pub const WILDCARD_MATCH_TERMINATOR: &str = "<*>";
 pub const IC_CERTIFICATE_HEADER: &str = "IC-Certificate";
 pub const IC_CERTIFICATE_EXPRESSION_HEADER: &str = "IC-CertificateExpression";
 pub const IC_STATUS_CODE_PSEUDO_HEADER: &str = ":ic-cert-status";
+pub const IC_METHOD_PSEUDO_HEADER: &str = ":ic-cert-method";
+pub const IC_HOST_REQUEST_HEADER: &str = "host";
 pub const IC_CERTIFICATE_EXPRESSION: &str = r#"default_certification(ValidationArgs{certification:Certification{no_request_certification:Empty{},response_certification:ResponseCertification{certified_response_headers:ResponseHeaderList{headers:[{headers}]}}}})"#;
+// The responses served from the root of a custom domain are certified for the requests with its Host header only
+pub const IC_CERTIFICATE_HOST_EXPRESSION: &str = r#"default_certification(ValidationArgs{certification:Certification{request_certification:RequestCertification{certified_request_headers:["host"],certified_query_parameters:[]},response_certification:ResponseCertification{certified_response_headers:ResponseHeaderList{headers:[{headers}]}}}})"#;
+pub const IC_CERTIFICATE_SKIP_EXPRESSION: &str =
+    "default_certification(ValidationArgs{no_certification:Empty{}})";
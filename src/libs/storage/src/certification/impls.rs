@@ -2,24 +2,37 @@ use crate::certification::constants::{
     EXACT_MATCH_TERMINATOR, LABEL_ASSETS_V1, LABEL_ASSETS_V2, WILDCARD_MATCH_TERMINATOR,
 };
 use crate::certification::tree_utils::{
-    fallback_paths, nested_tree_expr_path, nested_tree_key, nested_tree_path,
+    fallback_paths, nested_tree_expr_path, nested_tree_host_key, nested_tree_key, nested_tree_path,
+    nested_tree_skip_key,
 };
 use crate::certification::types::certified::CertifiedAssetHashes;
 use crate::constants::{
-    ENCODING_CERTIFICATION_ORDER, RESPONSE_STATUS_CODE_200, RESPONSE_STATUS_CODE_404,
-    ROOT_404_HTML, ROOT_INDEX_HTML, ROOT_PATH,
+    ENCODING_CERTIFICATION_ORDER, RESPONSE_STATUS_CODE_200, RESPONSE_STATUS_CODE_304,
+    RESPONSE_STATUS_CODE_404, RESPONSE_STATUS_CODE_416, ROOT_404_HTML, ROOT_INDEX_HTML, ROOT_PATH,
 };
+use crate::domains::domain_paths;
 use crate::http::headers::{build_headers, build_redirect_headers};
 use crate::http::types::{HeaderField, StatusCode};
-use crate::types::config::{StorageConfig, StorageConfigIFrame};
+use crate::rewrites::{
+    is_parameterized_redirect, parameterized_redirect_paths, parameterized_rewrite_paths,
+};
+use crate::types::config::{StorageConfig, StorageConfigIFrame, StorageConfigRedirect};
 use crate::types::state::FullPath;
 use crate::types::store::Asset;
 use crate::url::alternative_paths;
 use ic_certification::{
     fork, fork_hash, labeled, labeled_hash, merge_hash_trees, pruned, AsHashTree, Hash, HashTree,
 };
+use junobuild_shared::types::core::Blob;
 use sha2::{Digest, Sha256};
 
+// The responses without body served for the assets: Not Modified and Range Not Satisfiable.
+const EMPTY_RESPONSES_STATUS_CODES: [StatusCode; 2] =
+    [RESPONSE_STATUS_CODE_304, RESPONSE_STATUS_CODE_416];
+
+// The methods of the requests served by http_request. A response certified with its request is certified per method.
+const CERTIFIED_REQUEST_METHODS: [&str; 2] = ["GET", "HEAD"];
+
 impl CertifiedAssetHashes {
     /// Returns the root_hash of the asset certification tree.
     pub fn root_hash(&self) -> Hash {
@@ -92,6 +105,9 @@ impl CertifiedAssetHashes {
 
         self.insert_most_important_v1(asset, &full_path);
         self.insert_all_v2(asset, &full_path, config);
+        self.insert_parameterized_rewrites_v2(asset, &full_path, config);
+        self.insert_parameterized_redirects_v2(&full_path, config);
+        self.insert_domain_roots_v2(asset, &full_path, config);
     }
 
     // In v1, only the most important encoding is certified.
@@ -107,12 +123,92 @@ impl CertifiedAssetHashes {
     // In v2, all encoding must be certified.
     fn insert_all_v2(&mut self, asset: &Asset, full_path: &FullPath, config: &StorageConfig) {
         for (encoding_type, encoding) in &asset.encodings {
+            let headers = build_headers(asset, encoding, encoding_type, config);
+
             self.insert_v2(
                 full_path,
-                &build_headers(asset, encoding, encoding_type, config),
+                &headers,
                 RESPONSE_STATUS_CODE_200,
                 encoding.sha256,
             );
+
+            self.insert_empty_responses_v2(full_path, &headers);
+        }
+    }
+
+    // A rewrite whose destination is resolved from the captured parameters - e.g. /blog/:slug to /posts/:slug -
+    // serves a different asset for each path. Therefore, each path that is rewritten to the asset is certified.
+    fn insert_parameterized_rewrites_v2(
+        &mut self,
+        asset: &Asset,
+        full_path: &FullPath,
+        config: &StorageConfig,
+    ) {
+        let paths = parameterized_rewrite_paths(full_path, config);
+
+        for key in exact_leaves_v2(&paths, asset, config) {
+            self.tree_v2.insert(&key, vec![]);
+        }
+    }
+
+    // A redirect whose location is resolved from the captured parameters - e.g. /blog/:slug to /posts/:slug -
+    // cannot be certified for every path it matches. The paths that are redirected to the asset are certified
+    // with their resolved location, the others are served in an update call.
+    fn insert_parameterized_redirects_v2(&mut self, full_path: &FullPath, config: &StorageConfig) {
+        let iframe = config.unwrap_iframe();
+
+        for (path, redirect) in parameterized_redirect_paths(full_path, config) {
+            self.tree_v2
+                .insert(&redirect_leaf_v2(&path, &redirect, &iframe), vec![]);
+        }
+    }
+
+    // The root of a custom domain is served on its paths - e.g. tenant-a.com/about from /tenant-a/about.html.
+    // Its assets are certified on these paths for the requests with the Host of the domain only, so that
+    // a response cannot be verified on another domain. Its /index.html and /404.html are certified as the
+    // rewrite of its unknown paths - unlike the root of the satellite, both are certified when both exist.
+    fn insert_domain_roots_v2(
+        &mut self,
+        asset: &Asset,
+        full_path: &FullPath,
+        config: &StorageConfig,
+    ) {
+        self.delete_domain_roots_v2(full_path);
+
+        let keys: Vec<Vec<Blob>> = domain_paths(full_path, config)
+            .iter()
+            .flat_map(|(host, path)| domain_root_leaves_v2(host, path, asset, config))
+            .collect();
+
+        if keys.is_empty() {
+            return;
+        }
+
+        for key in &keys {
+            self.tree_v2.insert(key, vec![]);
+        }
+
+        self.domain_roots.insert(full_path.clone(), keys);
+    }
+
+    // The 304 answered to a matching If-None-Match and the 416 answered to an unsatisfiable Range have
+    // the headers of the encoding and no body. The Content-Range of the latter is not certified.
+    fn insert_empty_responses_v2(&mut self, full_path: &FullPath, headers: &[HeaderField]) {
+        let paths = [
+            vec![full_path.clone()],
+            alternative_paths(full_path).unwrap_or_default(),
+        ]
+        .concat();
+
+        let sha256: Hash = Sha256::digest(Vec::new()).into();
+
+        for path in paths {
+            for status_code in EMPTY_RESPONSES_STATUS_CODES {
+                self.tree_v2.insert(
+                    &nested_tree_key(&path, headers, sha256, EXACT_MATCH_TERMINATOR, status_code),
+                    vec![],
+                );
+            }
         }
     }
 
@@ -207,16 +303,21 @@ impl CertifiedAssetHashes {
 
     pub fn insert_redirect_v2(
         &mut self,
-        full_path: &FullPath,
-        status_code: StatusCode,
-        location: &str,
+        source: &str,
+        redirect: &StorageConfigRedirect,
         iframe: &StorageConfigIFrame,
     ) {
-        let headers = build_redirect_headers(location, iframe);
+        // The paths of a redirect that references captured parameters are certified with the assets they
+        // redirect to. A redirect that preserves the query string is certified for the requests without one.
+        if is_parameterized_redirect(source, redirect) {
+            return;
+        }
 
         let sha256 = Sha256::digest(Vec::new().clone()).into();
 
-        self.insert_v2(full_path, &headers, status_code, sha256);
+        let headers = build_redirect_headers(&redirect.location, iframe);
+
+        self.insert_v2(&source.to_string(), &headers, redirect.status_code, sha256);
     }
 
     pub fn insert_rewrite_v2(
@@ -258,11 +359,50 @@ impl CertifiedAssetHashes {
         );
     }
 
-    pub fn delete(&mut self, asset: &Asset) {
+    pub fn contains_redirect_v2(
+        &self,
+        full_path: &FullPath,
+        redirect: &StorageConfigRedirect,
+        iframe: &StorageConfigIFrame,
+    ) -> bool {
+        self.tree_v2
+            .contains_leaf(&redirect_leaf_v2(full_path, redirect, iframe))
+    }
+
+    /// Skips the certification of the responses to the requests on a path and the paths it prefixes -
+    /// e.g. `/api/webhooks` and `/api/webhooks/stripe` for `/api/webhooks`. Such responses are computed
+    /// when requested and cannot be certified ahead of the request.
+    pub fn insert_skip_v2(&mut self, full_path: &FullPath) {
+        self.tree_v2
+            .insert(&nested_tree_skip_key(full_path), vec![]);
+    }
+
+    pub fn contains_skip_v2(&self, full_path: &FullPath) -> bool {
+        self.tree_v2.contains_leaf(&nested_tree_skip_key(full_path))
+    }
+
+    pub fn delete(&mut self, asset: &Asset, config: &StorageConfig) {
         let full_path = asset.key.full_path.clone();
 
+        self.delete_domain_roots_v2(&full_path);
         self.delete_v1(&full_path);
         self.delete_v2(&full_path);
+
+        // Only the responses of the asset are removed, other assets may be certified on the same paths
+        let paths = parameterized_rewrite_paths(&full_path, config);
+
+        for key in exact_leaves_v2(&paths, asset, config) {
+            self.tree_v2.delete(&key);
+        }
+
+        // A path redirected to another asset with the same alternative path is served in an update call
+        // until the assets are certified again
+        let iframe = config.unwrap_iframe();
+
+        for (path, redirect) in parameterized_redirect_paths(&full_path, config) {
+            self.tree_v2
+                .delete(&redirect_leaf_v2(&path, &redirect, &iframe));
+        }
     }
 
     fn delete_v1(&mut self, full_path: &String) {
@@ -311,8 +451,353 @@ impl CertifiedAssetHashes {
         }
     }
 
+    fn delete_domain_roots_v2(&mut self, full_path: &FullPath) {
+        if let Some(keys) = self.domain_roots.remove(full_path) {
+            for key in keys {
+                self.tree_v2.delete(&key);
+            }
+        }
+    }
+
     fn delete_from_tree_v2(&mut self, full_path: &FullPath, terminator: &str) {
-        self.tree_v2
-            .delete(&nested_tree_path(full_path, terminator));
+        let path = nested_tree_path(full_path, terminator);
+
+        self.tree_v2.delete(&path);
+
+        // The leaves of the custom domains certified on the path are kept
+        let keys: Vec<Vec<Blob>> = self
+            .domain_roots
+            .values()
+            .flatten()
+            .filter(|key| key.starts_with(&path))
+            .cloned()
+            .collect();
+
+        for key in keys {
+            self.tree_v2.insert(&key, vec![]);
+        }
+    }
+}
+
+// The response of a redirect - without body - on an exact path.
+fn redirect_leaf_v2(
+    full_path: &FullPath,
+    redirect: &StorageConfigRedirect,
+    iframe: &StorageConfigIFrame,
+) -> Vec<Blob> {
+    let sha256: Hash = Sha256::digest(Vec::new()).into();
+
+    nested_tree_key(
+        full_path,
+        &build_redirect_headers(&redirect.location, iframe),
+        sha256,
+        EXACT_MATCH_TERMINATOR,
+        redirect.status_code,
+    )
+}
+
+// The responses - 200, 304 and 416 - of each encoding of an asset of the root of a custom domain on its path
+// for the requests with its Host. Its /index.html and /404.html are also the responses of its unknown paths.
+fn domain_root_leaves_v2(
+    host: &str,
+    path: &FullPath,
+    asset: &Asset,
+    config: &StorageConfig,
+) -> Vec<Vec<Blob>> {
+    let empty_sha256: Hash = Sha256::digest(Vec::new()).into();
+
+    let paths = [
+        vec![path.clone()],
+        alternative_paths(path).unwrap_or_default(),
+    ]
+    .concat();
+
+    let rewrite_status_code = match path.as_str() {
+        ROOT_INDEX_HTML => Some(RESPONSE_STATUS_CODE_200),
+        ROOT_404_HTML => Some(RESPONSE_STATUS_CODE_404),
+        _ => None,
+    };
+
+    let mut keys: Vec<Vec<Blob>> = Vec::new();
+
+    for (encoding_type, encoding) in &asset.encodings {
+        let headers = build_headers(asset, encoding, encoding_type, config);
+
+        for method in CERTIFIED_REQUEST_METHODS {
+            for path in &paths {
+                keys.push(nested_tree_host_key(
+                    path,
+                    &headers,
+                    encoding.sha256,
+                    EXACT_MATCH_TERMINATOR,
+                    RESPONSE_STATUS_CODE_200,
+                    host,
+                    method,
+                ));
+
+                for status_code in EMPTY_RESPONSES_STATUS_CODES {
+                    keys.push(nested_tree_host_key(
+                        path,
+                        &headers,
+                        empty_sha256,
+                        EXACT_MATCH_TERMINATOR,
+                        status_code,
+                        host,
+                        method,
+                    ));
+                }
+            }
+
+            if let Some(status_code) = rewrite_status_code {
+                keys.push(nested_tree_host_key(
+                    &ROOT_PATH.to_string(),
+                    &headers,
+                    encoding.sha256,
+                    WILDCARD_MATCH_TERMINATOR,
+                    status_code,
+                    host,
+                    method,
+                ));
+            }
+        }
+    }
+
+    keys
+}
+
+// The responses - 200, 304 and 416 - of each encoding of an asset on exact paths.
+fn exact_leaves_v2(paths: &[String], asset: &Asset, config: &StorageConfig) -> Vec<Vec<Blob>> {
+    let empty_sha256: Hash = Sha256::digest(Vec::new()).into();
+
+    asset
+        .encodings
+        .iter()
+        .flat_map(|(encoding_type, encoding)| {
+            let headers = build_headers(asset, encoding, encoding_type, config);
+
+            paths
+                .iter()
+                .flat_map(|path| {
+                    let empty_responses = EMPTY_RESPONSES_STATUS_CODES.map(|status_code| {
+                        nested_tree_key(
+                            path,
+                            &headers,
+                            empty_sha256,
+                            EXACT_MATCH_TERMINATOR,
+                            status_code,
+                        )
+                    });
+
+                    [
+                        vec![nested_tree_key(
+                            path,
+                            &headers,
+                            encoding.sha256,
+                            EXACT_MATCH_TERMINATOR,
+                            RESPONSE_STATUS_CODE_200,
+                        )],
+                        empty_responses.to_vec(),
+                    ]
+                    .concat()
+                })
+                .collect::<Vec<Vec<Blob>>>()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::certification::constants::IC_CERTIFICATE_SKIP_EXPRESSION;
+    use crate::types::config::StorageConfigDomainRoot;
+    use crate::types::store::AssetKey;
+    use crate::utils::map_content_encoding;
+    use candid::Principal;
+    use ic_certification::LookupResult;
+    use std::collections::HashMap;
+
+    fn asset(full_path: &str) -> Asset {
+        Asset {
+            key: AssetKey {
+                name: full_path.to_string(),
+                full_path: full_path.to_string(),
+                token: None,
+                collection: "#dapp".to_string(),
+                owner: Principal::anonymous(),
+                description: None,
+                expires_at: None,
+            },
+            headers: Vec::new(),
+            encodings: HashMap::new(),
+            created_at: 0,
+            updated_at: 0,
+            version: None,
+        }
+    }
+
+    fn skip_leaf_path(segments: &[&str]) -> Vec<Vec<u8>> {
+        let expr_hash: Hash = Sha256::digest(IC_CERTIFICATE_SKIP_EXPRESSION).into();
+
+        [
+            vec![LABEL_ASSETS_V2.to_vec()],
+            segments
+                .iter()
+                .map(|segment| segment.as_bytes().to_vec())
+                .collect(),
+            vec![
+                WILDCARD_MATCH_TERMINATOR.as_bytes().to_vec(),
+                expr_hash.to_vec(),
+            ],
+        ]
+        .concat()
+    }
+
+    #[test]
+    fn skips_certification_of_the_endpoint_and_its_sub_paths() {
+        let mut hashes = CertifiedAssetHashes::default();
+        let root_hash = hashes.root_hash();
+
+        hashes.insert_skip_v2(&"/api/webhooks".to_string());
+
+        assert_ne!(hashes.root_hash(), root_hash);
+        assert!(hashes.contains_skip_v2(&"/api/webhooks".to_string()));
+        assert!(!hashes.contains_skip_v2(&"/api".to_string()));
+        assert!(!hashes.contains_skip_v2(&"/api/webhooks/stripe".to_string()));
+    }
+
+    #[test]
+    fn witnesses_the_skip_leaf_for_the_requests_of_the_endpoint() {
+        let mut hashes = CertifiedAssetHashes::default();
+        hashes.insert_skip_v2(&"/api/webhooks".to_string());
+
+        for url in ["/api/webhooks", "/api/webhooks/stripe"] {
+            let witness = hashes.witness_rewrite_v2(url);
+
+            assert_eq!(witness.digest(), hashes.root_hash());
+            assert!(matches!(
+                witness.lookup_path(skip_leaf_path(&["api", "webhooks"])),
+                LookupResult::Found(_)
+            ));
+        }
+
+        assert_eq!(
+            hashes.expr_path_v2("/api/webhooks/stripe", &Some("/api/webhooks".to_string())),
+            vec!["http_expr", "api", "webhooks", WILDCARD_MATCH_TERMINATOR]
+        );
+    }
+
+    #[test]
+    fn certifies_the_paths_redirected_to_an_asset() {
+        let redirect = |location: &str| StorageConfigRedirect {
+            location: location.to_string(),
+            status_code: 301,
+            preserve_query: None,
+        };
+
+        let config = StorageConfig {
+            redirects: Some(HashMap::from([
+                ("/blog/:slug".to_string(), redirect("/posts/:slug")),
+                ("/about".to_string(), redirect("/team")),
+            ])),
+            ..Default::default()
+        };
+
+        let iframe = config.unwrap_iframe();
+
+        let mut hashes = CertifiedAssetHashes::default();
+
+        for (source, redirect) in config.unwrap_redirects() {
+            hashes.insert_redirect_v2(&source, &redirect, &iframe);
+        }
+
+        assert!(hashes.contains_redirect_v2(&"/about".to_string(), &redirect("/team"), &iframe));
+        assert!(!hashes.contains_redirect_v2(
+            &"/blog/hello".to_string(),
+            &redirect("/posts/hello"),
+            &iframe
+        ));
+
+        let asset = asset("/posts/hello.html");
+
+        hashes.insert(&asset, &config);
+
+        assert!(hashes.contains_redirect_v2(
+            &"/blog/hello".to_string(),
+            &redirect("/posts/hello"),
+            &iframe
+        ));
+        assert!(!hashes.contains_redirect_v2(
+            &"/blog/hello".to_string(),
+            &redirect("https://example.com"),
+            &iframe
+        ));
+        assert!(!hashes.contains_redirect_v2(
+            &"/blog/other".to_string(),
+            &redirect("/posts/other"),
+            &iframe
+        ));
+
+        hashes.delete(&asset, &config);
+
+        assert!(!hashes.contains_redirect_v2(
+            &"/blog/hello".to_string(),
+            &redirect("/posts/hello"),
+            &iframe
+        ));
+    }
+
+    #[test]
+    fn certifies_the_root_of_a_domain_for_its_host() {
+        let config = StorageConfig {
+            domain_roots: Some(HashMap::from([(
+                "tenant-a.com".to_string(),
+                StorageConfigDomainRoot::Path("/tenant-a".to_string()),
+            )])),
+            ..Default::default()
+        };
+
+        let with_content = |full_path: &str| Asset {
+            encodings: HashMap::from([(
+                "identity".to_string(),
+                map_content_encoding(&full_path.as_bytes().to_vec()),
+            )]),
+            ..asset(full_path)
+        };
+
+        let about = with_content("/about.html");
+        let tenant_about = with_content("/tenant-a/about.html");
+
+        let leaf = |host: &str| {
+            let encoding = tenant_about.encodings.get("identity").unwrap();
+
+            nested_tree_host_key(
+                &"/about.html".to_string(),
+                &build_headers(&tenant_about, encoding, &"identity".to_string(), &config),
+                encoding.sha256,
+                EXACT_MATCH_TERMINATOR,
+                RESPONSE_STATUS_CODE_200,
+                host,
+                "GET",
+            )
+        };
+
+        let mut hashes = CertifiedAssetHashes::default();
+
+        hashes.insert(&tenant_about, &config);
+        hashes.insert(&about, &config);
+
+        assert!(hashes.tree_v2.contains_leaf(&leaf("tenant-a.com")));
+        assert!(!hashes.tree_v2.contains_leaf(&leaf("tenant-b.com")));
+        assert!(!hashes.domain_roots.contains_key("/about.html"));
+
+        // The path of the satellite is deleted, the leaves of the domain on the same path are kept
+        hashes.delete(&about, &config);
+
+        assert!(hashes.tree_v2.contains_leaf(&leaf("tenant-a.com")));
+
+        hashes.delete(&tenant_about, &config);
+
+        assert!(!hashes.tree_v2.contains_leaf(&leaf("tenant-a.com")));
+        assert!(hashes.domain_roots.is_empty());
     }
 }
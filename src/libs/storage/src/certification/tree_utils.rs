@@ -1,6 +1,7 @@
 use crate::certification::constants::{
-    IC_CERTIFICATE_EXPRESSION, IC_CERTIFICATE_EXPRESSION_HEADER, IC_STATUS_CODE_PSEUDO_HEADER,
-    LABEL_HTTP_EXPR, WILDCARD_MATCH_TERMINATOR,
+    IC_CERTIFICATE_EXPRESSION, IC_CERTIFICATE_EXPRESSION_HEADER, IC_CERTIFICATE_HOST_EXPRESSION,
+    IC_CERTIFICATE_SKIP_EXPRESSION, IC_HOST_REQUEST_HEADER, IC_METHOD_PSEUDO_HEADER,
+    IC_STATUS_CODE_PSEUDO_HEADER, LABEL_HTTP_EXPR, WILDCARD_MATCH_TERMINATOR,
 };
 use crate::http::types::{HeaderField, StatusCode};
 use crate::types::state::FullPath;
@@ -18,11 +19,56 @@ pub fn nested_tree_key(
 ) -> Vec<Blob> {
     let mut segments = nested_tree_path(full_path, terminator);
 
-    let expr_hash: Hash = Sha256::digest(response_headers_expression(headers)).into();
+    let expression = response_headers_expression(headers);
+
+    let expr_hash: Hash = Sha256::digest(&expression).into();
     segments.push(Vec::from(expr_hash.as_slice()));
 
     segments.push(vec![]);
-    segments.push(Vec::from(response_hash(headers, status_code, &body_hash)));
+    segments.push(Vec::from(response_hash(
+        headers,
+        &expression,
+        status_code,
+        &body_hash,
+    )));
+
+    segments
+}
+
+// A response served from the root of a custom domain is certified with the request - its Host header and method.
+pub fn nested_tree_host_key(
+    full_path: &FullPath,
+    headers: &[HeaderField],
+    body_hash: Hash,
+    terminator: &str,
+    status_code: StatusCode,
+    host: &str,
+    method: &str,
+) -> Vec<Blob> {
+    let mut segments = nested_tree_path(full_path, terminator);
+
+    let expression = host_response_headers_expression(headers);
+
+    let expr_hash: Hash = Sha256::digest(&expression).into();
+    segments.push(Vec::from(expr_hash.as_slice()));
+
+    segments.push(Vec::from(request_hash(host, method)));
+    segments.push(Vec::from(response_hash(
+        headers,
+        &expression,
+        status_code,
+        &body_hash,
+    )));
+
+    segments
+}
+
+// A skipped certification has neither request nor response hash, its leaf is the hash of the expression.
+pub fn nested_tree_skip_key(full_path: &FullPath) -> Vec<Blob> {
+    let mut segments = nested_tree_path(full_path, WILDCARD_MATCH_TERMINATOR);
+
+    let expr_hash: Hash = Sha256::digest(IC_CERTIFICATE_SKIP_EXPRESSION).into();
+    segments.push(Vec::from(expr_hash.as_slice()));
 
     segments
 }
@@ -71,7 +117,35 @@ pub fn nested_tree_expr_path(absolute_path: &str, terminator: &str) -> Vec<Strin
     path
 }
 
-fn response_hash(headers: &[HeaderField], status_code: StatusCode, body_hash: &Hash) -> Hash {
+fn request_hash(host: &str, method: &str) -> Hash {
+    // certification v2 spec:
+    // Request hash is the hash of the concatenation of
+    //   - representation-independent hash of the certified request headers (here the host), plus
+    //     synthetic header `:ic-cert-method` with value <HTTP method of request>
+    //   - hash of the request body - empty for GET and HEAD
+    let certified_headers = vec![
+        (
+            IC_HOST_REQUEST_HEADER.to_string(),
+            Value::String(host.to_string()),
+        ),
+        (
+            IC_METHOD_PSEUDO_HEADER.to_string(),
+            Value::String(method.to_string()),
+        ),
+    ];
+
+    let header_hash = representation_independent_hash(&certified_headers);
+    let body_hash: Hash = Sha256::digest([]).into();
+
+    Sha256::digest([header_hash.as_ref(), &body_hash].concat()).into()
+}
+
+fn response_hash(
+    headers: &[HeaderField],
+    expression: &str,
+    status_code: StatusCode,
+    body_hash: &Hash,
+) -> Hash {
     // certification v2 spec:
     // Response hash is the hash of the concatenation of
     //   - representation-independent hash of headers
@@ -90,7 +164,7 @@ fn response_hash(headers: &[HeaderField], status_code: StatusCode, body_hash: &H
 
     certified_headers.push((
         IC_CERTIFICATE_EXPRESSION_HEADER.to_ascii_lowercase(),
-        Value::String(response_headers_expression(headers)),
+        Value::String(expression.to_string()),
     ));
 
     certified_headers.push((
@@ -103,6 +177,14 @@ fn response_hash(headers: &[HeaderField], status_code: StatusCode, body_hash: &H
 }
 
 pub fn response_headers_expression(headers: &[HeaderField]) -> String {
+    headers_expression(IC_CERTIFICATE_EXPRESSION, headers)
+}
+
+pub fn host_response_headers_expression(headers: &[HeaderField]) -> String {
+    headers_expression(IC_CERTIFICATE_HOST_EXPRESSION, headers)
+}
+
+fn headers_expression(expression: &str, headers: &[HeaderField]) -> String {
     let mut header_names: Vec<String> = headers
         .iter()
         .map(|field| format!("\"{}\"", field.0))
@@ -114,5 +196,5 @@ pub fn response_headers_expression(headers: &[HeaderField]) -> String {
 
     let headers = header_names.join(",");
 
-    IC_CERTIFICATE_EXPRESSION.replace("{headers}", &headers)
+    expression.replace("{headers}", &headers)
 }
@@ -1,11 +1,17 @@
 pub mod certified {
+    use crate::types::state::FullPath;
     use ic_certification::{Hash, NestedTree, RbTree};
     use junobuild_shared::types::core::Blob;
     use std::clone::Clone;
+    use std::collections::HashMap;
 
     #[derive(Default, Clone)]
     pub struct CertifiedAssetHashes {
         pub tree_v1: RbTree<String, Hash>,
         pub tree_v2: NestedTree<Blob, Blob>,
+        // The leaves certified for the Host of the custom domains per asset of their root - e.g. those of
+        // /tenant-a/about.html on /about.html for tenant-a.com. These share the paths of the other assets and
+        // are inserted again when such a path is deleted from the tree.
+        pub domain_roots: HashMap<FullPath, Vec<Vec<Blob>>>,
     }
 }
@@ -1,6 +1,6 @@
 use crate::certification::types::certified::CertifiedAssetHashes;
 use crate::memory::STATE;
-use crate::rewrites::rewrite_source_to_path;
+use crate::rewrites::{is_parameterized_rewrite, rewrite_source_to_path};
 use crate::routing::get_routing;
 use crate::runtime::init_certified_assets;
 use crate::strategies::{StorageCertificateStrategy, StorageStateStrategy};
@@ -65,7 +65,12 @@ fn extend_certified_assets(
     config: &StorageConfig,
     storage_state: &impl StorageStateStrategy,
 ) {
+    // Rewrites whose destination is resolved from the captured parameters are certified with their assets
     for (source, destination) in config.rewrites.clone() {
+        if is_parameterized_rewrite(&source, &destination) {
+            continue;
+        }
+
         if let Ok(Routing::Default(RoutingDefault { url: _, asset })) =
             get_routing(destination, &Vec::new(), false, storage_state)
         {
@@ -78,11 +83,11 @@ fn extend_certified_assets(
     }
 
     for (source, redirect) in config.unwrap_redirects() {
-        asset_hashes.insert_redirect_v2(
-            &source,
-            redirect.status_code,
-            &redirect.location,
-            &config.unwrap_iframe(),
-        );
+        asset_hashes.insert_redirect_v2(&source, &redirect, &config.unwrap_iframe());
+    }
+
+    // The responses of the custom HTTP endpoints are computed when requested
+    for endpoint in storage_state.get_http_endpoints() {
+        asset_hashes.insert_skip_v2(&endpoint);
     }
 }
@@ -0,0 +1,259 @@
+use crate::constants::{ASSET_ENCODING_BROTLI, ASSET_ENCODING_GZIP, ASSET_ENCODING_NO_COMPRESSION};
+use crate::http::types::HeaderField;
+use crate::types::config::{StorageConfig, StorageConfigCompression};
+use crate::types::store::{Asset, EncodingType};
+use brotli::enc::BrotliEncoderParams;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use junobuild_shared::types::core::Blob;
+use std::io::Write;
+
+// Larger contents are served as uploaded, compressing them would exceed the instructions limit of the commit.
+const COMPRESSION_MAX_CONTENT_LENGTH: usize = 10_000_000;
+
+// A trade-off between the compression ratio and the cost of the commit.
+const BROTLI_QUALITY: i32 = 5;
+
+/// Compresses the content of an asset with the encodings enabled in the configuration, except those
+/// listed in `skip` - e.g. the encodings uploaded by the client.
+///
+/// Only assets whose content type is compressible - text, JSON, JavaScript, XML, SVG or WebAssembly - are
+/// compressed. An encoding that cannot be produced is skipped, the asset remains served with the
+/// uploaded content.
+pub fn compress_content(
+    config: &StorageConfig,
+    headers: &[HeaderField],
+    content: &[u8],
+    skip: &[EncodingType],
+) -> Vec<(EncodingType, Blob)> {
+    let compression = match &config.compression {
+        None => return Vec::new(),
+        Some(compression) => compression,
+    };
+
+    if content.is_empty()
+        || content.len() > COMPRESSION_MAX_CONTENT_LENGTH
+        || !is_compressible(headers)
+    {
+        return Vec::new();
+    }
+
+    compression
+        .iter()
+        .map(|encoding| match encoding {
+            StorageConfigCompression::Gzip => ASSET_ENCODING_GZIP,
+            StorageConfigCompression::Brotli => ASSET_ENCODING_BROTLI,
+        })
+        .filter(|encoding_type| !skip.iter().any(|skip| skip == encoding_type))
+        .filter_map(|encoding_type| {
+            let compressed = match encoding_type {
+                ASSET_ENCODING_GZIP => gzip(content),
+                ASSET_ENCODING_BROTLI => brotli(content),
+                _ => None,
+            };
+
+            compressed.map(|content| (encoding_type.to_string(), content))
+        })
+        .collect()
+}
+
+/// The compressed encodings of an asset that were uploaded by the client - i.e. not generated on
+/// commit.
+pub fn uploaded_encoding_types(asset: &Asset) -> Vec<EncodingType> {
+    asset
+        .encodings
+        .iter()
+        .filter(|(encoding_type, encoding)| {
+            encoding_type.as_str() != ASSET_ENCODING_NO_COMPRESSION && !encoding.generated
+        })
+        .map(|(encoding_type, _)| encoding_type.clone())
+        .collect()
+}
+
+fn gzip(content: &[u8]) -> Option<Blob> {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(content).ok()?;
+    encoder.finish().ok()
+}
+
+fn brotli(content: &[u8]) -> Option<Blob> {
+    let params = BrotliEncoderParams {
+        quality: BROTLI_QUALITY,
+        ..Default::default()
+    };
+
+    let mut compressed = Vec::new();
+    brotli::BrotliCompress(&mut &content[..], &mut compressed, &params).ok()?;
+
+    Some(compressed)
+}
+
+fn is_compressible(headers: &[HeaderField]) -> bool {
+    let content_type = headers
+        .iter()
+        .find(|HeaderField(key, _)| key.eq_ignore_ascii_case("content-type"))
+        .map(|HeaderField(_, value)| value.to_lowercase());
+
+    let mime_type = match &content_type {
+        None => return false,
+        Some(content_type) => content_type.split(';').next().unwrap_or_default().trim(),
+    };
+
+    mime_type.starts_with("text/")
+        || mime_type.ends_with("+json")
+        || mime_type.ends_with("+xml")
+        || matches!(
+            mime_type,
+            "application/json"
+                | "application/javascript"
+                | "application/xml"
+                | "application/wasm"
+                | "image/svg+xml"
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::store::{AssetEncoding, AssetKey};
+    use candid::Principal;
+    use std::collections::HashMap;
+
+    fn config() -> StorageConfig {
+        StorageConfig {
+            compression: Some(vec![
+                StorageConfigCompression::Gzip,
+                StorageConfigCompression::Brotli,
+            ]),
+            ..Default::default()
+        }
+    }
+
+    fn headers(content_type: &str) -> Vec<HeaderField> {
+        vec![HeaderField(
+            "Content-Type".to_string(),
+            content_type.to_string(),
+        )]
+    }
+
+    fn encoding(generated: bool) -> AssetEncoding {
+        AssetEncoding {
+            modified: 0,
+            content_chunks: Vec::new(),
+            total_length: 0,
+            sha256: [0; 32],
+            generated,
+        }
+    }
+
+    fn asset(encodings: Vec<(&str, bool)>) -> Asset {
+        Asset {
+            key: AssetKey {
+                name: "index.html".to_string(),
+                full_path: "/index.html".to_string(),
+                token: None,
+                collection: "#dapp".to_string(),
+                owner: Principal::anonymous(),
+                description: None,
+                expires_at: None,
+            },
+            headers: Vec::new(),
+            encodings: encodings
+                .into_iter()
+                .map(|(encoding_type, generated)| (encoding_type.to_string(), encoding(generated)))
+                .collect::<HashMap<_, _>>(),
+            created_at: 0,
+            updated_at: 0,
+            version: None,
+        }
+    }
+
+    fn encoding_types(compressed: &[(EncodingType, Blob)]) -> Vec<&str> {
+        compressed
+            .iter()
+            .map(|(encoding_type, _)| encoding_type.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn detects_compressible_content_types() {
+        assert!(is_compressible(&headers("text/html; charset=utf-8")));
+        assert!(is_compressible(&headers("application/JSON")));
+        assert!(is_compressible(&headers("application/ld+json")));
+        assert!(is_compressible(&headers("image/svg+xml")));
+        assert!(is_compressible(&headers("application/wasm")));
+
+        assert!(!is_compressible(&headers("image/png")));
+        assert!(!is_compressible(&headers("application/octet-stream")));
+        assert!(!is_compressible(&[]));
+    }
+
+    #[test]
+    fn compresses_with_the_configured_encodings() {
+        let content = "hello world ".repeat(100);
+
+        let compressed =
+            compress_content(&config(), &headers("text/plain"), content.as_bytes(), &[]);
+
+        assert_eq!(
+            encoding_types(&compressed),
+            vec![ASSET_ENCODING_GZIP, ASSET_ENCODING_BROTLI]
+        );
+        assert!(compressed
+            .iter()
+            .all(|(_, compressed)| compressed.len() < content.len()));
+    }
+
+    #[test]
+    fn skips_uncompressible_or_unconfigured_content() {
+        let content = b"hello world";
+
+        assert!(compress_content(&config(), &headers("image/png"), content, &[]).is_empty());
+        assert!(compress_content(&config(), &headers("text/plain"), &[], &[]).is_empty());
+        assert!(compress_content(
+            &StorageConfig::default(),
+            &headers("text/plain"),
+            content,
+            &[]
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn skips_the_given_encodings() {
+        let compressed = compress_content(
+            &config(),
+            &headers("text/plain"),
+            b"hello world",
+            &[ASSET_ENCODING_GZIP.to_string()],
+        );
+
+        assert_eq!(encoding_types(&compressed), vec![ASSET_ENCODING_BROTLI]);
+    }
+
+    #[test]
+    fn finds_the_encodings_uploaded_by_the_client() {
+        let generated = asset(vec![
+            (ASSET_ENCODING_NO_COMPRESSION, false),
+            (ASSET_ENCODING_GZIP, true),
+            (ASSET_ENCODING_BROTLI, true),
+        ]);
+        assert!(uploaded_encoding_types(&generated).is_empty());
+
+        let uploaded = asset(vec![
+            (ASSET_ENCODING_NO_COMPRESSION, false),
+            (ASSET_ENCODING_GZIP, false),
+            (ASSET_ENCODING_BROTLI, true),
+        ]);
+        assert_eq!(
+            uploaded_encoding_types(&uploaded),
+            vec![ASSET_ENCODING_GZIP.to_string()]
+        );
+
+        let without_identity = asset(vec![(ASSET_ENCODING_BROTLI, false)]);
+        assert_eq!(
+            uploaded_encoding_types(&without_identity),
+            vec![ASSET_ENCODING_BROTLI.to_string()]
+        );
+    }
+}
@@ -1,13 +1,16 @@
 use crate::http::types::StatusCode;
 
 pub const ASSET_ENCODING_NO_COMPRESSION: &str = "identity";
+pub const ASSET_ENCODING_GZIP: &str = "gzip";
+pub const ASSET_ENCODING_BROTLI: &str = "br";
 pub const ENCODING_CERTIFICATION_ORDER: &[&str] = &[
     ASSET_ENCODING_NO_COMPRESSION,
-    "gzip",
+    ASSET_ENCODING_GZIP,
     "compress",
     "deflate",
-    "br",
+    ASSET_ENCODING_BROTLI,
 ];
+pub const WELL_KNOWN_PATH: &str = "/.well-known/";
 pub const WELL_KNOWN_CUSTOM_DOMAINS: &str = "/.well-known/ic-domains";
 pub const WELL_KNOWN_II_ALTERNATIVE_ORIGINS: &str = "/.well-known/ii-alternative-origins";
 
@@ -17,10 +20,13 @@ pub const ROOT_404_HTML: &str = "/404.html";
 pub const ROOT_PATHS: [&str; 5] = ["/index.html", "/index", "/", "/404", "/404.html"];
 
 pub const RESPONSE_STATUS_CODE_200: StatusCode = 200;
+pub const RESPONSE_STATUS_CODE_206: StatusCode = 206;
+pub const RESPONSE_STATUS_CODE_304: StatusCode = 304;
 pub const RESPONSE_STATUS_CODE_308: StatusCode = 308;
 pub const RESPONSE_STATUS_CODE_404: StatusCode = 404;
 pub const RESPONSE_STATUS_CODE_405: StatusCode = 405;
 pub const RESPONSE_STATUS_CODE_406: StatusCode = 406;
+pub const RESPONSE_STATUS_CODE_416: StatusCode = 416;
 pub const RESPONSE_STATUS_CODE_500: StatusCode = 500;
 
 pub const RAW_DOMAINS: [&str; 4] = [
@@ -0,0 +1,183 @@
+use crate::http::types::HeaderField;
+use crate::types::config::StorageConfig;
+use crate::types::state::FullPath;
+use junobuild_shared::types::core::DomainName;
+
+/// Returns the path prefix of the root configured for the custom domain of the request, if any.
+pub fn domain_root(req_headers: &[HeaderField], config: &StorageConfig) -> Option<String> {
+    let host = req_headers
+        .iter()
+        .find(|HeaderField(key, _)| key.eq_ignore_ascii_case("Host"))
+        .map(|HeaderField(_, value)| value.trim().to_lowercase())?;
+
+    // e.g. tenant-a.com:443 -> tenant-a.com
+    let domain = host.split(':').next().unwrap_or_default();
+
+    config
+        .unwrap_domain_roots()
+        .iter()
+        .find(|(domain_name, _)| domain_name.eq_ignore_ascii_case(domain))
+        .map(|(_, root)| root.to_path_prefix())
+}
+
+/// Returns, for each custom domain whose root contains a full path, a tuple of the domain and the path
+/// requested on that domain - e.g. `("tenant-a.com", "/about.html")` for `/tenant-a/about.html`.
+pub fn domain_paths(full_path: &str, config: &StorageConfig) -> Vec<(DomainName, FullPath)> {
+    let mut paths: Vec<(DomainName, FullPath)> = config
+        .unwrap_domain_roots()
+        .iter()
+        .filter_map(|(domain_name, root)| {
+            full_path
+                .strip_prefix(&root.to_path_prefix())
+                .filter(|path| path.starts_with('/'))
+                .map(|path| (domain_name.to_lowercase(), path.to_string()))
+        })
+        .collect();
+
+    paths.sort();
+
+    paths
+}
+
+/// Scopes the rewrites and redirects of the configuration to those whose source is in the root of
+/// a custom domain, so that each domain is served with its own routing.
+pub fn domain_config(config: &StorageConfig, prefix: &str) -> StorageConfig {
+    let in_root = |source: &String| {
+        source
+            .strip_prefix(prefix)
+            .is_some_and(|path| path.starts_with('/'))
+    };
+
+    StorageConfig {
+        rewrites: config
+            .rewrites
+            .iter()
+            .filter(|(source, _)| in_root(source))
+            .map(|(source, destination)| (source.clone(), destination.clone()))
+            .collect(),
+        redirects: config.redirects.as_ref().map(|redirects| {
+            redirects
+                .iter()
+                .filter(|(source, _)| in_root(source))
+                .map(|(source, redirect)| (source.clone(), redirect.clone()))
+                .collect()
+        }),
+        ..config.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::config::{StorageConfigDomainRoot, StorageConfigRedirect};
+    use std::collections::HashMap;
+
+    fn host(value: &str) -> Vec<HeaderField> {
+        vec![HeaderField("host".to_string(), value.to_string())]
+    }
+
+    fn config() -> StorageConfig {
+        StorageConfig {
+            domain_roots: Some(HashMap::from([
+                (
+                    "tenant-a.com".to_string(),
+                    StorageConfigDomainRoot::Path("/tenant-a".to_string()),
+                ),
+                (
+                    "tenant-b.com".to_string(),
+                    StorageConfigDomainRoot::Collection("tenant-b".to_string()),
+                ),
+            ])),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn finds_the_root_of_the_requested_domain() {
+        let config = config();
+
+        assert_eq!(
+            domain_root(&host("tenant-a.com"), &config),
+            Some("/tenant-a".to_string())
+        );
+        assert_eq!(
+            domain_root(&host(" Tenant-B.com:443 "), &config),
+            Some("/tenant-b".to_string())
+        );
+
+        assert_eq!(domain_root(&host("tenant-c.com"), &config), None);
+        assert_eq!(domain_root(&host("tenant-a.com.evil.com"), &config), None);
+        assert_eq!(domain_root(&[], &config), None);
+    }
+
+    #[test]
+    fn finds_the_paths_requested_on_the_domains() {
+        let config = StorageConfig {
+            domain_roots: Some(HashMap::from([
+                (
+                    "Tenant-A.com".to_string(),
+                    StorageConfigDomainRoot::Path("/tenant-a/".to_string()),
+                ),
+                (
+                    "www.tenant-a.com".to_string(),
+                    StorageConfigDomainRoot::Path("/tenant-a".to_string()),
+                ),
+                (
+                    "tenant-b.com".to_string(),
+                    StorageConfigDomainRoot::Collection("tenant-b".to_string()),
+                ),
+            ])),
+            ..config()
+        };
+
+        assert_eq!(
+            domain_paths("/tenant-a/about.html", &config),
+            vec![
+                ("tenant-a.com".to_string(), "/about.html".to_string()),
+                ("www.tenant-a.com".to_string(), "/about.html".to_string()),
+            ]
+        );
+        assert_eq!(
+            domain_paths("/tenant-b/index.html", &config),
+            vec![("tenant-b.com".to_string(), "/index.html".to_string())]
+        );
+        assert!(domain_paths("/tenant-ab/index.html", &config).is_empty());
+        assert!(domain_paths("/about.html", &config).is_empty());
+    }
+
+    #[test]
+    fn scopes_routing_to_the_root() {
+        let redirect = StorageConfigRedirect {
+            location: "/new".to_string(),
+            status_code: 301,
+            preserve_query: None,
+        };
+
+        let config = StorageConfig {
+            rewrites: HashMap::from([
+                ("/tenant-a/**".to_string(), "/tenant-a/app.html".to_string()),
+                (
+                    "/tenant-ab/**".to_string(),
+                    "/tenant-ab/app.html".to_string(),
+                ),
+                ("/**".to_string(), "/index.html".to_string()),
+            ]),
+            redirects: Some(HashMap::from([
+                ("/tenant-a/old".to_string(), redirect.clone()),
+                ("/old".to_string(), redirect),
+            ])),
+            ..config()
+        };
+
+        let scoped = domain_config(&config, "/tenant-a");
+
+        assert_eq!(
+            scoped.rewrites.keys().collect::<Vec<_>>(),
+            vec!["/tenant-a/**"]
+        );
+        assert_eq!(
+            scoped.unwrap_redirects().keys().collect::<Vec<_>>(),
+            vec!["/tenant-a/old"]
+        );
+    }
+}
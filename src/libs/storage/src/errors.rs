@@ -17,3 +17,17 @@ pub const JUNO_STORAGE_ERROR_CHUNK_NOT_INCLUDED_IN_BATCH: &str =
 // Asset exceed max allowed size
 pub const JUNO_STORAGE_ERROR_ASSET_MAX_ALLOWED_SIZE: &str =
     "juno.storage.error.asset_max_allowed_size";
+// Assets cannot be looked up through an index
+pub const JUNO_STORAGE_ERROR_INDEX_MATCHER_NOT_SUPPORTED: &str =
+    "juno.storage.error.index_matcher_not_supported";
+// Assets have no JSON data to match
+pub const JUNO_STORAGE_ERROR_DATA_MATCHER_NOT_SUPPORTED: &str =
+    "juno.storage.error.data_matcher_not_supported";
+// A root can only be configured for a custom domain of the satellite
+pub const JUNO_STORAGE_ERROR_DOMAIN_ROOT_UNKNOWN_DOMAIN: &str =
+    "juno.storage.error.domain_root_unknown_domain";
+// The root of a custom domain must be a path prefix other than /
+pub const JUNO_STORAGE_ERROR_INVALID_DOMAIN_ROOT: &str = "juno.storage.error.invalid_domain_root";
+// The sha256 of the committed content does not match the expected hash
+pub const JUNO_STORAGE_ERROR_CONTENT_HASH_MISMATCH: &str =
+    "juno.storage.error.content_hash_mismatch";
@@ -34,10 +34,7 @@ pub fn build_headers(
     // The Accept-Ranges HTTP response header is a marker used by the server to advertise its support for partial requests from the client for file downloads.
     headers.insert("accept-ranges".to_string(), "bytes".to_string());
 
-    headers.insert(
-        "etag".to_string(),
-        format!("\"{}\"", encode(encoding.sha256)),
-    );
+    headers.insert("etag".to_string(), build_etag(encoding));
 
     // Headers for security
     for HeaderField(key, value) in security_headers() {
@@ -59,6 +56,10 @@ pub fn build_headers(
         .collect()
 }
 
+pub fn build_etag(encoding: &AssetEncoding) -> String {
+    format!("\"{}\"", encode(encoding.sha256))
+}
+
 pub fn build_redirect_headers(location: &str, iframe: &StorageConfigIFrame) -> Vec<HeaderField> {
     let mut headers = Vec::new();
 
@@ -1,4 +1,5 @@
 pub mod headers;
+pub mod range;
 pub mod response;
 pub mod types;
 pub mod utils;
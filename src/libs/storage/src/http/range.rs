@@ -0,0 +1,326 @@
+use crate::http::types::HeaderField;
+use crate::strategies::StorageStateStrategy;
+use crate::types::store::AssetEncoding;
+use junobuild_collections::types::rules::Memory;
+use junobuild_shared::types::core::Blob;
+
+/// A range of bytes, inclusive bounds, resolved against the length of an encoding.
+pub struct ByteRange {
+    pub start: u128,
+    pub end: u128,
+}
+
+pub enum RequestedRange {
+    /// No or an unsupported (e.g. multiple ranges) Range header - the whole content is served.
+    None,
+    Satisfiable(ByteRange),
+    Unsatisfiable,
+}
+
+/// The part of a chunk that is served as partial content.
+pub struct PartialContent {
+    pub body: Blob,
+    pub range: ByteRange,
+}
+
+// ---------------------------------------------------------
+// Headers
+// ---------------------------------------------------------
+
+fn find_header<'a>(headers: &'a [HeaderField], name: &str) -> Option<&'a str> {
+    headers
+        .iter()
+        .find(|HeaderField(key, _)| key.eq_ignore_ascii_case(name))
+        .map(|HeaderField(_, value)| value.trim())
+}
+
+/// Returns true if the request carries an If-None-Match header that matches the etag of the served encoding.
+///
+/// Etags are compared weakly, as required by RFC 9110 for If-None-Match.
+pub fn is_not_modified(requested_headers: &[HeaderField], etag: &str) -> bool {
+    match find_header(requested_headers, "If-None-Match") {
+        None => false,
+        Some(value) => value.split(',').map(str::trim).any(|candidate| {
+            candidate == "*" || candidate.trim_start_matches("W/") == etag.trim_start_matches("W/")
+        }),
+    }
+}
+
+/// Parses the Range header of the request against the total length of the served encoding.
+///
+/// Only a single range of bytes is supported. If an If-Range header does not strongly match the etag
+/// - e.g. the asset changed or a date was provided - the range is ignored and the whole content is served.
+pub fn requested_range(
+    requested_headers: &[HeaderField],
+    etag: &str,
+    total_length: u128,
+) -> RequestedRange {
+    let range = match find_header(requested_headers, "Range") {
+        None => return RequestedRange::None,
+        Some(range) => range,
+    };
+
+    if let Some(if_range) = find_header(requested_headers, "If-Range") {
+        if if_range != etag {
+            return RequestedRange::None;
+        }
+    }
+
+    if total_length == 0 {
+        return RequestedRange::None;
+    }
+
+    let spec = match range.strip_prefix("bytes=") {
+        Some(spec) if !spec.contains(',') => spec.trim(),
+        _ => return RequestedRange::None,
+    };
+
+    let (start, end) = match spec.split_once('-') {
+        None => return RequestedRange::None,
+        Some((start, end)) => (start.trim(), end.trim()),
+    };
+
+    let last = total_length - 1;
+
+    let parsed = match (start.parse::<u128>(), end.parse::<u128>()) {
+        // bytes=-500: the last 500 bytes
+        (Err(_), Ok(suffix)) if start.is_empty() => match suffix {
+            0 => return RequestedRange::Unsatisfiable,
+            suffix => Some((total_length.saturating_sub(suffix), last)),
+        },
+        // bytes=500-
+        (Ok(start), Err(_)) if end.is_empty() => Some((start, last)),
+        // bytes=500-999
+        (Ok(start), Ok(end)) if start <= end => Some((start, end.min(last))),
+        _ => None,
+    };
+
+    match parsed {
+        None => RequestedRange::None,
+        Some((start, _)) if start > last => RequestedRange::Unsatisfiable,
+        Some((start, end)) => RequestedRange::Satisfiable(ByteRange { start, end }),
+    }
+}
+
+pub fn content_range_header(range: &ByteRange, total_length: u128) -> HeaderField {
+    HeaderField(
+        "content-range".to_string(),
+        format!("bytes {}-{}/{}", range.start, range.end, total_length),
+    )
+}
+
+pub fn unsatisfied_range_header(total_length: u128) -> HeaderField {
+    HeaderField(
+        "content-range".to_string(),
+        format!("bytes */{total_length}"),
+    )
+}
+
+// ---------------------------------------------------------
+// Content
+// ---------------------------------------------------------
+
+/// Reads the part of the requested range that is contained in the chunk where the range starts.
+///
+/// The response never spans multiple chunks - the served range may therefore be shorter than
+/// requested, in which case clients request the remaining bytes with a subsequent Range request.
+pub fn get_partial_content(
+    encoding: &AssetEncoding,
+    range: &ByteRange,
+    memory: &Memory,
+    storage_state: &impl StorageStateStrategy,
+) -> Option<PartialContent> {
+    let (chunk_start, chunk) = find_chunk(encoding, range.start, memory, storage_state)?;
+
+    let from = (range.start - chunk_start) as usize;
+    let to = ((range.end - chunk_start) as usize).min(chunk.len() - 1);
+
+    Some(PartialContent {
+        body: chunk[from..=to].to_vec(),
+        range: ByteRange {
+            start: range.start,
+            end: chunk_start + to as u128,
+        },
+    })
+}
+
+/// Finds the chunk that contains the offset and returns it with the offset of its first byte.
+fn find_chunk(
+    encoding: &AssetEncoding,
+    offset: u128,
+    memory: &Memory,
+    storage_state: &impl StorageStateStrategy,
+) -> Option<(u128, Blob)> {
+    let count = encoding.content_chunks.len();
+    let first = storage_state.get_content_chunks(encoding, 0, memory)?;
+    let chunk_size = first.len() as u128;
+
+    if chunk_size == 0 {
+        return None;
+    }
+
+    if offset < chunk_size {
+        return Some((0, first));
+    }
+
+    // Uploads are usually split in chunks of the same size, except the last one. If the lengths are
+    // consistent with such a layout, the chunk is located without reading the previous ones.
+    let index = (offset / chunk_size) as usize;
+    let uniform = (count as u128 - 1) * chunk_size < encoding.total_length
+        && encoding.total_length <= count as u128 * chunk_size;
+
+    if uniform && index < count {
+        let chunk = storage_state.get_content_chunks(encoding, index, memory)?;
+        let chunk_start = index as u128 * chunk_size;
+
+        let expected_length = if index + 1 < count {
+            chunk_size
+        } else {
+            encoding.total_length - chunk_start
+        };
+
+        if chunk.len() as u128 == expected_length {
+            return Some((chunk_start, chunk));
+        }
+    }
+
+    let mut chunk_start = chunk_size;
+
+    for index in 1..count {
+        let chunk = storage_state.get_content_chunks(encoding, index, memory)?;
+        let chunk_end = chunk_start + chunk.len() as u128;
+
+        if offset < chunk_end {
+            return Some((chunk_start, chunk));
+        }
+
+        chunk_start = chunk_end;
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ETAG: &str = "\"abc\"";
+
+    fn request(range: &str, total_length: u128) -> RequestedRange {
+        requested_range(
+            &[HeaderField("Range".to_string(), range.to_string())],
+            ETAG,
+            total_length,
+        )
+    }
+
+    fn bounds(requested: RequestedRange) -> (u128, u128) {
+        match requested {
+            RequestedRange::Satisfiable(ByteRange { start, end }) => (start, end),
+            _ => panic!("Expected a satisfiable range"),
+        }
+    }
+
+    #[test]
+    fn parses_closed_range() {
+        assert_eq!(bounds(request("bytes=0-99", 1000)), (0, 99));
+        assert_eq!(bounds(request("bytes=500-999", 1000)), (500, 999));
+    }
+
+    #[test]
+    fn clamps_end_to_length() {
+        assert_eq!(bounds(request("bytes=900-5000", 1000)), (900, 999));
+    }
+
+    #[test]
+    fn parses_open_ended_range() {
+        assert_eq!(bounds(request("bytes=500-", 1000)), (500, 999));
+    }
+
+    #[test]
+    fn parses_suffix_range() {
+        assert_eq!(bounds(request("bytes=-100", 1000)), (900, 999));
+        assert_eq!(bounds(request("bytes=-5000", 1000)), (0, 999));
+    }
+
+    #[test]
+    fn rejects_empty_suffix_range() {
+        assert!(matches!(
+            request("bytes=-0", 1000),
+            RequestedRange::Unsatisfiable
+        ));
+    }
+
+    #[test]
+    fn rejects_out_of_bounds_range() {
+        assert!(matches!(
+            request("bytes=1000-", 1000),
+            RequestedRange::Unsatisfiable
+        ));
+        assert!(matches!(
+            request("bytes=2000-3000", 1000),
+            RequestedRange::Unsatisfiable
+        ));
+    }
+
+    #[test]
+    fn serves_whole_content_for_multiple_ranges() {
+        assert!(matches!(
+            request("bytes=0-99,200-299", 1000),
+            RequestedRange::None
+        ));
+    }
+
+    #[test]
+    fn serves_whole_content_for_invalid_ranges() {
+        for range in ["bytes=500-100", "bytes=abc", "items=0-99", "bytes=-"] {
+            assert!(matches!(request(range, 1000), RequestedRange::None));
+        }
+    }
+
+    #[test]
+    fn serves_empty_content_whole() {
+        assert!(matches!(request("bytes=0-99", 0), RequestedRange::None));
+    }
+
+    #[test]
+    fn ignores_range_if_etag_differs() {
+        let headers = [
+            HeaderField("Range".to_string(), "bytes=0-99".to_string()),
+            HeaderField("If-Range".to_string(), "\"other\"".to_string()),
+        ];
+
+        assert!(matches!(
+            requested_range(&headers, ETAG, 1000),
+            RequestedRange::None
+        ));
+
+        let headers = [
+            HeaderField("range".to_string(), "bytes=0-99".to_string()),
+            HeaderField("if-range".to_string(), ETAG.to_string()),
+        ];
+
+        assert_eq!(bounds(requested_range(&headers, ETAG, 1000)), (0, 99));
+    }
+
+    #[test]
+    fn matches_if_none_match_weakly() {
+        let headers = |value: &str| [HeaderField("If-None-Match".to_string(), value.to_string())];
+
+        assert!(is_not_modified(&headers("\"abc\""), ETAG));
+        assert!(is_not_modified(&headers("W/\"abc\""), ETAG));
+        assert!(is_not_modified(&headers("\"other\", \"abc\""), ETAG));
+        assert!(is_not_modified(&headers("*"), ETAG));
+        assert!(!is_not_modified(&headers("\"other\""), ETAG));
+        assert!(!is_not_modified(&[], ETAG));
+    }
+
+    #[test]
+    fn formats_content_range() {
+        let HeaderField(_, value) = content_range_header(&ByteRange { start: 0, end: 99 }, 1000);
+        assert_eq!(value, "bytes 0-99/1000");
+
+        let HeaderField(_, value) = unsatisfied_range_header(1000);
+        assert_eq!(value, "bytes */1000");
+    }
+}
@@ -1,31 +1,42 @@
 use crate::constants::{
+    RESPONSE_STATUS_CODE_200, RESPONSE_STATUS_CODE_206, RESPONSE_STATUS_CODE_304,
     RESPONSE_STATUS_CODE_308, RESPONSE_STATUS_CODE_404, RESPONSE_STATUS_CODE_406,
-    RESPONSE_STATUS_CODE_500,
+    RESPONSE_STATUS_CODE_416, RESPONSE_STATUS_CODE_500,
+};
+use crate::http::headers::{build_etag, build_headers, build_redirect_headers};
+use crate::http::range::{
+    content_range_header, get_partial_content, is_not_modified, requested_range,
+    unsatisfied_range_header, PartialContent, RequestedRange,
 };
-use crate::http::headers::build_redirect_headers;
 use crate::http::types::{HeaderField, HttpResponse, StatusCode};
 use crate::http::utils::{
-    build_encodings, build_response_headers, build_response_redirect_headers, streaming_strategy,
+    build_encodings, build_response_headers, build_response_redirect_headers,
+    build_response_skip_certification_headers, streaming_strategy,
 };
 use crate::strategies::{StorageCertificateStrategy, StorageStateStrategy};
 use crate::types::config::{StorageConfigIFrame, StorageConfigRedirect};
-use crate::types::store::Asset;
+use crate::types::http_request::AssetAccess;
+use crate::types::state::FullPath;
+use crate::types::store::{Asset, AssetEncoding, AssetKey};
 use junobuild_collections::types::rules::Memory;
+use junobuild_shared::types::core::Blob;
 
 #[allow(clippy::too_many_arguments)]
 pub fn build_asset_response(
     requested_url: String,
     requested_headers: Vec<HeaderField>,
+    access: &AssetAccess,
     certificate_version: Option<u16>,
     asset: Option<(Asset, Memory)>,
     rewrite_source: Option<String>,
+    certified_host: bool,
     status_code: StatusCode,
     storage_state: &impl StorageStateStrategy,
     certificate: &impl StorageCertificateStrategy,
 ) -> HttpResponse {
     match asset {
         Some((asset, memory)) => {
-            let encodings = build_encodings(requested_headers);
+            let encodings = build_encodings(&requested_headers);
 
             for encoding_type in encodings.iter() {
                 if let Some(encoding) = asset.encodings.get(encoding_type) {
@@ -36,6 +47,7 @@ pub fn build_asset_response(
                         encoding_type,
                         &certificate_version,
                         &rewrite_source,
+                        certified_host,
                         &storage_state.get_config(),
                         certificate.get_pruned_labeled_sigs_root_hash_tree(),
                     );
@@ -44,40 +56,29 @@ pub fn build_asset_response(
 
                     match headers {
                         Ok(headers) => {
-                            // Note: We need to return the body regardless if the requested method is GET or HEAD.
-                            // It seems that the Boundary Nodes are expecting a body for HEAD requests otherwise
-                            // some checks are failing on their side and requests end in 503.
-                            let body = storage_state.get_content_chunks(encoding, 0, &memory);
-
-                            // TODO: support for HTTP response 304
-                            // On hold til DFINITY foundation implements:
-                            // "Add etag support to icx-proxy" - https://dfinity.atlassian.net/browse/BOUN-446
-                            // See const STATUS_CODES_TO_CERTIFY: [u16; 2] = [200, 304]; in sdk certified asset canister for implementation reference
-
-                            match body {
-                                Some(body) => {
-                                    return HttpResponse {
-                                        body: body.clone(),
-                                        headers: headers.clone(),
-                                        status_code,
-                                        streaming_strategy: streaming_strategy(
-                                            key,
-                                            encoding,
-                                            encoding_type,
-                                            &headers,
-                                            &memory,
-                                        ),
-                                    }
-                                }
-                                None => {
-                                    return HttpResponse {
-                                        body: vec![],
-                                        headers: headers.clone(),
-                                        status_code,
-                                        streaming_strategy: None,
-                                    }
+                            if supports_conditional_response(&certificate_version, &rewrite_source)
+                            {
+                                if let Some(response) = build_conditional_response(
+                                    &requested_headers,
+                                    &headers,
+                                    encoding,
+                                    &memory,
+                                    storage_state,
+                                ) {
+                                    return response;
                                 }
                             }
+
+                            return build_content_response(
+                                key,
+                                encoding,
+                                encoding_type,
+                                headers,
+                                status_code,
+                                &memory,
+                                access,
+                                storage_state,
+                            );
                         }
                         Err(err) => {
                             return error_response(
@@ -98,6 +99,159 @@ pub fn build_asset_response(
     }
 }
 
+/// Builds the response of an asset served in an update call - e.g. with a rewrite of the root of a custom domain.
+/// The response of an update call goes through consensus and is not certified.
+pub fn build_asset_update_response(
+    requested_headers: Vec<HeaderField>,
+    access: &AssetAccess,
+    asset: Option<(Asset, Memory)>,
+    status_code: StatusCode,
+    storage_state: &impl StorageStateStrategy,
+) -> HttpResponse {
+    match asset {
+        Some((asset, memory)) => {
+            let config = storage_state.get_config();
+
+            for encoding_type in build_encodings(&requested_headers).iter() {
+                if let Some(encoding) = asset.encodings.get(encoding_type) {
+                    let headers = build_headers(&asset, encoding, encoding_type, &config);
+
+                    if let Some(response) = build_conditional_response(
+                        &requested_headers,
+                        &headers,
+                        encoding,
+                        &memory,
+                        storage_state,
+                    ) {
+                        return response;
+                    }
+
+                    return build_content_response(
+                        &asset.key,
+                        encoding,
+                        encoding_type,
+                        headers,
+                        status_code,
+                        &memory,
+                        access,
+                        storage_state,
+                    );
+                }
+            }
+
+            error_response(
+                RESPONSE_STATUS_CODE_500,
+                "No asset encoding found.".to_string(),
+            )
+        }
+        None => error_response(RESPONSE_STATUS_CODE_404, "No asset found.".to_string()),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn build_content_response(
+    key: &AssetKey,
+    encoding: &AssetEncoding,
+    encoding_type: &str,
+    headers: Vec<HeaderField>,
+    status_code: StatusCode,
+    memory: &Memory,
+    access: &AssetAccess,
+    storage_state: &impl StorageStateStrategy,
+) -> HttpResponse {
+    // Note: We need to return the body regardless if the requested method is GET or HEAD.
+    // It seems that the Boundary Nodes are expecting a body for HEAD requests otherwise
+    // some checks are failing on their side and requests end in 503.
+    let body = storage_state.get_content_chunks(encoding, 0, memory);
+
+    match body {
+        Some(body) => HttpResponse {
+            body: body.clone(),
+            streaming_strategy: streaming_strategy(
+                key,
+                encoding,
+                encoding_type,
+                &headers,
+                memory,
+                access,
+            ),
+            headers,
+            status_code,
+            upgrade: None,
+        },
+        None => HttpResponse {
+            body: vec![],
+            headers,
+            status_code,
+            streaming_strategy: None,
+            upgrade: None,
+        },
+    }
+}
+
+/// 304 and 416 responses are certified alongside the assets with the certification v2 only, and
+/// the asset certificate is the one of the original path, not of a rewrite. Partial content follows
+/// the same rule so that a client never receives a range of a response that cannot be verified.
+fn supports_conditional_response(
+    certificate_version: &Option<u16>,
+    rewrite_source: &Option<String>,
+) -> bool {
+    matches!(certificate_version, Some(2)) && rewrite_source.is_none()
+}
+
+/// Answers an If-None-Match with a 304 Not Modified and a Range with a 206 Partial Content - or a
+/// 416 Range Not Satisfiable. Returns None if the whole content should be served.
+fn build_conditional_response(
+    requested_headers: &[HeaderField],
+    headers: &[HeaderField],
+    encoding: &AssetEncoding,
+    memory: &Memory,
+    storage_state: &impl StorageStateStrategy,
+) -> Option<HttpResponse> {
+    let etag = build_etag(encoding);
+
+    if is_not_modified(requested_headers, &etag) {
+        return Some(HttpResponse {
+            body: vec![],
+            headers: headers.to_vec(),
+            status_code: RESPONSE_STATUS_CODE_304,
+            streaming_strategy: None,
+            upgrade: None,
+        });
+    }
+
+    match requested_range(requested_headers, &etag, encoding.total_length) {
+        RequestedRange::None => None,
+        RequestedRange::Unsatisfiable => Some(HttpResponse {
+            body: vec![],
+            headers: [
+                headers.to_vec(),
+                vec![unsatisfied_range_header(encoding.total_length)],
+            ]
+            .concat(),
+            status_code: RESPONSE_STATUS_CODE_416,
+            streaming_strategy: None,
+            upgrade: None,
+        }),
+        RequestedRange::Satisfiable(range) => {
+            let PartialContent { body, range } =
+                get_partial_content(encoding, &range, memory, storage_state)?;
+
+            Some(HttpResponse {
+                body,
+                headers: [
+                    headers.to_vec(),
+                    vec![content_range_header(&range, encoding.total_length)],
+                ]
+                .concat(),
+                status_code: RESPONSE_STATUS_CODE_206,
+                streaming_strategy: None,
+                upgrade: None,
+            })
+        }
+    }
+}
+
 pub fn build_redirect_response(
     requested_url: String,
     certificate_version: Option<u16>,
@@ -119,6 +273,7 @@ pub fn build_redirect_response(
         headers: headers.clone(),
         status_code: redirect.status_code,
         streaming_strategy: None,
+        upgrade: None,
     }
 }
 
@@ -133,6 +288,68 @@ pub fn build_redirect_raw_response(
         headers: headers.clone(),
         status_code: RESPONSE_STATUS_CODE_308,
         streaming_strategy: None,
+        upgrade: None,
+    }
+}
+
+/// Builds the response of a redirect served in an update call - e.g. a redirect whose location is
+/// resolved from the request, which cannot be certified ahead of the request.
+pub fn build_redirect_update_response(
+    redirect: &StorageConfigRedirect,
+    iframe: &StorageConfigIFrame,
+) -> HttpResponse {
+    let headers = build_redirect_headers(&redirect.location, iframe);
+
+    HttpResponse {
+        body: Vec::new(),
+        headers,
+        status_code: redirect.status_code,
+        streaming_strategy: None,
+        upgrade: None,
+    }
+}
+
+/// Builds the response of a custom HTTP endpoint served in a query. The response is computed when
+/// requested, the certificate only proves that its certification is skipped for the endpoint.
+pub fn build_endpoint_response(
+    requested_url: String,
+    certificate_version: Option<u16>,
+    endpoint: &FullPath,
+    status_code: StatusCode,
+    headers: Vec<HeaderField>,
+    body: Blob,
+    certificate: &impl StorageCertificateStrategy,
+) -> HttpResponse {
+    let certification_headers = build_response_skip_certification_headers(
+        &requested_url,
+        endpoint,
+        &certificate_version,
+        certificate.get_pruned_labeled_sigs_root_hash_tree(),
+    );
+
+    match certification_headers {
+        Ok(certification_headers) => HttpResponse {
+            body,
+            headers: [headers, certification_headers].concat(),
+            status_code,
+            streaming_strategy: None,
+            upgrade: None,
+        },
+        Err(err) => error_response(
+            RESPONSE_STATUS_CODE_500,
+            ["Cannot certify the response. ", err].join(""),
+        ),
+    }
+}
+
+/// Asks the HTTP gateway to replay the request as an update call - i.e. to `http_request_update`.
+pub fn build_upgrade_response() -> HttpResponse {
+    HttpResponse {
+        body: Vec::new(),
+        headers: Vec::new(),
+        status_code: RESPONSE_STATUS_CODE_200,
+        streaming_strategy: None,
+        upgrade: Some(true),
     }
 }
 
@@ -142,5 +359,6 @@ pub fn error_response(status_code: StatusCode, body: String) -> HttpResponse {
         headers: Vec::new(),
         status_code,
         streaming_strategy: None,
+        upgrade: None,
     }
 }
@@ -1,4 +1,4 @@
-use crate::types::state::{AssetAccessToken, FullPath};
+use crate::types::state::{AssetAccessToken, AssetUrlSignature, FullPath};
 use crate::types::store::EncodingType;
 use candid::{define_function, CandidType};
 use junobuild_collections::types::rules::Memory;
@@ -26,6 +26,7 @@ pub struct HttpResponse {
     pub headers: Vec<HeaderField>,
     pub status_code: StatusCode,
     pub streaming_strategy: Option<StreamingStrategy>,
+    pub upgrade: Option<bool>,
 }
 
 define_function!(pub CallbackFunc : () -> () query);
@@ -41,7 +42,10 @@ pub enum StreamingStrategy {
 #[derive(CandidType, Deserialize, Clone)]
 pub struct StreamingCallbackToken {
     pub full_path: FullPath,
+    // The token and the signed URL presented with the request, verified again for each chunk. Never the token of
+    // the asset, which is not disclosed to a client that requested it with a signed URL.
     pub token: AssetAccessToken,
+    pub signature: Option<AssetUrlSignature>,
     pub headers: Vec<HeaderField>,
     pub sha256: Option<ByteBuf>,
     pub index: usize,
@@ -1,10 +1,14 @@
-use crate::certification::cert::{build_asset_certificate_header, build_certified_expression};
+use crate::certification::cert::{
+    build_asset_certificate_header, build_certified_expression, build_skip_certification_expression,
+};
 use crate::constants::ASSET_ENCODING_NO_COMPRESSION;
 use crate::http::headers::{build_headers, build_redirect_headers};
 use crate::http::types::{CallbackFunc, HeaderField, StreamingCallbackToken, StreamingStrategy};
 use crate::memory::STATE;
 use crate::types::config::{StorageConfig, StorageConfigIFrame};
+use crate::types::http_request::AssetAccess;
 use crate::types::runtime_state::StorageRuntimeState;
+use crate::types::state::FullPath;
 use crate::types::store::{Asset, AssetEncoding, AssetKey, EncodingType};
 use ic_certification::HashTree;
 use junobuild_collections::types::rules::Memory;
@@ -17,9 +21,10 @@ pub fn streaming_strategy(
     encoding_type: &str,
     headers: &[HeaderField],
     memory: &Memory,
+    access: &AssetAccess,
 ) -> Option<StreamingStrategy> {
     let streaming_token: Option<StreamingCallbackToken> =
-        create_token(key, 0, encoding, encoding_type, headers, memory);
+        create_token(key, 0, encoding, encoding_type, headers, memory, access);
 
     streaming_token.map(|streaming_token| StreamingStrategy::Callback {
         callback: CallbackFunc::new(id(), "http_request_streaming_callback".to_string()),
@@ -34,6 +39,7 @@ pub fn create_token(
     encoding_type: &str,
     headers: &[HeaderField],
     memory: &Memory,
+    AssetAccess { token, signature }: &AssetAccess,
 ) -> Option<StreamingCallbackToken> {
     if chunk_index + 1 >= encoding.content_chunks.len() {
         return None;
@@ -41,7 +47,8 @@ pub fn create_token(
 
     Some(StreamingCallbackToken {
         full_path: key.full_path.clone(),
-        token: key.token.clone(),
+        token: token.clone(),
+        signature: signature.clone(),
         headers: headers.to_owned(),
         index: chunk_index + 1,
         sha256: Some(ByteBuf::from(encoding.sha256)),
@@ -58,6 +65,7 @@ pub fn build_response_headers(
     encoding_type: &EncodingType,
     certificate_version: &Option<u16>,
     rewrite_source: &Option<String>,
+    certified_host: bool,
     config: &StorageConfig,
     sigs_tree: HashTree,
 ) -> Result<Vec<HeaderField>, &'static str> {
@@ -68,6 +76,7 @@ pub fn build_response_headers(
         url,
         certificate_version,
         rewrite_source,
+        certified_host,
         sigs_tree,
     )
 }
@@ -81,7 +90,30 @@ pub fn build_response_redirect_headers(
 ) -> Result<Vec<HeaderField>, &'static str> {
     let asset_headers = build_redirect_headers(location, iframe);
 
-    extend_headers_with_certification(asset_headers, url, certificate_version, &None, sigs_tree)
+    extend_headers_with_certification(
+        asset_headers,
+        url,
+        certificate_version,
+        &None,
+        false,
+        sigs_tree,
+    )
+}
+
+// The response of an endpoint is not certified, the certificate proves that its certification is skipped.
+pub fn build_response_skip_certification_headers(
+    url: &str,
+    endpoint: &FullPath,
+    certificate_version: &Option<u16>,
+    sigs_tree: HashTree,
+) -> Result<Vec<HeaderField>, &'static str> {
+    let certified_header =
+        build_certified_headers(url, certificate_version, &Some(endpoint.clone()), sigs_tree)?;
+
+    Ok(vec![
+        certified_header,
+        build_skip_certification_expression(),
+    ])
 }
 
 fn extend_headers_with_certification(
@@ -89,11 +121,13 @@ fn extend_headers_with_certification(
     url: &str,
     certificate_version: &Option<u16>,
     rewrite_source: &Option<String>,
+    certified_host: bool,
     sigs_tree: HashTree,
 ) -> Result<Vec<HeaderField>, &'static str> {
     let certified_header =
         build_certified_headers(url, certificate_version, rewrite_source, sigs_tree)?;
-    let certified_expression = build_certified_expression(&asset_headers, certificate_version)?;
+    let certified_expression =
+        build_certified_expression(&asset_headers, certificate_version, certified_host)?;
 
     match certified_expression {
         None => Ok([asset_headers, vec![certified_header]].concat()),
@@ -136,7 +170,7 @@ fn build_certified_headers_impl(
     )
 }
 
-pub fn build_encodings(headers: Vec<HeaderField>) -> Vec<String> {
+pub fn build_encodings(headers: &[HeaderField]) -> Vec<String> {
     let mut encodings: Vec<String> = vec![];
     for HeaderField(name, value) in headers.iter() {
         if name.eq_ignore_ascii_case("Accept-Encoding") {
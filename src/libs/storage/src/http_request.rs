@@ -1,16 +1,20 @@
-use crate::constants::{RESPONSE_STATUS_CODE_200, RESPONSE_STATUS_CODE_405};
+use crate::constants::{RESPONSE_STATUS_CODE_200, RESPONSE_STATUS_CODE_405, ROOT_PATH};
 use crate::http::response::{
-    build_asset_response, build_redirect_raw_response, build_redirect_response, error_response,
+    build_asset_response, build_asset_update_response, build_redirect_raw_response,
+    build_redirect_response, build_redirect_update_response, build_upgrade_response,
+    error_response,
 };
 use crate::http::types::{
     HttpRequest, HttpResponse, StreamingCallbackHttpResponse, StreamingCallbackToken,
 };
 use crate::http::utils::create_token;
-use crate::routing::get_routing;
+use crate::routing::{get_domain_root_routing, get_routing};
+use crate::runtime::is_redirect_certified;
 use crate::strategies::{StorageCertificateStrategy, StorageStateStrategy};
 use crate::types::http_request::{
-    Routing, RoutingDefault, RoutingRedirect, RoutingRedirectRaw, RoutingRewrite,
+    AssetAccess, Routing, RoutingDefault, RoutingRedirect, RoutingRedirectRaw, RoutingRewrite,
 };
+use crate::url::{alternative_paths, map_asset_access};
 use ic_cdk::trap;
 
 // ---------------------------------------------------------
@@ -32,16 +36,32 @@ pub fn http_request(
         return error_response(RESPONSE_STATUS_CODE_405, "Method Not Allowed.".to_string());
     }
 
+    // The responses served from the root of a custom domain are certified for its Host with the certification v2 only
+    let domain_root = get_domain_root_routing(&url, &req_headers, storage_state);
+
+    if domain_root.is_some() && certificate_version != Some(2) {
+        return build_upgrade_response();
+    }
+
+    let certified_host = domain_root.is_some();
+
+    let access = map_asset_access(&url);
+
     let result = get_routing(url, &req_headers, true, storage_state);
 
     match result {
+        Ok(routing) if !is_domain_root_certified(&routing, &domain_root) => {
+            build_upgrade_response()
+        }
         Ok(routing) => match routing {
             Routing::Default(RoutingDefault { url, asset }) => build_asset_response(
                 url,
                 req_headers,
+                &access,
                 certificate_version,
                 asset,
                 None,
+                certified_host,
                 RESPONSE_STATUS_CODE_200,
                 storage_state,
                 certificate,
@@ -54,9 +74,11 @@ pub fn http_request(
             }) => build_asset_response(
                 url,
                 req_headers,
+                &access,
                 certificate_version,
                 asset,
                 Some(source),
+                certified_host,
                 status_code,
                 storage_state,
                 certificate,
@@ -66,6 +88,12 @@ pub fn http_request(
                 redirect,
                 iframe,
             }) => {
+                // A redirect whose location is resolved from the request is certified only for the paths
+                // redirected to an asset and the requests without a query string to preserve
+                if !is_redirect_certified(&url, &redirect, &iframe) {
+                    return build_upgrade_response();
+                }
+
                 build_redirect_response(url, certificate_version, &redirect, &iframe, certificate)
             }
             Routing::RedirectRaw(RoutingRedirectRaw {
@@ -80,9 +108,87 @@ pub fn http_request(
     }
 }
 
+/// Serves the requests upgraded to an update call by `http_request` - i.e. those served with the rewrites and
+/// redirects of the root of a custom domain, or without the certification v2, and the redirects whose resolved
+/// location is not certified. The response of an update call goes through consensus and does not need to be certified.
+pub fn http_request_update(
+    HttpRequest {
+        method,
+        url,
+        headers: req_headers,
+        body: _,
+        certificate_version: _,
+    }: HttpRequest,
+    storage_state: &impl StorageStateStrategy,
+) -> HttpResponse {
+    if method != "GET" && method != "HEAD" {
+        return error_response(RESPONSE_STATUS_CODE_405, "Method Not Allowed.".to_string());
+    }
+
+    let access = map_asset_access(&url);
+
+    let result = get_routing(url, &req_headers, true, storage_state);
+
+    match result {
+        Ok(routing) => match routing {
+            Routing::Default(RoutingDefault { asset, .. }) => build_asset_update_response(
+                req_headers,
+                &access,
+                asset,
+                RESPONSE_STATUS_CODE_200,
+                storage_state,
+            ),
+            Routing::Rewrite(RoutingRewrite {
+                asset, status_code, ..
+            }) => {
+                build_asset_update_response(req_headers, &access, asset, status_code, storage_state)
+            }
+            Routing::Redirect(RoutingRedirect {
+                redirect, iframe, ..
+            }) => build_redirect_update_response(&redirect, &iframe),
+            Routing::RedirectRaw(RoutingRedirectRaw {
+                redirect_url,
+                iframe,
+            }) => build_redirect_raw_response(&redirect_url, &iframe),
+        },
+        Err(err) => error_response(
+            RESPONSE_STATUS_CODE_405,
+            ["Permission denied. Cannot perform this operation. ", err].join(""),
+        ),
+    }
+}
+
+// The assets of the root of a custom domain are certified on their paths in the root - e.g. /about for
+// /tenant-a/about.html - and its /index.html or /404.html as the rewrite of the unknown paths. The
+// rewrites and redirects configured for the root cannot be certified for the Host of the domain.
+fn is_domain_root_certified(routing: &Routing, domain_root: &Option<String>) -> bool {
+    let Some(prefix) = domain_root else {
+        return true;
+    };
+
+    match routing {
+        Routing::Default(RoutingDefault {
+            url,
+            asset: Some((asset, _)),
+        }) => asset
+            .key
+            .full_path
+            .strip_prefix(prefix.as_str())
+            .is_some_and(|path| {
+                path == url
+                    || alternative_paths(&path.to_string()).is_some_and(|paths| paths.contains(url))
+            }),
+        Routing::Default(RoutingDefault { asset: None, .. }) => true,
+        Routing::Rewrite(RoutingRewrite { source, .. }) => source == ROOT_PATH,
+        Routing::Redirect(_) => false,
+        Routing::RedirectRaw(_) => true,
+    }
+}
+
 pub fn http_request_streaming_callback(
     StreamingCallbackToken {
         token,
+        signature,
         headers,
         index,
         sha256: _,
@@ -92,7 +198,12 @@ pub fn http_request_streaming_callback(
     }: StreamingCallbackToken,
     storage_state: &impl StorageStateStrategy,
 ) -> StreamingCallbackHttpResponse {
-    let asset = storage_state.get_public_asset(full_path, token);
+    // Each chunk is served with the credentials of the request - i.e. a signed URL that expired
+    // while streaming stops the stream
+    let access = AssetAccess { token, signature };
+
+    let asset =
+        storage_state.get_public_asset(full_path, access.token.clone(), access.signature.clone());
 
     match asset {
         Some((asset, memory)) => {
@@ -111,6 +222,7 @@ pub fn http_request_streaming_callback(
                                 &encoding_type,
                                 &headers,
                                 &memory,
+                                &access,
                             ),
                             body: body.clone(),
                         },
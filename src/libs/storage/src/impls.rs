@@ -1,24 +1,24 @@
 use crate::http::types::HeaderField;
 use crate::types::config::{
-    StorageConfig, StorageConfigHeaders, StorageConfigIFrame, StorageConfigRawAccess,
-    StorageConfigRedirects, StorageConfigRewrites,
+    StorageConfig, StorageConfigDomainRoot, StorageConfigDomainRoots, StorageConfigHeaders,
+    StorageConfigIFrame, StorageConfigRawAccess, StorageConfigRedirects, StorageConfigRewrites,
 };
 use crate::types::interface::{AssetEncodingNoContent, AssetNoContent, SetStorageConfig};
 use crate::types::state::{AssetAccessToken, StorageHeapState};
-use crate::types::store::{Asset, AssetEncoding, AssetKey, Batch, BatchExpiry};
-use ic_cdk::api::time;
+use crate::types::store::{Asset, AssetEncoding, AssetKey, Batch, BatchExpiry, Chunk};
 use ic_stable_structures::storable::Bound;
 use ic_stable_structures::Storable;
 use junobuild_collections::constants::assets::DEFAULT_ASSETS_COLLECTIONS;
 use junobuild_collections::types::interface::SetRule;
 use junobuild_collections::types::rules::{Memory, Rule, Rules};
 use junobuild_shared::data::version::{next_version, next_version_from};
+use junobuild_shared::ic::api::time;
 use junobuild_shared::memory::serializers::{
     deserialize_from_bytes, serialize_into_bytes, serialize_to_bytes,
 };
 use junobuild_shared::types::core::{Blob, Hash, Hashable};
 use junobuild_shared::types::state::Timestamped;
-use junobuild_shared::types::state::{Timestamp, Version, Versioned};
+use junobuild_shared::types::state::{Expirable, Timestamp, Version, Versioned};
 use sha2::{Digest, Sha256};
 use std::borrow::Cow;
 use std::cmp::Ordering;
@@ -49,10 +49,17 @@ impl StorageHeapState {
                             max_size: rule.max_size,
                             max_capacity: rule.max_capacity,
                             max_changes_per_user: rule.max_changes_per_user,
+                            max_bytes_per_user: rule.max_bytes_per_user,
                             created_at: now,
                             updated_at: now,
                             version: rule.version,
                             rate_config: rule.rate_config,
+                            indexes: rule.indexes,
+                            history: rule.history,
+                            ttl: rule.ttl,
+                            schema: rule.schema,
+                            variants: rule.variants,
+                            content_validation: rule.content_validation,
                         },
                     )
                 })
@@ -68,8 +75,11 @@ impl StorageHeapState {
                 // For backwards compatibility start with None
                 version: None,
                 max_memory_size: None,
+                compression: None,
+                domain_roots: None,
             },
             custom_domains: HashMap::new(),
+            signing_key: None,
         }
     }
 }
@@ -93,6 +103,7 @@ impl From<&Vec<Blob>> for AssetEncoding {
             content_chunks: content_chunks.clone(),
             total_length,
             sha256,
+            generated: false,
         }
     }
 }
@@ -111,6 +122,22 @@ impl StorageConfig {
             .clone()
             .unwrap_or(StorageConfigRawAccess::Deny)
     }
+
+    pub fn unwrap_domain_roots(&self) -> StorageConfigDomainRoots {
+        self.domain_roots.clone().unwrap_or_default()
+    }
+}
+
+impl StorageConfigDomainRoot {
+    /// The path prefix of the root, without trailing slash - e.g. `/tenant-a`.
+    pub fn to_path_prefix(&self) -> String {
+        let path = match self {
+            StorageConfigDomainRoot::Path(path) => path.clone(),
+            StorageConfigDomainRoot::Collection(collection) => format!("/{collection}"),
+        };
+
+        format!("/{}", path.trim_matches('/'))
+    }
 }
 
 impl Timestamped for AssetNoContent {
@@ -174,6 +201,38 @@ impl Storable for Asset {
     const BOUND: Bound = Bound::Unbounded;
 }
 
+impl Storable for Batch {
+    fn to_bytes(&self) -> Cow<'_, [u8]> {
+        serialize_to_bytes(self)
+    }
+
+    fn into_bytes(self) -> Vec<u8> {
+        serialize_into_bytes(&self)
+    }
+
+    fn from_bytes(bytes: Cow<[u8]>) -> Self {
+        deserialize_from_bytes(bytes)
+    }
+
+    const BOUND: Bound = Bound::Unbounded;
+}
+
+impl Storable for Chunk {
+    fn to_bytes(&self) -> Cow<'_, [u8]> {
+        serialize_to_bytes(self)
+    }
+
+    fn into_bytes(self) -> Vec<u8> {
+        serialize_into_bytes(&self)
+    }
+
+    fn from_bytes(bytes: Cow<[u8]>) -> Self {
+        deserialize_from_bytes(bytes)
+    }
+
+    const BOUND: Bound = Bound::Unbounded;
+}
+
 impl Timestamped for Asset {
     fn created_at(&self) -> Timestamp {
         self.created_at
@@ -224,6 +283,15 @@ impl Asset {
         }
     }
 
+    /// The size in bytes of the content of the asset, all encodings included.
+    pub fn size(&self) -> u64 {
+        let total_length = self.encodings.values().fold(0u128, |size, encoding| {
+            size.saturating_add(encoding.total_length)
+        });
+
+        u64::try_from(total_length).unwrap_or(u64::MAX)
+    }
+
     pub fn update_token(current_asset: &Asset, token: &AssetAccessToken) -> Self {
         let now = time();
 
@@ -253,6 +321,12 @@ impl Versioned for &Asset {
     }
 }
 
+impl Expirable for Asset {
+    fn expires_at(&self) -> Option<Timestamp> {
+        self.key.expires_at
+    }
+}
+
 impl BatchExpiry for Batch {
     fn expires_at(&self) -> Timestamp {
         self.expires_at
@@ -272,6 +346,9 @@ impl Hashable for AssetKey {
         if let Some(description) = &self.description {
             hasher.update(description.as_bytes());
         }
+        if let Some(expires_at) = self.expires_at {
+            hasher.update(expires_at.to_le_bytes());
+        }
         hasher.finalize().into()
     }
 }
@@ -320,6 +397,8 @@ impl StorageConfig {
             iframe: user_config.iframe.clone(),
             raw_access: user_config.raw_access.clone(),
             max_memory_size: user_config.max_memory_size.clone(),
+            compression: user_config.compression.clone(),
+            domain_roots: user_config.domain_roots.clone(),
             created_at: Some(created_at),
             updated_at: Some(updated_at),
             version: Some(version),
@@ -1,7 +1,9 @@
 mod assert;
 pub mod certification;
 pub mod certified_assets;
+mod compression;
 pub mod constants;
+mod domains;
 pub mod errors;
 pub mod heap_utils;
 pub mod http;
@@ -11,6 +13,7 @@ mod memory;
 pub mod rewrites;
 pub mod routing;
 pub mod runtime;
+pub mod signatures;
 pub mod stable_utils;
 pub mod store;
 pub mod strategies;
@@ -1,39 +1,184 @@
 use crate::constants::ROOT_PATHS;
 use crate::types::config::{StorageConfig, StorageConfigRedirect};
-use crate::url::{matching_urls as matching_urls_utils, separator};
+use crate::types::state::FullPath;
+use crate::url::{alternative_paths, matches_url, separator};
 use std::cmp::Ordering;
 use std::collections::HashMap;
 
-pub fn rewrite_url(requested_path: &str, config: &StorageConfig) -> Option<(String, String)> {
+/// The segments captured by the source of a rewrite or redirect - e.g. `slug` for `/blog/:slug` - and
+/// the rest of the path matched by a trailing wildcard, captured as `splat`.
+type Params = HashMap<String, String>;
+
+const PARAM_PREFIX: char = ':';
+const SPLAT_PARAM: &str = "splat";
+
+/// Returns the rewrite of the requested path as a tuple of the source - used to certify the rewrite with
+/// a wildcard - and the destination.
+///
+/// The source is `None` if the destination references captured parameters. Such a rewrite resolves to
+/// a different asset for each path and is therefore certified on the exact requested path.
+pub fn rewrite_url(
+    requested_path: &str,
+    config: &StorageConfig,
+) -> Option<(Option<String>, String)> {
     let StorageConfig { rewrites, .. } = config;
 
     let matches = matching_urls(requested_path, rewrites);
 
-    matches
-        .first()
-        .map(|(source, destination)| (rewrite_source_to_path(source).clone(), destination.clone()))
+    matches.first().map(|(source, destination, params)| {
+        if is_parameterized_target(source, destination) {
+            return (None, substitute_params(destination, params));
+        }
+
+        (Some(rewrite_source_to_path(source)), destination.clone())
+    })
 }
 
 pub fn rewrite_source_to_path(source: &str) -> String {
-    [separator(source), source].join("").replace('*', "")
+    if !is_parameterized(source) {
+        return [separator(source), source].join("").replace('*', "");
+    }
+
+    // The static part of the source e.g. /blog/:slug -> /blog/
+    let segments: Vec<&str> = source
+        .trim_start_matches('/')
+        .split('/')
+        .take_while(|segment| !is_param_segment(segment) && !segment.contains('*'))
+        .collect();
+
+    format!("/{}/", segments.join("/")).replace("//", "/")
 }
 
 pub fn is_root_path(path: &str) -> bool {
     ROOT_PATHS.contains(&path)
 }
 
-pub fn redirect_url(requested_path: &str, config: &StorageConfig) -> Option<StorageConfigRedirect> {
+/// Returns the redirect of the requested path with its location resolved from the captured parameters
+/// and, if the redirect preserves it, the query string.
+pub fn redirect_url(
+    requested_path: &str,
+    query: &Option<String>,
+    config: &StorageConfig,
+) -> Option<StorageConfigRedirect> {
     let redirects = config.unwrap_redirects();
 
     let matches = matching_urls(requested_path, &redirects);
 
-    matches.first().map(|(_, redirect)| redirect.clone())
+    matches.first().map(|(_, redirect, params)| {
+        let location = substitute_params(&redirect.location, params);
+
+        let location = match (redirect.preserve_query.unwrap_or(false), query) {
+            (true, Some(query)) if !query.is_empty() => {
+                let separator = if location.contains('?') { '&' } else { '?' };
+                format!("{location}{separator}{query}")
+            }
+            _ => location,
+        };
+
+        StorageConfigRedirect {
+            location,
+            ..redirect.clone()
+        }
+    })
 }
 
-fn matching_urls<T: Clone>(requested_path: &str, config: &HashMap<String, T>) -> Vec<(String, T)> {
-    let mut matches: Vec<(String, T)> = matching_urls_utils(requested_path, config);
+/// A redirect whose location references captured parameters - e.g. `/blog/:slug -> /posts/:slug`.
+pub fn is_parameterized_redirect(source: &str, redirect: &StorageConfigRedirect) -> bool {
+    is_parameterized_target(source, &redirect.location)
+}
+
+/// A rewrite whose destination references captured parameters - e.g. `/blog/:slug -> /posts/:slug`.
+pub fn is_parameterized_rewrite(source: &str, destination: &str) -> bool {
+    is_parameterized_target(source, destination)
+}
 
-    matches.sort_by(|(a, _), (b, _)| {
+/// Returns the paths that are rewritten to an asset by the rewrites whose destination references
+/// captured parameters - e.g. `/blog/hello` for `/posts/hello.html` and `/blog/:slug -> /posts/:slug`.
+pub fn parameterized_rewrite_paths(full_path: &FullPath, config: &StorageConfig) -> Vec<String> {
+    let candidates = [
+        vec![full_path.clone()],
+        alternative_paths(full_path).unwrap_or_default(),
+    ]
+    .concat();
+
+    let mut paths: Vec<String> = config
+        .rewrites
+        .iter()
+        .filter(|(source, destination)| is_parameterized_target(source, destination))
+        .flat_map(|(source, destination)| {
+            candidates
+                .iter()
+                .filter_map(|candidate| match_params(destination, candidate))
+                .filter_map(|params| resolve_source(source, &params))
+                .collect::<Vec<String>>()
+        })
+        .collect();
+
+    paths.sort();
+    paths.dedup();
+
+    paths
+}
+
+/// Returns the paths that are redirected to an asset by the redirects whose location references
+/// captured parameters, with their resolved redirect - e.g. `/blog/hello` redirected to `/posts/hello`
+/// for `/posts/hello.html` and `/blog/:slug -> /posts/:slug`.
+///
+/// Only the locations on the same origin can be resolved from the paths of an asset.
+pub fn parameterized_redirect_paths(
+    full_path: &FullPath,
+    config: &StorageConfig,
+) -> Vec<(String, StorageConfigRedirect)> {
+    let candidates = [
+        vec![full_path.clone()],
+        alternative_paths(full_path).unwrap_or_default(),
+    ]
+    .concat();
+
+    let mut paths: Vec<(String, StorageConfigRedirect)> = config
+        .unwrap_redirects()
+        .into_iter()
+        .filter(|(source, redirect)| {
+            redirect.location.starts_with('/') && is_parameterized_redirect(source, redirect)
+        })
+        .flat_map(|(source, redirect)| {
+            candidates
+                .iter()
+                .filter_map(|candidate| match_params(&redirect.location, candidate))
+                .filter_map(|params| {
+                    resolve_source(&source, &params).map(|path| {
+                        (
+                            path,
+                            StorageConfigRedirect {
+                                location: substitute_params(&redirect.location, &params),
+                                ..redirect.clone()
+                            },
+                        )
+                    })
+                })
+                .collect::<Vec<(String, StorageConfigRedirect)>>()
+        })
+        .collect();
+
+    paths.sort_by(|(a, _), (b, _)| a.cmp(b));
+    paths.dedup_by(|(a, _), (b, _)| a == b);
+
+    paths
+}
+
+fn matching_urls<T: Clone>(
+    requested_path: &str,
+    config: &HashMap<String, T>,
+) -> Vec<(String, T, Params)> {
+    let mut matches: Vec<(String, T, Params)> = config
+        .iter()
+        .filter_map(|(source, target)| {
+            capture_params(source, requested_path)
+                .map(|params| (source.clone(), target.clone(), params))
+        })
+        .collect();
+
+    matches.sort_by(|(a, _, _), (b, _, _)| {
         let a_parts: Vec<&str> = a.split('/').collect();
         let b_parts: Vec<&str> = b.split('/').collect();
 
@@ -50,3 +195,383 @@ fn matching_urls<T: Clone>(requested_path: &str, config: &HashMap<String, T>) ->
 
     matches
 }
+
+// ---------------------------------------------------------
+// Params
+// ---------------------------------------------------------
+
+fn is_param_segment(segment: &str) -> bool {
+    segment.len() > 1
+        && segment.starts_with(PARAM_PREFIX)
+        && segment[1..]
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Splits a segment that starts with a parameter into its name and the literal suffix that follows
+/// it - e.g. `slug` and `.html` for `:slug.html`.
+fn split_param_segment(segment: &str) -> Option<(&str, &str)> {
+    let reference = segment.strip_prefix(PARAM_PREFIX)?;
+
+    let length = reference
+        .find(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+        .unwrap_or(reference.len());
+
+    match length {
+        0 => None,
+        _ => Some(reference.split_at(length)),
+    }
+}
+
+fn is_splat_segment(segment: &str) -> bool {
+    segment == "*" || segment == "**"
+}
+
+fn is_parameterized(source: &str) -> bool {
+    source.split('/').any(is_param_segment)
+}
+
+fn param_names(source: &str) -> Vec<String> {
+    let mut names: Vec<String> = source
+        .split('/')
+        .filter(|segment| is_param_segment(segment))
+        .map(|segment| segment[1..].to_string())
+        .collect();
+
+    if source.ends_with('*') {
+        names.push(SPLAT_PARAM.to_string());
+    }
+
+    names
+}
+
+fn is_parameterized_target(source: &str, target: &str) -> bool {
+    param_names(source)
+        .iter()
+        .any(|name| !find_param(target, name).is_empty())
+}
+
+/// Matches the requested path against the source and captures its parameters.
+///
+/// Sources without parameters are matched as glob patterns, as they always were. Sources with
+/// parameters are matched segment by segment: a `:name` segment matches any non-empty segment and a
+/// trailing `*` matches the rest of the path.
+fn capture_params(source: &str, requested_path: &str) -> Option<Params> {
+    if !is_parameterized(source) {
+        if !matches_url(requested_path, source) {
+            return None;
+        }
+
+        let mut params = Params::new();
+
+        if source.ends_with('*') {
+            let prefix = [separator(source), source.trim_end_matches('*')].join("");
+
+            if let Some(splat) = requested_path.strip_prefix(&prefix) {
+                params.insert(SPLAT_PARAM.to_string(), splat.to_string());
+            }
+        }
+
+        return Some(params);
+    }
+
+    match_params(source, requested_path)
+}
+
+/// Matches a path against a pattern whose parameters start a segment - e.g. `/blog/:slug`,
+/// `/posts/:slug.html` or `/docs/:splat` - and returns the captured values.
+fn match_params(pattern: &str, path: &str) -> Option<Params> {
+    let pattern_segments: Vec<&str> = pattern.trim_start_matches('/').split('/').collect();
+    let path_segments: Vec<&str> = path.trim_start_matches('/').split('/').collect();
+
+    let mut params = Params::new();
+
+    for (index, segment) in pattern_segments.iter().enumerate() {
+        let is_last = index == pattern_segments.len() - 1;
+
+        if is_last && (is_splat_segment(segment) || *segment == ":splat") {
+            let rest = path_segments.get(index..).unwrap_or_default().join("/");
+            params.insert(SPLAT_PARAM.to_string(), rest);
+            return Some(params);
+        }
+
+        let value = path_segments.get(index)?;
+
+        match split_param_segment(segment) {
+            Some((name, suffix)) => {
+                let value = value
+                    .strip_suffix(suffix)
+                    .filter(|value| !value.is_empty())?;
+
+                params.insert(name.to_string(), value.to_string());
+            }
+            None if segment != value => return None,
+            None => (),
+        }
+    }
+
+    if path_segments.len() != pattern_segments.len() {
+        return None;
+    }
+
+    Some(params)
+}
+
+/// Returns the path of a source with its parameters replaced by captured values, or `None` if the
+/// source contains a pattern that cannot be resolved.
+fn resolve_source(source: &str, params: &Params) -> Option<String> {
+    let segments: Vec<&str> = source.trim_start_matches('/').split('/').collect();
+    let last = segments.len() - 1;
+
+    let resolved: Option<Vec<String>> = segments
+        .iter()
+        .enumerate()
+        .map(|(index, segment)| {
+            if is_param_segment(segment) {
+                return params.get(&segment[1..]).cloned();
+            }
+
+            if index == last && is_splat_segment(segment) {
+                return params.get(SPLAT_PARAM).cloned();
+            }
+
+            match segment.contains(['*', '?', '[', '{']) {
+                true => None,
+                false => Some(segment.to_string()),
+            }
+        })
+        .collect();
+
+    resolved.map(|segments| format!("/{}", segments.join("/")))
+}
+
+/// Replaces the `:name` references of a redirect location or a rewrite destination with the captured
+/// values. References to parameters that were not captured - e.g. a port - are left as is.
+fn substitute_params(target: &str, params: &Params) -> String {
+    let mut names: Vec<&String> = params.keys().collect();
+
+    // Longest names first so that :id does not replace the beginning of :identifier
+    names.sort_by_key(|name| std::cmp::Reverse(name.len()));
+
+    names.iter().fold(target.to_string(), |target, name| {
+        let value = &params[*name];
+
+        find_param(&target, name)
+            .iter()
+            .rev()
+            .fold(target.clone(), |mut target, index| {
+                target.replace_range(*index..*index + name.len() + 1, value);
+                target
+            })
+    })
+}
+
+/// Returns the positions of the references to a parameter in a target. A reference is a `:name` not
+/// followed by another character of a name.
+fn find_param(target: &str, name: &str) -> Vec<usize> {
+    let reference = format!("{PARAM_PREFIX}{name}");
+
+    target
+        .match_indices(&reference)
+        .filter(|(index, _)| {
+            target[index + reference.len()..]
+                .chars()
+                .next()
+                .is_none_or(|c| !c.is_ascii_alphanumeric() && c != '_')
+        })
+        .map(|(index, _)| index)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn params(entries: &[(&str, &str)]) -> Params {
+        entries
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect()
+    }
+
+    fn redirect(location: &str, preserve_query: Option<bool>) -> StorageConfigRedirect {
+        StorageConfigRedirect {
+            location: location.to_string(),
+            status_code: 301,
+            preserve_query,
+        }
+    }
+
+    #[test]
+    fn captures_named_params() {
+        assert_eq!(
+            capture_params("/blog/:slug", "/blog/hello"),
+            Some(params(&[("slug", "hello")]))
+        );
+        assert_eq!(
+            capture_params("/:lang/docs/:page", "/en/docs/intro"),
+            Some(params(&[("lang", "en"), ("page", "intro")]))
+        );
+
+        assert_eq!(capture_params("/blog/:slug", "/blog/"), None);
+        assert_eq!(capture_params("/blog/:slug", "/blog/hello/world"), None);
+        assert_eq!(capture_params("/blog/:slug", "/posts/hello"), None);
+    }
+
+    #[test]
+    fn captures_splat_of_wildcards() {
+        assert_eq!(
+            capture_params("/docs/**", "/docs/guides/intro"),
+            Some(params(&[("splat", "guides/intro")]))
+        );
+        assert_eq!(
+            capture_params("/blog/:year/*", "/blog/2024/hello/world"),
+            Some(params(&[("year", "2024"), ("splat", "hello/world")]))
+        );
+        assert_eq!(capture_params("/about", "/about"), Some(Params::new()));
+        assert_eq!(capture_params("/about", "/contact"), None);
+    }
+
+    #[test]
+    fn matches_params_segment_by_segment() {
+        assert_eq!(
+            match_params("/posts/:slug.html", "/posts/hello.html"),
+            Some(params(&[("slug", "hello")]))
+        );
+        assert_eq!(match_params("/posts/:slug.html", "/posts/.html"), None);
+        assert_eq!(match_params("/posts/:slug.html", "/posts/hello.md"), None);
+        assert_eq!(
+            match_params("/posts/:slug", "/posts/hello.html"),
+            Some(params(&[("slug", "hello.html")]))
+        );
+        assert_eq!(
+            match_params("/docs/:splat", "/docs/a/b/c"),
+            Some(params(&[("splat", "a/b/c")]))
+        );
+        assert_eq!(match_params("/posts/:slug", "/posts"), None);
+    }
+
+    #[test]
+    fn substitutes_params() {
+        let captured = params(&[("id", "1"), ("identifier", "abc"), ("splat", "a/b")]);
+
+        assert_eq!(
+            substitute_params("/users/:identifier/:id", &captured),
+            "/users/abc/1"
+        );
+        assert_eq!(substitute_params("/files/:splat", &captured), "/files/a/b");
+        assert_eq!(
+            substitute_params("http://localhost:5987/:id", &captured),
+            "http://localhost:5987/1"
+        );
+        assert_eq!(substitute_params("/static", &captured), "/static");
+    }
+
+    #[test]
+    fn resolves_sources() {
+        let captured = params(&[("slug", "hello"), ("splat", "a/b")]);
+
+        assert_eq!(
+            resolve_source("/blog/:slug", &captured),
+            Some("/blog/hello".to_string())
+        );
+        assert_eq!(
+            resolve_source("/docs/**", &captured),
+            Some("/docs/a/b".to_string())
+        );
+        assert_eq!(resolve_source("/blog/:missing", &captured), None);
+        assert_eq!(resolve_source("/blog/*.html/:slug", &captured), None);
+    }
+
+    #[test]
+    fn rewrites_parameterized_destinations_on_the_requested_path() {
+        let config = StorageConfig {
+            rewrites: HashMap::from([
+                ("/blog/:slug".to_string(), "/posts/:slug.html".to_string()),
+                ("/app/**".to_string(), "/app/index.html".to_string()),
+            ]),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            rewrite_url("/blog/hello", &config),
+            Some((None, "/posts/hello.html".to_string()))
+        );
+        assert_eq!(
+            rewrite_url("/app/settings", &config),
+            Some((Some("/app/".to_string()), "/app/index.html".to_string()))
+        );
+        assert_eq!(rewrite_url("/other", &config), None);
+    }
+
+    #[test]
+    fn resolves_redirect_locations() {
+        let config = StorageConfig {
+            redirects: Some(HashMap::from([
+                ("/blog/:slug".to_string(), redirect("/posts/:slug", None)),
+                ("/old".to_string(), redirect("/new", Some(true))),
+                ("/about".to_string(), redirect("/team", None)),
+            ])),
+            ..Default::default()
+        };
+
+        let location = |path: &str, query: Option<&str>| {
+            redirect_url(path, &query.map(str::to_string), &config)
+                .map(|redirect| redirect.location)
+        };
+
+        assert_eq!(
+            location("/blog/hello", None),
+            Some("/posts/hello".to_string())
+        );
+        assert_eq!(location("/old", Some("a=1")), Some("/new?a=1".to_string()));
+        assert_eq!(location("/old", None), Some("/new".to_string()));
+        assert_eq!(location("/about", Some("a=1")), Some("/team".to_string()));
+    }
+
+    #[test]
+    fn finds_paths_redirected_to_an_asset() {
+        let config = StorageConfig {
+            redirects: Some(HashMap::from([
+                ("/blog/:slug".to_string(), redirect("/posts/:slug", None)),
+                (
+                    "/old/:slug".to_string(),
+                    redirect("https://example.com/:slug", None),
+                ),
+                ("/about".to_string(), redirect("/team", None)),
+            ])),
+            ..Default::default()
+        };
+
+        let paths: Vec<(String, String)> =
+            parameterized_redirect_paths(&"/posts/hello.html".to_string(), &config)
+                .into_iter()
+                .map(|(path, redirect)| (path, redirect.location))
+                .collect();
+
+        assert_eq!(
+            paths,
+            vec![
+                ("/blog/hello".to_string(), "/posts/hello".to_string()),
+                (
+                    "/blog/hello.html".to_string(),
+                    "/posts/hello.html".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn finds_paths_rewritten_to_an_asset() {
+        let config = StorageConfig {
+            rewrites: HashMap::from([("/blog/:slug".to_string(), "/posts/:slug.html".to_string())]),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            parameterized_rewrite_paths(&"/posts/hello.html".to_string(), &config),
+            vec!["/blog/hello".to_string()]
+        );
+        assert!(parameterized_rewrite_paths(&"/posts/hello.md".to_string(), &config).is_empty());
+    }
+}
@@ -1,20 +1,34 @@
 use crate::constants::{
     RAW_DOMAINS, RESPONSE_STATUS_CODE_200, RESPONSE_STATUS_CODE_404, ROOT_404_HTML,
-    ROOT_INDEX_HTML, ROOT_PATH,
+    ROOT_INDEX_HTML, ROOT_PATH, WELL_KNOWN_PATH,
 };
+use crate::domains::{domain_config, domain_root};
 use crate::http::types::HeaderField;
 use crate::rewrites::{is_root_path, redirect_url, rewrite_url};
 use crate::strategies::StorageStateStrategy;
-use crate::types::config::StorageConfigRawAccess;
+use crate::types::config::{StorageConfig, StorageConfigRawAccess};
 use crate::types::http_request::{
     MapUrl, Routing, RoutingDefault, RoutingRedirect, RoutingRedirectRaw, RoutingRewrite,
 };
-use crate::types::state::FullPath;
+use crate::types::state::{AssetUrlSignature, FullPath};
 use crate::types::store::Asset;
 use crate::url::{map_alternative_paths, map_url};
 use junobuild_collections::types::rules::Memory;
 use junobuild_shared::ic::api::id;
 
+/// Returns the custom HTTP endpoint - i.e. its path prefix - that serves the requested url, if any.
+pub fn get_http_endpoint(url: &str, storage_state: &impl StorageStateStrategy) -> Option<FullPath> {
+    let MapUrl { path, .. } = map_url(url).ok()?;
+
+    storage_state
+        .get_http_endpoints()
+        .into_iter()
+        .find(|endpoint| {
+            path.strip_prefix(endpoint.as_str())
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+        })
+}
+
 pub fn get_routing(
     url: String,
     req_headers: &[HeaderField],
@@ -41,11 +55,26 @@ pub fn get_routing(
     // url: /hello/something?param=123
     // path: /hello/something
 
-    let MapUrl { path, token } = map_url(&url)?;
+    let MapUrl {
+        path,
+        token,
+        signature,
+        query,
+    } = map_url(&url)?;
+
+    // A custom domain can be served from a root e.g. tenant-a.com/about -> /tenant-a/about
+    // The memory contains the path of the asset in the root. Such responses are certified for the Host of the domain.
+    let config = storage_state.get_config();
+    let root = get_routing_root(&path, req_headers, &config);
+
+    let asset_path = match &root {
+        None => path.clone(),
+        Some(prefix) => format!("{prefix}{path}"),
+    };
 
     // We return the asset that matches the effective path
     let asset: Option<(Asset, Memory)> =
-        storage_state.get_public_asset(path.clone(), token.clone());
+        storage_state.get_public_asset(asset_path.clone(), token.clone(), signature.clone());
 
     match asset {
         None => (),
@@ -57,7 +86,7 @@ pub fn get_routing(
     // ⚠️ Limitation: requesting an url without extension try to resolve first a corresponding asset
     // e.g. /.well-known/hello -> try to find /.well-known/hello.html
     // Therefore if a file without extension is uploaded to the storage, it is important to not upload an .html file with the same name next to it or a folder/index.html
-    let alternative_asset = get_alternative_asset(&path, &token, storage_state);
+    let alternative_asset = get_alternative_asset(&asset_path, &token, &signature, storage_state);
     match alternative_asset {
         None => (),
         Some(alternative_asset) => {
@@ -69,8 +98,16 @@ pub fn get_routing(
     }
 
     if include_alternative_routing {
+        // Each custom domain with a root is served with the rewrites and redirects of its root only
+        let config = match &root {
+            None => config,
+            Some(prefix) => domain_config(&config, prefix),
+        };
+
+        let prefix = root.unwrap_or_default();
+
         // Search for potential redirect
-        let redirect = get_routing_redirect(&path, storage_state);
+        let redirect = get_routing_redirect(&path, &asset_path, &query, &config);
 
         match redirect {
             None => (),
@@ -80,7 +117,14 @@ pub fn get_routing(
         }
 
         // Search for potential rewrite
-        let rewrite = get_routing_rewrite(&path, &token, storage_state);
+        let rewrite = get_routing_rewrite(
+            &path,
+            &asset_path,
+            &token,
+            &signature,
+            &config,
+            storage_state,
+        );
 
         match rewrite {
             None => (),
@@ -90,7 +134,7 @@ pub fn get_routing(
         }
 
         // Search for potential default rewrite for HTML pages
-        let root_rewrite = get_routing_root_rewrite(&path, storage_state);
+        let root_rewrite = get_routing_root_rewrite(&path, &prefix, storage_state);
 
         match root_rewrite {
             None => (),
@@ -106,16 +150,42 @@ pub fn get_routing(
     }))
 }
 
+/// Returns the root of the custom domain - i.e. its path prefix - that serves the request, if any - e.g.
+/// /tenant-a for tenant-a.com/about.
+pub fn get_domain_root_routing(
+    url: &str,
+    req_headers: &[HeaderField],
+    storage_state: &impl StorageStateStrategy,
+) -> Option<String> {
+    let MapUrl { path, .. } = map_url(url).ok()?;
+
+    get_routing_root(&path, req_headers, &storage_state.get_config())
+}
+
+fn get_routing_root(
+    path: &FullPath,
+    req_headers: &[HeaderField],
+    config: &StorageConfig,
+) -> Option<String> {
+    // The well-known files, such as the custom domains, are served from the root of the satellite
+    if path.starts_with(WELL_KNOWN_PATH) {
+        return None;
+    }
+
+    domain_root(req_headers, config)
+}
+
 fn get_alternative_asset(
     path: &String,
     token: &Option<String>,
+    signature: &Option<AssetUrlSignature>,
     storage_state: &impl StorageStateStrategy,
 ) -> Option<(Asset, Memory)> {
     let alternative_paths = map_alternative_paths(path);
 
     for alternative_path in alternative_paths {
         let asset: Option<(Asset, Memory)> =
-            storage_state.get_public_asset(alternative_path, token.clone());
+            storage_state.get_public_asset(alternative_path, token.clone(), signature.clone());
 
         // We return the first match
         match asset {
@@ -131,12 +201,15 @@ fn get_alternative_asset(
 
 fn get_routing_rewrite(
     path: &FullPath,
+    asset_path: &FullPath,
     token: &Option<String>,
+    signature: &Option<AssetUrlSignature>,
+    config: &StorageConfig,
     storage_state: &impl StorageStateStrategy,
 ) -> Option<Routing> {
     // If we have found no asset, we try a rewrite rule
     // This is for example useful for single-page app to redirect all urls to /index.html
-    let rewrite = rewrite_url(path, &storage_state.get_config());
+    let rewrite = rewrite_url(asset_path, config);
 
     match rewrite {
         None => (),
@@ -145,34 +218,28 @@ fn get_routing_rewrite(
 
             // Search for rewrite configured as an alternative path
             // e.g. rewrite /demo/* to /sample
-            let rewrite_asset = get_alternative_asset(&destination, token, storage_state);
+            let rewrite_asset =
+                get_alternative_asset(&destination, token, signature, storage_state);
 
             match rewrite_asset {
                 None => (),
                 Some(_) => {
-                    return Some(Routing::Rewrite(RoutingRewrite {
-                        url: path.clone(),
-                        asset: rewrite_asset,
-                        source,
-                        status_code: RESPONSE_STATUS_CODE_200,
-                    }));
+                    return Some(map_routing_rewrite(path, rewrite_asset, source));
                 }
             }
 
             // Rewrite is maybe configured as an absolute path
             // e.g. write /demo/* to /sample.html
-            let rewrite_absolute_asset: Option<(Asset, Memory)> =
-                storage_state.get_public_asset(destination.clone(), token.clone());
+            let rewrite_absolute_asset: Option<(Asset, Memory)> = storage_state.get_public_asset(
+                destination.clone(),
+                token.clone(),
+                signature.clone(),
+            );
 
             match rewrite_absolute_asset {
                 None => (),
                 Some(_) => {
-                    return Some(Routing::Rewrite(RoutingRewrite {
-                        url: path.clone(),
-                        asset: rewrite_absolute_asset,
-                        source,
-                        status_code: RESPONSE_STATUS_CODE_200,
-                    }));
+                    return Some(map_routing_rewrite(path, rewrite_absolute_asset, source));
                 }
             }
         }
@@ -181,14 +248,36 @@ fn get_routing_rewrite(
     None
 }
 
+fn map_routing_rewrite(
+    path: &FullPath,
+    asset: Option<(Asset, Memory)>,
+    source: Option<String>,
+) -> Routing {
+    match source {
+        // A rewrite with a destination resolved from the captured parameters - e.g. /blog/:slug to /posts/:slug -
+        // is certified on the requested path.
+        None => Routing::Default(RoutingDefault {
+            url: path.clone(),
+            asset,
+        }),
+        Some(source) => Routing::Rewrite(RoutingRewrite {
+            url: path.clone(),
+            asset,
+            source,
+            status_code: RESPONSE_STATUS_CODE_200,
+        }),
+    }
+}
+
 fn get_routing_root_rewrite(
     path: &FullPath,
+    prefix: &str,
     storage_state: &impl StorageStateStrategy,
 ) -> Option<Routing> {
     if !is_root_path(path) {
         // Search for potential /404.html to rewrite to
         let asset_404: Option<(Asset, Memory)> =
-            storage_state.get_public_asset(ROOT_404_HTML.to_string(), None);
+            storage_state.get_public_asset(format!("{prefix}{ROOT_404_HTML}"), None, None);
 
         match asset_404 {
             None => (),
@@ -204,7 +293,7 @@ fn get_routing_root_rewrite(
 
         // Search for potential /index.html to rewrite to
         let asset_index: Option<(Asset, Memory)> =
-            storage_state.get_public_asset(ROOT_INDEX_HTML.to_string(), None);
+            storage_state.get_public_asset(format!("{prefix}{ROOT_INDEX_HTML}"), None, None);
 
         match asset_index {
             None => (),
@@ -224,10 +313,11 @@ fn get_routing_root_rewrite(
 
 fn get_routing_redirect(
     path: &FullPath,
-    storage_state: &impl StorageStateStrategy,
+    asset_path: &FullPath,
+    query: &Option<String>,
+    config: &StorageConfig,
 ) -> Option<Routing> {
-    let config = storage_state.get_config();
-    let redirect = redirect_url(path, &config);
+    let redirect = redirect_url(asset_path, query, config);
 
     match redirect {
         None => (),
@@ -270,3 +360,217 @@ fn get_routing_redirect_raw(
 
     None
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::config::{StorageConfigDomainRoot, StorageConfigRedirect};
+    use crate::types::state::AssetAccessToken;
+    use crate::types::store::{AssetEncoding, AssetKey};
+    use candid::Principal;
+    use junobuild_collections::types::core::CollectionKey;
+    use junobuild_collections::types::rules::Rule;
+    use junobuild_shared::types::core::Blob;
+    use junobuild_shared::types::domain::CustomDomains;
+    use std::collections::HashMap;
+
+    struct TestStorage {
+        paths: Vec<&'static str>,
+        config: StorageConfig,
+    }
+
+    impl StorageStateStrategy for TestStorage {
+        fn get_content_chunks(&self, _: &AssetEncoding, _: usize, _: &Memory) -> Option<Blob> {
+            None
+        }
+
+        fn get_public_asset(
+            &self,
+            full_path: FullPath,
+            _: AssetAccessToken,
+            _: Option<AssetUrlSignature>,
+        ) -> Option<(Asset, Memory)> {
+            self.paths
+                .contains(&full_path.as_str())
+                .then(|| (asset(&full_path), Memory::Heap))
+        }
+
+        fn get_rule(&self, _: &CollectionKey) -> Result<Rule, String> {
+            Err("No rule.".to_string())
+        }
+
+        fn get_config(&self) -> StorageConfig {
+            self.config.clone()
+        }
+
+        fn get_domains(&self) -> CustomDomains {
+            CustomDomains::new()
+        }
+
+        fn get_http_endpoints(&self) -> Vec<FullPath> {
+            Vec::new()
+        }
+
+        fn get_asset(&self, _: &CollectionKey, _: &FullPath, _: &Rule) -> Option<Asset> {
+            None
+        }
+
+        fn insert_asset(&self, _: &CollectionKey, _: &FullPath, _: &Asset, _: &Rule) {}
+
+        fn insert_asset_encoding(
+            &self,
+            _: &FullPath,
+            _: &str,
+            _: &AssetEncoding,
+            _: &mut Asset,
+            _: &Rule,
+        ) {
+        }
+
+        fn delete_asset(&self, _: &CollectionKey, _: &FullPath, _: &Rule) -> Option<Asset> {
+            None
+        }
+
+        fn certify_all_assets(&self) {}
+    }
+
+    fn asset(full_path: &str) -> Asset {
+        Asset {
+            key: AssetKey {
+                name: full_path.to_string(),
+                full_path: full_path.to_string(),
+                token: None,
+                collection: "#dapp".to_string(),
+                owner: Principal::anonymous(),
+                description: None,
+                expires_at: None,
+            },
+            headers: Vec::new(),
+            encodings: HashMap::new(),
+            created_at: 0,
+            updated_at: 0,
+            version: None,
+        }
+    }
+
+    fn storage() -> TestStorage {
+        TestStorage {
+            paths: vec![
+                "/index.html",
+                "/about.html",
+                "/tenant-a/index.html",
+                "/tenant-a/about.html",
+                "/.well-known/ic-domains",
+            ],
+            config: StorageConfig {
+                domain_roots: Some(HashMap::from([(
+                    "tenant-a.com".to_string(),
+                    StorageConfigDomainRoot::Path("/tenant-a".to_string()),
+                )])),
+                redirects: Some(HashMap::from([(
+                    "/tenant-a/old".to_string(),
+                    StorageConfigRedirect {
+                        location: "/about".to_string(),
+                        status_code: 301,
+                        preserve_query: None,
+                    },
+                )])),
+                ..Default::default()
+            },
+        }
+    }
+
+    fn host(value: &str) -> Vec<HeaderField> {
+        vec![HeaderField("Host".to_string(), value.to_string())]
+    }
+
+    fn routed_path(routing: Routing) -> Option<(FullPath, FullPath)> {
+        match routing {
+            Routing::Default(RoutingDefault { url, asset })
+            | Routing::Rewrite(RoutingRewrite { url, asset, .. }) => {
+                asset.map(|(asset, _)| (url, asset.key.full_path))
+            }
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn detects_requests_served_from_a_domain_root() {
+        let storage = storage();
+
+        assert_eq!(
+            get_domain_root_routing("/about", &host("tenant-a.com"), &storage),
+            Some("/tenant-a".to_string())
+        );
+        assert_eq!(
+            get_domain_root_routing("/about", &host("satellite.com"), &storage),
+            None
+        );
+        assert_eq!(get_domain_root_routing("/about", &[], &storage), None);
+        assert_eq!(
+            get_domain_root_routing("/.well-known/ic-domains", &host("tenant-a.com"), &storage),
+            None
+        );
+    }
+
+    #[test]
+    fn routes_a_domain_to_its_root() {
+        let storage = storage();
+
+        let routing = get_routing("/about".to_string(), &host("tenant-a.com"), true, &storage);
+        assert_eq!(
+            routed_path(routing.ok().unwrap()),
+            Some(("/about".to_string(), "/tenant-a/about.html".to_string()))
+        );
+
+        let routing = get_routing("/about".to_string(), &[], true, &storage);
+        assert_eq!(
+            routed_path(routing.ok().unwrap()),
+            Some(("/about".to_string(), "/about.html".to_string()))
+        );
+
+        let routing = get_routing(
+            "/.well-known/ic-domains".to_string(),
+            &host("tenant-a.com"),
+            true,
+            &storage,
+        );
+        assert_eq!(
+            routed_path(routing.ok().unwrap()),
+            Some((
+                "/.well-known/ic-domains".to_string(),
+                "/.well-known/ic-domains".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn rewrites_a_domain_to_the_index_of_its_root() {
+        let storage = storage();
+
+        let routing = get_routing(
+            "/unknown".to_string(),
+            &host("tenant-a.com"),
+            true,
+            &storage,
+        );
+        assert_eq!(
+            routed_path(routing.ok().unwrap()),
+            Some(("/unknown".to_string(), "/tenant-a/index.html".to_string()))
+        );
+    }
+
+    #[test]
+    fn redirects_a_domain_with_the_redirects_of_its_root() {
+        let storage = storage();
+
+        let routing = get_routing("/old".to_string(), &host("tenant-a.com"), true, &storage);
+        assert!(matches!(
+            routing,
+            Ok(Routing::Redirect(RoutingRedirect { redirect, .. })) if redirect.location == "/about"
+        ));
+
+        let routing = get_routing("/old".to_string(), &[], true, &storage);
+        assert!(!matches!(routing, Ok(Routing::Redirect(_))));
+    }
+}
@@ -1,14 +1,15 @@
 use crate::certification::types::certified::CertifiedAssetHashes;
 use crate::memory::STATE;
 use crate::strategies::StorageCertificateStrategy;
-use crate::types::config::StorageConfig;
+use crate::types::config::{StorageConfig, StorageConfigIFrame, StorageConfigRedirect};
 use crate::types::runtime_state::{
     BatchId, Batches, ChunkId, Chunks, RuntimeState, StorageRuntimeState,
 };
+use crate::types::state::FullPath;
 use crate::types::store::{Asset, Batch, BatchExpiry, Chunk};
-use ic_cdk::api::time;
 use ic_certification::Hash;
 use junobuild_collections::types::core::CollectionKey;
+use junobuild_shared::ic::api::time;
 use junobuild_shared::rate::types::RateConfig;
 use junobuild_shared::rate::utils::increment_and_assert_rate_store;
 use std::collections::HashMap;
@@ -35,14 +36,44 @@ pub fn update_certified_asset(
     certificate.update_certified_data();
 }
 
-pub fn delete_certified_asset(asset: &Asset, certificate: &impl StorageCertificateStrategy) {
+pub fn delete_certified_asset(
+    asset: &Asset,
+    config: &StorageConfig,
+    certificate: &impl StorageCertificateStrategy,
+) {
     // 1. Remove the asset in tree
-    STATE.with(|state| delete_certified_asset_impl(asset, &mut state.borrow_mut().runtime));
+    STATE.with(|state| delete_certified_asset_impl(asset, config, &mut state.borrow_mut().runtime));
 
     // 2. Update the root hash and the canister certified data
     certificate.update_certified_data();
 }
 
+pub fn is_certification_skipped(full_path: &FullPath) -> bool {
+    STATE.with(|state| {
+        state
+            .borrow()
+            .runtime
+            .storage
+            .asset_hashes
+            .contains_skip_v2(full_path)
+    })
+}
+
+pub fn is_redirect_certified(
+    full_path: &FullPath,
+    redirect: &StorageConfigRedirect,
+    iframe: &StorageConfigIFrame,
+) -> bool {
+    STATE.with(|state| {
+        state
+            .borrow()
+            .runtime
+            .storage
+            .asset_hashes
+            .contains_redirect_v2(full_path, redirect, iframe)
+    })
+}
+
 pub fn certified_assets_root_hash() -> Hash {
     STATE.with(|state| certified_assets_root_hash_impl(&state.borrow().runtime.storage))
 }
@@ -58,8 +89,8 @@ fn update_certified_asset_impl(asset: &Asset, config: &StorageConfig, runtime: &
     runtime.storage.asset_hashes.insert(asset, config);
 }
 
-fn delete_certified_asset_impl(asset: &Asset, runtime: &mut RuntimeState) {
-    runtime.storage.asset_hashes.delete(asset);
+fn delete_certified_asset_impl(asset: &Asset, config: &StorageConfig, runtime: &mut RuntimeState) {
+    runtime.storage.asset_hashes.delete(asset, config);
 }
 
 fn certified_assets_root_hash_impl(storage: &StorageRuntimeState) -> Hash {
@@ -96,10 +127,12 @@ pub fn clear_expired_batches() {
     STATE.with(|state| clear_expired_batches_impl(&mut state.borrow_mut().runtime.storage.batches));
 }
 
-pub fn clear_batch(batch_id: &BatchId, chunk_ids: &[ChunkId]) {
-    STATE.with(|state| {
-        clear_batch_impl(batch_id, chunk_ids, &mut state.borrow_mut().runtime.storage)
-    });
+pub fn clear_batch(batch_id: &BatchId) {
+    STATE.with(|state| clear_batch_impl(batch_id, &mut state.borrow_mut().runtime.storage));
+}
+
+pub fn last_batch_id() -> Option<BatchId> {
+    STATE.with(|state| state.borrow().runtime.storage.batches.keys().max().copied())
 }
 
 fn insert_batch_impl(batch_id: &BatchId, batch: Batch, batches: &mut Batches) {
@@ -133,10 +166,8 @@ where
     }
 }
 
-fn clear_batch_impl(batch_id: &BatchId, chunk_ids: &[ChunkId], state: &mut StorageRuntimeState) {
-    for chunk_id in chunk_ids.iter() {
-        state.chunks.remove(chunk_id);
-    }
+fn clear_batch_impl(batch_id: &BatchId, state: &mut StorageRuntimeState) {
+    state.chunks.retain(|_, chunk| chunk.batch_id != *batch_id);
 
     state.batches.remove(batch_id);
 }
@@ -153,6 +184,20 @@ pub fn get_chunk(chunk_id: &ChunkId) -> Option<Chunk> {
     })
 }
 
+pub fn get_batch_chunks(batch_id: &BatchId) -> Vec<(ChunkId, Chunk)> {
+    STATE.with(|state| {
+        state
+            .borrow()
+            .runtime
+            .storage
+            .chunks
+            .iter()
+            .filter(|(_, chunk)| chunk.batch_id == *batch_id)
+            .map(|(chunk_id, chunk)| (*chunk_id, chunk.clone()))
+            .collect()
+    })
+}
+
 pub fn clear_expired_chunks() {
     STATE.with(|state| clear_expired_chunks_impl(&mut state.borrow_mut().runtime.storage));
 }
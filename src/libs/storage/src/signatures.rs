@@ -0,0 +1,207 @@
+use crate::types::state::{AssetUrlSignature, FullPath};
+use crate::types::store::Asset;
+use hmac::{Hmac, Mac};
+use junobuild_shared::types::state::Timestamp;
+use sha2::Sha256;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Returns the URL of an asset signed until the expiry - e.g.
+/// `/invoices/123.pdf?expires=1700000000000000000&signature=9f86d0...`.
+///
+/// The signature covers the path, the expiry and the token of the asset. Setting a new token therefore
+/// revokes the URLs signed previously.
+pub fn sign_asset_url(asset: &Asset, expires_at: Timestamp, key: &[u8]) -> Option<String> {
+    let mac = asset_url_mac(asset, expires_at, key)?;
+
+    let signature = hex::encode(mac.finalize().into_bytes());
+
+    Some(format!(
+        "{}?expires={}&signature={}",
+        asset.key.full_path, expires_at, signature
+    ))
+}
+
+/// Returns true if the signature of a URL was issued for the asset and has not yet expired.
+pub fn is_valid_asset_url_signature(
+    asset: &Asset,
+    url_signature: &AssetUrlSignature,
+    key: &[u8],
+    now: Timestamp,
+) -> bool {
+    let AssetUrlSignature {
+        expires_at,
+        signature,
+    } = url_signature;
+
+    if *expires_at <= now {
+        return false;
+    }
+
+    let signature = match hex::decode(signature) {
+        Err(_) => return false,
+        Ok(signature) => signature,
+    };
+
+    match asset_url_mac(asset, *expires_at, key) {
+        None => false,
+        Some(mac) => mac.verify_slice(&signature).is_ok(),
+    }
+}
+
+fn asset_url_mac(asset: &Asset, expires_at: Timestamp, key: &[u8]) -> Option<HmacSha256> {
+    let mut mac = HmacSha256::new_from_slice(key).ok()?;
+
+    mac.update(&asset_url_message(
+        &asset.key.full_path,
+        expires_at,
+        &asset.key.token,
+    ));
+
+    Some(mac)
+}
+
+fn asset_url_message(
+    full_path: &FullPath,
+    expires_at: Timestamp,
+    token: &Option<String>,
+) -> Vec<u8> {
+    [
+        full_path.as_bytes(),
+        &expires_at.to_be_bytes(),
+        token.as_deref().unwrap_or_default().as_bytes(),
+    ]
+    .join(&0u8)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::store::AssetKey;
+    use candid::Principal;
+    use std::collections::HashMap;
+
+    const KEY: &[u8] = b"secret";
+    const NOW: Timestamp = 1_700_000_000_000_000_000;
+    const EXPIRES_AT: Timestamp = NOW + 60_000_000_000;
+
+    fn asset(full_path: &str, token: Option<&str>) -> Asset {
+        Asset {
+            key: AssetKey {
+                name: "123.pdf".to_string(),
+                full_path: full_path.to_string(),
+                token: token.map(str::to_string),
+                collection: "invoices".to_string(),
+                owner: Principal::anonymous(),
+                description: None,
+                expires_at: None,
+            },
+            headers: Vec::new(),
+            encodings: HashMap::new(),
+            created_at: 0,
+            updated_at: 0,
+            version: None,
+        }
+    }
+
+    fn url_signature(asset: &Asset, expires_at: Timestamp, key: &[u8]) -> AssetUrlSignature {
+        let url = sign_asset_url(asset, expires_at, key).unwrap();
+        let (_, signature) = url.split_once("&signature=").unwrap();
+
+        AssetUrlSignature {
+            expires_at,
+            signature: signature.to_string(),
+        }
+    }
+
+    #[test]
+    fn signs_the_url_of_the_asset() {
+        let asset = asset("/invoices/123.pdf", Some("a-token"));
+
+        let url = sign_asset_url(&asset, EXPIRES_AT, KEY).unwrap();
+
+        assert!(url.starts_with(&format!(
+            "/invoices/123.pdf?expires={EXPIRES_AT}&signature="
+        )));
+    }
+
+    #[test]
+    fn accepts_a_valid_signature() {
+        let asset = asset("/invoices/123.pdf", Some("a-token"));
+        let signature = url_signature(&asset, EXPIRES_AT, KEY);
+
+        assert!(is_valid_asset_url_signature(&asset, &signature, KEY, NOW));
+    }
+
+    #[test]
+    fn rejects_an_expired_signature() {
+        let asset = asset("/invoices/123.pdf", Some("a-token"));
+        let signature = url_signature(&asset, EXPIRES_AT, KEY);
+
+        assert!(!is_valid_asset_url_signature(
+            &asset, &signature, KEY, EXPIRES_AT
+        ));
+        assert!(!is_valid_asset_url_signature(
+            &asset,
+            &signature,
+            KEY,
+            EXPIRES_AT + 1
+        ));
+    }
+
+    #[test]
+    fn rejects_a_signature_issued_for_another_path() {
+        let signature = url_signature(
+            &asset("/invoices/123.pdf", Some("a-token")),
+            EXPIRES_AT,
+            KEY,
+        );
+
+        let other = asset("/invoices/456.pdf", Some("a-token"));
+
+        assert!(!is_valid_asset_url_signature(&other, &signature, KEY, NOW));
+    }
+
+    #[test]
+    fn rejects_a_tampered_expiry() {
+        let asset = asset("/invoices/123.pdf", Some("a-token"));
+        let signature = AssetUrlSignature {
+            expires_at: EXPIRES_AT + 1,
+            ..url_signature(&asset, EXPIRES_AT, KEY)
+        };
+
+        assert!(!is_valid_asset_url_signature(&asset, &signature, KEY, NOW));
+    }
+
+    #[test]
+    fn rejects_a_signature_issued_with_another_key() {
+        let asset = asset("/invoices/123.pdf", Some("a-token"));
+        let signature = url_signature(&asset, EXPIRES_AT, b"another-secret");
+
+        assert!(!is_valid_asset_url_signature(&asset, &signature, KEY, NOW));
+    }
+
+    #[test]
+    fn rejects_a_signature_revoked_by_a_new_token() {
+        let signature = url_signature(
+            &asset("/invoices/123.pdf", Some("a-token")),
+            EXPIRES_AT,
+            KEY,
+        );
+
+        let asset = asset("/invoices/123.pdf", Some("a-new-token"));
+
+        assert!(!is_valid_asset_url_signature(&asset, &signature, KEY, NOW));
+    }
+
+    #[test]
+    fn rejects_a_malformed_signature() {
+        let asset = asset("/invoices/123.pdf", Some("a-token"));
+        let signature = AssetUrlSignature {
+            expires_at: EXPIRES_AT,
+            signature: "not-hex".to_string(),
+        };
+
+        assert!(!is_valid_asset_url_signature(&asset, &signature, KEY, NOW));
+    }
+}
@@ -1,42 +1,46 @@
 use crate::assert::{
     assert_commit_batch, assert_commit_chunks, assert_commit_chunks_new_asset,
-    assert_commit_chunks_update, assert_create_batch, assert_create_chunk,
+    assert_commit_chunks_update, assert_content_hash, assert_create_batch, assert_create_chunk,
 };
+use crate::compression::{compress_content, uploaded_encoding_types};
 use crate::constants::{ASSET_ENCODING_NO_COMPRESSION, ENCODING_CERTIFICATION_ORDER};
 use crate::errors::{
     JUNO_STORAGE_ERROR_ASSET_MAX_ALLOWED_SIZE, JUNO_STORAGE_ERROR_BATCH_NOT_FOUND,
     JUNO_STORAGE_ERROR_CANNOT_COMMIT_BATCH, JUNO_STORAGE_ERROR_CHUNK_NOT_FOUND,
     JUNO_STORAGE_ERROR_CHUNK_NOT_INCLUDED_IN_BATCH,
 };
-use crate::runtime::{
-    clear_batch as clear_runtime_batch, clear_expired_batches as clear_expired_runtime_batches,
-    clear_expired_chunks as clear_expired_runtime_chunks, get_batch as get_runtime_batch,
-    get_chunk as get_runtime_chunk, insert_batch as insert_runtime_batch,
-    insert_chunk as insert_runtime_chunk,
+use crate::strategies::{
+    StorageAssertionsStrategy, StorageBatchesStrategy, StorageStateStrategy, StorageUploadStrategy,
 };
-use crate::strategies::{StorageAssertionsStrategy, StorageStateStrategy, StorageUploadStrategy};
 use crate::types::config::StorageConfig;
-use crate::types::interface::{CommitBatch, InitAssetKey, UploadChunk};
+use crate::types::interface::{
+    CommitBatch, InitAssetKey, UploadChunk, UploadSession, UploadSessionChunk,
+};
 use crate::types::runtime_state::{BatchId, ChunkId};
 use crate::types::store::{
     Asset, AssetEncoding, AssetKey, Batch, Chunk, EncodingType, ReferenceId,
 };
+use crate::utils::map_content_encoding;
 use candid::Principal;
-use ic_cdk::api::time;
 use junobuild_collections::types::rules::Rule;
+use junobuild_shared::ic::api::time;
 use junobuild_shared::types::core::Blob;
 use junobuild_shared::types::state::AccessKeys;
+use junobuild_shared::utils::principal_not_equal;
 use std::ptr::addr_of;
 
 // ---------------------------------------------------------
 // Upload batch and chunks
 // ---------------------------------------------------------
 
-const BATCH_EXPIRY_NANOS: u64 = 300_000_000_000;
+// How long a batch is kept without receiving a chunk. Long enough for an upload to be resumed,
+// e.g. after a mobile device lost its connection for a while.
+const BATCH_EXPIRY_NANOS: u64 = 60 * 60 * 1_000_000_000;
 
 static mut NEXT_BATCH_ID: BatchId = 0;
 static mut NEXT_CHUNK_ID: ChunkId = 0;
 
+#[allow(clippy::too_many_arguments)]
 pub fn create_batch(
     caller: Principal,
     controllers: &AccessKeys,
@@ -45,6 +49,7 @@ pub fn create_batch(
     reference_id: Option<ReferenceId>,
     assertions: &impl StorageAssertionsStrategy,
     storage_state: &impl StorageStateStrategy,
+    storage_batches: &impl StorageBatchesStrategy,
 ) -> Result<BatchId, String> {
     assert_create_batch(
         caller,
@@ -58,7 +63,12 @@ pub fn create_batch(
     // Assert supported encoding type
     get_encoding_type(&init.encoding_type)?;
 
-    Ok(create_batch_impl(caller, init, reference_id))
+    Ok(create_batch_impl(
+        caller,
+        init,
+        reference_id,
+        storage_batches,
+    ))
 }
 
 fn create_batch_impl(
@@ -70,15 +80,18 @@ fn create_batch_impl(
         encoding_type,
         full_path,
         description,
+        expires_at,
     }: InitAssetKey,
     reference_id: Option<ReferenceId>,
+    storage_batches: &impl StorageBatchesStrategy,
 ) -> BatchId {
     let now = time();
 
     unsafe {
-        clear_expired_batches();
+        storage_batches.clear_expired_batches();
 
-        NEXT_BATCH_ID += 1;
+        // The batches in progress can outlive the sequence e.g. if they are kept in stable memory across upgrades
+        NEXT_BATCH_ID = NEXT_BATCH_ID.max(storage_batches.last_batch_id().unwrap_or_default()) + 1;
 
         let key: AssetKey = AssetKey {
             full_path,
@@ -87,9 +100,10 @@ fn create_batch_impl(
             token,
             name,
             description,
+            expires_at,
         };
 
-        insert_runtime_batch(
+        storage_batches.insert_batch(
             &*addr_of!(NEXT_BATCH_ID),
             Batch {
                 key,
@@ -111,8 +125,9 @@ pub fn create_chunk(
         content,
         order_id,
     }: UploadChunk,
+    storage_batches: &impl StorageBatchesStrategy,
 ) -> Result<ChunkId, String> {
-    let batch = get_runtime_batch(&batch_id);
+    let batch = storage_batches.get_batch(&batch_id);
 
     match batch {
         None => Err(JUNO_STORAGE_ERROR_BATCH_NOT_FOUND.to_string()),
@@ -122,7 +137,7 @@ pub fn create_chunk(
             let now = time();
 
             // Update batch to extend expires_at
-            insert_runtime_batch(
+            storage_batches.insert_batch(
                 &batch_id,
                 Batch {
                     expires_at: now + BATCH_EXPIRY_NANOS,
@@ -131,9 +146,11 @@ pub fn create_chunk(
             );
 
             unsafe {
-                NEXT_CHUNK_ID += 1;
+                NEXT_CHUNK_ID = NEXT_CHUNK_ID
+                    .max(storage_batches.last_chunk_id(&batch_id).unwrap_or_default())
+                    + 1;
 
-                insert_runtime_chunk(
+                storage_batches.insert_chunk(
                     &*addr_of!(NEXT_CHUNK_ID),
                     Chunk {
                         batch_id,
@@ -148,6 +165,50 @@ pub fn create_chunk(
     }
 }
 
+/// Returns the chunks received for a batch, and their offset in the content, so that an interrupted upload
+/// can be resumed. Only the caller that initialized the batch can inspect it.
+pub fn get_upload_session(
+    caller: Principal,
+    batch_id: &BatchId,
+    storage_batches: &impl StorageBatchesStrategy,
+) -> Result<UploadSession, String> {
+    let batch = match storage_batches.get_batch(batch_id) {
+        Some(batch) if time() <= batch.expires_at => batch,
+        _ => return Err(JUNO_STORAGE_ERROR_BATCH_NOT_FOUND.to_string()),
+    };
+
+    if principal_not_equal(caller, batch.key.owner) {
+        return Err(JUNO_STORAGE_ERROR_BATCH_NOT_FOUND.to_string());
+    }
+
+    let mut summaries = storage_batches.list_chunks(batch_id);
+    summaries.sort_by_key(|summary| (summary.order_id, summary.chunk_id));
+
+    let mut chunks: Vec<UploadSessionChunk> = Vec::with_capacity(summaries.len());
+    let mut total_length: u128 = 0;
+
+    for summary in summaries {
+        chunks.push(UploadSessionChunk {
+            chunk_id: summary.chunk_id,
+            order_id: summary.order_id,
+            offset: total_length,
+            length: summary.length,
+        });
+
+        total_length += summary.length;
+    }
+
+    Ok(UploadSession {
+        batch_id: *batch_id,
+        collection: batch.key.collection,
+        full_path: batch.key.full_path,
+        expires_at: batch.expires_at,
+        chunks,
+        total_length,
+    })
+}
+
+#[allow(clippy::too_many_arguments)]
 pub fn commit_batch(
     caller: Principal,
     controllers: &AccessKeys,
@@ -156,8 +217,9 @@ pub fn commit_batch(
     assertions: &impl StorageAssertionsStrategy,
     storage_state: &impl StorageStateStrategy,
     storage_upload: &impl StorageUploadStrategy,
+    storage_batches: &impl StorageBatchesStrategy,
 ) -> Result<Asset, String> {
-    let batch = get_runtime_batch(&commit_batch.batch_id);
+    let batch = storage_batches.get_batch(&commit_batch.batch_id);
 
     match batch {
         None => Err(JUNO_STORAGE_ERROR_CANNOT_COMMIT_BATCH.to_string()),
@@ -171,6 +233,7 @@ pub fn commit_batch(
                 assertions,
                 storage_state,
                 storage_upload,
+                storage_batches,
             )?;
             Ok(asset)
         }
@@ -187,6 +250,7 @@ fn secure_commit_chunks(
     assertions: &impl StorageAssertionsStrategy,
     storage_state: &impl StorageStateStrategy,
     storage_upload: &impl StorageUploadStrategy,
+    storage_batches: &impl StorageBatchesStrategy,
 ) -> Result<Asset, String> {
     let rule = assert_commit_batch(caller, controllers, batch, assertions, storage_state)?;
 
@@ -211,12 +275,14 @@ fn secure_commit_chunks(
             commit_chunks(
                 caller,
                 controllers,
+                config,
                 commit_batch,
                 batch,
                 &rule,
                 &None,
                 assertions,
                 storage_upload,
+                storage_batches,
             )
         }
         Some(current) => secure_commit_chunks_update(
@@ -229,6 +295,7 @@ fn secure_commit_chunks(
             current,
             assertions,
             storage_upload,
+            storage_batches,
         ),
     }
 }
@@ -244,6 +311,7 @@ fn secure_commit_chunks_update(
     current: Asset,
     assertions: &impl StorageAssertionsStrategy,
     storage_upload: &impl StorageUploadStrategy,
+    storage_batches: &impl StorageBatchesStrategy,
 ) -> Result<Asset, String> {
     assert_commit_chunks_update(
         caller,
@@ -258,12 +326,14 @@ fn secure_commit_chunks_update(
     commit_chunks(
         caller,
         controllers,
+        config,
         commit_batch,
         batch,
         &rule,
         &Some(current),
         assertions,
         storage_upload,
+        storage_batches,
     )
 }
 
@@ -271,63 +341,72 @@ fn secure_commit_chunks_update(
 fn commit_chunks(
     caller: Principal,
     controllers: &AccessKeys,
+    config: &StorageConfig,
     commit_batch: CommitBatch,
     batch: &Batch,
     rule: &Rule,
     current: &Option<Asset>,
     assertions: &impl StorageAssertionsStrategy,
     storage_upload: &impl StorageUploadStrategy,
+    storage_batches: &impl StorageBatchesStrategy,
 ) -> Result<Asset, String> {
     let now = time();
 
     if now > batch.expires_at {
-        clear_expired_batches();
+        storage_batches.clear_expired_batches();
         return Err("Batch did not complete in time. Chunks cannot be committed.".to_string());
     }
 
-    assert_commit_chunks(
-        caller,
-        controllers,
-        &commit_batch,
-        batch,
-        current,
-        rule,
-        assertions,
-    )?;
-
-    let CommitBatch {
-        chunk_ids,
-        batch_id,
-        headers,
-    } = commit_batch;
-
     // Collect all chunks
-    let mut chunks: Vec<Chunk> = vec![];
+    let mut chunks: Vec<(ChunkId, Chunk)> = vec![];
 
-    for chunk_id in chunk_ids.iter() {
-        let chunk = get_runtime_chunk(chunk_id);
+    for chunk_id in commit_batch.chunk_ids.iter() {
+        let chunk = storage_batches.get_chunk(&commit_batch.batch_id, chunk_id);
 
         match chunk {
             None => {
                 return Err(JUNO_STORAGE_ERROR_CHUNK_NOT_FOUND.to_string());
             }
             Some(c) => {
-                if batch_id != c.batch_id {
+                if commit_batch.batch_id != c.batch_id {
                     return Err(JUNO_STORAGE_ERROR_CHUNK_NOT_INCLUDED_IN_BATCH.to_string());
                 }
 
-                chunks.push(c);
+                chunks.push((*chunk_id, c));
             }
         }
     }
 
     // Sort with ordering
-    chunks.sort_by_key(|a| a.order_id);
+    chunks.sort_by_key(|(_, c)| c.order_id);
+
+    // The assertions read the content chunk by chunk, in the order it is committed
+    let commit_batch = CommitBatch {
+        chunk_ids: chunks.iter().map(|(chunk_id, _)| *chunk_id).collect(),
+        ..commit_batch
+    };
+
+    assert_commit_chunks(
+        caller,
+        controllers,
+        &commit_batch,
+        batch,
+        current,
+        rule,
+        assertions,
+    )?;
+
+    let CommitBatch {
+        batch_id,
+        headers,
+        sha256,
+        ..
+    } = commit_batch;
 
     let mut content_chunks: Vec<Blob> = vec![];
 
     // Collect content
-    for c in chunks.iter() {
+    for (_, c) in chunks.iter() {
         content_chunks.push(c.content.clone());
     }
 
@@ -335,8 +414,12 @@ fn commit_chunks(
     // However, the owner remains the one who originally created the asset.
     let owner = current.as_ref().map_or(caller, |asset| asset.key.owner);
 
+    // Without an explicit expiration, the default time-to-live of the collection - if any - applies.
+    let expires_at = rule.expires_at(&batch.key.expires_at, now);
+
     let key = AssetKey {
         owner,
+        expires_at,
         ..batch.clone().key
     };
 
@@ -346,9 +429,12 @@ fn commit_chunks(
 
     let encoding = AssetEncoding::from(&content_chunks);
 
+    // The batch is kept so that the client can upload the missing or corrupted chunks and commit again.
+    assert_content_hash(&sha256, &encoding)?;
+
     match rule.max_size {
         Some(max_size) if encoding.total_length > max_size => {
-            clear_runtime_batch(&batch_id, &chunk_ids);
+            storage_batches.clear_batch(&batch_id);
             return Err(JUNO_STORAGE_ERROR_ASSET_MAX_ALLOWED_SIZE.to_string());
         }
         _ => (),
@@ -363,9 +449,36 @@ fn commit_chunks(
         rule,
     )?;
 
+    // The compressed encodings are produced again each time the uncompressed content is uploaded,
+    // except those the client uploaded. An encoding uploaded afterwards replaces the produced one.
+    if encoding_type == ASSET_ENCODING_NO_COMPRESSION {
+        let content = content_chunks.concat();
+
+        let uploaded_types = current
+            .as_ref()
+            .map(uploaded_encoding_types)
+            .unwrap_or_default();
+
+        for (compressed_type, compressed) in
+            compress_content(config, &asset.headers, &content, &uploaded_types)
+        {
+            storage_upload.insert_asset_encoding(
+                &batch.reference_id,
+                &batch.key.full_path,
+                &compressed_type,
+                &AssetEncoding {
+                    generated: true,
+                    ..map_content_encoding(&compressed)
+                },
+                &mut asset,
+                rule,
+            )?;
+        }
+    }
+
     storage_upload.insert_asset(batch, &asset, rule)?;
 
-    clear_runtime_batch(&batch_id, &chunk_ids);
+    storage_batches.clear_batch(&batch_id);
 
     Ok(asset)
 }
@@ -385,11 +498,3 @@ fn get_encoding_type(encoding_type: &Option<EncodingType>) -> Result<EncodingTyp
 
     Ok(provided_type)
 }
-
-fn clear_expired_batches() {
-    // Remove expired batches
-    clear_expired_runtime_batches();
-
-    // Remove chunk without existing batches (those we just deleted above)
-    clear_expired_runtime_chunks();
-}
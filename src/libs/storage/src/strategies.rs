@@ -1,7 +1,8 @@
 use crate::types::config::StorageConfig;
-use crate::types::state::{AssetAccessToken, FullPath};
+use crate::types::runtime_state::{BatchId, ChunkId};
+use crate::types::state::{AssetAccessToken, AssetUrlSignature, FullPath};
 use crate::types::store::{
-    Asset, AssetAssertUpload, AssetEncoding, Batch, EncodingType, ReferenceId,
+    Asset, AssetAssertUpload, AssetEncoding, Batch, Chunk, ChunkSummary, EncodingType, ReferenceId,
 };
 use candid::Principal;
 use ic_certification::HashTree;
@@ -81,6 +82,7 @@ pub trait StorageStateStrategy {
         &self,
         full_path: FullPath,
         token: AssetAccessToken,
+        signature: Option<AssetUrlSignature>,
     ) -> Option<(Asset, Memory)>;
 
     fn get_rule(&self, collection: &CollectionKey) -> Result<Rule, String>;
@@ -89,6 +91,9 @@ pub trait StorageStateStrategy {
 
     fn get_domains(&self) -> CustomDomains;
 
+    // The path prefixes served by custom HTTP endpoints rather than by the assets
+    fn get_http_endpoints(&self) -> Vec<FullPath>;
+
     fn get_asset(
         &self,
         collection: &CollectionKey,
@@ -145,6 +150,30 @@ pub trait StorageUploadStrategy {
     ) -> Result<Option<Asset>, String>;
 }
 
+pub trait StorageBatchesStrategy {
+    fn get_batch(&self, batch_id: &BatchId) -> Option<Batch>;
+
+    // The greatest identifier of the batches in progress, used to resume the sequence of identifiers
+    fn last_batch_id(&self) -> Option<BatchId>;
+
+    fn insert_batch(&self, batch_id: &BatchId, batch: Batch);
+
+    fn get_chunk(&self, batch_id: &BatchId, chunk_id: &ChunkId) -> Option<Chunk>;
+
+    // The greatest identifier of the chunks received for a batch
+    fn last_chunk_id(&self, batch_id: &BatchId) -> Option<ChunkId>;
+
+    fn insert_chunk(&self, chunk_id: &ChunkId, chunk: Chunk);
+
+    fn list_chunks(&self, batch_id: &BatchId) -> Vec<ChunkSummary>;
+
+    // Removes the batch and all its chunks
+    fn clear_batch(&self, batch_id: &BatchId);
+
+    // Removes the expired batches, unless they are removed by other means - e.g. a timer
+    fn clear_expired_batches(&self);
+}
+
 pub trait StorageCertificateStrategy {
     fn update_certified_data(&self);
 
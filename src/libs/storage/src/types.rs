@@ -3,8 +3,9 @@ pub mod state {
     use crate::types::store::Asset;
     use candid::{CandidType, Deserialize};
     use junobuild_collections::types::rules::Rules;
-    use junobuild_shared::types::core::Key;
+    use junobuild_shared::types::core::{Blob, Key};
     use junobuild_shared::types::domain::CustomDomains;
+    use junobuild_shared::types::state::Timestamp;
     use serde::Serialize;
     use std::collections::HashMap;
 
@@ -19,6 +20,17 @@ pub mod state {
     /// Private as in practically unguessable (if complex enough and not shared of course).
     pub type AssetAccessToken = Option<String>;
 
+    /// The expiry and signature of a signed URL - e.g. `?expires=1700000000000000000&signature=9f86d0...` -
+    /// that grants access to a token protected asset until it expires.
+    #[derive(CandidType, Deserialize, Clone)]
+    pub struct AssetUrlSignature {
+        pub expires_at: Timestamp,
+        pub signature: String,
+    }
+
+    /// The secret used to sign the URLs of the assets. Generated on first use and never exposed.
+    pub type AssetSigningKey = Blob;
+
     pub type AssetsHeap = HashMap<FullPath, Asset>;
 
     #[derive(CandidType, Serialize, Deserialize, Clone)]
@@ -27,6 +39,7 @@ pub mod state {
         pub rules: Rules,
         pub config: StorageConfig,
         pub custom_domains: CustomDomains,
+        pub signing_key: Option<AssetSigningKey>,
     }
 }
 
@@ -67,7 +80,7 @@ pub mod runtime_state {
 pub mod store {
     use crate::http::types::HeaderField;
     use crate::types::interface::CommitBatch;
-    use crate::types::runtime_state::BatchId;
+    use crate::types::runtime_state::{BatchId, ChunkId};
     use crate::types::state::{AssetAccessToken, FullPath};
     use candid::CandidType;
     use ic_certification::Hash;
@@ -78,22 +91,37 @@ pub mod store {
     use std::clone::Clone;
     use std::collections::HashMap;
 
-    #[derive(CandidType, Deserialize, Clone)]
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
     pub struct Chunk {
         pub batch_id: BatchId,
         pub order_id: u128,
+        #[serde(with = "serde_bytes")]
         pub content: Blob,
     }
 
+    /// A chunk received for a batch, without its content.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct ChunkSummary {
+        pub chunk_id: ChunkId,
+        pub order_id: u128,
+        pub length: u128,
+    }
+
     // When stable memory is used, chunks are saved within a StableBTreeMap and their keys - StableEncodingChunkKey - are saved for reference as serialized values
     pub type BlobOrKey = Blob;
 
+    /// An encoding of the content of an asset.
+    ///
+    /// `generated` is set for the compressed encodings produced by the Satellite when the
+    /// uncompressed content is committed, in contrast to those uploaded by the client.
     #[derive(CandidType, Serialize, Deserialize, Clone)]
     pub struct AssetEncoding {
         pub modified: Timestamp,
         pub content_chunks: Vec<BlobOrKey>,
         pub total_length: u128,
         pub sha256: Hash,
+        #[serde(default)]
+        pub generated: bool,
     }
 
     #[derive(CandidType, Serialize, Deserialize, Clone)]
@@ -110,6 +138,8 @@ pub mod store {
         pub owner: UserId,
         // A description field which can be useful for search purpose
         pub description: Option<String>,
+        // After this timestamp the asset is considered deleted and eventually purged
+        pub expires_at: Option<Timestamp>,
     }
 
     pub type EncodingType = String;
@@ -142,6 +172,7 @@ pub mod store {
     pub struct AssetAssertUpload {
         pub current: Option<Asset>,
         pub batch: Batch,
+        // The chunk ids of the commit are ordered as their content is committed
         pub commit_batch: CommitBatch,
     }
 }
@@ -156,8 +187,9 @@ pub mod interface {
 
     use crate::http::types::HeaderField;
     use crate::types::config::{
-        StorageConfigHeaders, StorageConfigIFrame, StorageConfigMaxMemorySize,
-        StorageConfigRawAccess, StorageConfigRedirects, StorageConfigRewrites,
+        StorageConfigCompression, StorageConfigDomainRoots, StorageConfigHeaders,
+        StorageConfigIFrame, StorageConfigMaxMemorySize, StorageConfigRawAccess,
+        StorageConfigRedirects, StorageConfigRewrites,
     };
     use crate::types::runtime_state::{BatchId, ChunkId};
     use crate::types::state::{AssetAccessToken, FullPath};
@@ -171,6 +203,7 @@ pub mod interface {
         pub collection: CollectionKey,
         pub encoding_type: Option<EncodingType>,
         pub description: Option<String>,
+        pub expires_at: Option<Timestamp>,
     }
 
     #[derive(CandidType)]
@@ -195,6 +228,29 @@ pub mod interface {
         pub batch_id: BatchId,
         pub headers: Vec<HeaderField>,
         pub chunk_ids: Vec<ChunkId>,
+        // The expected sha256 of the content, verified before the asset is committed
+        pub sha256: Option<Hash>,
+    }
+
+    /// The state of an upload - i.e. a batch - that has not been committed yet. A client can use it to resume
+    /// an interrupted upload by sending only the chunks that were not received.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct UploadSession {
+        pub batch_id: BatchId,
+        pub collection: CollectionKey,
+        pub full_path: FullPath,
+        pub expires_at: Timestamp,
+        pub chunks: Vec<UploadSessionChunk>,
+        pub total_length: u128,
+    }
+
+    /// A received chunk and its position, in bytes, in the content assembled by order.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct UploadSessionChunk {
+        pub chunk_id: ChunkId,
+        pub order_id: u128,
+        pub offset: u128,
+        pub length: u128,
     }
 
     #[derive(CandidType, Deserialize, Clone)]
@@ -222,6 +278,8 @@ pub mod interface {
         pub iframe: Option<StorageConfigIFrame>,
         pub raw_access: Option<StorageConfigRawAccess>,
         pub max_memory_size: Option<StorageConfigMaxMemorySize>,
+        pub compression: Option<Vec<StorageConfigCompression>>,
+        pub domain_roots: Option<StorageConfigDomainRoots>,
         pub version: Option<Version>,
     }
 
@@ -253,7 +311,9 @@ pub mod interface {
 pub mod config {
     use crate::http::types::{HeaderField, StatusCode};
     use candid::CandidType;
+    use junobuild_collections::types::core::CollectionKey;
     use junobuild_shared::types::config::ConfigMaxMemorySize;
+    use junobuild_shared::types::core::DomainName;
     use junobuild_shared::types::state::{Timestamp, Version};
     use serde::{Deserialize, Serialize};
     use std::collections::HashMap;
@@ -277,6 +337,36 @@ pub mod config {
 
     pub type StorageConfigMaxMemorySize = ConfigMaxMemorySize;
 
+    /// The encodings produced on upload when an asset of a compressible content type - e.g. HTML,
+    /// JSON or SVG - is committed without compression.
+    #[derive(CandidType, Serialize, Deserialize, Clone, PartialEq)]
+    pub enum StorageConfigCompression {
+        Gzip,
+        Brotli,
+    }
+
+    /// The root of the assets served for a custom domain - e.g. `tenant-a.com/about` served from
+    /// `/tenant-a/about` - either a path prefix or the path of a collection.
+    ///
+    /// A domain with a root is served with the rewrites and redirects whose source is in that root -
+    /// e.g. `/tenant-a/*` - and falls back to the `/404.html` or `/index.html` of its root.
+    ///
+    /// The assets of a root are certified on their paths in the root for the requests with the Host of
+    /// the domain only - e.g. `/about.html` for `tenant-a.com` - so that they cannot be verified on
+    /// another domain. The requests served with its rewrites and redirects, or without the certification
+    /// v2, are upgraded to update calls, whose responses are not certified but go through consensus.
+    ///
+    /// ⚠️ Limitation: the assets of the satellite are certified without the Host and remain verifiable
+    /// on the domain, and an unknown path of the domain that is an asset of the satellite cannot be
+    /// rewritten to the `/404.html` or `/index.html` of its root with a verifiable response.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub enum StorageConfigDomainRoot {
+        Path(String),
+        Collection(CollectionKey),
+    }
+
+    pub type StorageConfigDomainRoots = HashMap<DomainName, StorageConfigDomainRoot>;
+
     #[derive(Default, CandidType, Serialize, Deserialize, Clone)]
     pub struct StorageConfig {
         pub headers: StorageConfigHeaders,
@@ -285,30 +375,48 @@ pub mod config {
         pub iframe: Option<StorageConfigIFrame>,
         pub raw_access: Option<StorageConfigRawAccess>,
         pub max_memory_size: Option<StorageConfigMaxMemorySize>,
+        pub compression: Option<Vec<StorageConfigCompression>>,
+        pub domain_roots: Option<StorageConfigDomainRoots>,
         pub version: Option<Version>,
         pub created_at: Option<Timestamp>,
         pub updated_at: Option<Timestamp>,
     }
 
+    /// A redirect of the requested path to a location.
+    ///
+    /// The location can reference the segments captured by the source - e.g. `/blog/:slug` redirected
+    /// to `/posts/:slug` - or the rest of the path matched by a trailing wildcard with `:splat`.
     #[derive(Default, CandidType, Serialize, Deserialize, Clone)]
     pub struct StorageConfigRedirect {
         pub location: String,
         pub status_code: StatusCode,
+        // Appends the query string of the request to the location
+        pub preserve_query: Option<bool>,
     }
 }
 
 pub mod http_request {
     use crate::http::types::StatusCode;
     use crate::types::config::{StorageConfigIFrame, StorageConfigRedirect};
-    use crate::types::state::AssetAccessToken;
+    use crate::types::state::{AssetAccessToken, AssetUrlSignature};
     use crate::types::store::Asset;
     use candid::{CandidType, Deserialize};
     use junobuild_collections::types::rules::Memory;
 
+    /// The credentials presented with a request to a token protected asset - i.e. its token or the
+    /// signature of a signed URL.
+    #[derive(Default, CandidType, Deserialize, Clone)]
+    pub struct AssetAccess {
+        pub token: AssetAccessToken,
+        pub signature: Option<AssetUrlSignature>,
+    }
+
     #[derive(CandidType, Deserialize, Clone)]
     pub struct MapUrl {
         pub path: String,
         pub token: AssetAccessToken,
+        pub signature: Option<AssetUrlSignature>,
+        pub query: Option<String>,
     }
 
     #[derive(CandidType, Deserialize, Clone)]
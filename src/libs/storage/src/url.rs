@@ -1,5 +1,5 @@
-use crate::types::http_request::MapUrl;
-use crate::types::state::FullPath;
+use crate::types::http_request::{AssetAccess, MapUrl};
+use crate::types::state::{AssetUrlSignature, FullPath};
 use globset::Glob;
 use std::collections::HashMap;
 use url::Url;
@@ -13,12 +13,28 @@ pub fn map_url(url: &str) -> Result<MapUrl, &'static str> {
 
     let token = map_token(parsed_url.clone());
 
+    let signature = map_signature(&parsed_url);
+
+    let query = parsed_url.query().map(str::to_string);
+
     Ok(MapUrl {
         path: requested_path,
         token,
+        signature,
+        query,
     })
 }
 
+pub fn map_asset_access(url: &str) -> AssetAccess {
+    map_url(url)
+        .map(
+            |MapUrl {
+                 token, signature, ..
+             }| AssetAccess { token, signature },
+        )
+        .unwrap_or_default()
+}
+
 pub fn map_alternative_paths(path: &String) -> Vec<String> {
     aliases_of(&path.to_string())
 }
@@ -140,23 +156,43 @@ fn map_token(parsed_url: Url) -> Option<String> {
     None
 }
 
+/// Find reserved query keywords "expires" and "signature" of signed URLs
+fn map_signature(parsed_url: &Url) -> Option<AssetUrlSignature> {
+    let param = |key: &str| {
+        parsed_url
+            .query_pairs()
+            .find(|(name, _)| name == key)
+            .map(|(_, value)| value.into_owned())
+    };
+
+    let expires_at = param("expires")?.parse::<u64>().ok()?;
+    let signature = param("signature")?;
+
+    Some(AssetUrlSignature {
+        expires_at,
+        signature,
+    })
+}
+
 pub fn matching_urls<T: Clone>(
     requested_path: &str,
     config: &HashMap<String, T>,
 ) -> Vec<(String, T)> {
     config
         .iter()
-        .filter(|(source, _)| {
-            let glob = Glob::new(source);
-
-            match glob {
-                Err(_) => false,
-                Ok(glob) => {
-                    let matcher = glob.compile_matcher();
-                    matcher.is_match(requested_path)
-                }
-            }
-        })
+        .filter(|(source, _)| matches_url(requested_path, source))
         .map(|(source, destination)| (source.clone(), destination.clone()))
         .collect()
 }
+
+pub fn matches_url(requested_path: &str, source: &str) -> bool {
+    let glob = Glob::new(source);
+
+    match glob {
+        Err(_) => false,
+        Ok(glob) => {
+            let matcher = glob.compile_matcher();
+            matcher.is_match(requested_path)
+        }
+    }
+}
@@ -1,4 +1,7 @@
 use crate::constants::{WELL_KNOWN_CUSTOM_DOMAINS, WELL_KNOWN_II_ALTERNATIVE_ORIGINS};
+use crate::errors::{
+    JUNO_STORAGE_ERROR_DATA_MATCHER_NOT_SUPPORTED, JUNO_STORAGE_ERROR_INDEX_MATCHER_NOT_SUPPORTED,
+};
 use crate::http::types::HeaderField;
 use crate::strategies::StorageAssertionsStrategy;
 use crate::types::interface::AssetNoContent;
@@ -10,9 +13,10 @@ use junobuild_collections::constants::assets::COLLECTION_ASSET_KEY;
 use junobuild_collections::types::core::CollectionKey;
 use junobuild_collections::types::rules::Permission;
 use junobuild_shared::data::list::{filter_timestamps, matcher_regex};
+use junobuild_shared::ic::api::time;
 use junobuild_shared::types::core::Blob;
 use junobuild_shared::types::list::ListParams;
-use junobuild_shared::types::state::{AccessKeys, UserId};
+use junobuild_shared::types::state::{AccessKeys, Expirable, UserId};
 use regex::Regex;
 
 pub fn map_asset_no_content(asset: &Asset) -> (FullPath, AssetNoContent) {
@@ -33,12 +37,29 @@ pub fn filter_values<'a>(
     assets: &'a [(&'a FullPath, &'a Asset)],
     assertions: &impl StorageAssertionsStrategy,
 ) -> Result<Vec<(&'a FullPath, &'a Asset)>, String> {
+    if matcher
+        .as_ref()
+        .is_some_and(|matcher| matcher.index.is_some())
+    {
+        return Err(JUNO_STORAGE_ERROR_INDEX_MATCHER_NOT_SUPPORTED.to_string());
+    }
+
+    if matcher
+        .as_ref()
+        .is_some_and(|matcher| matcher.data.is_some())
+    {
+        return Err(JUNO_STORAGE_ERROR_DATA_MATCHER_NOT_SUPPORTED.to_string());
+    }
+
     let (regex_key, regex_description) = matcher_regex(matcher)?;
 
+    let now = time();
+
     let result = assets
         .iter()
         .filter_map(|(key, asset)| {
-            if filter_collection(collection.clone(), asset)
+            if !asset.is_expired(now)
+                && filter_collection(collection.clone(), asset)
                 && filter_full_path(&regex_key, asset)
                 && filter_description(&regex_description, asset)
                 && filter_owner(*owner, asset)
@@ -107,7 +107,9 @@ fn delete_asset(
     let asset = storage_state.delete_asset(&collection, full_path, &rule);
 
     if let Some(asset) = asset {
-        delete_certified_asset(&asset, certificate);
+        let config = storage_state.get_config();
+
+        delete_certified_asset(&asset, &config, certificate);
     }
 
     Ok(())
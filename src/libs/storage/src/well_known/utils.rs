@@ -2,10 +2,10 @@ use crate::constants::{WELL_KNOWN_CUSTOM_DOMAINS, WELL_KNOWN_II_ALTERNATIVE_ORIG
 use crate::types::store::{Asset, AssetKey};
 use crate::utils::{create_asset_with_content, map_content_type_headers};
 use crate::well_known::types::WellKnownAsset;
-use ic_cdk::api::time;
 use junobuild_collections::constants::assets::COLLECTION_ASSET_KEY;
 use junobuild_shared::data::version::next_version;
 use junobuild_shared::ic::api::id;
+use junobuild_shared::ic::api::time;
 use junobuild_shared::types::domain::CustomDomain;
 use junobuild_shared::types::state::Timestamp;
 
@@ -20,6 +20,7 @@ pub fn map_custom_domains_asset(
         collection: COLLECTION_ASSET_KEY.to_string(),
         owner: id(),
         description: None,
+        expires_at: None,
     };
 
     let headers = map_content_type_headers("application/octet-stream");
@@ -38,6 +39,7 @@ pub fn map_alternative_origins_asset(
         collection: COLLECTION_ASSET_KEY.to_string(),
         owner: id(),
         description: None,
+        expires_at: None,
     };
 
     let headers = map_content_type_headers("application/json");
@@ -3,7 +3,8 @@ use ic_cdk::call::Call;
 use junobuild_shared::ic::DecodeCandid;
 use junobuild_shared::mgmt::ic::update_canister_controllers;
 use junobuild_shared::segments::access_keys::{
-    assert_access_key_expiration, assert_controllers, filter_admin_access_keys, into_access_key_ids,
+    assert_access_key_expiration, assert_access_key_restrictions, assert_controllers,
+    filter_admin_access_keys, into_access_key_ids,
 };
 use junobuild_shared::types::interface::{DeleteControllersArgs, SetAccessKey, SetControllersArgs};
 use junobuild_shared::types::state::{AccessKeyId, AccessKeys};
@@ -17,6 +18,8 @@ pub async fn set_segment_controllers(
 
     assert_access_key_expiration(controller)?;
 
+    assert_access_key_restrictions(controller)?;
+
     let satellite_admin_controllers = set_controllers(segment_id, controllers, controller).await?;
 
     // We update the IC controllers because it is possible that an existing controller was updated.
@@ -13,6 +13,7 @@ pub fn defer_restart_monitoring() {
         OpenIdProvider::Google,
         OpenIdProvider::GitHubAuth,
         OpenIdProvider::GitHubActions,
+        OpenIdProvider::GitLabCi,
     ]
     .into_iter()
     .filter(is_scheduler_enabled)
@@ -32,6 +33,7 @@ async fn restart_monitoring() {
         OpenIdProvider::Google,
         OpenIdProvider::GitHubAuth,
         OpenIdProvider::GitHubActions,
+        OpenIdProvider::GitLabCi,
     ] {
         schedule_certificate_update(provider, None);
     }
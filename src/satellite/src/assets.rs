@@ -19,6 +19,7 @@ pub fn init_asset() -> Result<(), String> {
         collection,
         owner: id(),
         description: None,
+        expires_at: None,
     };
 
     let headers = vec![HeaderField(
@@ -0,0 +1 @@
+mod on_http_request;
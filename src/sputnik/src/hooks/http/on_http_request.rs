@@ -0,0 +1,28 @@
+use crate::hooks::js::runtime::http::on_http_request::OnHttpRequest;
+use crate::hooks::js::runtime::types::HttpJsHook;
+use crate::js::runtime::execute_sync_js;
+use crate::sdk::js::init_sdk;
+use crate::state::store::get_on_http_request_paths;
+use junobuild_satellite::{OnHttpRequestContext, OnHttpRequestResponse};
+use rquickjs::CatchResultExt;
+
+#[no_mangle]
+pub extern "Rust" fn juno_on_http_request(
+    context: OnHttpRequestContext,
+) -> Result<OnHttpRequestResponse, String> {
+    execute_sync_js(|ctx| {
+        init_sdk(ctx).map_err(|e| e.to_string())?;
+
+        OnHttpRequest
+            .execute(ctx, context.clone())
+            .catch(ctx)
+            .map_err(|e| e.to_string())
+    })
+    .map_err(|e| e.to_string())
+}
+
+#[no_mangle]
+pub extern "Rust" fn juno_on_http_request_paths() -> Option<Vec<String>> {
+    let paths = get_on_http_request_paths();
+    Some(paths)
+}
@@ -0,0 +1,17 @@
+use crate::js::types::candid::JsRawPrincipal;
+use crate::sdk::js::types::hooks::JsHookContext;
+use crate::sdk::js::types::http::JsHttpRequest;
+use junobuild_satellite::OnHttpRequestContext;
+use rquickjs::{Ctx, Error as JsError};
+
+impl<'js> JsHookContext<'js, JsHttpRequest<'js>> {
+    pub fn from_on_http_request(
+        ctx: &Ctx<'js>,
+        original: OnHttpRequestContext,
+    ) -> Result<Self, JsError> {
+        Ok(JsHookContext {
+            caller: JsRawPrincipal::from_principal(ctx, &original.caller)?,
+            data: JsHttpRequest::from_http_request(ctx, original.data)?,
+        })
+    }
+}
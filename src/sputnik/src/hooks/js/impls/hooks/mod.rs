@@ -1,4 +1,5 @@
 mod context;
 mod db;
+mod http;
 mod storage;
 mod utils;
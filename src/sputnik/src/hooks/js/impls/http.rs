@@ -0,0 +1,80 @@
+use crate::js::types::candid::JsUint8Array;
+use crate::sdk::js::types::http::{JsHttpRequest, JsHttpResponse};
+use crate::sdk::js::types::storage::{JsHeaderFieldRecord, JsHeaderFields};
+use junobuild_satellite::OnHttpRequestResponse;
+use junobuild_storage::http::types::{HeaderField, HttpRequest, StatusCode};
+use rquickjs::{Ctx, FromJs, IntoJs, Object, Result as JsResult, Value};
+
+impl<'js> JsHttpRequest<'js> {
+    pub fn from_http_request(ctx: &Ctx<'js>, request: HttpRequest) -> JsResult<Self> {
+        Ok(Self {
+            url: request.url,
+            method: request.method,
+            headers: JsHeaderFields(
+                request
+                    .headers
+                    .into_iter()
+                    .map(|HeaderField(key, value)| JsHeaderFieldRecord(key, value))
+                    .collect(),
+            ),
+            body: JsUint8Array::from_bytes(ctx, &request.body)?,
+            certificate_version: request.certificate_version,
+        })
+    }
+}
+
+impl<'js> JsHttpResponse<'js> {
+    pub fn to_response(&self) -> JsResult<OnHttpRequestResponse> {
+        Ok(OnHttpRequestResponse {
+            status_code: self.status_code,
+            headers: self.headers.to_header_fields(),
+            body: self.body.to_vec()?,
+        })
+    }
+}
+
+// ---------------------------------------------------------
+// IntoJs
+// ---------------------------------------------------------
+
+impl<'js> IntoJs<'js> for JsHttpRequest<'js> {
+    fn into_js(self, ctx: &Ctx<'js>) -> JsResult<Value<'js>> {
+        let obj = Object::new(ctx.clone())?;
+        obj.set("url", self.url)?;
+        obj.set("method", self.method)?;
+        obj.set("headers", self.headers.into_js(ctx)?)?;
+        obj.set("body", self.body)?;
+        obj.set("certificate_version", self.certificate_version)?;
+
+        Ok(obj.into_value())
+    }
+}
+
+// ---------------------------------------------------------
+// FromJs
+// ---------------------------------------------------------
+
+impl<'js> FromJs<'js> for JsHttpResponse<'js> {
+    fn from_js(ctx: &Ctx<'js>, value: Value<'js>) -> JsResult<Self> {
+        let obj = Object::from_value(value)?;
+
+        let status_code: StatusCode = obj.get("status_code")?;
+
+        // Headers and body are optional e.g. for a 204 No Content
+        let headers = match obj.get::<_, Option<Value>>("headers")? {
+            None => JsHeaderFields(Vec::new()),
+            Some(headers) => JsHeaderFields::from_js(ctx, headers)?,
+        };
+
+        let body = match obj.get::<_, Option<JsUint8Array>>("body")? {
+            None => JsUint8Array::from_bytes(ctx, &[])?,
+            Some(body) => body,
+        };
+
+        Ok(JsHttpResponse {
+            status_code,
+            headers,
+            body,
+        })
+    }
+}
@@ -4,16 +4,20 @@ use crate::sdk::js::types::db::JsDoc;
 use crate::sdk::js::types::hooks::JsKey;
 use crate::sdk::js::types::interface::JsAssetNoContent;
 use crate::sdk::js::types::list::{
+    JsDataFieldMatcher, JsDataMatcher, JsDataValueMatcher, JsIndexMatcher, JsIndexValueMatcher,
     JsListMatcher, JsListOrder, JsListOrderField, JsListPaginate, JsListParams, JsListResults,
     JsTimestampMatcher,
 };
 use junobuild_satellite::Doc;
 use junobuild_shared::types::list::{
-    ListMatcher, ListOrder, ListOrderField, ListPaginate, ListParams, ListResults, TimestampMatcher,
+    DataFieldMatcher, DataMatcher, DataValueMatcher, IndexMatcher, IndexValue, IndexValueMatcher,
+    ListMatcher, ListOrder, ListOrderField, ListPaginate, ListParams, ListResults,
+    TimestampMatcher,
 };
 use junobuild_storage::types::interface::AssetNoContent;
 use rquickjs::{
-    Array, BigInt, Ctx, Error as JsError, FromJs, IntoJs, Object, Result as JsResult, Value,
+    Array, BigInt, Ctx, Error as JsError, FromJs, IntoJs, Object, Result as JsResult, TypedArray,
+    Value,
 };
 
 impl<'js> JsListParams<'js> {
@@ -46,15 +50,69 @@ impl JsListMatcher {
                 .as_ref()
                 .map(|m| m.to_matcher())
                 .transpose()?,
+            index: self.index.as_ref().map(|m| m.to_matcher()),
+            data: self.data.as_ref().map(|m| m.to_matcher()),
         })
     }
 }
 
+impl JsIndexMatcher {
+    pub fn to_matcher(&self) -> IndexMatcher {
+        IndexMatcher {
+            field: self.field.clone(),
+            value: match &self.value {
+                JsIndexValueMatcher::Equal(value) => IndexValueMatcher::Equal(value.clone()),
+                JsIndexValueMatcher::GreaterThan(value) => {
+                    IndexValueMatcher::GreaterThan(value.clone())
+                }
+                JsIndexValueMatcher::LessThan(value) => IndexValueMatcher::LessThan(value.clone()),
+                JsIndexValueMatcher::Between(start, end) => {
+                    IndexValueMatcher::Between(start.clone(), end.clone())
+                }
+            },
+        }
+    }
+}
+
+impl JsDataMatcher {
+    pub fn to_matcher(&self) -> DataMatcher {
+        match self {
+            JsDataMatcher::Field(JsDataFieldMatcher { field, value }) => {
+                DataMatcher::Field(DataFieldMatcher {
+                    field: field.clone(),
+                    value: value.to_matcher(),
+                })
+            }
+            JsDataMatcher::And(matchers) => {
+                DataMatcher::And(matchers.iter().map(|m| m.to_matcher()).collect())
+            }
+            JsDataMatcher::Or(matchers) => {
+                DataMatcher::Or(matchers.iter().map(|m| m.to_matcher()).collect())
+            }
+            JsDataMatcher::Not(matcher) => DataMatcher::Not(Box::new(matcher.to_matcher())),
+        }
+    }
+}
+
+impl JsDataValueMatcher {
+    pub fn to_matcher(&self) -> DataValueMatcher {
+        match self {
+            JsDataValueMatcher::Equal(value) => DataValueMatcher::Equal(value.clone()),
+            JsDataValueMatcher::GreaterThan(value) => DataValueMatcher::GreaterThan(*value),
+            JsDataValueMatcher::LessThan(value) => DataValueMatcher::LessThan(*value),
+            JsDataValueMatcher::Between(start, end) => DataValueMatcher::Between(*start, *end),
+            JsDataValueMatcher::In(values) => DataValueMatcher::In(values.clone()),
+            JsDataValueMatcher::Exists => DataValueMatcher::Exists,
+        }
+    }
+}
+
 impl JsListPaginate {
     pub fn to_paginate(&self) -> JsResult<ListPaginate> {
         Ok(ListPaginate {
             start_after: self.start_after.clone(),
             limit: self.limit.as_ref().map(|b| b.to_usize()),
+            cursor: self.cursor.clone(),
         })
     }
 }
@@ -101,6 +159,7 @@ impl<'js> JsListResults<JsDoc<'js>> {
             items_page: results.items_page.map(JsUsize),
             matches_length: JsUsize(results.matches_length),
             matches_pages: results.matches_pages.map(JsUsize),
+            next_cursor: results.next_cursor.clone(),
         })
     }
 }
@@ -125,6 +184,7 @@ impl<'js> JsListResults<JsAssetNoContent<'js>> {
             items_page: results.items_page.map(JsUsize),
             matches_length: JsUsize(results.matches_length),
             matches_pages: results.matches_pages.map(JsUsize),
+            next_cursor: results.next_cursor.clone(),
         })
     }
 }
@@ -151,6 +211,12 @@ impl<'js> IntoJs<'js> for JsListResults<JsDoc<'js>> {
         obj.set("items_page", self.items_page)?;
         obj.set("matches_length", self.matches_length)?;
         obj.set("matches_pages", self.matches_pages)?;
+        obj.set(
+            "next_cursor",
+            self.next_cursor
+                .map(|cursor| TypedArray::<u8>::new(ctx.clone(), cursor))
+                .transpose()?,
+        )?;
 
         Ok(obj.into_value())
     }
@@ -174,6 +240,12 @@ impl<'js> IntoJs<'js> for JsListResults<JsAssetNoContent<'js>> {
         obj.set("items_page", self.items_page)?;
         obj.set("matches_length", self.matches_length)?;
         obj.set("matches_pages", self.matches_pages)?;
+        obj.set(
+            "next_cursor",
+            self.next_cursor
+                .map(|cursor| TypedArray::<u8>::new(ctx.clone(), cursor))
+                .transpose()?,
+        )?;
 
         Ok(obj.into_value())
     }
@@ -187,9 +259,20 @@ impl<'js> FromJs<'js> for JsListPaginate {
     fn from_js(_ctx: &Ctx<'js>, value: Value<'js>) -> JsResult<Self> {
         let obj = Object::from_value(value)?;
 
+        let cursor = obj
+            .get::<_, Option<TypedArray<u8>>>("cursor")?
+            .map(|cursor| {
+                cursor
+                    .as_bytes()
+                    .map(|bytes| bytes.to_vec())
+                    .ok_or_else(|| JsError::new_from_js("TypedArray", "ListCursor"))
+            })
+            .transpose()?;
+
         Ok(Self {
             start_after: obj.get("start_after").ok(),
             limit: obj.get("limit").ok(),
+            cursor,
         })
     }
 }
@@ -254,6 +337,148 @@ impl<'js> FromJs<'js> for JsTimestampMatcher {
     }
 }
 
+fn index_value_from_js(value: Value<'_>) -> JsResult<IndexValue> {
+    if value.is_null() {
+        return Ok(IndexValue::Null);
+    }
+
+    if let Some(value) = value.as_bool() {
+        return Ok(IndexValue::Bool(value));
+    }
+
+    if let Some(value) = value.as_number() {
+        return Ok(IndexValue::Number(value));
+    }
+
+    if let Some(value) = value.as_string() {
+        return Ok(IndexValue::Text(value.to_string()?));
+    }
+
+    Err(JsError::new_from_js("IndexValue", "Invalid index value"))
+}
+
+impl<'js> FromJs<'js> for JsIndexValueMatcher {
+    fn from_js(_ctx: &Ctx<'js>, value: Value<'js>) -> JsResult<Self> {
+        let obj = Object::from_value(value)?;
+
+        if let Ok(equal) = obj.get::<_, Value>("equal") {
+            if !equal.is_undefined() {
+                return Ok(Self::Equal(index_value_from_js(equal)?));
+            }
+        }
+
+        if let Ok(gt) = obj.get::<_, Value>("greater_than") {
+            if !gt.is_undefined() {
+                return Ok(Self::GreaterThan(index_value_from_js(gt)?));
+            }
+        }
+
+        if let Ok(lt) = obj.get::<_, Value>("less_than") {
+            if !lt.is_undefined() {
+                return Ok(Self::LessThan(index_value_from_js(lt)?));
+            }
+        }
+
+        if let Ok(vec) = obj.get::<_, Vec<Value>>("between") {
+            if vec.len() == 2 {
+                let from = index_value_from_js(vec[0].clone())?;
+                let to = index_value_from_js(vec[1].clone())?;
+                return Ok(Self::Between(from, to));
+            }
+        }
+
+        Err(JsError::new_from_js(
+            "JsIndexValueMatcher",
+            "Invalid matcher",
+        ))
+    }
+}
+
+impl<'js> FromJs<'js> for JsIndexMatcher {
+    fn from_js(ctx: &Ctx<'js>, value: Value<'js>) -> JsResult<Self> {
+        let obj = Object::from_value(value)?;
+
+        Ok(Self {
+            field: obj.get("field")?,
+            value: JsIndexValueMatcher::from_js(ctx, obj.get("value")?)?,
+        })
+    }
+}
+
+impl<'js> FromJs<'js> for JsDataValueMatcher {
+    fn from_js(_ctx: &Ctx<'js>, value: Value<'js>) -> JsResult<Self> {
+        let obj = Object::from_value(value)?;
+
+        if let Ok(equal) = obj.get::<_, Value>("equal") {
+            if !equal.is_undefined() {
+                return Ok(Self::Equal(index_value_from_js(equal)?));
+            }
+        }
+
+        if let Ok(gt) = obj.get::<_, f64>("greater_than") {
+            return Ok(Self::GreaterThan(gt));
+        }
+
+        if let Ok(lt) = obj.get::<_, f64>("less_than") {
+            return Ok(Self::LessThan(lt));
+        }
+
+        if let Ok(vec) = obj.get::<_, Vec<f64>>("between") {
+            if vec.len() == 2 {
+                return Ok(Self::Between(vec[0], vec[1]));
+            }
+        }
+
+        if let Ok(values) = obj.get::<_, Vec<Value>>("in") {
+            let values = values
+                .into_iter()
+                .map(index_value_from_js)
+                .collect::<JsResult<Vec<IndexValue>>>()?;
+            return Ok(Self::In(values));
+        }
+
+        if let Ok(true) = obj.get::<_, bool>("exists") {
+            return Ok(Self::Exists);
+        }
+
+        Err(JsError::new_from_js(
+            "JsDataValueMatcher",
+            "Invalid matcher",
+        ))
+    }
+}
+
+impl<'js> FromJs<'js> for JsDataMatcher {
+    fn from_js(ctx: &Ctx<'js>, value: Value<'js>) -> JsResult<Self> {
+        let obj = Object::from_value(value)?;
+
+        if let Ok(matchers) = obj.get::<_, Vec<Value>>("and") {
+            let matchers = matchers
+                .into_iter()
+                .map(|value| JsDataMatcher::from_js(ctx, value))
+                .collect::<JsResult<Vec<JsDataMatcher>>>()?;
+            return Ok(Self::And(matchers));
+        }
+
+        if let Ok(matchers) = obj.get::<_, Vec<Value>>("or") {
+            let matchers = matchers
+                .into_iter()
+                .map(|value| JsDataMatcher::from_js(ctx, value))
+                .collect::<JsResult<Vec<JsDataMatcher>>>()?;
+            return Ok(Self::Or(matchers));
+        }
+
+        if let Some(matcher) = obj.get::<_, Option<Value>>("not")? {
+            return Ok(Self::Not(Box::new(JsDataMatcher::from_js(ctx, matcher)?)));
+        }
+
+        Ok(Self::Field(JsDataFieldMatcher {
+            field: obj.get("field")?,
+            value: JsDataValueMatcher::from_js(ctx, obj.get("value")?)?,
+        }))
+    }
+}
+
 impl<'js> FromJs<'js> for JsListMatcher {
     fn from_js(ctx: &Ctx<'js>, value: Value<'js>) -> JsResult<Self> {
         let obj = Object::from_value(value)?;
@@ -268,11 +493,23 @@ impl<'js> FromJs<'js> for JsListMatcher {
             .map(|value| JsTimestampMatcher::from_js(ctx, value))
             .transpose()?;
 
+        let index = obj
+            .get::<_, Option<Value>>("index")?
+            .map(|value| JsIndexMatcher::from_js(ctx, value))
+            .transpose()?;
+
+        let data = obj
+            .get::<_, Option<Value>>("data")?
+            .map(|value| JsDataMatcher::from_js(ctx, value))
+            .transpose()?;
+
         Ok(Self {
             key: obj.get("key").ok(),
             description: obj.get("description").ok(),
             created_at,
             updated_at,
+            index,
+            data,
         })
     }
 }
@@ -1,5 +1,6 @@
 mod db;
 mod hooks;
+mod http;
 mod list;
 mod rules;
 mod shared;
@@ -3,11 +3,12 @@ use crate::js::utils::primitives::{
     from_bigint_js, from_optional_bigint_js, into_bigint_js, into_optional_bigint_js,
 };
 use crate::sdk::js::types::shared::{
-    JsAccessKey, JsAccessKeyKind, JsAccessKeyRecord, JsAccessKeyScope, JsAccessKeys,
-    JsMetadataRecord,
+    JsAccessKey, JsAccessKeyCollection, JsAccessKeyKind, JsAccessKeyRecord,
+    JsAccessKeyRestrictions, JsAccessKeyScope, JsAccessKeys, JsMetadataRecord,
 };
 use junobuild_shared::types::state::{
-    AccessKey, AccessKeyKind, AccessKeyScope, AccessKeys, Timestamp,
+    AccessKey, AccessKeyCollection, AccessKeyKind, AccessKeyRestrictions, AccessKeyScope,
+    AccessKeys, Timestamp,
 };
 use rquickjs::{
     Array, BigInt, Ctx, Error as JsError, FromJs, IntoJs, Object, Result as JsResult, Value,
@@ -33,6 +34,22 @@ impl<'js> JsAccessKey {
                 AccessKeyKind::Automation => JsAccessKeyKind::Automation,
                 AccessKeyKind::Emulator => JsAccessKeyKind::Emulator,
             }),
+            restrictions: access_key
+                .restrictions
+                .map(|restrictions| JsAccessKeyRestrictions {
+                    collections: restrictions.collections.map(|collections| {
+                        collections
+                            .into_iter()
+                            .map(|collection| match collection {
+                                AccessKeyCollection::Db(name) => JsAccessKeyCollection::Db(name),
+                                AccessKeyCollection::Storage(name) => {
+                                    JsAccessKeyCollection::Storage(name)
+                                }
+                            })
+                            .collect()
+                    }),
+                    read_only: restrictions.read_only,
+                }),
         })
     }
 
@@ -55,6 +72,25 @@ impl<'js> JsAccessKey {
                 JsAccessKeyKind::Automation => AccessKeyKind::Automation,
                 JsAccessKeyKind::Emulator => AccessKeyKind::Emulator,
             }),
+            restrictions: self
+                .restrictions
+                .as_ref()
+                .map(|restrictions| AccessKeyRestrictions {
+                    collections: restrictions.collections.as_ref().map(|collections| {
+                        collections
+                            .iter()
+                            .map(|collection| match collection {
+                                JsAccessKeyCollection::Db(name) => {
+                                    AccessKeyCollection::Db(name.clone())
+                                }
+                                JsAccessKeyCollection::Storage(name) => {
+                                    AccessKeyCollection::Storage(name.clone())
+                                }
+                            })
+                            .collect()
+                    }),
+                    read_only: restrictions.read_only,
+                }),
         })
     }
 }
@@ -128,6 +164,32 @@ impl<'js> IntoJs<'js> for JsAccessKey {
 
         obj.set("kind", self.kind)?;
 
+        obj.set("restrictions", self.restrictions)?;
+
+        Ok(obj.into_value())
+    }
+}
+
+impl<'js> IntoJs<'js> for JsAccessKeyCollection {
+    fn into_js(self, ctx: &Ctx<'js>) -> JsResult<Value<'js>> {
+        let obj = Object::new(ctx.clone())?;
+
+        match self {
+            JsAccessKeyCollection::Db(collection) => obj.set("db", collection)?,
+            JsAccessKeyCollection::Storage(collection) => obj.set("storage", collection)?,
+        }
+
+        Ok(obj.into_value())
+    }
+}
+
+impl<'js> IntoJs<'js> for JsAccessKeyRestrictions {
+    fn into_js(self, ctx: &Ctx<'js>) -> JsResult<Value<'js>> {
+        let obj = Object::new(ctx.clone())?;
+
+        obj.set("collections", self.collections)?;
+        obj.set("read_only", self.read_only)?;
+
         Ok(obj.into_value())
     }
 }
@@ -188,6 +250,39 @@ impl<'js> FromJs<'js> for JsAccessKeyKind {
     }
 }
 
+impl<'js> FromJs<'js> for JsAccessKeyCollection {
+    fn from_js(_ctx: &Ctx<'js>, value: Value<'js>) -> JsResult<Self> {
+        let obj = Object::from_value(value)?;
+
+        if let Some(collection) = obj.get::<_, Option<String>>("db")? {
+            return Ok(Self::Db(collection));
+        }
+
+        if let Some(collection) = obj.get::<_, Option<String>>("storage")? {
+            return Ok(Self::Storage(collection));
+        }
+
+        Err(JsError::new_from_js(
+            "JsAccessKeyCollection",
+            "AccessKeyCollection",
+        ))
+    }
+}
+
+impl<'js> FromJs<'js> for JsAccessKeyRestrictions {
+    fn from_js(_ctx: &Ctx<'js>, value: Value<'js>) -> JsResult<Self> {
+        let obj = Object::from_value(value)?;
+
+        let collections: Option<Vec<JsAccessKeyCollection>> = obj.get("collections")?;
+        let read_only: bool = obj.get("read_only")?;
+
+        Ok(Self {
+            collections,
+            read_only,
+        })
+    }
+}
+
 impl<'js> FromJs<'js> for JsMetadataRecord {
     fn from_js(_ctx: &Ctx<'js>, value: Value<'js>) -> JsResult<Self> {
         let arr = Array::from_value(value)?;
@@ -221,6 +316,11 @@ impl<'js> FromJs<'js> for JsAccessKey {
             .map(|value| JsAccessKeyKind::from_js(ctx, value))
             .transpose()?;
 
+        let restrictions = obj
+            .get::<_, Option<Value>>("restrictions")?
+            .map(|value| JsAccessKeyRestrictions::from_js(ctx, value))
+            .transpose()?;
+
         Ok(Self {
             metadata,
             created_at,
@@ -228,6 +328,7 @@ impl<'js> FromJs<'js> for JsAccessKey {
             expires_at,
             scope,
             kind,
+            restrictions,
         })
     }
 }
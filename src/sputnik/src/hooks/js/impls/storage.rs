@@ -1,6 +1,8 @@
 use crate::js::types::candid::JsRawPrincipal;
 use crate::js::types::primitives::JsU128Compat;
-use crate::js::utils::primitives::{from_bigint_js, into_bigint_js, into_optional_bigint_js};
+use crate::js::utils::primitives::{
+    from_bigint_js, from_optional_bigint_js, into_bigint_js, into_optional_bigint_js,
+};
 use crate::sdk::js::types::interface::{
     JsAssetEncodingNoContent, JsAssetEncodingNotContentRecord, JsAssetNoContent, JsCommitBatch,
 };
@@ -11,7 +13,9 @@ use crate::sdk::js::types::storage::{
 use junobuild_storage::http::types::HeaderField;
 use junobuild_storage::types::interface::{AssetEncodingNoContent, AssetNoContent, CommitBatch};
 use junobuild_storage::types::store::{Asset, AssetEncoding, AssetKey, Batch, BlobOrKey};
-use rquickjs::{Array, Ctx, Error as JsError, FromJs, IntoJs, Object, Result as JsResult, Value};
+use rquickjs::{
+    Array, BigInt, Ctx, Error as JsError, FromJs, IntoJs, Object, Result as JsResult, Value,
+};
 
 impl<'js> JsAssetKey<'js> {
     pub fn from_asset_key(ctx: &Ctx<'js>, key: AssetKey) -> JsResult<JsAssetKey<'js>> {
@@ -22,6 +26,7 @@ impl<'js> JsAssetKey<'js> {
             collection: key.collection,
             owner: JsRawPrincipal::from_principal(ctx, &key.owner)?,
             description: key.description,
+            expires_at: key.expires_at,
         })
     }
 
@@ -33,6 +38,7 @@ impl<'js> JsAssetKey<'js> {
             collection: self.collection.clone(),
             owner: self.owner.to_principal()?,
             description: self.description.clone(),
+            expires_at: self.expires_at,
         })
     }
 }
@@ -78,6 +84,7 @@ impl<'js> JsAssetEncoding<'js> {
             content_chunks,
             total_length,
             sha256,
+            generated: false,
         })
     }
 }
@@ -243,6 +250,7 @@ impl<'js> IntoJs<'js> for JsAssetKey<'js> {
         obj.set("collection", self.collection)?;
         obj.set("owner", self.owner)?;
         obj.set("description", self.description)?;
+        obj.set("expires_at", into_optional_bigint_js(ctx, self.expires_at)?)?;
         Ok(obj.into_value())
     }
 }
@@ -382,6 +390,7 @@ impl<'js> FromJs<'js> for JsAssetKey<'js> {
             collection: obj.get("collection")?,
             owner: obj.get("owner")?,
             description: obj.get("description").ok(),
+            expires_at: from_optional_bigint_js(obj.get::<_, Option<BigInt>>("expires_at")?)?,
         })
     }
 }
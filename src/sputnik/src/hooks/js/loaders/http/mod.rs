@@ -0,0 +1,10 @@
+mod on_http_request;
+
+use crate::hooks::js::loaders::http::on_http_request::init_on_http_request_loader;
+use rquickjs::{Ctx, Error as JsError};
+
+pub fn init_http_loaders(ctx: &Ctx) -> Result<(), JsError> {
+    init_on_http_request_loader(ctx)?;
+
+    Ok(())
+}
@@ -0,0 +1,20 @@
+use crate::state::store::set_on_http_request_paths;
+use rquickjs::{Ctx, Error as JsError, Result as JsResult};
+
+pub fn init_on_http_request_loader(ctx: &Ctx) -> Result<(), JsError> {
+    let global = ctx.globals();
+
+    global.set(
+        "__juno_satellite_on_http_request_loader",
+        js_on_http_request_loader,
+    )?;
+
+    Ok(())
+}
+
+#[rquickjs::function]
+fn on_http_request_loader<'js>(_ctx: Ctx<'js>, paths: Vec<String>) -> JsResult<()> {
+    set_on_http_request_paths(&paths);
+
+    Ok(())
+}
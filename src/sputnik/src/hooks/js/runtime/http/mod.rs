@@ -0,0 +1 @@
+pub mod on_http_request;
@@ -0,0 +1,26 @@
+use crate::hooks::js::runtime::runner::{execute_handler, make_paths_loader_code};
+use crate::hooks::js::runtime::types::{HttpJsHook, JsHook};
+use crate::sdk::js::types::hooks::JsHookContext;
+use junobuild_satellite::{OnHttpRequestContext, OnHttpRequestResponse};
+use rquickjs::{Ctx, Error as JsError};
+
+const HANDLER_FUNCTION: &str = "onHttpRequest";
+
+pub struct OnHttpRequest;
+
+impl JsHook for OnHttpRequest {
+    fn get_loader_code(&self) -> String {
+        make_paths_loader_code(HANDLER_FUNCTION, "__juno_satellite_on_http_request_loader")
+    }
+}
+
+impl HttpJsHook<OnHttpRequestContext> for OnHttpRequest {
+    fn execute<'js>(
+        &self,
+        ctx: &Ctx<'js>,
+        context: OnHttpRequestContext,
+    ) -> Result<OnHttpRequestResponse, JsError> {
+        let js_context = JsHookContext::from_on_http_request(ctx, context)?;
+        execute_handler(ctx, js_context, HANDLER_FUNCTION)?.to_response()
+    }
+}
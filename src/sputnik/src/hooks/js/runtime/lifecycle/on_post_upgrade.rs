@@ -5,6 +5,7 @@ use crate::hooks::js::runtime::db::on_delete_filtered_docs::OnDeleteFilteredDocs
 use crate::hooks::js::runtime::db::on_delete_many_docs::OnDeleteManyDocs;
 use crate::hooks::js::runtime::db::on_set_doc::OnSetDoc;
 use crate::hooks::js::runtime::db::on_set_many_docs::OnSetManyDocs;
+use crate::hooks::js::runtime::http::on_http_request::OnHttpRequest;
 use crate::hooks::js::runtime::storage::assert_delete_asset::AssertDeleteAsset;
 use crate::hooks::js::runtime::storage::assert_upload_asset::AssertUploadAsset;
 use crate::hooks::js::runtime::storage::on_delete_asset::OnDeleteAsset;
@@ -35,6 +36,7 @@ fn evaluate_loaders<'js>(ctx: &Ctx<'js>) -> Result<(), JsError> {
         AssertDeleteDoc.get_loader_code(),
         AssertUploadAsset.get_loader_code(),
         AssertDeleteAsset.get_loader_code(),
+        OnHttpRequest.get_loader_code(),
     ];
 
     let loaders_code = loaders.join("\n");
@@ -1,4 +1,5 @@
 pub mod db;
+pub mod http;
 pub mod lifecycle;
 mod runner;
 pub mod storage;
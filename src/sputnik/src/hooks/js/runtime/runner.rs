@@ -1,6 +1,7 @@
 use crate::js::constants::{DEV_MODULE_NAME, HOOKS_MODULE_NAME};
 use crate::js::module::engine::{evaluate_async_module, evaluate_module};
 use crate::sdk::js::types::hooks::JsHookContext;
+use crate::sdk::js::types::http::JsHttpResponse;
 use rquickjs::IntoJs;
 use rquickjs::{Ctx, Error as JsError};
 
@@ -16,6 +17,18 @@ pub fn make_loader_code(assertion: &str, loader: &str) -> String {
     )
 }
 
+pub fn make_paths_loader_code(handler: &str, loader: &str) -> String {
+    format!(
+        r#"const {{ {handler} }} = await import("{DEV_MODULE_NAME}");
+
+            if (typeof {handler} !== 'undefined') {{
+                const config = typeof {handler} === 'function' ? {handler}({{}}) : {handler};
+                {loader}(config.paths);
+            }}
+            "#
+    )
+}
+
 pub fn execute_assertion<'js>(
     ctx: &Ctx<'js>,
     js_context: JsHookContext<'js, impl IntoJs<'js>>,
@@ -59,3 +72,27 @@ pub async fn execute_hook<'js>(
 
     evaluate_async_module(ctx, HOOKS_MODULE_NAME, &code).await
 }
+
+pub fn execute_handler<'js>(
+    ctx: &Ctx<'js>,
+    js_context: JsHookContext<'js, impl IntoJs<'js>>,
+    handler: &str,
+) -> Result<JsHttpResponse<'js>, JsError> {
+    let js_obj = js_context.into_js(ctx)?;
+
+    ctx.globals().set("jsContext", js_obj)?;
+
+    let code = format!(
+        r#"const {{ {handler} }} = await import("{DEV_MODULE_NAME}");
+
+            if (typeof {handler} !== 'undefined') {{
+                const config = typeof {handler} === 'function' ? {handler}({{}}) : {handler};
+                globalThis.jsHttpResponse = config.handle(jsContext);
+            }}
+            "#,
+    );
+
+    evaluate_module(ctx, HOOKS_MODULE_NAME, &code)?;
+
+    ctx.globals().get("jsHttpResponse")
+}
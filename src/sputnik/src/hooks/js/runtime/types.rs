@@ -1,3 +1,4 @@
+use junobuild_satellite::OnHttpRequestResponse;
 use rquickjs::{Ctx, Error as JsError};
 
 pub trait JsHook {
@@ -8,6 +9,10 @@ pub trait AssertJsHook<T> {
     fn execute<'js>(&self, ctx: &Ctx<'js>, context: T) -> Result<(), JsError>;
 }
 
+pub trait HttpJsHook<T> {
+    fn execute<'js>(&self, ctx: &Ctx<'js>, context: T) -> Result<OnHttpRequestResponse, JsError>;
+}
+
 pub trait OnJsHook<T> {
     async fn execute<'js>(&self, ctx: &Ctx<'js>, context: T) -> Result<(), JsError>;
 }
@@ -1,4 +1,5 @@
 mod db;
+mod http;
 mod js;
 mod lifecycle;
 mod storage;
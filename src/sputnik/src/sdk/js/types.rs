@@ -23,6 +23,18 @@ pub mod shared {
         Emulator,
     }
 
+    #[derive(Clone)]
+    pub enum JsAccessKeyCollection {
+        Db(String),
+        Storage(String),
+    }
+
+    #[derive(Clone)]
+    pub struct JsAccessKeyRestrictions {
+        pub collections: Option<Vec<JsAccessKeyCollection>>,
+        pub read_only: bool,
+    }
+
     #[derive(Clone)]
     pub struct JsMetadataRecord(pub String, pub String);
 
@@ -34,6 +46,7 @@ pub mod shared {
         pub expires_at: Option<JsTimestamp>,
         pub scope: JsAccessKeyScope,
         pub kind: Option<JsAccessKeyKind>,
+        pub restrictions: Option<JsAccessKeyRestrictions>,
     }
 
     #[derive(Clone)]
@@ -114,6 +127,7 @@ pub mod db {
         pub created_at: JsTimestamp,
         pub updated_at: JsTimestamp,
         pub version: Option<JsVersion>,
+        pub expires_at: Option<JsTimestamp>,
     }
 }
 
@@ -158,6 +172,7 @@ pub mod storage {
         pub collection: String,
         pub owner: JsUserId<'js>,
         pub description: Option<String>,
+        pub expires_at: Option<JsTimestamp>,
     }
 
     #[derive(Clone)]
@@ -181,6 +196,28 @@ pub mod storage {
     }
 }
 
+pub mod http {
+    use crate::js::types::candid::JsUint8Array;
+    use crate::sdk::js::types::storage::JsHeaderFields;
+    use junobuild_storage::http::types::StatusCode;
+
+    #[derive(Clone)]
+    pub struct JsHttpRequest<'js> {
+        pub url: String,
+        pub method: String,
+        pub headers: JsHeaderFields,
+        pub body: JsUint8Array<'js>,
+        pub certificate_version: Option<u16>,
+    }
+
+    #[derive(Clone)]
+    pub struct JsHttpResponse<'js> {
+        pub status_code: StatusCode,
+        pub headers: JsHeaderFields,
+        pub body: JsUint8Array<'js>,
+    }
+}
+
 pub mod interface {
     use crate::js::types::primitives::JsU128Compat;
     use crate::sdk::js::types::hooks::JsRawData;
@@ -193,6 +230,7 @@ pub mod interface {
         pub data: JsRawData<'js>,
         pub description: Option<String>,
         pub version: Option<JsVersion>,
+        pub expires_at: Option<JsTimestamp>,
     }
 
     #[derive(Clone)]
@@ -235,11 +273,13 @@ pub mod list {
     use crate::js::types::primitives::JsUsize;
     use crate::sdk::js::types::hooks::JsKey;
     use crate::sdk::js::types::shared::{JsTimestamp, JsUserId};
+    use junobuild_shared::types::list::IndexValue;
 
     #[derive(Clone)]
     pub struct JsListPaginate {
         pub start_after: Option<JsKey>,
         pub limit: Option<JsUsize>,
+        pub cursor: Option<Vec<u8>>,
     }
 
     #[derive(Clone)]
@@ -263,12 +303,52 @@ pub mod list {
         Between(JsTimestamp, JsTimestamp),
     }
 
+    #[derive(Clone)]
+    pub enum JsIndexValueMatcher {
+        Equal(IndexValue),
+        GreaterThan(IndexValue),
+        LessThan(IndexValue),
+        Between(IndexValue, IndexValue),
+    }
+
+    #[derive(Clone)]
+    pub struct JsIndexMatcher {
+        pub field: String,
+        pub value: JsIndexValueMatcher,
+    }
+
+    #[derive(Clone)]
+    pub enum JsDataValueMatcher {
+        Equal(IndexValue),
+        GreaterThan(f64),
+        LessThan(f64),
+        Between(f64, f64),
+        In(Vec<IndexValue>),
+        Exists,
+    }
+
+    #[derive(Clone)]
+    pub struct JsDataFieldMatcher {
+        pub field: String,
+        pub value: JsDataValueMatcher,
+    }
+
+    #[derive(Clone)]
+    pub enum JsDataMatcher {
+        Field(JsDataFieldMatcher),
+        And(Vec<JsDataMatcher>),
+        Or(Vec<JsDataMatcher>),
+        Not(Box<JsDataMatcher>),
+    }
+
     #[derive(Clone)]
     pub struct JsListMatcher {
         pub key: Option<JsKey>,
         pub description: Option<String>,
         pub created_at: Option<JsTimestampMatcher>,
         pub updated_at: Option<JsTimestampMatcher>,
+        pub index: Option<JsIndexMatcher>,
+        pub data: Option<JsDataMatcher>,
     }
 
     #[derive(Clone)]
@@ -286,5 +366,6 @@ pub mod list {
         pub items_page: Option<JsUsize>,
         pub matches_length: JsUsize,
         pub matches_pages: Option<JsUsize>,
+        pub next_cursor: Option<Vec<u8>>,
     }
 }
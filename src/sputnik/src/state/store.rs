@@ -136,3 +136,11 @@ pub fn set_assert_delete_asset_collections(collections: &[CollectionKey]) {
 pub fn get_assert_delete_asset_collections() -> Vec<CollectionKey> {
     read_state(|state| state.runtime.hooks.assert_delete_asset_collections.clone())
 }
+
+pub fn set_on_http_request_paths(paths: &[String]) {
+    mutate_state(|state| state.runtime.hooks.on_http_request_paths = paths.to_owned());
+}
+
+pub fn get_on_http_request_paths() -> Vec<String> {
+    read_state(|state| state.runtime.hooks.on_http_request_paths.clone())
+}
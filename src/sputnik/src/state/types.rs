@@ -29,5 +29,7 @@ pub mod state {
 
         pub assert_upload_asset_collections: Vec<CollectionKey>,
         pub assert_delete_asset_collections: Vec<CollectionKey>,
+
+        pub on_http_request_paths: Vec<String>,
     }
 }